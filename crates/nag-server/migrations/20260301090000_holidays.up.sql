@@ -0,0 +1,7 @@
+-- Holidays: quiet days on which chores never become overdue.
+-- Dues falling on a holiday are shifted to the next non-holiday day.
+CREATE TABLE holidays (
+    date TEXT PRIMARY KEY NOT NULL,
+    name TEXT,
+    created_at TEXT NOT NULL
+);
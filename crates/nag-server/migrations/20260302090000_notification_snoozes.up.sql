@@ -0,0 +1,7 @@
+-- Snoozes postpone due notifications for a chore until `snoozed_until`.
+CREATE TABLE notification_snoozes (
+    chore_id BLOB PRIMARY KEY NOT NULL REFERENCES chores(id) ON DELETE CASCADE CHECK(length(chore_id) = 16),
+    snoozed_until TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
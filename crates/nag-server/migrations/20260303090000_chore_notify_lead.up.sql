@@ -0,0 +1,2 @@
+-- Per-chore lead time: send the due notification this many minutes early.
+ALTER TABLE chores ADD COLUMN notify_lead_minutes INTEGER CHECK(notify_lead_minutes IS NULL OR notify_lead_minutes >= 0);
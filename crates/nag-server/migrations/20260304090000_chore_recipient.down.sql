@@ -0,0 +1 @@
+ALTER TABLE chores DROP COLUMN recipient;
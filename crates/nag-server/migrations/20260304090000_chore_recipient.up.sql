@@ -0,0 +1,2 @@
+-- Named notification recipient (resolved to a chat by the channel config).
+ALTER TABLE chores ADD COLUMN recipient TEXT;
@@ -0,0 +1,23 @@
+-- Revert: remove 'days_of_week' schedule type
+-- Delete any chores using the days_of_week schedule first
+
+DELETE FROM chores WHERE schedule_type = 'days_of_week';
+
+PRAGMA writable_schema = ON;
+
+UPDATE sqlite_master
+SET sql = replace(
+    replace(
+        sql,
+        '''once_in_a_while'', ''days_of_week''))',
+        '''once_in_a_while''))'
+    ),
+    ' OR
+        (schedule_type = ''days_of_week'' AND days_of_week IS NOT NULL)',
+    ''
+)
+WHERE type = 'table' AND name = 'chores';
+
+PRAGMA writable_schema = RESET;
+
+ALTER TABLE chores DROP COLUMN days_of_week;
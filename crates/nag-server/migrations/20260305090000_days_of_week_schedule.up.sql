@@ -0,0 +1,26 @@
+-- Add 'days_of_week' schedule type (e.g. every Tuesday and Friday).
+-- Selected days are stored as a comma-separated list of 0-6 (0 = Sunday).
+--
+-- Migrations run inside a transaction, where `PRAGMA foreign_keys = OFF` has
+-- no effect, so recreating `chores` would cascade-delete its completions,
+-- tags and notifications. The CHECK constraints only get wider, so rewrite
+-- them in place instead (https://www.sqlite.org/lang_altertable.html#otheralter).
+
+ALTER TABLE chores ADD COLUMN days_of_week TEXT;
+
+PRAGMA writable_schema = ON;
+
+UPDATE sqlite_master
+SET sql = replace(
+    replace(
+        sql,
+        '''once_in_a_while''))',
+        '''once_in_a_while'', ''days_of_week''))'
+    ),
+    '(schedule_type = ''once_in_a_while'')',
+    '(schedule_type = ''once_in_a_while'') OR
+        (schedule_type = ''days_of_week'' AND days_of_week IS NOT NULL)'
+)
+WHERE type = 'table' AND name = 'chores';
+
+PRAGMA writable_schema = RESET;
@@ -0,0 +1 @@
+ALTER TABLE chores DROP COLUMN auto_complete_with;
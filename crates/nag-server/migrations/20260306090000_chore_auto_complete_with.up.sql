@@ -0,0 +1,2 @@
+-- Completing the referenced chore also completes this one.
+ALTER TABLE chores ADD COLUMN auto_complete_with BLOB REFERENCES chores(id) ON DELETE SET NULL;
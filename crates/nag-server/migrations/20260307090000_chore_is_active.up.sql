@@ -0,0 +1,2 @@
+-- Paused chores keep their history but are not due and do not notify.
+ALTER TABLE chores ADD COLUMN is_active BOOLEAN NOT NULL DEFAULT 1;
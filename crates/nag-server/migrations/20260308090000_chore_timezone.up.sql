@@ -0,0 +1,2 @@
+-- IANA timezone cron schedules are evaluated in; NULL means UTC.
+ALTER TABLE chores ADD COLUMN timezone TEXT;
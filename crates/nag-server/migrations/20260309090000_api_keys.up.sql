@@ -0,0 +1,14 @@
+-- API keys let machine clients authenticate without the OIDC redirect flow.
+-- Only the SHA-256 hash of a key is stored; `prefix` helps users tell keys apart.
+CREATE TABLE api_keys (
+    id BLOB PRIMARY KEY NOT NULL CHECK(length(id) = 16),
+    user_id BLOB NOT NULL REFERENCES users(id) ON DELETE CASCADE CHECK(length(user_id) = 16),
+    name TEXT NOT NULL,
+    prefix TEXT NOT NULL,
+    key_hash TEXT NOT NULL UNIQUE,
+    created_at TEXT NOT NULL,
+    last_used_at TEXT,
+    revoked_at TEXT
+);
+
+CREATE INDEX idx_api_keys_user_id ON api_keys(user_id);
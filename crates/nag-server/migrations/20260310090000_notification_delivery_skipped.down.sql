@@ -0,0 +1,15 @@
+-- Revert 'skipped' delivery status (skipped deliveries become pending again).
+
+UPDATE notification_deliveries SET status = 'pending' WHERE status = 'skipped';
+
+PRAGMA writable_schema = ON;
+
+UPDATE sqlite_master
+SET sql = replace(
+    sql,
+    'CHECK(status IN (''pending'', ''failed'', ''delivered'', ''skipped''))',
+    'CHECK(status IN (''pending'', ''failed'', ''delivered''))'
+)
+WHERE type = 'table' AND name = 'notification_deliveries';
+
+PRAGMA writable_schema = RESET;
@@ -0,0 +1,17 @@
+-- Add 'skipped' delivery status: no sender was registered for the channel.
+-- Skipped deliveries are retried without counting towards the attempt limit.
+--
+-- The CHECK constraint only gets wider, so rewrite it in place like
+-- 20260305090000_days_of_week_schedule does.
+
+PRAGMA writable_schema = ON;
+
+UPDATE sqlite_master
+SET sql = replace(
+    sql,
+    'CHECK(status IN (''pending'', ''failed'', ''delivered''))',
+    'CHECK(status IN (''pending'', ''failed'', ''delivered'', ''skipped''))'
+)
+WHERE type = 'table' AND name = 'notification_deliveries';
+
+PRAGMA writable_schema = RESET;
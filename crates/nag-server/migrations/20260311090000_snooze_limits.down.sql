@@ -0,0 +1,2 @@
+ALTER TABLE notification_snoozes DROP COLUMN snooze_count;
+ALTER TABLE chores DROP COLUMN max_snoozes;
@@ -0,0 +1,4 @@
+-- Optional cap on how often a chore's current occurrence may be snoozed
+-- (NULL means unlimited). The count is reset whenever the chore is completed.
+ALTER TABLE chores ADD COLUMN max_snoozes INTEGER CHECK(max_snoozes IS NULL OR max_snoozes >= 0);
+ALTER TABLE notification_snoozes ADD COLUMN snooze_count INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,2 @@
+DROP INDEX idx_chores_user_id;
+ALTER TABLE chores DROP COLUMN user_id;
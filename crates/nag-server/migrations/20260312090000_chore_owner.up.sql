@@ -0,0 +1,5 @@
+-- Owning user of a chore. Chores created while auth was disabled stay
+-- unowned (NULL) and remain visible to every user.
+ALTER TABLE chores ADD COLUMN user_id BLOB REFERENCES users(id) ON DELETE SET NULL CHECK(user_id IS NULL OR length(user_id) = 16);
+
+CREATE INDEX idx_chores_user_id ON chores(user_id);
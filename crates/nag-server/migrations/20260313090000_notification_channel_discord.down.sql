@@ -0,0 +1,15 @@
+-- Remove the 'discord' channel, dropping its deliveries.
+
+DELETE FROM notification_deliveries WHERE channel = 'discord';
+
+PRAGMA writable_schema = ON;
+
+UPDATE sqlite_master
+SET sql = replace(
+    sql,
+    'CHECK(channel IN (''telegram'', ''discord''))',
+    'CHECK(channel IN (''telegram''))'
+)
+WHERE type = 'table' AND name = 'notification_deliveries';
+
+PRAGMA writable_schema = RESET;
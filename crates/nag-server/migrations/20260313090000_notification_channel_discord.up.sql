@@ -0,0 +1,16 @@
+-- Add 'discord' notification channel (webhook deliveries).
+--
+-- The CHECK constraint only gets wider, so rewrite it in place like
+-- 20260305090000_days_of_week_schedule does.
+
+PRAGMA writable_schema = ON;
+
+UPDATE sqlite_master
+SET sql = replace(
+    sql,
+    'CHECK(channel IN (''telegram''))',
+    'CHECK(channel IN (''telegram'', ''discord''))'
+)
+WHERE type = 'table' AND name = 'notification_deliveries';
+
+PRAGMA writable_schema = RESET;
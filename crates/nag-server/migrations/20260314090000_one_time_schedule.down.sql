@@ -0,0 +1,23 @@
+-- Revert: remove 'one_time' schedule type
+-- Delete any chores using the one_time schedule first
+
+DELETE FROM chores WHERE schedule_type = 'one_time';
+
+PRAGMA writable_schema = ON;
+
+UPDATE sqlite_master
+SET sql = replace(
+    replace(
+        sql,
+        '''once_in_a_while'', ''days_of_week'', ''one_time''))',
+        '''once_in_a_while'', ''days_of_week''))'
+    ),
+    ' OR
+        (schedule_type = ''one_time'' AND due_at IS NOT NULL)',
+    ''
+)
+WHERE type = 'table' AND name = 'chores';
+
+PRAGMA writable_schema = RESET;
+
+ALTER TABLE chores DROP COLUMN due_at;
@@ -0,0 +1,25 @@
+-- Add 'one_time' schedule type: a single occurrence due at `due_at`.
+--
+-- Migrations run inside a transaction, where `PRAGMA foreign_keys = OFF` has
+-- no effect, so recreating `chores` would cascade-delete its completions,
+-- tags and notifications. The CHECK constraints only get wider, so rewrite
+-- them in place instead (https://www.sqlite.org/lang_altertable.html#otheralter).
+
+ALTER TABLE chores ADD COLUMN due_at TEXT;
+
+PRAGMA writable_schema = ON;
+
+UPDATE sqlite_master
+SET sql = replace(
+    replace(
+        sql,
+        '''once_in_a_while'', ''days_of_week''))',
+        '''once_in_a_while'', ''days_of_week'', ''one_time''))'
+    ),
+    '(schedule_type = ''days_of_week'' AND days_of_week IS NOT NULL)',
+    '(schedule_type = ''days_of_week'' AND days_of_week IS NOT NULL) OR
+        (schedule_type = ''one_time'' AND due_at IS NOT NULL)'
+)
+WHERE type = 'table' AND name = 'chores';
+
+PRAGMA writable_schema = RESET;
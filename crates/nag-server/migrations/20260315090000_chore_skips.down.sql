@@ -0,0 +1 @@
+DROP TABLE chore_skips;
@@ -0,0 +1,10 @@
+-- Skipped occurrences. `skipped_at` is the due time of the occurrence that
+-- was skipped; the next one is computed from there, as after a completion.
+CREATE TABLE chore_skips (
+    id BLOB PRIMARY KEY NOT NULL CHECK(length(id) = 16),
+    chore_id BLOB NOT NULL REFERENCES chores(id) ON DELETE CASCADE CHECK(length(chore_id) = 16),
+    skipped_at TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_chore_skips_chore_id ON chore_skips(chore_id);
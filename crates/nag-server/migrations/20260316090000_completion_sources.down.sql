@@ -0,0 +1,2 @@
+ALTER TABLE chores DROP COLUMN allowed_completion_sources;
+ALTER TABLE completions DROP COLUMN source;
@@ -0,0 +1,6 @@
+-- Where a completion was recorded from
+ALTER TABLE completions ADD COLUMN source TEXT NOT NULL DEFAULT 'api'
+    CHECK (source IN ('api', 'telegram'));
+
+-- Comma-separated sources allowed to complete the chore (any when NULL)
+ALTER TABLE chores ADD COLUMN allowed_completion_sources TEXT;
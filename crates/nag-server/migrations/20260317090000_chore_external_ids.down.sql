@@ -0,0 +1,2 @@
+DROP INDEX idx_chores_external_id;
+ALTER TABLE chores DROP COLUMN external_id;
@@ -0,0 +1,4 @@
+-- Identifier assigned by an external system (e.g. a sync tool)
+ALTER TABLE chores ADD COLUMN external_id TEXT;
+
+CREATE UNIQUE INDEX idx_chores_external_id ON chores(external_id);
@@ -0,0 +1 @@
+ALTER TABLE notification_deliveries DROP COLUMN next_attempt_at;
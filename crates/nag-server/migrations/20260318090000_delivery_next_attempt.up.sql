@@ -0,0 +1,2 @@
+-- Earliest time a failed or skipped delivery may be retried (immediately when NULL)
+ALTER TABLE notification_deliveries ADD COLUMN next_attempt_at TEXT;
@@ -0,0 +1,23 @@
+-- Revert: remove the 'email' completion source
+-- Keep email completions, recorded as API completions
+
+UPDATE completions SET source = 'api' WHERE source = 'email';
+
+UPDATE chores
+SET allowed_completion_sources = nullif(
+    trim(replace(',' || allowed_completion_sources || ',', ',email,', ','), ','),
+    ''
+)
+WHERE instr(',' || allowed_completion_sources || ',', ',email,') > 0;
+
+PRAGMA writable_schema = ON;
+
+UPDATE sqlite_master
+SET sql = replace(
+    sql,
+    'CHECK (source IN (''api'', ''telegram'', ''email''))',
+    'CHECK (source IN (''api'', ''telegram''))'
+)
+WHERE type = 'table' AND name = 'completions';
+
+PRAGMA writable_schema = RESET;
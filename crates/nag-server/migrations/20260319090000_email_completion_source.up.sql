@@ -0,0 +1,16 @@
+-- Allow 'email' as a completion source (replies to email reminders).
+--
+-- The CHECK constraint only gets wider, so rewrite it in place like
+-- 20260314090000_one_time_schedule does.
+
+PRAGMA writable_schema = ON;
+
+UPDATE sqlite_master
+SET sql = replace(
+    sql,
+    'CHECK (source IN (''api'', ''telegram''))',
+    'CHECK (source IN (''api'', ''telegram'', ''email''))'
+)
+WHERE type = 'table' AND name = 'completions';
+
+PRAGMA writable_schema = RESET;
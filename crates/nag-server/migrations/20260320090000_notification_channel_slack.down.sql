@@ -0,0 +1,15 @@
+-- Revert: remove the 'slack' channel, dropping its deliveries
+
+DELETE FROM notification_deliveries WHERE channel = 'slack';
+
+PRAGMA writable_schema = ON;
+
+UPDATE sqlite_master
+SET sql = replace(
+    sql,
+    'CHECK(channel IN (''telegram'', ''discord'', ''slack''))',
+    'CHECK(channel IN (''telegram'', ''discord''))'
+)
+WHERE type = 'table' AND name = 'notification_deliveries';
+
+PRAGMA writable_schema = RESET;
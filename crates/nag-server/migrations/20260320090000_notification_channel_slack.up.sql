@@ -0,0 +1,16 @@
+-- Add 'slack' notification channel (incoming webhook deliveries).
+--
+-- The CHECK constraint only gets wider, so rewrite it in place like
+-- 20260314090000_one_time_schedule does.
+
+PRAGMA writable_schema = ON;
+
+UPDATE sqlite_master
+SET sql = replace(
+    sql,
+    'CHECK(channel IN (''telegram'', ''discord''))',
+    'CHECK(channel IN (''telegram'', ''discord'', ''slack''))'
+)
+WHERE type = 'table' AND name = 'notification_deliveries';
+
+PRAGMA writable_schema = RESET;
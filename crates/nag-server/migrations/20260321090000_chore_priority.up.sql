@@ -0,0 +1,3 @@
+-- Priority of a chore, used to order chores that are due at the same time
+ALTER TABLE chores ADD COLUMN priority TEXT NOT NULL DEFAULT 'normal'
+    CHECK (priority IN ('low', 'normal', 'high'));
@@ -0,0 +1,4 @@
+-- Whether a cron/interval chore's next occurrence follows its last completion
+-- or stays on the schedule it was created with
+ALTER TABLE chores ADD COLUMN anchor_mode TEXT NOT NULL DEFAULT 'from_completion'
+    CHECK (anchor_mode IN ('from_completion', 'from_schedule'));
@@ -0,0 +1,14 @@
+-- Changes to chores, with the chore's schedule before and after as JSON.
+-- Entries outlive the chore, so deletions stay on record.
+CREATE TABLE audit_log (
+    id BLOB PRIMARY KEY NOT NULL CHECK(length(id) = 16),
+    chore_id BLOB NOT NULL CHECK(length(chore_id) = 16),
+    action TEXT NOT NULL CHECK (action IN ('create', 'update', 'delete')),
+    -- Acting user; NULL when auth is disabled
+    user_id BLOB REFERENCES users(id) ON DELETE SET NULL CHECK(user_id IS NULL OR length(user_id) = 16),
+    schedule_before TEXT,
+    schedule_after TEXT,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_audit_log_chore_id ON audit_log(chore_id, created_at);
@@ -0,0 +1,3 @@
+-- Minutes a chore is expected to take, for planning a day's chores
+ALTER TABLE chores ADD COLUMN estimated_minutes INTEGER
+    CHECK (estimated_minutes IS NULL OR estimated_minutes > 0);
@@ -0,0 +1,2 @@
+DELETE FROM tags WHERE deleted_at IS NOT NULL;
+ALTER TABLE tags DROP COLUMN deleted_at;
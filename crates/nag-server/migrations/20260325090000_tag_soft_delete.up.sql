@@ -0,0 +1,2 @@
+-- Deleted tags are hidden but keep their chore associations until purged
+ALTER TABLE tags ADD COLUMN deleted_at TEXT;
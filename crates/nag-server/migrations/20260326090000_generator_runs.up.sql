@@ -0,0 +1,6 @@
+-- When each background generator last completed a run, so that a run can
+-- cover everything that fell due since the previous one
+CREATE TABLE generator_runs (
+    name TEXT PRIMARY KEY NOT NULL,
+    last_run_at TEXT NOT NULL
+);
@@ -0,0 +1,2 @@
+DELETE FROM chores WHERE deleted_at IS NOT NULL;
+ALTER TABLE chores DROP COLUMN deleted_at;
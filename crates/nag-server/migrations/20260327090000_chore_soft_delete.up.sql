@@ -0,0 +1,2 @@
+-- Deleted chores go to the trash with their completions until purged
+ALTER TABLE chores ADD COLUMN deleted_at TEXT;
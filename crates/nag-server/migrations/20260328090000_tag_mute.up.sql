@@ -0,0 +1,2 @@
+-- Muted tags silence due notifications for every chore carrying them
+ALTER TABLE tags ADD COLUMN muted BOOLEAN NOT NULL DEFAULT 0;
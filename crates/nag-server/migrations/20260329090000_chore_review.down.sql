@@ -0,0 +1,18 @@
+-- Revert: remove review prompts, dropping their notification events
+
+DELETE FROM notification_events WHERE event_type = 'review';
+
+PRAGMA writable_schema = ON;
+
+UPDATE sqlite_master
+SET sql = replace(
+    sql,
+    'CHECK(event_type IN (''due'', ''review''))',
+    'CHECK(event_type IN (''due''))'
+)
+WHERE type = 'table' AND name = 'notification_events';
+
+PRAGMA writable_schema = RESET;
+
+ALTER TABLE chores DROP COLUMN last_reviewed_at;
+ALTER TABLE chores DROP COLUMN review_interval_days;
@@ -0,0 +1,20 @@
+-- Periodic prompts to review whether a chore is still needed, raised as
+-- 'review' notification events.
+--
+-- The CHECK constraint only gets wider, so rewrite it in place like
+-- 20260314090000_one_time_schedule does.
+
+ALTER TABLE chores ADD COLUMN review_interval_days INTEGER;
+ALTER TABLE chores ADD COLUMN last_reviewed_at TEXT;
+
+PRAGMA writable_schema = ON;
+
+UPDATE sqlite_master
+SET sql = replace(
+    sql,
+    'CHECK(event_type IN (''due''))',
+    'CHECK(event_type IN (''due'', ''review''))'
+)
+WHERE type = 'table' AND name = 'notification_events';
+
+PRAGMA writable_schema = RESET;
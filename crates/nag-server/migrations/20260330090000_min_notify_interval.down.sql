@@ -0,0 +1 @@
+ALTER TABLE chores DROP COLUMN min_notify_interval_minutes;
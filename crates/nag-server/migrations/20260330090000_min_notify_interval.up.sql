@@ -0,0 +1,2 @@
+-- Per-chore override of the shortest time between two due notifications
+ALTER TABLE chores ADD COLUMN min_notify_interval_minutes INTEGER;
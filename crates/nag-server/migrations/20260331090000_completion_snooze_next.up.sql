@@ -0,0 +1,2 @@
+-- Time the occurrence after a completion was snoozed to when completing
+ALTER TABLE completions ADD COLUMN snooze_next_until TEXT;
@@ -0,0 +1,8 @@
+-- Chats a multi-chat delivery already reached, so a retry after a partial
+-- failure only sends to the chats that missed it
+CREATE TABLE notification_delivery_chats (
+    delivery_id BLOB NOT NULL REFERENCES notification_deliveries(id) ON DELETE CASCADE CHECK(length(delivery_id) = 16),
+    chat_id INTEGER NOT NULL,
+    delivered_at TEXT NOT NULL,
+    PRIMARY KEY (delivery_id, chat_id)
+);
@@ -0,0 +1,4 @@
+DROP INDEX idx_notification_events_due_at;
+ALTER TABLE notification_events DROP COLUMN due_at;
+ALTER TABLE notification_events RENAME COLUMN dedup_at TO due_at;
+CREATE INDEX idx_notification_events_due_at ON notification_events(due_at);
@@ -0,0 +1,10 @@
+-- Repeated reminders and reminders re-armed by a snooze are keyed by a time
+-- other than the occurrence's due time. Keep that key in `dedup_at`, which
+-- takes over the unique constraint, so `due_at` is always the chore's real
+-- due time.
+ALTER TABLE notification_events RENAME COLUMN due_at TO dedup_at;
+ALTER TABLE notification_events ADD COLUMN due_at TEXT NOT NULL DEFAULT '';
+UPDATE notification_events SET due_at = dedup_at;
+
+DROP INDEX idx_notification_events_due_at;
+CREATE INDEX idx_notification_events_due_at ON notification_events(due_at);
@@ -1,5 +1,10 @@
 use color_eyre::eyre::bail;
 use envconfig::Envconfig;
+use tower_sessions::cookie::SameSite;
+
+use nag_server::db::{models::NotificationChannel, tags::TagColorStrategy};
+use nag_server::http::SessionSettings;
+use nag_server::services::{DueSort, QuietHours, ScheduleLimits, chore_service::MAX_INTERVAL_DAYS};
 
 #[derive(Debug, Clone, Envconfig)]
 pub struct Config {
@@ -9,6 +14,21 @@ pub struct Config {
     pub server_port: u16,
     #[envconfig(from = "JSON_LOGS", default = "false")]
     pub json_logs: bool,
+    /// Default ordering of `/api/chores/due` when no `sort` is given
+    /// (`due_asc`, `overdue_desc` or `priority`).
+    #[envconfig(from = "DUE_DEFAULT_SORT", default = "due_asc")]
+    pub due_default_sort: DueSort,
+    /// How tags created without a color get one: `hash` leaves it to clients
+    /// to derive from the name, `least_used` picks the palette color the
+    /// fewest tags have.
+    #[envconfig(from = "TAG_COLOR_STRATEGY", default = "hash")]
+    pub tag_color_strategy: TagColorStrategy,
+    /// Shortest time between two occurrences of a cron schedule
+    #[envconfig(from = "MIN_CRON_INTERVAL_MINUTES", default = "60")]
+    pub min_cron_interval_minutes: i64,
+    /// Shortest interval of an interval schedule, in days
+    #[envconfig(from = "MIN_INTERVAL_DAYS", default = "1")]
+    pub min_interval_days: i32,
 
     /// Enable OIDC authentication. When `false`, all API routes are publicly
     /// accessible and `/auth/*` endpoints are not registered.
@@ -25,6 +45,18 @@ pub struct Config {
     #[envconfig(from = "OIDC_REDIRECT_URL")]
     pub oidc_redirect_url: Option<String>,
 
+    /// Mark the session cookie `Secure`; enable when served over HTTPS,
+    /// including behind a TLS-terminating reverse proxy.
+    #[envconfig(from = "SESSION_SECURE", default = "false")]
+    pub session_secure: bool,
+    /// `SameSite` attribute of the session cookie: `lax`, `strict` or
+    /// `none` (which requires `SESSION_SECURE=true`)
+    #[envconfig(from = "SESSION_SAME_SITE", default = "lax")]
+    pub session_same_site: String,
+    /// Hours without a request after which a session expires
+    #[envconfig(from = "SESSION_TTL_HOURS", default = "24")]
+    pub session_ttl_hours: i64,
+
     #[envconfig(from = "NOTIFICATIONS_ENABLED", default = "false")]
     pub notifications_enabled: bool,
     #[envconfig(from = "NOTIFICATION_POLL_INTERVAL_SECONDS", default = "60")]
@@ -35,11 +67,76 @@ pub struct Config {
     pub notification_max_attempts: i32,
     #[envconfig(from = "NOTIFICATION_BATCH_SIZE", default = "50")]
     pub notification_batch_size: i64,
+    #[envconfig(from = "NOTIFICATION_RETRY_BASE_DELAY_SECONDS", default = "30")]
+    pub notification_retry_base_delay_seconds: i64,
+    #[envconfig(from = "NOTIFICATION_VERIFY_ON_START", default = "false")]
+    pub notification_verify_on_start: bool,
+    /// Start (`HH:MM`, UTC) of the daily window without due notifications
+    #[envconfig(from = "NOTIFICATION_QUIET_START")]
+    pub notification_quiet_start: Option<String>,
+    /// End (`HH:MM`, UTC) of the daily window without due notifications
+    #[envconfig(from = "NOTIFICATION_QUIET_END")]
+    pub notification_quiet_end: Option<String>,
+    /// Longest due notification body, in characters (unlimited when unset)
+    #[envconfig(from = "NOTIFICATION_MAX_BODY_CHARS")]
+    pub notification_max_body_chars: Option<usize>,
+    /// Hours between repeated reminders for a chore that stays overdue
+    /// (notified once when unset). Every reminder retries up to
+    /// `NOTIFICATION_MAX_ATTEMPTS` times on its own.
+    #[envconfig(from = "NOTIFICATION_REMINDER_REPEAT_HOURS")]
+    pub notification_reminder_repeat_hours: Option<u32>,
+    /// Shortest time between two due notifications of one chore, in minutes
+    /// (no limit when unset). Chores can set their own.
+    #[envconfig(from = "NOTIFICATION_MIN_INTERVAL_MINUTES")]
+    pub notification_min_interval_minutes: Option<u32>,
 
     #[envconfig(from = "TELEGRAM_BOT_TOKEN")]
     pub telegram_bot_token: Option<String>,
+    /// Default chats, comma-separated; each gets every notification without
+    /// a named recipient.
     #[envconfig(from = "TELEGRAM_CHAT_ID")]
     pub telegram_chat_id: Option<String>,
+    /// Named recipients for per-chore routing, as comma-separated
+    /// `name=chat_id` pairs (e.g. `partner=12345,me=67890`).
+    #[envconfig(from = "TELEGRAM_RECIPIENTS")]
+    pub telegram_recipients: Option<String>,
+
+    /// Discord incoming webhook; notifications are also posted there when set.
+    #[envconfig(from = "DISCORD_WEBHOOK_URL")]
+    pub discord_webhook_url: Option<String>,
+    /// Slack incoming webhook; notifications are also posted there when set.
+    #[envconfig(from = "SLACK_WEBHOOK_URL")]
+    pub slack_webhook_url: Option<String>,
+
+    /// Shared secret of `POST /api/integrations/email-inbound`; the inbound
+    /// email webhook is disabled when unset.
+    #[envconfig(from = "EMAIL_INBOUND_SECRET")]
+    pub email_inbound_secret: Option<String>,
+
+    /// Note given to API completions recorded without one; `{time}` is
+    /// replaced with the completion time.
+    #[envconfig(from = "COMPLETION_NOTE_TEMPLATE")]
+    pub completion_note_template: Option<String>,
+    /// Minutes after recording a completion during which it can be undone
+    /// (unlimited when unset)
+    #[envconfig(from = "UNDO_WINDOW_MINUTES")]
+    pub undo_window_minutes: Option<u32>,
+    /// PIN that `DELETE` requests, tag merges and completion undos must send
+    /// in the `X-Nag-Pin` header, also when `AUTH_ENABLED=false`; they are
+    /// not guarded when unset.
+    #[envconfig(from = "DESTRUCTIVE_ACTION_PIN")]
+    pub destructive_action_pin: Option<String>,
+
+    /// Channel (`telegram`, `discord` or `slack`) operators are alerted on when
+    /// deliveries fail; no alerts are sent when unset.
+    #[envconfig(from = "OPERATOR_ALERT_CHANNEL")]
+    pub operator_alert_channel: Option<NotificationChannel>,
+    /// Named Telegram recipient for operator alerts (default chat when unset)
+    #[envconfig(from = "OPERATOR_ALERT_RECIPIENT")]
+    pub operator_alert_recipient: Option<String>,
+    /// Minimum time between two alerts about the same error
+    #[envconfig(from = "OPERATOR_ALERT_COOLDOWN_SECONDS", default = "3600")]
+    pub operator_alert_cooldown_seconds: i64,
 }
 
 impl Config {
@@ -71,6 +168,45 @@ impl Config {
         Ok(())
     }
 
+    /// Session cookie settings from `SESSION_SECURE`, `SESSION_SAME_SITE` and
+    /// `SESSION_TTL_HOURS`.
+    pub fn session_settings(&self) -> color_eyre::Result<SessionSettings> {
+        let same_site = match self.session_same_site.to_ascii_lowercase().as_str() {
+            "lax" => SameSite::Lax,
+            "strict" => SameSite::Strict,
+            "none" => SameSite::None,
+            other => bail!("Unknown SESSION_SAME_SITE '{other}' (expected lax, strict or none)"),
+        };
+        if same_site == SameSite::None && !self.session_secure {
+            // Browsers drop SameSite=None cookies that aren't Secure
+            bail!("SESSION_SAME_SITE=none requires SESSION_SECURE=true");
+        }
+        if self.session_ttl_hours < 1 {
+            bail!("SESSION_TTL_HOURS must be at least 1");
+        }
+
+        Ok(SessionSettings {
+            secure: self.session_secure,
+            same_site,
+            ttl: time::Duration::hours(self.session_ttl_hours),
+        })
+    }
+
+    /// Schedule limits from `MIN_CRON_INTERVAL_MINUTES` and `MIN_INTERVAL_DAYS`.
+    pub fn schedule_limits(&self) -> color_eyre::Result<ScheduleLimits> {
+        if self.min_cron_interval_minutes < 1 {
+            bail!("MIN_CRON_INTERVAL_MINUTES must be at least 1");
+        }
+        if !(1..=MAX_INTERVAL_DAYS).contains(&self.min_interval_days) {
+            bail!("MIN_INTERVAL_DAYS must be between 1 and {MAX_INTERVAL_DAYS}");
+        }
+
+        Ok(ScheduleLimits {
+            min_cron_interval: chrono::Duration::minutes(self.min_cron_interval_minutes),
+            min_interval_days: self.min_interval_days,
+        })
+    }
+
     /// Validate that notification channel config is present when notifications are enabled.
     pub fn validate_notifications(&self) -> color_eyre::Result<()> {
         if !self.notifications_enabled {
@@ -93,6 +229,44 @@ impl Config {
             );
         }
 
+        if self.operator_alert_channel == Some(NotificationChannel::Discord)
+            && self.discord_webhook_url.is_none()
+        {
+            bail!("OPERATOR_ALERT_CHANNEL=discord requires DISCORD_WEBHOOK_URL to be set");
+        }
+
+        if self.operator_alert_channel == Some(NotificationChannel::Slack)
+            && self.slack_webhook_url.is_none()
+        {
+            bail!("OPERATOR_ALERT_CHANNEL=slack requires SLACK_WEBHOOK_URL to be set");
+        }
+
+        if self.notification_max_body_chars == Some(0) {
+            bail!("NOTIFICATION_MAX_BODY_CHARS must be at least 1");
+        }
+
+        if self.notification_reminder_repeat_hours == Some(0) {
+            bail!("NOTIFICATION_REMINDER_REPEAT_HOURS must be at least 1");
+        }
+
+        if self.notification_min_interval_minutes == Some(0) {
+            bail!("NOTIFICATION_MIN_INTERVAL_MINUTES must be at least 1");
+        }
+
+        self.notification_quiet_hours()?;
+
         Ok(())
     }
+
+    /// Quiet hours from `NOTIFICATION_QUIET_START`/`NOTIFICATION_QUIET_END`;
+    /// `None` when neither is set.
+    pub fn notification_quiet_hours(&self) -> color_eyre::Result<Option<QuietHours>> {
+        match (&self.notification_quiet_start, &self.notification_quiet_end) {
+            (None, None) => Ok(None),
+            (Some(start), Some(end)) => QuietHours::parse(start, end)
+                .map(Some)
+                .map_err(|e| color_eyre::eyre::eyre!("Invalid notification quiet hours: {e}")),
+            _ => bail!("NOTIFICATION_QUIET_START and NOTIFICATION_QUIET_END must be set together"),
+        }
+    }
 }
@@ -0,0 +1,104 @@
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use super::models::ApiKey;
+
+pub struct ApiKeyRepository;
+
+impl ApiKeyRepository {
+    /// Store a new API key for a user.
+    ///
+    /// Only the hash of the key is persisted; the caller is responsible for
+    /// handing the raw key to the user.
+    pub async fn create(
+        pool: &SqlitePool,
+        user_id: Uuid,
+        name: &str,
+        prefix: &str,
+        key_hash: &str,
+    ) -> sqlx::Result<ApiKey> {
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+
+        sqlx::query(
+            r#"
+            INSERT INTO api_keys (id, user_id, name, prefix, key_hash, created_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .bind(name)
+        .bind(prefix)
+        .bind(key_hash)
+        .bind(now)
+        .execute(pool)
+        .await?;
+
+        Ok(ApiKey {
+            id,
+            user_id,
+            name: name.to_string(),
+            prefix: prefix.to_string(),
+            created_at: now,
+            last_used_at: None,
+            revoked_at: None,
+        })
+    }
+
+    /// List a user's API keys, including revoked ones, newest first
+    pub async fn list_for_user(pool: &SqlitePool, user_id: Uuid) -> sqlx::Result<Vec<ApiKey>> {
+        sqlx::query_as::<_, ApiKey>(
+            r#"
+            SELECT id, user_id, name, prefix, created_at, last_used_at, revoked_at
+            FROM api_keys
+            WHERE user_id = ?
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Revoke one of a user's API keys. Returns `false` if no such active key exists.
+    pub async fn revoke(pool: &SqlitePool, id: Uuid, user_id: Uuid) -> sqlx::Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE api_keys
+            SET revoked_at = ?
+            WHERE id = ? AND user_id = ? AND revoked_at IS NULL
+            "#,
+        )
+        .bind(Utc::now())
+        .bind(id)
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Look up an active key by its hash, recording the use.
+    ///
+    /// Returns the owning user's ID, or `None` for unknown or revoked keys.
+    pub async fn authenticate(
+        pool: &SqlitePool,
+        key_hash: &str,
+        now: DateTime<Utc>,
+    ) -> sqlx::Result<Option<Uuid>> {
+        sqlx::query_scalar(
+            r#"
+            UPDATE api_keys
+            SET last_used_at = ?
+            WHERE key_hash = ? AND revoked_at IS NULL
+            RETURNING user_id
+            "#,
+        )
+        .bind(now)
+        .bind(key_hash)
+        .fetch_optional(pool)
+        .await
+    }
+}
@@ -0,0 +1,60 @@
+use chrono::Utc;
+use sqlx::{SqliteConnection, SqlitePool, types::Json};
+use uuid::Uuid;
+
+use super::models::{AuditAction, AuditLogEntry, ScheduleSnapshot};
+
+pub struct AuditRepository;
+
+impl AuditRepository {
+    /// Record a change to a chore.
+    ///
+    /// Takes a connection rather than the pool so the entry is written in the
+    /// transaction making the change.
+    pub async fn record(
+        conn: &mut SqliteConnection,
+        chore_id: Uuid,
+        action: AuditAction,
+        user_id: Option<Uuid>,
+        before: Option<&ScheduleSnapshot>,
+        after: Option<&ScheduleSnapshot>,
+    ) -> sqlx::Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO audit_log (
+                id, chore_id, action, user_id, schedule_before, schedule_after, created_at
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(chore_id)
+        .bind(action)
+        .bind(user_id)
+        .bind(before.map(Json))
+        .bind(after.map(Json))
+        .bind(Utc::now())
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Recorded changes to a chore, oldest first
+    pub async fn list_for_chore(
+        pool: &SqlitePool,
+        chore_id: Uuid,
+    ) -> sqlx::Result<Vec<AuditLogEntry>> {
+        sqlx::query_as::<_, AuditLogEntry>(
+            r#"
+            SELECT id, chore_id, action, user_id, schedule_before, schedule_after, created_at
+            FROM audit_log
+            WHERE chore_id = ?
+            ORDER BY created_at, rowid
+            "#,
+        )
+        .bind(chore_id)
+        .fetch_all(pool)
+        .await
+    }
+}
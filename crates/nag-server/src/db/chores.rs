@@ -1,11 +1,260 @@
-use chrono::Utc;
-use sqlx::SqlitePool;
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use sqlx::{SqliteConnection, SqlitePool};
 use uuid::Uuid;
 
-use super::models::{Chore, ChoreWithLastCompletion, ScheduleType};
+use super::audit::AuditRepository;
+use super::models::{
+    AuditAction, Chore, ChoreAnchorMode, ChorePriority, ChoreWithLastCompletion, ScheduleSnapshot,
+    ScheduleType, TrashedChore,
+};
 
 pub const DEFAULT_PAGE_SIZE: i64 = 20;
 
+/// Key the chore list is ordered by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChoreSort {
+    /// By id, the historical list order
+    #[default]
+    Id,
+    /// By name, case-insensitive
+    Name,
+    CreatedAt,
+    UpdatedAt,
+    /// By last completion; never-completed chores sort first
+    LastCompletedAt,
+    /// By how many schedule periods have passed since the last completion or
+    /// skip. Depends on the current time, so the list is ordered in memory
+    /// rather than by the database.
+    Neglect,
+}
+
+impl ChoreSort {
+    /// SQL expression the list is ordered by. Never NULL, so it can be
+    /// compared against a cursor as part of a row value.
+    fn expression(self) -> &'static str {
+        match self {
+            // Neglect is ordered by the caller; the database lists by id
+            Self::Id | Self::Neglect => "c.id",
+            Self::Name => "c.name COLLATE NOCASE",
+            Self::CreatedAt => "c.created_at",
+            Self::UpdatedAt => "c.updated_at",
+            Self::LastCompletedAt => {
+                "COALESCE((SELECT MAX(completed_at) FROM completions WHERE chore_id = c.id), '')"
+            }
+        }
+    }
+
+    /// Value of [`Self::expression`] for `chore`, or `None` when sorting by id
+    fn value(self, chore: &ChoreWithLastCompletion) -> Option<String> {
+        // Timestamps are stored as sqlx encodes them; compare in that format
+        let stored = |at: DateTime<Utc>| at.to_rfc3339_opts(SecondsFormat::AutoSi, false);
+        match self {
+            Self::Id | Self::Neglect => None,
+            Self::Name => Some(chore.name.clone()),
+            Self::CreatedAt => Some(stored(chore.created_at)),
+            Self::UpdatedAt => Some(stored(chore.updated_at)),
+            Self::LastCompletedAt => Some(chore.last_completed_at.map(stored).unwrap_or_default()),
+        }
+    }
+}
+
+impl FromStr for ChoreSort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "name" => Ok(Self::Name),
+            "created_at" => Ok(Self::CreatedAt),
+            "updated_at" => Ok(Self::UpdatedAt),
+            "last_completed_at" => Ok(Self::LastCompletedAt),
+            "neglect" => Ok(Self::Neglect),
+            other => Err(format!(
+                "Unknown sort '{}' (expected name, created_at, updated_at, last_completed_at or neglect)",
+                other
+            )),
+        }
+    }
+}
+
+impl ChoreSort {
+    /// Direction used when none is requested: most neglected first for
+    /// [`Self::Neglect`], ascending otherwise
+    pub fn default_dir(self) -> SortDirection {
+        match self {
+            Self::Neglect => SortDirection::Desc,
+            _ => SortDirection::Asc,
+        }
+    }
+}
+
+/// Direction of a [`ChoreSort`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl FromStr for SortDirection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "asc" => Ok(Self::Asc),
+            "desc" => Ok(Self::Desc),
+            other => Err(format!(
+                "Unknown sort direction '{}' (expected asc or desc)",
+                other
+            )),
+        }
+    }
+}
+
+/// How a [`TagFilter`] with several tags matches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TagMatch {
+    /// Chores carrying at least one of the tags
+    #[default]
+    Any,
+    /// Chores carrying every one of the tags
+    All,
+}
+
+impl FromStr for TagMatch {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "any" => Ok(Self::Any),
+            "all" => Ok(Self::All),
+            other => Err(format!(
+                "Unknown tag match '{}' (expected any or all)",
+                other
+            )),
+        }
+    }
+}
+
+/// Filter on tag names (case-insensitive). Without names every chore matches.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TagFilter {
+    pub names: Vec<String>,
+    pub tag_match: TagMatch,
+}
+
+impl TagFilter {
+    /// Filter on a comma-separated list of tag names, ignoring blank entries
+    pub fn parse(tags: Option<&str>, tag_match: TagMatch) -> Self {
+        let mut names: Vec<String> = Vec::new();
+        for name in tags.unwrap_or_default().split(',').map(str::trim) {
+            if !name.is_empty() && !names.iter().any(|n| n.eq_ignore_ascii_case(name)) {
+                names.push(name.to_string());
+            }
+        }
+        Self { names, tag_match }
+    }
+
+    /// Lowercased names as a JSON array, for `json_each` in [`Self::condition`]
+    fn names_json(&self) -> String {
+        let names: Vec<String> = self.names.iter().map(|n| n.to_ascii_lowercase()).collect();
+        serde_json::Value::from(names).to_string()
+    }
+
+    fn match_all(&self) -> bool {
+        self.tag_match == TagMatch::All
+    }
+
+    /// SQL condition on chore `c`, with `names` bound to [`Self::names_json`]
+    /// and `all` to [`Self::match_all`]. Filtering in the query rather than
+    /// afterwards keeps pages full and counts right.
+    fn condition(names: &str, all: &str) -> String {
+        format!(
+            r#"(
+                json_array_length({names}) = 0
+                OR (
+                    SELECT COUNT(DISTINCT lower(t.name))
+                    FROM chore_tags ct
+                    INNER JOIN tags t ON t.id = ct.tag_id AND t.deleted_at IS NULL
+                    WHERE ct.chore_id = c.id
+                      AND lower(t.name) IN (SELECT value FROM json_each({names}))
+                ) >= CASE WHEN {all} THEN json_array_length({names}) ELSE 1 END
+            )"#
+        )
+    }
+}
+
+/// Ordering of the chore list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ChoreOrder {
+    pub sort: ChoreSort,
+    pub dir: SortDirection,
+}
+
+/// Position after the last chore of a page: its id and, unless sorting by
+/// id, the value it was sorted by.
+///
+/// Rendered as the bare id, or as `<id>.<hex-encoded value>` so the cursor
+/// stays safe to put in a query string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChoreCursor {
+    pub id: Uuid,
+    pub sort_value: Option<String>,
+}
+
+impl ChoreCursor {
+    /// Cursor pointing after `chore` in a list ordered by `sort`
+    pub fn after(chore: &ChoreWithLastCompletion, sort: ChoreSort) -> Self {
+        Self {
+            id: chore.id,
+            sort_value: sort.value(chore),
+        }
+    }
+}
+
+impl fmt::Display for ChoreCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.id)?;
+        if let Some(value) = &self.sort_value {
+            f.write_str(".")?;
+            for byte in value.bytes() {
+                write!(f, "{:02x}", byte)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ChoreCursor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid cursor '{}'", s);
+        let (id, value) = match s.split_once('.') {
+            Some((id, value)) => (id, Some(value)),
+            None => (s, None),
+        };
+        let id = id.parse().map_err(|_| invalid())?;
+        let sort_value = value
+            .map(|hex| {
+                if hex.len() % 2 != 0 {
+                    return Err(invalid());
+                }
+                let bytes = (0..hex.len())
+                    .step_by(2)
+                    .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+                    .collect::<Option<Vec<u8>>>()
+                    .ok_or_else(invalid)?;
+                String::from_utf8(bytes).map_err(|_| invalid())
+            })
+            .transpose()?;
+
+        Ok(Self { id, sort_value })
+    }
+}
+
 /// Parameters for creating a new chore
 pub struct CreateChoreParams<'a> {
     pub name: &'a str,
@@ -15,6 +264,25 @@ pub struct CreateChoreParams<'a> {
     pub interval_days: Option<i32>,
     pub interval_time_hour: Option<i32>,
     pub interval_time_minute: Option<i32>,
+    pub days_of_week: Option<&'a str>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub timezone: Option<&'a str>,
+    pub notify_lead_minutes: Option<i32>,
+    pub max_snoozes: Option<i32>,
+    pub estimated_minutes: Option<i32>,
+    pub review_interval_days: Option<i32>,
+    pub min_notify_interval_minutes: Option<i32>,
+    pub recipient: Option<&'a str>,
+    pub auto_complete_with: Option<Uuid>,
+    /// Comma-separated completion sources (any when `None`)
+    pub allowed_completion_sources: Option<&'a str>,
+    pub external_id: Option<&'a str>,
+    pub priority: ChorePriority,
+    pub anchor_mode: ChoreAnchorMode,
+    /// Owning user; `None` leaves the chore visible to everyone
+    pub user_id: Option<Uuid>,
+    /// `false` creates the chore paused
+    pub is_active: bool,
 }
 
 /// Parameters for updating a chore's schedule
@@ -24,65 +292,197 @@ pub struct UpdateScheduleParams<'a> {
     pub interval_days: Option<i32>,
     pub interval_time_hour: Option<i32>,
     pub interval_time_minute: Option<i32>,
+    pub days_of_week: Option<&'a str>,
+    pub due_at: Option<DateTime<Utc>>,
+}
+
+/// Parameters for updating a chore. `None` leaves a field unchanged.
+#[derive(Default)]
+pub struct UpdateChoreParams<'a> {
+    pub name: Option<&'a str>,
+    pub description: Option<Option<&'a str>>,
+    pub schedule: Option<UpdateScheduleParams<'a>>,
+    pub timezone: Option<Option<&'a str>>,
+    pub notify_lead_minutes: Option<Option<i32>>,
+    pub max_snoozes: Option<Option<i32>>,
+    pub estimated_minutes: Option<Option<i32>>,
+    pub review_interval_days: Option<Option<i32>>,
+    pub min_notify_interval_minutes: Option<Option<i32>>,
+    pub recipient: Option<Option<&'a str>>,
+    pub auto_complete_with: Option<Option<Uuid>>,
+    pub allowed_completion_sources: Option<Option<&'a str>>,
+    pub priority: Option<ChorePriority>,
+    pub anchor_mode: Option<ChoreAnchorMode>,
+    pub is_active: Option<bool>,
 }
 
 pub struct ChoreRepository;
 
 impl ChoreRepository {
-    /// List all chores with cursor-based pagination
+    /// List chores in `order` with cursor-based pagination, only those
+    /// matching `tags`
+    ///
+    /// With an `owner`, only that user's chores and unowned chores are listed.
+    /// Paused chores are skipped unless `include_paused` is set. Ties in the
+    /// sort key are broken by id, so a `cursor` from [`ChoreCursor::after`]
+    /// resumes exactly after the previous page.
     pub async fn list(
         pool: &SqlitePool,
-        cursor: Option<Uuid>,
+        cursor: Option<&ChoreCursor>,
         limit: Option<i64>,
+        tags: &TagFilter,
+        owner: Option<Uuid>,
+        include_paused: bool,
+        order: ChoreOrder,
     ) -> sqlx::Result<Vec<ChoreWithLastCompletion>> {
         let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE);
 
-        match cursor {
-            Some(cursor_id) => {
-                sqlx::query_as::<_, ChoreWithLastCompletion>(
-                    r#"
-                    SELECT
-                        c.id, c.name, c.description,
-                        c.schedule_type, c.cron_schedule,
-                        c.interval_days, c.interval_time_hour, c.interval_time_minute,
-                        c.created_at, c.updated_at,
-                        (SELECT MAX(completed_at) FROM completions WHERE chore_id = c.id) as last_completed_at
-                    FROM chores c
-                    WHERE c.id > ?
-                    ORDER BY c.id
-                    LIMIT ?
-                    "#,
-                )
-                .bind(cursor_id)
-                .bind(limit)
-                .fetch_all(pool)
-                .await
-            }
-            None => {
-                sqlx::query_as::<_, ChoreWithLastCompletion>(
-                    r#"
-                    SELECT
-                        c.id, c.name, c.description,
-                        c.schedule_type, c.cron_schedule,
-                        c.interval_days, c.interval_time_hour, c.interval_time_minute,
-                        c.created_at, c.updated_at,
-                        (SELECT MAX(completed_at) FROM completions WHERE chore_id = c.id) as last_completed_at
-                    FROM chores c
-                    ORDER BY c.id
-                    LIMIT ?
-                    "#,
+        let (after, dir) = match order.dir {
+            SortDirection::Asc => (">", "ASC"),
+            SortDirection::Desc => ("<", "DESC"),
+        };
+        let (after_cursor, order_by) = match order.sort {
+            ChoreSort::Id => (format!("c.id {after} ?1"), format!("c.id {dir}")),
+            sort => {
+                let expr = sort.expression();
+                (
+                    format!("({expr}, c.id) {after} (?6, ?1)"),
+                    format!("{expr} {dir}, c.id {dir}"),
                 )
-                .bind(limit)
-                .fetch_all(pool)
-                .await
             }
-        }
+        };
+
+        // The tag filter must be applied before LIMIT so pages stay full and
+        // the cursor never skips matching chores
+        let tag_condition = TagFilter::condition("?3", "?7");
+        let query = format!(
+            r#"
+            SELECT
+                c.id, c.name, c.description,
+                c.schedule_type, c.cron_schedule,
+                c.interval_days, c.interval_time_hour, c.interval_time_minute,
+                c.days_of_week, c.due_at, c.timezone,
+                c.notify_lead_minutes, c.max_snoozes, c.estimated_minutes, c.review_interval_days, c.min_notify_interval_minutes, c.last_reviewed_at,
+                c.recipient, c.auto_complete_with,
+                c.allowed_completion_sources, c.external_id, c.priority, c.anchor_mode, c.is_active,
+                c.created_at, c.updated_at,
+                (SELECT MAX(completed_at) FROM completions WHERE chore_id = c.id) as last_completed_at,
+                (SELECT MAX(skipped_at) FROM chore_skips WHERE chore_id = c.id) as last_skipped_at,
+                (SELECT snooze_next_until FROM completions WHERE chore_id = c.id
+                    ORDER BY completed_at DESC, id DESC LIMIT 1) as next_snoozed_until
+            FROM chores c
+            WHERE c.deleted_at IS NULL
+              AND (?1 IS NULL OR {after_cursor})
+              AND (?2 OR c.is_active)
+              AND {tag_condition}
+              AND (?5 IS NULL OR c.user_id IS NULL OR c.user_id = ?5)
+            ORDER BY {order_by}
+            LIMIT ?4
+            "#
+        );
+
+        sqlx::query_as::<_, ChoreWithLastCompletion>(&query)
+            .bind(cursor.map(|c| c.id))
+            .bind(include_paused)
+            .bind(tags.names_json())
+            .bind(limit)
+            .bind(owner)
+            .bind(cursor.and_then(|c| c.sort_value.as_deref()))
+            .bind(tags.match_all())
+            .fetch_all(pool)
+            .await
     }
 
-    /// Get a single chore by ID with last completion time
+    /// Search chores by name or description (case-insensitive substring match)
+    /// with the same cursor-based pagination and `owner` filter as [`Self::list`]
+    pub async fn search(
+        pool: &SqlitePool,
+        query: &str,
+        cursor: Option<Uuid>,
+        limit: Option<i64>,
+        owner: Option<Uuid>,
+        include_paused: bool,
+    ) -> sqlx::Result<Vec<ChoreWithLastCompletion>> {
+        let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE);
+        // Match the query literally: escape LIKE wildcards
+        let pattern = format!(
+            "%{}%",
+            query
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_")
+        );
+
+        sqlx::query_as::<_, ChoreWithLastCompletion>(
+            r#"
+            SELECT
+                c.id, c.name, c.description,
+                c.schedule_type, c.cron_schedule,
+                c.interval_days, c.interval_time_hour, c.interval_time_minute,
+                c.days_of_week, c.due_at, c.timezone,
+                c.notify_lead_minutes, c.max_snoozes, c.estimated_minutes, c.review_interval_days, c.min_notify_interval_minutes, c.last_reviewed_at,
+                c.recipient, c.auto_complete_with,
+                c.allowed_completion_sources, c.external_id, c.priority, c.anchor_mode, c.is_active,
+                c.created_at, c.updated_at,
+                (SELECT MAX(completed_at) FROM completions WHERE chore_id = c.id) as last_completed_at,
+                (SELECT MAX(skipped_at) FROM chore_skips WHERE chore_id = c.id) as last_skipped_at,
+                (SELECT snooze_next_until FROM completions WHERE chore_id = c.id
+                    ORDER BY completed_at DESC, id DESC LIMIT 1) as next_snoozed_until
+            FROM chores c
+            WHERE c.deleted_at IS NULL
+              AND (c.name LIKE ?1 ESCAPE '\' OR c.description LIKE ?1 ESCAPE '\')
+              AND (?2 IS NULL OR c.id > ?2)
+              AND (?3 OR c.is_active)
+              AND (?5 IS NULL OR c.user_id IS NULL OR c.user_id = ?5)
+            ORDER BY c.id
+            LIMIT ?4
+            "#,
+        )
+        .bind(pattern)
+        .bind(cursor)
+        .bind(include_paused)
+        .bind(limit)
+        .bind(owner)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Count chores matching `tags`
+    ///
+    /// Applies the same `owner` and `include_paused` filters as [`Self::list`].
+    pub async fn count(
+        pool: &SqlitePool,
+        tags: &TagFilter,
+        owner: Option<Uuid>,
+        include_paused: bool,
+    ) -> sqlx::Result<i64> {
+        let tag_condition = TagFilter::condition("?2", "?4");
+        let query = format!(
+            r#"
+            SELECT COUNT(*)
+            FROM chores c
+            WHERE c.deleted_at IS NULL
+              AND (?1 OR c.is_active)
+              AND {tag_condition}
+              AND (?3 IS NULL OR c.user_id IS NULL OR c.user_id = ?3)
+            "#
+        );
+
+        sqlx::query_scalar(&query)
+            .bind(include_paused)
+            .bind(tags.names_json())
+            .bind(owner)
+            .bind(tags.match_all())
+            .fetch_one(pool)
+            .await
+    }
+
+    /// Get a single chore by ID with last completion time, visible to `owner`
+    /// like in [`Self::list`]
     pub async fn get_by_id(
         pool: &SqlitePool,
         id: Uuid,
+        owner: Option<Uuid>,
     ) -> sqlx::Result<Option<ChoreWithLastCompletion>> {
         sqlx::query_as::<_, ChoreWithLastCompletion>(
             r#"
@@ -90,20 +490,106 @@ impl ChoreRepository {
                 c.id, c.name, c.description,
                 c.schedule_type, c.cron_schedule,
                 c.interval_days, c.interval_time_hour, c.interval_time_minute,
+                c.days_of_week, c.due_at, c.timezone,
+                c.notify_lead_minutes, c.max_snoozes, c.estimated_minutes, c.review_interval_days, c.min_notify_interval_minutes, c.last_reviewed_at,
+                c.recipient, c.auto_complete_with,
+                c.allowed_completion_sources, c.external_id, c.priority, c.anchor_mode, c.is_active,
                 c.created_at, c.updated_at,
-                (SELECT MAX(completed_at) FROM completions WHERE chore_id = c.id) as last_completed_at
+                (SELECT MAX(completed_at) FROM completions WHERE chore_id = c.id) as last_completed_at,
+                (SELECT MAX(skipped_at) FROM chore_skips WHERE chore_id = c.id) as last_skipped_at,
+                (SELECT snooze_next_until FROM completions WHERE chore_id = c.id
+                    ORDER BY completed_at DESC, id DESC LIMIT 1) as next_snoozed_until
             FROM chores c
-            WHERE c.id = ?
+            WHERE c.id = ?1
+              AND c.deleted_at IS NULL
+              AND (?2 IS NULL OR c.user_id IS NULL OR c.user_id = ?2)
             "#,
         )
         .bind(id)
+        .bind(owner)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Get the chore synced under `external_id`, visible to `owner` like in
+    /// [`Self::list`]
+    pub async fn get_by_external_id(
+        pool: &SqlitePool,
+        external_id: &str,
+        owner: Option<Uuid>,
+    ) -> sqlx::Result<Option<ChoreWithLastCompletion>> {
+        sqlx::query_as::<_, ChoreWithLastCompletion>(
+            r#"
+            SELECT
+                c.id, c.name, c.description,
+                c.schedule_type, c.cron_schedule,
+                c.interval_days, c.interval_time_hour, c.interval_time_minute,
+                c.days_of_week, c.due_at, c.timezone,
+                c.notify_lead_minutes, c.max_snoozes, c.estimated_minutes, c.review_interval_days, c.min_notify_interval_minutes, c.last_reviewed_at,
+                c.recipient, c.auto_complete_with,
+                c.allowed_completion_sources, c.external_id, c.priority, c.anchor_mode, c.is_active,
+                c.created_at, c.updated_at,
+                (SELECT MAX(completed_at) FROM completions WHERE chore_id = c.id) as last_completed_at,
+                (SELECT MAX(skipped_at) FROM chore_skips WHERE chore_id = c.id) as last_skipped_at,
+                (SELECT snooze_next_until FROM completions WHERE chore_id = c.id
+                    ORDER BY completed_at DESC, id DESC LIMIT 1) as next_snoozed_until
+            FROM chores c
+            WHERE c.external_id = ?1
+              AND c.deleted_at IS NULL
+              AND (?2 IS NULL OR c.user_id IS NULL OR c.user_id = ?2)
+            "#,
+        )
+        .bind(external_id)
+        .bind(owner)
         .fetch_optional(pool)
         .await
     }
 
-    /// Create a new chore
+    /// Check whether a chore with `id` exists and is visible to `owner` like
+    /// in [`Self::list`], in the trash or not
+    pub async fn visible_to(
+        pool: &SqlitePool,
+        id: Uuid,
+        owner: Option<Uuid>,
+    ) -> sqlx::Result<bool> {
+        sqlx::query_scalar(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM chores
+                WHERE id = ?1 AND (?2 IS NULL OR user_id IS NULL OR user_id = ?2)
+            )
+            "#,
+        )
+        .bind(id)
+        .bind(owner)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Check whether a chore with `id` exists, regardless of owner
+    pub async fn exists(conn: &mut SqliteConnection, id: Uuid) -> sqlx::Result<bool> {
+        sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM chores WHERE id = ?)")
+            .bind(id)
+            .fetch_one(conn)
+            .await
+    }
+
+    /// Create a new chore, recording its owner as the creator in the audit log
     pub async fn create(pool: &SqlitePool, params: CreateChoreParams<'_>) -> sqlx::Result<Chore> {
-        let id = Uuid::new_v4();
+        let mut tx = pool.begin().await?;
+        let chore = Self::insert(&mut tx, Uuid::new_v4(), params).await?;
+        tx.commit().await?;
+
+        Ok(chore)
+    }
+
+    /// [`Self::create`] with a given `id` on an existing connection, so
+    /// several chores can be inserted in one transaction
+    pub async fn insert(
+        conn: &mut SqliteConnection,
+        id: Uuid,
+        params: CreateChoreParams<'_>,
+    ) -> sqlx::Result<Chore> {
         let now = Utc::now();
 
         sqlx::query(
@@ -112,9 +598,13 @@ impl ChoreRepository {
                 id, name, description,
                 schedule_type, cron_schedule,
                 interval_days, interval_time_hour, interval_time_minute,
-                created_at, updated_at
+                days_of_week, due_at, timezone,
+                notify_lead_minutes, max_snoozes, estimated_minutes, review_interval_days,
+                min_notify_interval_minutes, recipient, auto_complete_with,
+                allowed_completion_sources, external_id, priority, anchor_mode, user_id,
+                is_active, created_at, updated_at
             )
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(id)
@@ -125,12 +615,28 @@ impl ChoreRepository {
         .bind(params.interval_days)
         .bind(params.interval_time_hour)
         .bind(params.interval_time_minute)
+        .bind(params.days_of_week)
+        .bind(params.due_at)
+        .bind(params.timezone)
+        .bind(params.notify_lead_minutes)
+        .bind(params.max_snoozes)
+        .bind(params.estimated_minutes)
+        .bind(params.review_interval_days)
+        .bind(params.min_notify_interval_minutes)
+        .bind(params.recipient)
+        .bind(params.auto_complete_with)
+        .bind(params.allowed_completion_sources)
+        .bind(params.external_id)
+        .bind(params.priority)
+        .bind(params.anchor_mode)
+        .bind(params.user_id)
+        .bind(params.is_active)
         .bind(now)
         .bind(now)
-        .execute(pool)
+        .execute(&mut *conn)
         .await?;
 
-        Ok(Chore {
+        let chore = Chore {
             id,
             name: params.name.to_string(),
             description: params.description.map(String::from),
@@ -139,20 +645,48 @@ impl ChoreRepository {
             interval_days: params.interval_days,
             interval_time_hour: params.interval_time_hour,
             interval_time_minute: params.interval_time_minute,
+            days_of_week: params.days_of_week.map(String::from),
+            due_at: params.due_at,
+            timezone: params.timezone.map(String::from),
+            notify_lead_minutes: params.notify_lead_minutes,
+            max_snoozes: params.max_snoozes,
+            estimated_minutes: params.estimated_minutes,
+            review_interval_days: params.review_interval_days,
+            min_notify_interval_minutes: params.min_notify_interval_minutes,
+            last_reviewed_at: None,
+            recipient: params.recipient.map(String::from),
+            auto_complete_with: params.auto_complete_with,
+            allowed_completion_sources: params.allowed_completion_sources.map(String::from),
+            external_id: params.external_id.map(String::from),
+            priority: params.priority,
+            anchor_mode: params.anchor_mode,
+            is_active: params.is_active,
             created_at: now,
             updated_at: now,
-        })
+        };
+
+        AuditRepository::record(
+            conn,
+            id,
+            AuditAction::Create,
+            params.user_id,
+            None,
+            Some(&ScheduleSnapshot::from(&chore)),
+        )
+        .await?;
+
+        Ok(chore)
     }
 
-    /// Update an existing chore
+    /// Update an existing chore, recording the change as made by `actor`
     pub async fn update(
         pool: &SqlitePool,
         id: Uuid,
-        name: Option<&str>,
-        description: Option<Option<&str>>,
-        schedule: Option<UpdateScheduleParams<'_>>,
+        params: UpdateChoreParams<'_>,
+        actor: Option<Uuid>,
     ) -> sqlx::Result<Option<Chore>> {
         let now = Utc::now();
+        let mut tx = pool.begin().await?;
 
         // First get the existing chore
         let existing = sqlx::query_as::<_, Chore>(
@@ -161,31 +695,74 @@ impl ChoreRepository {
                 id, name, description,
                 schedule_type, cron_schedule,
                 interval_days, interval_time_hour, interval_time_minute,
+                days_of_week, due_at, timezone,
+                notify_lead_minutes, max_snoozes, estimated_minutes, review_interval_days, min_notify_interval_minutes, last_reviewed_at,
+                recipient, auto_complete_with,
+                allowed_completion_sources, external_id, priority, anchor_mode, is_active,
                 created_at, updated_at
-            FROM chores WHERE id = ?
+            FROM chores WHERE id = ? AND deleted_at IS NULL
             "#,
         )
         .bind(id)
-        .fetch_optional(pool)
+        .fetch_optional(&mut *tx)
         .await?;
 
         let Some(mut chore) = existing else {
             return Ok(None);
         };
+        let before = ScheduleSnapshot::from(&chore);
 
         // Apply updates
-        if let Some(n) = name {
+        if let Some(n) = params.name {
             chore.name = n.to_string();
         }
-        if let Some(d) = description {
+        if let Some(d) = params.description {
             chore.description = d.map(String::from);
         }
-        if let Some(s) = schedule {
+        if let Some(s) = params.schedule {
             chore.schedule_type = s.schedule_type;
             chore.cron_schedule = s.cron_schedule.map(String::from);
             chore.interval_days = s.interval_days;
             chore.interval_time_hour = s.interval_time_hour;
             chore.interval_time_minute = s.interval_time_minute;
+            chore.days_of_week = s.days_of_week.map(String::from);
+            chore.due_at = s.due_at;
+        }
+        if let Some(tz) = params.timezone {
+            chore.timezone = tz.map(String::from);
+        }
+        if let Some(lead) = params.notify_lead_minutes {
+            chore.notify_lead_minutes = lead;
+        }
+        if let Some(max) = params.max_snoozes {
+            chore.max_snoozes = max;
+        }
+        if let Some(minutes) = params.estimated_minutes {
+            chore.estimated_minutes = minutes;
+        }
+        if let Some(days) = params.review_interval_days {
+            chore.review_interval_days = days;
+        }
+        if let Some(minutes) = params.min_notify_interval_minutes {
+            chore.min_notify_interval_minutes = minutes;
+        }
+        if let Some(r) = params.recipient {
+            chore.recipient = r.map(String::from);
+        }
+        if let Some(link) = params.auto_complete_with {
+            chore.auto_complete_with = link;
+        }
+        if let Some(sources) = params.allowed_completion_sources {
+            chore.allowed_completion_sources = sources.map(String::from);
+        }
+        if let Some(priority) = params.priority {
+            chore.priority = priority;
+        }
+        if let Some(anchor_mode) = params.anchor_mode {
+            chore.anchor_mode = anchor_mode;
+        }
+        if let Some(active) = params.is_active {
+            chore.is_active = active;
         }
         chore.updated_at = now;
 
@@ -195,6 +772,10 @@ impl ChoreRepository {
             SET name = ?, description = ?,
                 schedule_type = ?, cron_schedule = ?,
                 interval_days = ?, interval_time_hour = ?, interval_time_minute = ?,
+                days_of_week = ?, due_at = ?, timezone = ?,
+                notify_lead_minutes = ?, max_snoozes = ?, estimated_minutes = ?, review_interval_days = ?,
+                min_notify_interval_minutes = ?, recipient = ?, auto_complete_with = ?,
+                allowed_completion_sources = ?, priority = ?, anchor_mode = ?, is_active = ?,
                 updated_at = ?
             WHERE id = ?
             "#,
@@ -206,16 +787,129 @@ impl ChoreRepository {
         .bind(chore.interval_days)
         .bind(chore.interval_time_hour)
         .bind(chore.interval_time_minute)
+        .bind(&chore.days_of_week)
+        .bind(chore.due_at)
+        .bind(&chore.timezone)
+        .bind(chore.notify_lead_minutes)
+        .bind(chore.max_snoozes)
+        .bind(chore.estimated_minutes)
+        .bind(chore.review_interval_days)
+        .bind(chore.min_notify_interval_minutes)
+        .bind(&chore.recipient)
+        .bind(chore.auto_complete_with)
+        .bind(&chore.allowed_completion_sources)
+        .bind(chore.priority)
+        .bind(chore.anchor_mode)
+        .bind(chore.is_active)
         .bind(chore.updated_at)
         .bind(id)
-        .execute(pool)
+        .execute(&mut *tx)
+        .await?;
+
+        AuditRepository::record(
+            &mut tx,
+            id,
+            AuditAction::Update,
+            actor,
+            Some(&before),
+            Some(&ScheduleSnapshot::from(&chore)),
+        )
         .await?;
+        tx.commit().await?;
 
         Ok(Some(chore))
     }
 
-    /// Delete a chore by ID
-    pub async fn delete(pool: &SqlitePool, id: Uuid) -> sqlx::Result<bool> {
+    /// Record that the occurrence due at `skipped_at` was skipped
+    pub async fn skip(pool: &SqlitePool, id: Uuid, skipped_at: DateTime<Utc>) -> sqlx::Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO chore_skips (id, chore_id, skipped_at, created_at)
+            VALUES (?, ?, ?, ?)
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(id)
+        .bind(skipped_at)
+        .bind(Utc::now())
+        .execute(pool)
+        .await?;
+
+        // Like a completion, a skip ends the current occurrence and its snoozes
+        sqlx::query("UPDATE notification_snoozes SET snooze_count = 0 WHERE chore_id = ?")
+            .bind(id)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Pause or resume a chore. Returns `false` if the chore does not exist.
+    pub async fn set_active(pool: &SqlitePool, id: Uuid, is_active: bool) -> sqlx::Result<bool> {
+        let result = sqlx::query(
+            "UPDATE chores SET is_active = ?, updated_at = ? WHERE id = ? AND deleted_at IS NULL",
+        )
+        .bind(is_active)
+        .bind(Utc::now())
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Record that a chore was reviewed at `at`, restarting its review
+    /// interval. Returns `false` if there is no such chore.
+    pub async fn mark_reviewed(
+        pool: &SqlitePool,
+        id: Uuid,
+        at: DateTime<Utc>,
+    ) -> sqlx::Result<bool> {
+        let result = sqlx::query(
+            "UPDATE chores SET last_reviewed_at = ? WHERE id = ? AND deleted_at IS NULL",
+        )
+        .bind(at)
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Move a chore to the trash, recording the deletion as made by `actor`.
+    /// Its completions are kept until the chore is purged. Returns `false`
+    /// if there is no such chore or it is already in the trash.
+    pub async fn delete(pool: &SqlitePool, id: Uuid, actor: Option<Uuid>) -> sqlx::Result<bool> {
+        let mut tx = pool.begin().await?;
+
+        let deleted = sqlx::query_as::<_, ScheduleSnapshot>(
+            r#"
+            UPDATE chores SET deleted_at = ?
+            WHERE id = ? AND deleted_at IS NULL
+            RETURNING
+                schedule_type, cron_schedule,
+                interval_days, interval_time_hour, interval_time_minute,
+                days_of_week, due_at, timezone, anchor_mode
+            "#,
+        )
+        .bind(Utc::now())
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some(before) = deleted else {
+            return Ok(false);
+        };
+        AuditRepository::record(&mut tx, id, AuditAction::Delete, actor, Some(&before), None)
+            .await?;
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    /// Permanently delete a chore by ID, in the trash or not, along with its
+    /// completions
+    pub async fn purge(pool: &SqlitePool, id: Uuid) -> sqlx::Result<bool> {
         let result = sqlx::query("DELETE FROM chores WHERE id = ?")
             .bind(id)
             .execute(pool)
@@ -224,23 +918,143 @@ impl ChoreRepository {
         Ok(result.rows_affected() > 0)
     }
 
-    /// Get all chores with their last completion (for due calculation)
-    pub async fn list_all_with_last_completion(
+    /// Take a chore out of the trash. Restoring a chore that isn't in the
+    /// trash leaves it unchanged; returns `false` only if there is no such
+    /// chore.
+    pub async fn restore(pool: &SqlitePool, id: Uuid) -> sqlx::Result<bool> {
+        let result = sqlx::query("UPDATE chores SET deleted_at = NULL WHERE id = ?")
+            .bind(id)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Chores in the trash visible to `owner`, most recently deleted first
+    pub async fn list_trash(
         pool: &SqlitePool,
-    ) -> sqlx::Result<Vec<ChoreWithLastCompletion>> {
-        sqlx::query_as::<_, ChoreWithLastCompletion>(
+        owner: Option<Uuid>,
+    ) -> sqlx::Result<Vec<TrashedChore>> {
+        sqlx::query_as::<_, TrashedChore>(
             r#"
             SELECT
                 c.id, c.name, c.description,
                 c.schedule_type, c.cron_schedule,
                 c.interval_days, c.interval_time_hour, c.interval_time_minute,
+                c.days_of_week, c.due_at, c.timezone,
+                c.notify_lead_minutes, c.max_snoozes, c.estimated_minutes, c.review_interval_days, c.min_notify_interval_minutes, c.last_reviewed_at,
+                c.recipient, c.auto_complete_with,
+                c.allowed_completion_sources, c.external_id, c.priority, c.anchor_mode, c.is_active,
                 c.created_at, c.updated_at,
-                (SELECT MAX(completed_at) FROM completions WHERE chore_id = c.id) as last_completed_at
+                (SELECT MAX(completed_at) FROM completions WHERE chore_id = c.id) as last_completed_at,
+                (SELECT MAX(skipped_at) FROM chore_skips WHERE chore_id = c.id) as last_skipped_at,
+                (SELECT snooze_next_until FROM completions WHERE chore_id = c.id
+                    ORDER BY completed_at DESC, id DESC LIMIT 1) as next_snoozed_until,
+                c.deleted_at
             FROM chores c
-            ORDER BY c.name
+            WHERE c.deleted_at IS NOT NULL
+              AND (?1 IS NULL OR c.user_id IS NULL OR c.user_id = ?1)
+            ORDER BY c.deleted_at DESC, c.id
             "#,
         )
+        .bind(owner)
         .fetch_all(pool)
         .await
     }
+
+    /// Whether linking `chore_id` to auto-complete with `target_id` would form
+    /// a cycle, i.e. `target_id` already (transitively) auto-completes with
+    /// `chore_id`.
+    pub async fn auto_complete_creates_cycle(
+        pool: &SqlitePool,
+        chore_id: Uuid,
+        target_id: Uuid,
+    ) -> sqlx::Result<bool> {
+        sqlx::query_scalar::<_, bool>(
+            r#"
+            WITH RECURSIVE chain(id) AS (
+                SELECT ?
+                UNION
+                SELECT c.auto_complete_with
+                FROM chores c
+                INNER JOIN chain ON c.id = chain.id
+                WHERE c.auto_complete_with IS NOT NULL
+            )
+            SELECT EXISTS(SELECT 1 FROM chain WHERE id = ?)
+            "#,
+        )
+        .bind(target_id)
+        .bind(chore_id)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Every chore visible to `owner`, paused ones included, oldest first
+    pub async fn dump(pool: &SqlitePool, owner: Option<Uuid>) -> sqlx::Result<Vec<Chore>> {
+        sqlx::query_as::<_, Chore>(
+            r#"
+            SELECT
+                id, name, description,
+                schedule_type, cron_schedule,
+                interval_days, interval_time_hour, interval_time_minute,
+                days_of_week, due_at, timezone,
+                notify_lead_minutes, max_snoozes, estimated_minutes, review_interval_days, min_notify_interval_minutes, last_reviewed_at,
+                recipient, auto_complete_with,
+                allowed_completion_sources, external_id, priority, anchor_mode, is_active,
+                created_at, updated_at
+            FROM chores
+            WHERE deleted_at IS NULL
+              AND (?1 IS NULL OR user_id IS NULL OR user_id = ?1)
+            ORDER BY created_at, id
+            "#,
+        )
+        .bind(owner)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Get all chores with their last completion (for due calculation)
+    ///
+    /// With an `owner`, only that user's chores and unowned chores are returned.
+    /// Paused chores are skipped unless `include_paused` is set, and only
+    /// chores matching `tags` are returned.
+    pub async fn list_all_with_last_completion(
+        pool: &SqlitePool,
+        owner: Option<Uuid>,
+        include_paused: bool,
+        tags: &TagFilter,
+    ) -> sqlx::Result<Vec<ChoreWithLastCompletion>> {
+        let tag_condition = TagFilter::condition("?3", "?4");
+        let query = format!(
+            r#"
+            SELECT
+                c.id, c.name, c.description,
+                c.schedule_type, c.cron_schedule,
+                c.interval_days, c.interval_time_hour, c.interval_time_minute,
+                c.days_of_week, c.due_at, c.timezone,
+                c.notify_lead_minutes, c.max_snoozes, c.estimated_minutes, c.review_interval_days, c.min_notify_interval_minutes, c.last_reviewed_at,
+                c.recipient, c.auto_complete_with,
+                c.allowed_completion_sources, c.external_id, c.priority, c.anchor_mode, c.is_active,
+                c.created_at, c.updated_at,
+                (SELECT MAX(completed_at) FROM completions WHERE chore_id = c.id) as last_completed_at,
+                (SELECT MAX(skipped_at) FROM chore_skips WHERE chore_id = c.id) as last_skipped_at,
+                (SELECT snooze_next_until FROM completions WHERE chore_id = c.id
+                    ORDER BY completed_at DESC, id DESC LIMIT 1) as next_snoozed_until
+            FROM chores c
+            WHERE c.deleted_at IS NULL
+              AND (?2 OR c.is_active)
+              AND (?1 IS NULL OR c.user_id IS NULL OR c.user_id = ?1)
+              AND {tag_condition}
+            ORDER BY c.name
+            "#
+        );
+
+        sqlx::query_as::<_, ChoreWithLastCompletion>(&query)
+            .bind(owner)
+            .bind(include_paused)
+            .bind(tags.names_json())
+            .bind(tags.match_all())
+            .fetch_all(pool)
+            .await
+    }
 }
@@ -1,109 +1,766 @@
-use chrono::{DateTime, Utc};
-use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use chrono_tz::Tz;
+use croner::Cron;
+use sqlx::{SqliteConnection, SqlitePool};
 use uuid::Uuid;
 
-use super::models::Completion;
+use super::chores::ChoreCursor;
+use super::models::{Chore, Completion, CompletionSource, CompletionWithChore, ScheduleType};
 
 const DEFAULT_PAGE_SIZE: i64 = 20;
 
+/// Page of a chore's completions (`co`), newest first: `?1` is the chore id,
+/// `?2` the cursor and `?3` the limit
+const CHORE_COMPLETIONS_PAGE: &str = r#"
+    WHERE co.chore_id = ?1
+      AND (?4 IS NULL OR co.completed_at >= ?4)
+      AND (?5 IS NULL OR co.completed_at <= ?5)
+      AND (
+        ?2 IS NULL
+        OR co.completed_at < (SELECT completed_at FROM completions WHERE id = ?2)
+        OR (
+            co.completed_at = (SELECT completed_at FROM completions WHERE id = ?2)
+            AND co.id > ?2
+        )
+      )
+    ORDER BY co.completed_at DESC, co.id
+    LIMIT ?3
+"#;
+
+/// Note given to API completions recorded without one; `{time}` is replaced
+/// with the completion time
+#[derive(Debug, Clone)]
+pub struct CompletionNoteTemplate(pub String);
+
+impl CompletionNoteTemplate {
+    pub fn render(&self, completed_at: DateTime<Utc>) -> String {
+        self.0.replace(
+            "{time}",
+            &completed_at.format("%Y-%m-%d %H:%M UTC").to_string(),
+        )
+    }
+}
+
+/// Position in the completions feed after a given completion
+///
+/// Rendered like a [`ChoreCursor`], as `<id>.<hex-encoded completed_at>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionCursor {
+    pub completed_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl CompletionCursor {
+    /// Cursor pointing after `completion` in the feed
+    pub fn after(completion: &Completion) -> Self {
+        Self {
+            completed_at: completion.completed_at,
+            id: completion.id,
+        }
+    }
+}
+
+impl fmt::Display for CompletionCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        ChoreCursor {
+            id: self.id,
+            sort_value: Some(self.completed_at.to_rfc3339()),
+        }
+        .fmt(f)
+    }
+}
+
+impl FromStr for CompletionCursor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ChoreCursor { id, sort_value } = s.parse()?;
+        let completed_at = sort_value
+            .and_then(|value| DateTime::parse_from_rfc3339(&value).ok())
+            .ok_or_else(|| format!("Invalid cursor '{}'", s))?;
+
+        Ok(Self {
+            completed_at: completed_at.with_timezone(&Utc),
+            id,
+        })
+    }
+}
+
+/// Filters of the completions feed; every page of a feed must use the same
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CompletionFeedFilter<'a> {
+    /// First day (UTC, inclusive)
+    pub from: Option<NaiveDate>,
+    /// Last day (UTC, inclusive)
+    pub to: Option<NaiveDate>,
+    /// Only completions of chores carrying this tag
+    pub tag: Option<&'a str>,
+    /// Only completions of this user's chores and unowned chores
+    pub owner: Option<Uuid>,
+}
+
+/// Range of completion times a chore's completions are listed in; every
+/// page of a listing must use the same
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CompletedAtRange {
+    /// Earliest completion time (inclusive)
+    pub from: Option<DateTime<Utc>>,
+    /// Latest completion time (inclusive)
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// Window (in days) used for the recent completion count in [`CompletionStats`]
+pub const RECENT_COMPLETIONS_DAYS: i64 = 30;
+
+/// Aggregated completion statistics for a single chore
+#[derive(Debug, Clone)]
+pub struct CompletionStats {
+    pub total_completions: i64,
+    /// Consecutive on-schedule completions ending with the latest one.
+    /// Zero when the chore has since missed its window.
+    pub current_streak: i64,
+    pub longest_streak: i64,
+    pub completions_last_30_days: i64,
+    /// Mean time between consecutive completions, in seconds
+    pub average_interval_seconds: Option<i64>,
+    /// Longest time between consecutive completions, in seconds
+    pub longest_gap_seconds: Option<i64>,
+}
+
 pub struct CompletionRepository;
 
 impl CompletionRepository {
-    /// List completions for a chore with cursor-based pagination
+    /// List completions for a chore completed within `range`, newest first,
+    /// with cursor-based pagination
+    ///
+    /// The cursor is the id of the last completion of the previous page; the
+    /// page continues after that row's `(completed_at, id)` position.
     pub async fn list_for_chore(
         pool: &SqlitePool,
         chore_id: Uuid,
+        range: CompletedAtRange,
         cursor: Option<Uuid>,
         limit: Option<i64>,
     ) -> sqlx::Result<Vec<Completion>> {
         let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE);
 
-        match cursor {
-            Some(cursor_id) => {
-                sqlx::query_as::<_, Completion>(
-                    r#"
-                    SELECT id, chore_id, completed_at, notes, created_at
-                    FROM completions
-                    WHERE chore_id = ? AND id > ?
-                    ORDER BY completed_at DESC, id
-                    LIMIT ?
-                    "#,
-                )
-                .bind(chore_id)
-                .bind(cursor_id)
-                .bind(limit)
-                .fetch_all(pool)
-                .await
-            }
-            None => {
-                sqlx::query_as::<_, Completion>(
-                    r#"
-                    SELECT id, chore_id, completed_at, notes, created_at
-                    FROM completions
-                    WHERE chore_id = ?
-                    ORDER BY completed_at DESC, id
-                    LIMIT ?
-                    "#,
+        let query = format!(
+            r#"
+            SELECT co.id, co.chore_id, co.completed_at, co.notes, co.source, co.created_at
+            FROM completions co
+            {CHORE_COMPLETIONS_PAGE}
+            "#
+        );
+
+        sqlx::query_as::<_, Completion>(&query)
+            .bind(chore_id)
+            .bind(cursor)
+            .bind(limit)
+            .bind(range.from)
+            .bind(range.to)
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Same page as [`Self::list_for_chore`], with the chore joined in
+    pub async fn list_for_chore_with_chore(
+        pool: &SqlitePool,
+        chore_id: Uuid,
+        range: CompletedAtRange,
+        cursor: Option<Uuid>,
+        limit: Option<i64>,
+    ) -> sqlx::Result<Vec<CompletionWithChore>> {
+        let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE);
+
+        let query = format!(
+            r#"
+            SELECT co.id, co.chore_id, co.completed_at, co.notes, co.source, co.created_at,
+                   ch.name AS chore_name, ch.schedule_type AS chore_schedule_type
+            FROM completions co
+            JOIN chores ch ON ch.id = co.chore_id
+            {CHORE_COMPLETIONS_PAGE}
+            "#
+        );
+
+        sqlx::query_as::<_, CompletionWithChore>(&query)
+            .bind(chore_id)
+            .bind(cursor)
+            .bind(limit)
+            .bind(range.from)
+            .bind(range.to)
+            .fetch_all(pool)
+            .await
+    }
+
+    /// List completions across all chores, newest first, with the chore joined
+    ///
+    /// Ties in `completed_at` are broken by id, so a `cursor` from
+    /// [`CompletionCursor::after`] resumes exactly after the previous page.
+    pub async fn list_feed(
+        pool: &SqlitePool,
+        filter: CompletionFeedFilter<'_>,
+        cursor: Option<&CompletionCursor>,
+        limit: Option<i64>,
+    ) -> sqlx::Result<Vec<CompletionWithChore>> {
+        let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE);
+
+        sqlx::query_as::<_, CompletionWithChore>(
+            r#"
+            SELECT co.id, co.chore_id, co.completed_at, co.notes, co.source, co.created_at,
+                   ch.name AS chore_name, ch.schedule_type AS chore_schedule_type
+            FROM completions co
+            JOIN chores ch ON ch.id = co.chore_id AND ch.deleted_at IS NULL
+            WHERE (?1 IS NULL OR date(co.completed_at) >= ?1)
+              AND (?2 IS NULL OR date(co.completed_at) <= ?2)
+              AND (
+                ?3 IS NULL
+                OR EXISTS (
+                    SELECT 1
+                    FROM chore_tags ct
+                    INNER JOIN tags t ON t.id = ct.tag_id AND t.deleted_at IS NULL
+                    WHERE ct.chore_id = co.chore_id AND t.name = ?3 COLLATE NOCASE
                 )
-                .bind(chore_id)
-                .bind(limit)
-                .fetch_all(pool)
-                .await
-            }
-        }
+              )
+              AND (?4 IS NULL OR ch.user_id IS NULL OR ch.user_id = ?4)
+              AND (?5 IS NULL OR (co.completed_at, co.id) < (?5, ?6))
+            ORDER BY co.completed_at DESC, co.id DESC
+            LIMIT ?7
+            "#,
+        )
+        .bind(filter.from)
+        .bind(filter.to)
+        .bind(filter.tag)
+        .bind(filter.owner)
+        .bind(cursor.map(|c| c.completed_at))
+        .bind(cursor.map(|c| c.id))
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// List every completion of a chore, oldest first
+    pub async fn list_all_for_chore(
+        pool: &SqlitePool,
+        chore_id: Uuid,
+    ) -> sqlx::Result<Vec<Completion>> {
+        sqlx::query_as::<_, Completion>(
+            r#"
+            SELECT id, chore_id, completed_at, notes, source, created_at
+            FROM completions
+            WHERE chore_id = ?
+            ORDER BY completed_at, id
+            "#,
+        )
+        .bind(chore_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// List every completion, oldest first, with the chore joined
+    ///
+    /// With an `owner`, only completions of that user's chores and unowned
+    /// chores are returned.
+    pub async fn list_all(
+        pool: &SqlitePool,
+        owner: Option<Uuid>,
+    ) -> sqlx::Result<Vec<CompletionWithChore>> {
+        sqlx::query_as::<_, CompletionWithChore>(
+            r#"
+            SELECT co.id, co.chore_id, co.completed_at, co.notes, co.source, co.created_at,
+                   ch.name AS chore_name, ch.schedule_type AS chore_schedule_type
+            FROM completions co
+            JOIN chores ch ON ch.id = co.chore_id AND ch.deleted_at IS NULL
+            WHERE ?1 IS NULL OR ch.user_id IS NULL OR ch.user_id = ?1
+            ORDER BY co.completed_at, co.id
+            "#,
+        )
+        .bind(owner)
+        .fetch_all(pool)
+        .await
     }
 
     /// Create a new completion record
+    ///
+    /// API completions without `notes` get `default_note` rendered instead;
+    /// other sources keep the notes they were given.
     pub async fn create(
         pool: &SqlitePool,
         chore_id: Uuid,
         completed_at: Option<DateTime<Utc>>,
         notes: Option<&str>,
+        source: CompletionSource,
+        default_note: Option<&CompletionNoteTemplate>,
+    ) -> sqlx::Result<Completion> {
+        let mut tx = pool.begin().await?;
+        let completion = Self::insert(
+            &mut tx,
+            chore_id,
+            completed_at,
+            notes,
+            source,
+            default_note,
+            None,
+        )
+        .await?;
+        tx.commit().await?;
+        Ok(completion)
+    }
+
+    /// Insert a completion on an open connection, see [`Self::create`].
+    ///
+    /// `snooze_next_until` postpones the chore's next occurrence to at least
+    /// that time.
+    pub async fn insert(
+        conn: &mut SqliteConnection,
+        chore_id: Uuid,
+        completed_at: Option<DateTime<Utc>>,
+        notes: Option<&str>,
+        source: CompletionSource,
+        default_note: Option<&CompletionNoteTemplate>,
+        snooze_next_until: Option<DateTime<Utc>>,
     ) -> sqlx::Result<Completion> {
         let id = Uuid::new_v4();
         let now = Utc::now();
         let completed_at = completed_at.unwrap_or(now);
+        let notes = match (notes, source, default_note) {
+            (None, CompletionSource::Api, Some(template)) => Some(template.render(completed_at)),
+            _ => notes.map(String::from),
+        };
 
         sqlx::query(
             r#"
-            INSERT INTO completions (id, chore_id, completed_at, notes, created_at)
-            VALUES (?, ?, ?, ?, ?)
+            INSERT INTO completions
+                (id, chore_id, completed_at, notes, source, created_at, snooze_next_until)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(id)
         .bind(chore_id)
         .bind(completed_at)
-        .bind(notes)
+        .bind(&notes)
+        .bind(source)
         .bind(now)
-        .execute(pool)
+        .bind(snooze_next_until)
+        .execute(&mut *conn)
         .await?;
 
+        // A completion ends the current occurrence, so its snoozes no longer
+        // count towards the chore's snooze limit
+        sqlx::query("UPDATE notification_snoozes SET snooze_count = 0 WHERE chore_id = ?")
+            .bind(chore_id)
+            .execute(&mut *conn)
+            .await?;
+
         Ok(Completion {
             id,
             chore_id,
             completed_at,
-            notes: notes.map(String::from),
+            notes,
+            source,
             created_at: now,
         })
     }
 
-    /// Delete a completion by ID
-    pub async fn delete(pool: &SqlitePool, id: Uuid) -> sqlx::Result<bool> {
-        let result = sqlx::query("DELETE FROM completions WHERE id = ?")
+    /// Insert a completion restored from a backup, keeping its id and times.
+    ///
+    /// Returns `false`, writing nothing, when a completion with the same id
+    /// already exists.
+    pub async fn restore(
+        conn: &mut SqliteConnection,
+        completion: &Completion,
+    ) -> sqlx::Result<bool> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO completions (id, chore_id, completed_at, notes, source, created_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+            ON CONFLICT (id) DO NOTHING
+            "#,
+        )
+        .bind(completion.id)
+        .bind(completion.chore_id)
+        .bind(completion.completed_at)
+        .bind(&completion.notes)
+        .bind(completion.source)
+        .bind(completion.created_at)
+        .execute(conn)
+        .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    /// Record completions for every chore set to auto-complete with `chore_id`.
+    ///
+    /// Only direct dependents are completed; their own dependents are not.
+    /// Dependents that don't allow `source` are left alone.
+    pub async fn create_for_linked_chores(
+        conn: &mut SqliteConnection,
+        chore_id: Uuid,
+        completed_at: DateTime<Utc>,
+        source: CompletionSource,
+    ) -> sqlx::Result<Vec<Completion>> {
+        let linked = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            SELECT id FROM chores
+            WHERE auto_complete_with = ?1 AND id != ?1 AND deleted_at IS NULL
+              AND (
+                allowed_completion_sources IS NULL
+                OR instr(',' || allowed_completion_sources || ',', ',' || ?2 || ',') > 0
+              )
+            "#,
+        )
+        .bind(chore_id)
+        .bind(source)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut completions = Vec::with_capacity(linked.len());
+        for linked_id in linked {
+            completions.push(
+                Self::insert(
+                    &mut *conn,
+                    linked_id,
+                    Some(completed_at),
+                    Some("Auto-completed with linked chore"),
+                    source,
+                    None,
+                    None,
+                )
+                .await?,
+            );
+        }
+        Ok(completions)
+    }
+
+    /// Update a completion's time and notes. `None` leaves a field unchanged,
+    /// `Some(None)` clears the notes.
+    ///
+    /// Returns `None` if the completion doesn't exist, its chore is in the
+    /// trash or the chore isn't visible to `owner`.
+    pub async fn update(
+        pool: &SqlitePool,
+        id: Uuid,
+        owner: Option<Uuid>,
+        completed_at: Option<DateTime<Utc>>,
+        notes: Option<Option<&str>>,
+    ) -> sqlx::Result<Option<Completion>> {
+        let existing = sqlx::query_as::<_, Completion>(
+            r#"
+            SELECT co.id, co.chore_id, co.completed_at, co.notes, co.source, co.created_at
+            FROM completions co
+            JOIN chores ch ON ch.id = co.chore_id AND ch.deleted_at IS NULL
+            WHERE co.id = ?1 AND (?2 IS NULL OR ch.user_id IS NULL OR ch.user_id = ?2)
+            "#,
+        )
+        .bind(id)
+        .bind(owner)
+        .fetch_optional(pool)
+        .await?;
+
+        let Some(mut completion) = existing else {
+            return Ok(None);
+        };
+
+        if let Some(at) = completed_at {
+            completion.completed_at = at;
+        }
+        if let Some(n) = notes {
+            completion.notes = n.map(String::from);
+        }
+
+        sqlx::query("UPDATE completions SET completed_at = ?, notes = ? WHERE id = ?")
+            .bind(completion.completed_at)
+            .bind(&completion.notes)
             .bind(id)
             .execute(pool)
             .await?;
 
+        Ok(Some(completion))
+    }
+
+    /// The completion with the latest `completed_at` of a chore, the one its
+    /// `last_completed_at` reflects
+    pub async fn latest_for_chore(
+        pool: &SqlitePool,
+        chore_id: Uuid,
+    ) -> sqlx::Result<Option<Completion>> {
+        sqlx::query_as::<_, Completion>(
+            r#"
+            SELECT id, chore_id, completed_at, notes, source, created_at
+            FROM completions
+            WHERE chore_id = ?
+            ORDER BY completed_at DESC, id DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(chore_id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Delete a completion by ID if its chore is visible to `owner`.
+    /// Completions of chores in the trash are left alone, so they come back
+    /// when the chore is restored.
+    pub async fn delete(pool: &SqlitePool, id: Uuid, owner: Option<Uuid>) -> sqlx::Result<bool> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM completions
+            WHERE id = ?1
+              AND chore_id IN (
+                SELECT id FROM chores
+                WHERE deleted_at IS NULL
+                  AND (?2 IS NULL OR user_id IS NULL OR user_id = ?2)
+              )
+            "#,
+        )
+        .bind(id)
+        .bind(owner)
+        .execute(pool)
+        .await?;
+
         Ok(result.rows_affected() > 0)
     }
 
-    /// Check if a chore exists
-    pub async fn chore_exists(pool: &SqlitePool, chore_id: Uuid) -> sqlx::Result<bool> {
-        let result = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM chores WHERE id = ?")
-            .bind(chore_id)
-            .fetch_one(pool)
-            .await?;
+    /// Compute completion statistics for a chore.
+    ///
+    /// Streaks follow the chore's cadence: a completion continues the streak
+    /// when it happens before the previous completion's window closes (see
+    /// [`streak_deadline`]). `once_in_a_while` and `one_time` chores have no
+    /// cadence and therefore never build a streak.
+    pub async fn stats(
+        pool: &SqlitePool,
+        chore_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> sqlx::Result<CompletionStats> {
+        let chore = sqlx::query_as::<_, Chore>(
+            r#"
+            SELECT
+                id, name, description,
+                schedule_type, cron_schedule,
+                interval_days, interval_time_hour, interval_time_minute,
+                days_of_week, due_at, timezone,
+                notify_lead_minutes, max_snoozes, estimated_minutes, review_interval_days, min_notify_interval_minutes, last_reviewed_at,
+                recipient, auto_complete_with,
+                allowed_completion_sources, external_id, priority, anchor_mode, is_active,
+                created_at, updated_at
+            FROM chores WHERE id = ?
+            "#,
+        )
+        .bind(chore_id)
+        .fetch_one(pool)
+        .await?;
+
+        let completed = Self::completed_times(pool, chore_id).await?;
+
+        let recent_cutoff = now - Duration::days(RECENT_COMPLETIONS_DAYS);
+        let completions_last_30_days = completed.iter().filter(|c| **c >= recent_cutoff).count();
+
+        let gaps: Vec<i64> = completed
+            .windows(2)
+            .map(|pair| (pair[1] - pair[0]).num_seconds())
+            .collect();
+        let average_interval_seconds =
+            (!gaps.is_empty()).then(|| gaps.iter().sum::<i64>() / gaps.len() as i64);
+        let longest_gap_seconds = gaps.iter().copied().max();
+
+        let mut run = 0_i64;
+        let mut longest_streak = 0_i64;
+        let mut current_streak = 0_i64;
+        if !matches!(
+            chore.schedule_type,
+            ScheduleType::OnceInAWhile | ScheduleType::OneTime
+        ) {
+            let mut previous: Option<DateTime<Utc>> = None;
+            for completed_at in &completed {
+                let continues = previous
+                    .and_then(|prev| streak_deadline(&chore, prev))
+                    .is_some_and(|deadline| *completed_at <= deadline);
+                run = if continues { run + 1 } else { 1 };
+                longest_streak = longest_streak.max(run);
+                previous = Some(*completed_at);
+            }
+
+            if let Some(deadline) = previous.and_then(|last| streak_deadline(&chore, last))
+                && now <= deadline
+            {
+                current_streak = run;
+            }
+        }
+
+        Ok(CompletionStats {
+            total_completions: completed.len() as i64,
+            current_streak,
+            longest_streak,
+            completions_last_30_days: completions_last_30_days as i64,
+            average_interval_seconds,
+            longest_gap_seconds,
+        })
+    }
+
+    /// All completion times of a chore, oldest first
+    pub async fn completed_times(
+        pool: &SqlitePool,
+        chore_id: Uuid,
+    ) -> sqlx::Result<Vec<DateTime<Utc>>> {
+        sqlx::query_scalar(
+            r#"
+            SELECT completed_at
+            FROM completions
+            WHERE chore_id = ?
+            ORDER BY completed_at ASC
+            "#,
+        )
+        .bind(chore_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Completion times of several chores, oldest first, keyed by chore id
+    pub async fn completed_times_for_chores(
+        pool: &SqlitePool,
+        chore_ids: &[Uuid],
+    ) -> sqlx::Result<HashMap<Uuid, Vec<DateTime<Utc>>>> {
+        if chore_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        // SQLite doesn't support array binds, so we build placeholders
+        let placeholders: Vec<&str> = chore_ids.iter().map(|_| "?").collect();
+        let query = format!(
+            r#"
+            SELECT chore_id, completed_at
+            FROM completions
+            WHERE chore_id IN ({})
+            ORDER BY completed_at ASC
+            "#,
+            placeholders.join(", ")
+        );
+
+        let mut query_builder = sqlx::query_as::<_, (Uuid, DateTime<Utc>)>(&query);
+        for id in chore_ids {
+            query_builder = query_builder.bind(id);
+        }
+
+        let mut map: HashMap<Uuid, Vec<DateTime<Utc>>> = HashMap::new();
+        for (chore_id, completed_at) in query_builder.fetch_all(pool).await? {
+            map.entry(chore_id).or_default().push(completed_at);
+        }
+
+        Ok(map)
+    }
+
+    /// Count completions per UTC day within an inclusive date range,
+    /// optionally restricted to chores carrying the given tag. With an
+    /// `owner`, only that user's chores and unowned chores are counted.
+    /// Days without completions are omitted.
+    pub async fn daily_counts(
+        pool: &SqlitePool,
+        from: NaiveDate,
+        to: NaiveDate,
+        tag: Option<&str>,
+        owner: Option<Uuid>,
+    ) -> sqlx::Result<Vec<(NaiveDate, i64)>> {
+        sqlx::query_as::<_, (NaiveDate, i64)>(
+            r#"
+            SELECT date(c.completed_at) AS day, COUNT(*) AS count
+            FROM completions c
+            INNER JOIN chores ch ON ch.id = c.chore_id AND ch.deleted_at IS NULL
+            WHERE date(c.completed_at) BETWEEN ?1 AND ?2
+              AND (
+                ?3 IS NULL
+                OR EXISTS (
+                    SELECT 1
+                    FROM chore_tags ct
+                    INNER JOIN tags t ON t.id = ct.tag_id AND t.deleted_at IS NULL
+                    WHERE ct.chore_id = c.chore_id AND LOWER(t.name) = LOWER(?3)
+                )
+              )
+              AND (?4 IS NULL OR ch.user_id IS NULL OR ch.user_id = ?4)
+            GROUP BY day
+            ORDER BY day
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .bind(tag)
+        .bind(owner)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Check if a chore exists and is visible to `owner`: theirs or unowned
+    pub async fn chore_exists(
+        pool: &SqlitePool,
+        chore_id: Uuid,
+        owner: Option<Uuid>,
+    ) -> sqlx::Result<bool> {
+        let result = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT COUNT(*) FROM chores
+            WHERE id = ?1
+              AND deleted_at IS NULL
+              AND (?2 IS NULL OR user_id IS NULL OR user_id = ?2)
+            "#,
+        )
+        .bind(chore_id)
+        .bind(owner)
+        .fetch_one(pool)
+        .await?;
 
         Ok(result > 0)
     }
 }
+
+/// Latest time the next completion may happen and still continue a streak.
+///
+/// - Interval chores: by the end of the day the chore falls due again.
+/// - Cron chores: before the occurrence *after* the one that fell due, so each
+///   occurrence is matched by a completion.
+/// - Days-of-week chores: like cron, before the selected day after the one
+///   that fell due.
+/// - Once-in-a-while chores: no cadence, so `None`.
+fn streak_deadline(chore: &Chore, completed_at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    match chore.schedule_type {
+        ScheduleType::Interval => {
+            let days = i64::from(chore.interval_days?) + 1;
+            let day_after_due = completed_at.date_naive() + Duration::days(days);
+            Some(day_after_due.and_hms_opt(0, 0, 0)?.and_utc())
+        }
+        ScheduleType::Cron => {
+            let cron = Cron::new(chore.cron_schedule.as_deref()?).parse().ok()?;
+            let tz: Tz = chore
+                .timezone
+                .as_deref()
+                .and_then(|name| name.parse().ok())
+                .unwrap_or(Tz::UTC);
+            let due = cron
+                .find_next_occurrence(&completed_at.with_timezone(&tz), false)
+                .ok()?;
+            cron.find_next_occurrence(&due, false)
+                .ok()
+                .map(|next| next.with_timezone(&Utc))
+        }
+        ScheduleType::DaysOfWeek => {
+            let days: Vec<u32> = chore
+                .days_of_week
+                .as_deref()?
+                .split(',')
+                .filter_map(|d| d.trim().parse().ok())
+                .collect();
+            let next_selected = |after: NaiveDate| {
+                (1..=7)
+                    .map(|offset| after + Duration::days(offset))
+                    .find(|date| days.contains(&date.weekday().num_days_from_sunday()))
+            };
+            let due = next_selected(completed_at.date_naive())?;
+            let following = next_selected(due)?;
+            let hour = chore.interval_time_hour.unwrap_or(0) as u32;
+            let minute = chore.interval_time_minute.unwrap_or(0) as u32;
+            Some(following.and_hms_opt(hour, minute, 0)?.and_utc())
+        }
+        ScheduleType::OnceInAWhile | ScheduleType::OneTime => None,
+    }
+}
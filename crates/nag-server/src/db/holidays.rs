@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+
+use chrono::{NaiveDate, Utc};
+use sqlx::SqlitePool;
+
+use super::models::Holiday;
+
+pub struct HolidayRepository;
+
+impl HolidayRepository {
+    /// List all holidays ordered by date
+    pub async fn list(pool: &SqlitePool) -> sqlx::Result<Vec<Holiday>> {
+        sqlx::query_as::<_, Holiday>(
+            r#"
+            SELECT date, name, created_at
+            FROM holidays
+            ORDER BY date
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Load all holiday dates (for due calculation)
+    pub async fn list_dates(pool: &SqlitePool) -> sqlx::Result<HashSet<NaiveDate>> {
+        let dates = sqlx::query_scalar::<_, NaiveDate>("SELECT date FROM holidays")
+            .fetch_all(pool)
+            .await?;
+
+        Ok(dates.into_iter().collect())
+    }
+
+    /// Check if a holiday exists for the given date
+    pub async fn exists(pool: &SqlitePool, date: NaiveDate) -> sqlx::Result<bool> {
+        let result = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM holidays WHERE date = ?")
+            .bind(date)
+            .fetch_one(pool)
+            .await?;
+
+        Ok(result > 0)
+    }
+
+    /// Create a new holiday
+    pub async fn create(
+        pool: &SqlitePool,
+        date: NaiveDate,
+        name: Option<&str>,
+    ) -> sqlx::Result<Holiday> {
+        let now = Utc::now();
+
+        sqlx::query(
+            r#"
+            INSERT INTO holidays (date, name, created_at)
+            VALUES (?, ?, ?)
+            "#,
+        )
+        .bind(date)
+        .bind(name)
+        .bind(now)
+        .execute(pool)
+        .await?;
+
+        Ok(Holiday {
+            date,
+            name: name.map(String::from),
+            created_at: now,
+        })
+    }
+
+    /// Delete a holiday by date
+    pub async fn delete(pool: &SqlitePool, date: NaiveDate) -> sqlx::Result<bool> {
+        let result = sqlx::query("DELETE FROM holidays WHERE date = ?")
+            .bind(date)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use sqlx::SqlitePool;
+
+/// Applied vs. embedded migration state
+#[derive(Debug, Clone)]
+pub struct MigrationStatus {
+    /// Highest successfully applied migration version
+    pub current_version: Option<i64>,
+    /// Number of migrations recorded as applied
+    pub applied: usize,
+    /// Embedded migration versions not yet applied
+    pub pending: Vec<i64>,
+    /// Whether any migration was left partially applied
+    pub dirty: bool,
+}
+
+pub struct MigrationRepository;
+
+impl MigrationRepository {
+    /// Compare the `_sqlx_migrations` table against the embedded migrations
+    pub async fn status(pool: &SqlitePool) -> sqlx::Result<MigrationStatus> {
+        let rows = sqlx::query_as::<_, (i64, bool)>(
+            "SELECT version, success FROM _sqlx_migrations ORDER BY version",
+        )
+        .fetch_all(pool)
+        .await?;
+        let applied: HashMap<i64, bool> = rows.into_iter().collect();
+
+        let pending = sqlx::migrate!()
+            .iter()
+            .filter(|m| m.migration_type.is_up_migration())
+            .map(|m| m.version)
+            .filter(|version| !applied.contains_key(version))
+            .collect();
+
+        Ok(MigrationStatus {
+            current_version: applied
+                .iter()
+                .filter(|(_, success)| **success)
+                .map(|(version, _)| *version)
+                .max(),
+            applied: applied.len(),
+            pending,
+            dirty: applied.values().any(|success| !success),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::{Connection, SqliteConnection};
+    use uuid::Uuid;
+
+    /// Apply the embedded up migrations in `versions`, each in its own
+    /// transaction as `sqlx::migrate!().run` does
+    async fn apply(conn: &mut SqliteConnection, versions: impl Fn(i64) -> bool) {
+        for migration in sqlx::migrate!()
+            .iter()
+            .filter(|m| m.migration_type.is_up_migration() && versions(m.version))
+        {
+            let mut tx = conn.begin().await.expect("begin");
+            sqlx::raw_sql(&migration.sql)
+                .execute(&mut *tx)
+                .await
+                .unwrap_or_else(|e| panic!("migration {}: {e}", migration.version));
+            tx.commit().await.expect("commit");
+        }
+    }
+
+    async fn count(conn: &mut SqliteConnection, table: &str) -> i64 {
+        sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {table}"))
+            .fetch_one(conn)
+            .await
+            .expect("count rows")
+    }
+
+    #[tokio::test]
+    async fn test_upgrade_keeps_chore_data() {
+        // Before the first migration that rewrites a schema CHECK
+        const SEEDED_AT: i64 = 20260304090000;
+
+        let mut conn = SqliteConnection::connect("sqlite::memory:")
+            .await
+            .expect("connect");
+        apply(&mut conn, |version| version <= SEEDED_AT).await;
+
+        let (chore_id, tag_id, event_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        sqlx::raw_sql(&format!(
+            r#"
+            INSERT INTO chores (id, name, schedule_type, interval_days, created_at, updated_at)
+            VALUES (x'{chore}', 'Vacuum', 'interval', 7, '2026-03-01T09:00:00Z', '2026-03-01T09:00:00Z');
+            INSERT INTO completions (id, chore_id, completed_at, created_at)
+            VALUES (x'{completion}', x'{chore}', '2026-03-02T09:00:00Z', '2026-03-02T09:00:00Z');
+            INSERT INTO tags (id, name, created_at) VALUES (x'{tag}', 'cleaning', '2026-03-01T09:00:00Z');
+            INSERT INTO chore_tags (chore_id, tag_id) VALUES (x'{chore}', x'{tag}');
+            INSERT INTO notification_events (id, chore_id, event_type, due_at, title, body, created_at)
+            VALUES (x'{event}', x'{chore}', 'due', '2026-03-08T09:00:00Z', 'Chore due', 'Vacuum is due', '2026-03-08T09:00:00Z');
+            INSERT INTO notification_deliveries (id, event_id, channel, status, created_at, updated_at)
+            VALUES (x'{delivery}', x'{event}', 'telegram', 'delivered', '2026-03-08T09:00:00Z', '2026-03-08T09:00:00Z');
+            INSERT INTO notification_snoozes (chore_id, snoozed_until, created_at, updated_at)
+            VALUES (x'{chore}', '2026-03-09T09:00:00Z', '2026-03-08T09:00:00Z', '2026-03-08T09:00:00Z');
+            "#,
+            chore = chore_id.simple(),
+            completion = Uuid::new_v4().simple(),
+            tag = tag_id.simple(),
+            event = event_id.simple(),
+            delivery = Uuid::new_v4().simple(),
+        ))
+        .execute(&mut conn)
+        .await
+        .expect("seed data");
+
+        apply(&mut conn, |version| version > SEEDED_AT).await;
+
+        for table in [
+            "chores",
+            "completions",
+            "tags",
+            "chore_tags",
+            "notification_events",
+            "notification_deliveries",
+            "notification_snoozes",
+        ] {
+            assert_eq!(count(&mut conn, table).await, 1, "rows left in {table}");
+        }
+    }
+}
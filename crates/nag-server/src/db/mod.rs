@@ -1,5 +1,9 @@
+pub mod api_keys;
+pub mod audit;
 pub mod chores;
 pub mod completions;
+pub mod holidays;
+pub mod migrations;
 pub mod models;
 pub mod notifications;
 pub mod tags;
@@ -7,8 +11,12 @@ pub mod users;
 
 use sqlx::{SqlitePool, sqlite::SqlitePoolOptions};
 
+pub use api_keys::ApiKeyRepository;
+pub use audit::AuditRepository;
 pub use chores::ChoreRepository;
 pub use completions::CompletionRepository;
+pub use holidays::HolidayRepository;
+pub use migrations::MigrationRepository;
 pub use notifications::NotificationRepository;
 pub use tags::TagRepository;
 pub use users::UserRepository;
@@ -24,3 +32,9 @@ pub async fn create_pool(database_url: &str) -> color_eyre::Result<SqlitePool> {
 
     Ok(pool)
 }
+
+/// Run a trivial query to check that the database answers
+pub async fn ping(pool: &SqlitePool) -> sqlx::Result<()> {
+    sqlx::query("SELECT 1").execute(pool).await?;
+    Ok(())
+}
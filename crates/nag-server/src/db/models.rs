@@ -1,5 +1,8 @@
-use chrono::{DateTime, Utc};
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -14,6 +17,170 @@ pub enum ScheduleType {
     Interval,
     /// No fixed schedule — done whenever needed, completions are still logged
     OnceInAWhile,
+    /// Fixed weekdays (e.g., "every Tuesday and Friday")
+    DaysOfWeek,
+    /// A single occurrence at a fixed time; never due again once completed
+    OneTime,
+}
+
+/// Where a completion was recorded from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+pub enum CompletionSource {
+    /// The HTTP API, including the web UI
+    Api,
+    /// The "Mark done" button of a Telegram notification
+    Telegram,
+    /// A "done" reply to an email reminder
+    Email,
+}
+
+impl CompletionSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Api => "api",
+            Self::Telegram => "telegram",
+            Self::Email => "email",
+        }
+    }
+}
+
+impl FromStr for CompletionSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "api" => Ok(Self::Api),
+            "telegram" => Ok(Self::Telegram),
+            "email" => Ok(Self::Email),
+            other => Err(format!("unknown completion source '{other}'")),
+        }
+    }
+}
+
+/// How much a chore matters relative to others due at the same time
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    sqlx::Type,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+pub enum ChorePriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+impl FromStr for ChorePriority {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "low" => Ok(Self::Low),
+            "normal" => Ok(Self::Normal),
+            "high" => Ok(Self::High),
+            other => Err(format!(
+                "Unknown priority '{other}' (expected low, normal or high)"
+            )),
+        }
+    }
+}
+
+/// What the next occurrence of a cron or interval chore is counted from
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+pub enum ChoreAnchorMode {
+    /// The latest completion or skip
+    #[default]
+    FromCompletion,
+    /// The schedule as laid out from the chore's creation; a completion
+    /// counts for the occurrence nearest to it
+    FromSchedule,
+}
+
+impl FromStr for ChoreAnchorMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "from_completion" => Ok(Self::FromCompletion),
+            "from_schedule" => Ok(Self::FromSchedule),
+            other => Err(format!(
+                "Unknown anchor_mode '{other}' (expected from_completion or from_schedule)"
+            )),
+        }
+    }
+}
+
+/// Kind of change recorded in the audit log
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+}
+
+/// A chore's schedule at one point in time, as kept in the audit log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct ScheduleSnapshot {
+    pub schedule_type: ScheduleType,
+    pub cron_schedule: Option<String>,
+    pub interval_days: Option<i32>,
+    pub interval_time_hour: Option<i32>,
+    pub interval_time_minute: Option<i32>,
+    pub days_of_week: Option<String>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub anchor_mode: ChoreAnchorMode,
+}
+
+impl From<&Chore> for ScheduleSnapshot {
+    fn from(chore: &Chore) -> Self {
+        Self {
+            schedule_type: chore.schedule_type,
+            cron_schedule: chore.cron_schedule.clone(),
+            interval_days: chore.interval_days,
+            interval_time_hour: chore.interval_time_hour,
+            interval_time_minute: chore.interval_time_minute,
+            days_of_week: chore.days_of_week.clone(),
+            due_at: chore.due_at,
+            timezone: chore.timezone.clone(),
+            anchor_mode: chore.anchor_mode,
+        }
+    }
+}
+
+/// A change to a chore, as recorded in the audit log
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct AuditLogEntry {
+    pub id: Uuid,
+    pub chore_id: Uuid,
+    pub action: AuditAction,
+    /// Acting user; `None` when auth is disabled
+    pub user_id: Option<Uuid>,
+    /// Schedule before the change (`None` for creations)
+    pub schedule_before: Option<Json<ScheduleSnapshot>>,
+    /// Schedule after the change (`None` for deletions)
+    pub schedule_after: Option<Json<ScheduleSnapshot>>,
+    pub created_at: DateTime<Utc>,
 }
 
 /// A recurring chore/task
@@ -27,6 +194,39 @@ pub struct Chore {
     pub interval_days: Option<i32>,
     pub interval_time_hour: Option<i32>,
     pub interval_time_minute: Option<i32>,
+    /// Comma-separated weekdays for days-of-week schedules (0 = Sunday)
+    pub days_of_week: Option<String>,
+    /// Due time of a one-time chore
+    pub due_at: Option<DateTime<Utc>>,
+    /// IANA timezone cron schedules are evaluated in (UTC when `None`)
+    pub timezone: Option<String>,
+    /// Minutes before the due time to send the due notification
+    pub notify_lead_minutes: Option<i32>,
+    /// Times the current occurrence may be snoozed (unlimited when `None`)
+    pub max_snoozes: Option<i32>,
+    /// Minutes the chore is expected to take
+    pub estimated_minutes: Option<i32>,
+    /// Days between prompts to review whether the chore is still needed
+    /// (never prompted when `None`)
+    pub review_interval_days: Option<i32>,
+    /// Shortest time between two due notifications, in minutes (the
+    /// server's `NOTIFICATION_MIN_INTERVAL_MINUTES` when `None`)
+    pub min_notify_interval_minutes: Option<i32>,
+    /// When the chore was last reviewed; its review interval counts from
+    /// its creation until then
+    pub last_reviewed_at: Option<DateTime<Utc>>,
+    /// Named notification recipient (channel default when `None`)
+    pub recipient: Option<String>,
+    /// Completing this chore also completes the current one
+    pub auto_complete_with: Option<Uuid>,
+    /// Comma-separated sources allowed to complete the chore (any when `None`)
+    pub allowed_completion_sources: Option<String>,
+    /// Identifier assigned by an external system, unique across chores
+    pub external_id: Option<String>,
+    pub priority: ChorePriority,
+    pub anchor_mode: ChoreAnchorMode,
+    /// `false` while the chore is paused
+    pub is_active: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -38,9 +238,19 @@ pub struct Completion {
     pub chore_id: Uuid,
     pub completed_at: DateTime<Utc>,
     pub notes: Option<String>,
+    pub source: CompletionSource,
     pub created_at: DateTime<Utc>,
 }
 
+/// A completion with a summary of the chore it belongs to
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct CompletionWithChore {
+    #[sqlx(flatten)]
+    pub completion: Completion,
+    pub chore_name: String,
+    pub chore_schedule_type: ScheduleType,
+}
+
 /// Chore with its last completion time (for list queries)
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct ChoreWithLastCompletion {
@@ -52,9 +262,55 @@ pub struct ChoreWithLastCompletion {
     pub interval_days: Option<i32>,
     pub interval_time_hour: Option<i32>,
     pub interval_time_minute: Option<i32>,
+    /// Comma-separated weekdays for days-of-week schedules (0 = Sunday)
+    pub days_of_week: Option<String>,
+    /// Due time of a one-time chore
+    pub due_at: Option<DateTime<Utc>>,
+    /// IANA timezone cron schedules are evaluated in (UTC when `None`)
+    pub timezone: Option<String>,
+    /// Minutes before the due time to send the due notification
+    pub notify_lead_minutes: Option<i32>,
+    /// Times the current occurrence may be snoozed (unlimited when `None`)
+    pub max_snoozes: Option<i32>,
+    /// Minutes the chore is expected to take
+    pub estimated_minutes: Option<i32>,
+    /// Days between prompts to review whether the chore is still needed
+    /// (never prompted when `None`)
+    pub review_interval_days: Option<i32>,
+    /// Shortest time between two due notifications, in minutes (the
+    /// server's `NOTIFICATION_MIN_INTERVAL_MINUTES` when `None`)
+    pub min_notify_interval_minutes: Option<i32>,
+    /// When the chore was last reviewed; its review interval counts from
+    /// its creation until then
+    pub last_reviewed_at: Option<DateTime<Utc>>,
+    /// Named notification recipient (channel default when `None`)
+    pub recipient: Option<String>,
+    /// Completing this chore also completes the current one
+    pub auto_complete_with: Option<Uuid>,
+    /// Comma-separated sources allowed to complete the chore (any when `None`)
+    pub allowed_completion_sources: Option<String>,
+    /// Identifier assigned by an external system, unique across chores
+    pub external_id: Option<String>,
+    pub priority: ChorePriority,
+    pub anchor_mode: ChoreAnchorMode,
+    /// `false` while the chore is paused
+    pub is_active: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub last_completed_at: Option<DateTime<Utc>>,
+    /// Due time of the most recently skipped occurrence
+    pub last_skipped_at: Option<DateTime<Utc>>,
+    /// Time the occurrence after the latest completion was snoozed to when
+    /// completing it
+    pub next_snoozed_until: Option<DateTime<Utc>>,
+}
+
+/// A chore in the trash, with the time it was deleted
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct TrashedChore {
+    #[sqlx(flatten)]
+    pub chore: ChoreWithLastCompletion,
+    pub deleted_at: DateTime<Utc>,
 }
 
 /// A tag for categorizing chores
@@ -63,6 +319,16 @@ pub struct Tag {
     pub id: Uuid,
     pub name: String,
     pub color: Option<String>,
+    /// Whether due notifications are silenced for chores carrying the tag
+    pub muted: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A quiet day on which chores don't become overdue
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Holiday {
+    pub date: NaiveDate,
+    pub name: Option<String>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -79,30 +345,65 @@ pub struct User {
     pub updated_at: DateTime<Utc>,
 }
 
+/// An API key for machine clients (the raw key itself is never stored)
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ApiKey {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    /// Leading characters of the key, for display
+    pub prefix: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
 /// Notification event type
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
 #[serde(rename_all = "snake_case")]
 #[sqlx(type_name = "TEXT", rename_all = "snake_case")]
 pub enum NotificationEventType {
     Due,
+    /// Prompt to review whether a chore is still needed
+    Review,
+    /// Alert to operators about failing deliveries; never stored
+    OperatorAlert,
 }
 
 /// Notification delivery channel
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
 #[serde(rename_all = "snake_case")]
 #[sqlx(type_name = "TEXT", rename_all = "snake_case")]
 pub enum NotificationChannel {
     Telegram,
+    Discord,
+    Slack,
+}
+
+impl FromStr for NotificationChannel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "telegram" => Ok(Self::Telegram),
+            "discord" => Ok(Self::Discord),
+            "slack" => Ok(Self::Slack),
+            other => Err(format!("unknown notification channel '{other}'")),
+        }
+    }
 }
 
 /// Notification delivery status
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
 #[serde(rename_all = "snake_case")]
 #[sqlx(type_name = "TEXT", rename_all = "snake_case")]
 pub enum NotificationDeliveryStatus {
     Pending,
     Failed,
     Delivered,
+    /// No sender was registered for the channel; retried without counting
+    /// as an attempt
+    Skipped,
 }
 
 /// A unique notification event (deduplicated by chore + event + due time)
@@ -1,8 +1,71 @@
-use chrono::{DateTime, Utc};
-use sqlx::SqlitePool;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{Executor, Sqlite, SqlitePool};
 use uuid::Uuid;
 
-use super::models::{NotificationChannel, NotificationDeliveryStatus, NotificationEventType};
+use super::chores::{ChoreCursor, DEFAULT_PAGE_SIZE};
+use super::models::{
+    NotificationChannel, NotificationDelivery, NotificationDeliveryStatus, NotificationEvent,
+    NotificationEventType,
+};
+
+/// Position after an event in the event history: its due time and id
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotificationEventCursor {
+    pub due_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl NotificationEventCursor {
+    /// Cursor pointing after `event` in the history
+    pub fn after(event: &NotificationEvent) -> Self {
+        Self {
+            due_at: event.due_at,
+            id: event.id,
+        }
+    }
+}
+
+impl fmt::Display for NotificationEventCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        ChoreCursor {
+            id: self.id,
+            sort_value: Some(self.due_at.to_rfc3339()),
+        }
+        .fmt(f)
+    }
+}
+
+impl FromStr for NotificationEventCursor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ChoreCursor { id, sort_value } = s.parse()?;
+        let due_at = sort_value
+            .and_then(|value| DateTime::parse_from_rfc3339(&value).ok())
+            .ok_or_else(|| format!("Invalid cursor '{}'", s))?;
+
+        Ok(Self {
+            due_at: due_at.with_timezone(&Utc),
+            id,
+        })
+    }
+}
+
+/// Filters of the event history; every page of a listing must use the same
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NotificationEventFilter {
+    pub event_type: Option<NotificationEventType>,
+    /// Only events of chores visible to this user: theirs or unowned
+    pub owner: Option<Uuid>,
+    /// Earliest due time (inclusive)
+    pub from: Option<DateTime<Utc>>,
+    /// Latest due time (inclusive)
+    pub to: Option<DateTime<Utc>>,
+}
 
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct PendingNotification {
@@ -15,11 +78,81 @@ pub struct PendingNotification {
     pub due_at: DateTime<Utc>,
     pub title: String,
     pub body: String,
+    /// Named recipient configured on the chore
+    pub recipient: Option<String>,
+}
+
+/// A delivery joined with the event it belongs to
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ChoreNotificationDelivery {
+    pub delivery_id: Uuid,
+    pub event_id: Uuid,
+    pub chore_id: Uuid,
+    pub event_type: NotificationEventType,
+    pub due_at: DateTime<Utc>,
+    pub title: String,
+    pub channel: NotificationChannel,
+    pub status: NotificationDeliveryStatus,
+    pub attempt_count: i32,
+    pub last_error: Option<String>,
+    pub last_attempted_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// An event to raise, unique per chore and type by `dedup_at`
+struct NewNotificationEvent<'a> {
+    chore_id: Uuid,
+    event_type: NotificationEventType,
+    due_at: DateTime<Utc>,
+    dedup_at: DateTime<Utc>,
+    title: &'a str,
+    body: &'a str,
 }
 
 pub struct NotificationRepository;
 
 impl NotificationRepository {
+    /// Check whether the due event keyed by `dedup_at` was already raised
+    pub async fn due_event_exists(
+        pool: &SqlitePool,
+        chore_id: Uuid,
+        dedup_at: DateTime<Utc>,
+    ) -> sqlx::Result<bool> {
+        sqlx::query_scalar(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM notification_events
+                WHERE chore_id = ? AND event_type = ? AND dedup_at = ?
+            )
+            "#,
+        )
+        .bind(chore_id)
+        .bind(NotificationEventType::Due)
+        .bind(dedup_at)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// When the latest due event of a chore was raised
+    pub async fn last_due_event_at(
+        pool: &SqlitePool,
+        chore_id: Uuid,
+    ) -> sqlx::Result<Option<DateTime<Utc>>> {
+        sqlx::query_scalar::<_, DateTime<Utc>>(
+            r#"
+            SELECT created_at FROM notification_events
+            WHERE chore_id = ? AND event_type = ?
+            ORDER BY created_at DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(chore_id)
+        .bind(NotificationEventType::Due)
+        .fetch_optional(pool)
+        .await
+    }
+
     /// Create or fetch a unique due event and enqueue pending deliveries for channels.
     pub async fn upsert_due_event_with_deliveries(
         pool: &SqlitePool,
@@ -28,24 +161,89 @@ impl NotificationRepository {
         title: &str,
         body: &str,
         channels: &[NotificationChannel],
+    ) -> sqlx::Result<Uuid> {
+        Self::upsert_due_reminder_with_deliveries(
+            pool, chore_id, due_at, due_at, title, body, channels,
+        )
+        .await
+    }
+
+    /// Like [`Self::upsert_due_event_with_deliveries`], for a reminder of the
+    /// occurrence due at `due_at` that is deduplicated by `dedup_at` instead,
+    /// e.g. a repeated reminder or one re-armed by a snooze.
+    pub async fn upsert_due_reminder_with_deliveries(
+        pool: &SqlitePool,
+        chore_id: Uuid,
+        due_at: DateTime<Utc>,
+        dedup_at: DateTime<Utc>,
+        title: &str,
+        body: &str,
+        channels: &[NotificationChannel],
+    ) -> sqlx::Result<Uuid> {
+        Self::insert_event_with_deliveries(
+            pool,
+            NewNotificationEvent {
+                chore_id,
+                event_type: NotificationEventType::Due,
+                due_at,
+                dedup_at,
+                title,
+                body,
+            },
+            channels,
+        )
+        .await
+    }
+
+    /// Create or fetch a unique event of `event_type` keyed by `due_at` and
+    /// enqueue pending deliveries for channels.
+    pub async fn upsert_event_with_deliveries(
+        pool: &SqlitePool,
+        chore_id: Uuid,
+        event_type: NotificationEventType,
+        due_at: DateTime<Utc>,
+        title: &str,
+        body: &str,
+        channels: &[NotificationChannel],
+    ) -> sqlx::Result<Uuid> {
+        Self::insert_event_with_deliveries(
+            pool,
+            NewNotificationEvent {
+                chore_id,
+                event_type,
+                due_at,
+                dedup_at: due_at,
+                title,
+                body,
+            },
+            channels,
+        )
+        .await
+    }
+
+    async fn insert_event_with_deliveries(
+        pool: &SqlitePool,
+        event: NewNotificationEvent<'_>,
+        channels: &[NotificationChannel],
     ) -> sqlx::Result<Uuid> {
         let now = Utc::now();
-        let event_type = NotificationEventType::Due;
         let event_id = Uuid::new_v4();
 
         let insert = sqlx::query(
             r#"
-            INSERT INTO notification_events (id, chore_id, event_type, due_at, title, body, created_at)
-            VALUES (?, ?, ?, ?, ?, ?, ?)
-            ON CONFLICT(chore_id, event_type, due_at) DO NOTHING
+            INSERT INTO notification_events
+                (id, chore_id, event_type, due_at, dedup_at, title, body, created_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(chore_id, event_type, dedup_at) DO NOTHING
             "#,
         )
         .bind(event_id)
-        .bind(chore_id)
-        .bind(event_type)
-        .bind(due_at)
-        .bind(title)
-        .bind(body)
+        .bind(event.chore_id)
+        .bind(event.event_type)
+        .bind(event.due_at)
+        .bind(event.dedup_at)
+        .bind(event.title)
+        .bind(event.body)
         .bind(now)
         .execute(pool)
         .await?;
@@ -57,12 +255,12 @@ impl NotificationRepository {
                 r#"
                 SELECT id
                 FROM notification_events
-                WHERE chore_id = ? AND event_type = ? AND due_at = ?
+                WHERE chore_id = ? AND event_type = ? AND dedup_at = ?
                 "#,
             )
-            .bind(chore_id)
-            .bind(event_type)
-            .bind(due_at)
+            .bind(event.chore_id)
+            .bind(event.event_type)
+            .bind(event.dedup_at)
             .fetch_one(pool)
             .await?
         };
@@ -92,11 +290,15 @@ impl NotificationRepository {
         Ok(actual_event_id)
     }
 
-    /// Fetch pending and retryable failed deliveries.
+    /// List deliveries that are ready to be (re)sent.
+    ///
+    /// Failed and skipped deliveries are held back until their
+    /// `next_attempt_at`, see [`Self::mark_failed`].
     pub async fn list_pending(
         pool: &SqlitePool,
         limit: i64,
         max_attempts: i32,
+        now: DateTime<Utc>,
     ) -> sqlx::Result<Vec<PendingNotification>> {
         sqlx::query_as::<_, PendingNotification>(
             r#"
@@ -109,24 +311,365 @@ impl NotificationRepository {
                 e.event_type AS event_type,
                 e.due_at AS due_at,
                 e.title AS title,
-                e.body AS body
+                e.body AS body,
+                c.recipient AS recipient
             FROM notification_deliveries d
             INNER JOIN notification_events e ON e.id = d.event_id
+            INNER JOIN chores c ON c.id = e.chore_id AND c.deleted_at IS NULL
             WHERE
-                (d.status = ? OR d.status = ?)
+                d.status IN (?, ?, ?)
                 AND d.attempt_count < ?
+                AND (
+                    d.next_attempt_at IS NULL
+                    OR unixepoch(d.next_attempt_at) <= unixepoch(?)
+                )
             ORDER BY e.due_at ASC, d.created_at ASC
             LIMIT ?
             "#,
         )
         .bind(NotificationDeliveryStatus::Pending)
         .bind(NotificationDeliveryStatus::Failed)
+        .bind(NotificationDeliveryStatus::Skipped)
         .bind(max_attempts)
+        .bind(now)
         .bind(limit)
         .fetch_all(pool)
         .await
     }
 
+    /// List all deliveries for a chore's notification events, newest event first.
+    pub async fn list_for_chore(
+        pool: &SqlitePool,
+        chore_id: Uuid,
+    ) -> sqlx::Result<Vec<ChoreNotificationDelivery>> {
+        sqlx::query_as::<_, ChoreNotificationDelivery>(
+            r#"
+            SELECT
+                d.id AS delivery_id,
+                d.event_id AS event_id,
+                e.chore_id AS chore_id,
+                e.event_type AS event_type,
+                e.due_at AS due_at,
+                e.title AS title,
+                d.channel AS channel,
+                d.status AS status,
+                d.attempt_count AS attempt_count,
+                d.last_error AS last_error,
+                d.last_attempted_at AS last_attempted_at,
+                d.delivered_at AS delivered_at,
+                d.created_at AS created_at
+            FROM notification_deliveries d
+            INNER JOIN notification_events e ON e.id = d.event_id
+            WHERE e.chore_id = ?
+            ORDER BY e.due_at DESC, d.created_at DESC
+            "#,
+        )
+        .bind(chore_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// List deliveries across all chores visible to `owner`, newest first,
+    /// optionally filtered by status.
+    ///
+    /// The cursor is the id of the last delivery of the previous page.
+    pub async fn list_deliveries(
+        pool: &SqlitePool,
+        status: Option<NotificationDeliveryStatus>,
+        owner: Option<Uuid>,
+        cursor: Option<Uuid>,
+        limit: Option<i64>,
+    ) -> sqlx::Result<Vec<ChoreNotificationDelivery>> {
+        sqlx::query_as::<_, ChoreNotificationDelivery>(
+            r#"
+            SELECT
+                d.id AS delivery_id,
+                d.event_id AS event_id,
+                e.chore_id AS chore_id,
+                e.event_type AS event_type,
+                e.due_at AS due_at,
+                e.title AS title,
+                d.channel AS channel,
+                d.status AS status,
+                d.attempt_count AS attempt_count,
+                d.last_error AS last_error,
+                d.last_attempted_at AS last_attempted_at,
+                d.delivered_at AS delivered_at,
+                d.created_at AS created_at
+            FROM notification_deliveries d
+            INNER JOIN notification_events e ON e.id = d.event_id
+            INNER JOIN chores c ON c.id = e.chore_id
+            WHERE (?1 IS NULL OR d.status = ?1)
+              AND (?4 IS NULL OR c.user_id IS NULL OR c.user_id = ?4)
+              AND (
+                ?2 IS NULL
+                OR d.created_at < (SELECT created_at FROM notification_deliveries WHERE id = ?2)
+                OR (
+                    d.created_at = (SELECT created_at FROM notification_deliveries WHERE id = ?2)
+                    AND d.id > ?2
+                )
+              )
+            ORDER BY d.created_at DESC, d.id
+            LIMIT ?3
+            "#,
+        )
+        .bind(status)
+        .bind(cursor)
+        .bind(limit.unwrap_or(DEFAULT_PAGE_SIZE))
+        .bind(owner)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// List notification events matching `filter`, latest due time first.
+    ///
+    /// Ties in the due time are broken by id, so a `cursor` from
+    /// [`NotificationEventCursor::after`] resumes exactly after the previous
+    /// page.
+    pub async fn list_events(
+        pool: &SqlitePool,
+        filter: NotificationEventFilter,
+        cursor: Option<&NotificationEventCursor>,
+        limit: Option<i64>,
+    ) -> sqlx::Result<Vec<NotificationEvent>> {
+        sqlx::query_as::<_, NotificationEvent>(
+            r#"
+            SELECT e.id, e.chore_id, e.event_type, e.due_at, e.title, e.body, e.created_at
+            FROM notification_events e
+            INNER JOIN chores c ON c.id = e.chore_id
+            WHERE (?1 IS NULL OR e.event_type = ?1)
+              AND (?2 IS NULL OR e.due_at >= ?2)
+              AND (?3 IS NULL OR e.due_at <= ?3)
+              AND (?4 IS NULL OR (e.due_at, e.id) < (?4, ?5))
+              AND (?7 IS NULL OR c.user_id IS NULL OR c.user_id = ?7)
+            ORDER BY e.due_at DESC, e.id DESC
+            LIMIT ?6
+            "#,
+        )
+        .bind(filter.event_type)
+        .bind(filter.from)
+        .bind(filter.to)
+        .bind(cursor.map(|c| c.due_at))
+        .bind(cursor.map(|c| c.id))
+        .bind(limit.unwrap_or(DEFAULT_PAGE_SIZE))
+        .bind(filter.owner)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Deliveries of several events, keyed by event id, in channel order
+    pub async fn deliveries_for_events(
+        pool: &SqlitePool,
+        event_ids: &[Uuid],
+    ) -> sqlx::Result<HashMap<Uuid, Vec<NotificationDelivery>>> {
+        if event_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        // SQLite doesn't support array binds, so we build placeholders
+        let placeholders: Vec<&str> = event_ids.iter().map(|_| "?").collect();
+        let query = format!(
+            r#"
+            SELECT
+                id, event_id, channel, status, attempt_count,
+                last_error, last_attempted_at, delivered_at, created_at, updated_at
+            FROM notification_deliveries
+            WHERE event_id IN ({})
+            ORDER BY channel
+            "#,
+            placeholders.join(", ")
+        );
+
+        let mut query_builder = sqlx::query_as::<_, NotificationDelivery>(&query);
+        for id in event_ids {
+            query_builder = query_builder.bind(id);
+        }
+
+        let mut map: HashMap<Uuid, Vec<NotificationDelivery>> = HashMap::new();
+        for delivery in query_builder.fetch_all(pool).await? {
+            map.entry(delivery.event_id).or_default().push(delivery);
+        }
+
+        Ok(map)
+    }
+
+    /// Get a single delivery with its event, if its chore is visible to
+    /// `owner`
+    pub async fn get_delivery(
+        pool: &SqlitePool,
+        delivery_id: Uuid,
+        owner: Option<Uuid>,
+    ) -> sqlx::Result<Option<ChoreNotificationDelivery>> {
+        sqlx::query_as::<_, ChoreNotificationDelivery>(
+            r#"
+            SELECT
+                d.id AS delivery_id,
+                d.event_id AS event_id,
+                e.chore_id AS chore_id,
+                e.event_type AS event_type,
+                e.due_at AS due_at,
+                e.title AS title,
+                d.channel AS channel,
+                d.status AS status,
+                d.attempt_count AS attempt_count,
+                d.last_error AS last_error,
+                d.last_attempted_at AS last_attempted_at,
+                d.delivered_at AS delivered_at,
+                d.created_at AS created_at
+            FROM notification_deliveries d
+            INNER JOIN notification_events e ON e.id = d.event_id
+            INNER JOIN chores c ON c.id = e.chore_id
+            WHERE d.id = ?1 AND (?2 IS NULL OR c.user_id IS NULL OR c.user_id = ?2)
+            "#,
+        )
+        .bind(delivery_id)
+        .bind(owner)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Requeue a delivery as pending with a fresh attempt budget, to be sent
+    /// to every chat again.
+    ///
+    /// Returns `false` if the delivery does not exist or its chore isn't
+    /// visible to `owner`.
+    pub async fn reset_delivery(
+        pool: &SqlitePool,
+        delivery_id: Uuid,
+        owner: Option<Uuid>,
+    ) -> sqlx::Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE notification_deliveries
+            SET
+                status = ?1,
+                attempt_count = 0,
+                last_error = NULL,
+                last_attempted_at = NULL,
+                next_attempt_at = NULL,
+                updated_at = ?2
+            WHERE id = ?3
+              AND event_id IN (
+                SELECT e.id
+                FROM notification_events e
+                INNER JOIN chores c ON c.id = e.chore_id
+                WHERE ?4 IS NULL OR c.user_id IS NULL OR c.user_id = ?4
+              )
+            "#,
+        )
+        .bind(NotificationDeliveryStatus::Pending)
+        .bind(Utc::now())
+        .bind(delivery_id)
+        .bind(owner)
+        .execute(pool)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        Self::clear_delivered_chats(pool, delivery_id).await?;
+        Ok(true)
+    }
+
+    /// Snooze due notifications for a chore until the given time.
+    ///
+    /// An existing snooze is only ever advanced, never shortened. Each call
+    /// counts towards the chore's `max_snoozes`; returns `None` (leaving the
+    /// snooze untouched) once the limit is reached or if the chore is unknown.
+    pub async fn snooze_until<'e>(
+        executor: impl Executor<'e, Database = Sqlite>,
+        chore_id: Uuid,
+        until: DateTime<Utc>,
+    ) -> sqlx::Result<Option<DateTime<Utc>>> {
+        let now = Utc::now();
+        sqlx::query_scalar::<_, DateTime<Utc>>(
+            r#"
+            INSERT INTO notification_snoozes (chore_id, snoozed_until, snooze_count, created_at, updated_at)
+            SELECT c.id, ?, 1, ?, ?
+            FROM chores c
+            WHERE c.id = ? AND (c.max_snoozes IS NULL OR c.max_snoozes >= 1)
+            ON CONFLICT(chore_id) DO UPDATE SET
+                snoozed_until = MAX(snoozed_until, excluded.snoozed_until),
+                snooze_count = snooze_count + 1,
+                updated_at = excluded.updated_at
+            WHERE (SELECT max_snoozes FROM chores WHERE id = excluded.chore_id) IS NULL
+                OR snooze_count < (SELECT max_snoozes FROM chores WHERE id = excluded.chore_id)
+            RETURNING snoozed_until
+            "#,
+        )
+        .bind(until)
+        .bind(now)
+        .bind(now)
+        .bind(chore_id)
+        .fetch_optional(executor)
+        .await
+    }
+
+    /// Get the time a chore's notifications are (or were last) snoozed until.
+    pub async fn snoozed_until(
+        pool: &SqlitePool,
+        chore_id: Uuid,
+    ) -> sqlx::Result<Option<DateTime<Utc>>> {
+        sqlx::query_scalar::<_, DateTime<Utc>>(
+            "SELECT snoozed_until FROM notification_snoozes WHERE chore_id = ?",
+        )
+        .bind(chore_id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// When the generator called `name` last completed a run
+    pub async fn last_generator_run(
+        pool: &SqlitePool,
+        name: &str,
+    ) -> sqlx::Result<Option<DateTime<Utc>>> {
+        sqlx::query_scalar::<_, DateTime<Utc>>(
+            "SELECT last_run_at FROM generator_runs WHERE name = ?",
+        )
+        .bind(name)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Record that the generator called `name` completed a run at `at`
+    pub async fn record_generator_run(
+        pool: &SqlitePool,
+        name: &str,
+        at: DateTime<Utc>,
+    ) -> sqlx::Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO generator_runs (name, last_run_at)
+            VALUES (?, ?)
+            ON CONFLICT(name) DO UPDATE SET last_run_at = excluded.last_run_at
+            "#,
+        )
+        .bind(name)
+        .bind(at)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Check whether a chore's notifications are snoozed at `now`.
+    pub async fn is_snoozed(
+        pool: &SqlitePool,
+        chore_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> sqlx::Result<bool> {
+        Ok(Self::snoozed_until(pool, chore_id)
+            .await?
+            .is_some_and(|until| until > now))
+    }
+
+    /// Count deliveries waiting for their first attempt, or requeued for one
+    pub async fn count_pending(pool: &SqlitePool) -> sqlx::Result<i64> {
+        sqlx::query_scalar("SELECT COUNT(*) FROM notification_deliveries WHERE status = ?")
+            .bind(NotificationDeliveryStatus::Pending)
+            .fetch_one(pool)
+            .await
+    }
+
     pub async fn mark_delivered(pool: &SqlitePool, delivery_id: Uuid) -> sqlx::Result<()> {
         let now = Utc::now();
         sqlx::query(
@@ -151,31 +694,124 @@ impl NotificationRepository {
         Ok(())
     }
 
+    /// Record a failed attempt and schedule the next one.
+    ///
+    /// Retries back off exponentially: the `n`th failure holds the delivery
+    /// back for `retry_base_delay_seconds * 4^(n-1)`, i.e. 30s, 2m, 8m, ...
+    /// with the default 30s base.
     pub async fn mark_failed(
         pool: &SqlitePool,
         delivery_id: Uuid,
         error: &str,
+        retry_base_delay_seconds: i64,
     ) -> sqlx::Result<()> {
         let now = Utc::now();
+        // `attempt_count` still holds the count before this failure; the
+        // shift is capped so long-failing deliveries cannot overflow it
         sqlx::query(
             r#"
             UPDATE notification_deliveries
             SET
-                status = ?,
+                status = ?1,
                 attempt_count = attempt_count + 1,
+                last_error = ?2,
+                last_attempted_at = ?3,
+                next_attempt_at = strftime(
+                    '%Y-%m-%dT%H:%M:%SZ',
+                    unixepoch(?3) + ?4 * (1 << (2 * MIN(attempt_count, 15))),
+                    'unixepoch'
+                ),
+                updated_at = ?3
+            WHERE id = ?5
+            "#,
+        )
+        .bind(NotificationDeliveryStatus::Failed)
+        .bind(error)
+        .bind(now)
+        .bind(retry_base_delay_seconds)
+        .bind(delivery_id)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Record that no sender was available for a delivery.
+    ///
+    /// Unlike [`Self::mark_failed`] this does not count as an attempt, so the
+    /// delivery is retried once a sender for its channel is registered. The
+    /// retry waits a flat `retry_base_delay_seconds`.
+    pub async fn mark_skipped(
+        pool: &SqlitePool,
+        delivery_id: Uuid,
+        reason: &str,
+        retry_base_delay_seconds: i64,
+    ) -> sqlx::Result<()> {
+        let now = Utc::now();
+        sqlx::query(
+            r#"
+            UPDATE notification_deliveries
+            SET
+                status = ?,
                 last_error = ?,
                 last_attempted_at = ?,
+                next_attempt_at = ?,
                 updated_at = ?
             WHERE id = ?
             "#,
         )
-        .bind(NotificationDeliveryStatus::Failed)
-        .bind(error)
+        .bind(NotificationDeliveryStatus::Skipped)
+        .bind(reason)
         .bind(now)
+        .bind(now + Duration::seconds(retry_base_delay_seconds))
         .bind(now)
         .bind(delivery_id)
         .execute(pool)
         .await?;
         Ok(())
     }
+
+    /// Chats a delivery already reached on earlier attempts
+    pub async fn delivered_chats(
+        pool: &SqlitePool,
+        delivery_id: Uuid,
+    ) -> sqlx::Result<HashSet<i64>> {
+        let chat_ids = sqlx::query_scalar::<_, i64>(
+            "SELECT chat_id FROM notification_delivery_chats WHERE delivery_id = ?",
+        )
+        .bind(delivery_id)
+        .fetch_all(pool)
+        .await?;
+        Ok(chat_ids.into_iter().collect())
+    }
+
+    /// Remember that a delivery reached `chat_id`
+    pub async fn mark_chat_delivered(
+        pool: &SqlitePool,
+        delivery_id: Uuid,
+        chat_id: i64,
+    ) -> sqlx::Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO notification_delivery_chats (delivery_id, chat_id, delivered_at)
+            VALUES (?, ?, ?)
+            ON CONFLICT (delivery_id, chat_id) DO NOTHING
+            "#,
+        )
+        .bind(delivery_id)
+        .bind(chat_id)
+        .bind(Utc::now())
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Forget the chats a delivery reached, once it no longer needs retrying
+    /// or is to be sent to every chat again
+    pub async fn clear_delivered_chats(pool: &SqlitePool, delivery_id: Uuid) -> sqlx::Result<()> {
+        sqlx::query("DELETE FROM notification_delivery_chats WHERE delivery_id = ?")
+            .bind(delivery_id)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
 }
@@ -1,20 +1,73 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
 
 use chrono::Utc;
-use sqlx::SqlitePool;
+use sqlx::{Executor, Sqlite, SqliteConnection, SqlitePool};
 use uuid::Uuid;
 
 use super::models::Tag;
 
+/// Tag color keys (earthy palette)
+pub const TAG_PALETTE: &[&str] = &[
+    "terracotta",
+    "sage",
+    "ocean",
+    "amber",
+    "plum",
+    "clay",
+    "moss",
+    "slate",
+    "mauve",
+    "sand",
+];
+
+/// Palette color for a tag without an explicit one, derived from its
+/// lowercased name so it stays the same across renames that only change case
+///
+/// Uses the same string hash as the frontend.
+pub fn auto_tag_color(name: &str) -> &'static str {
+    let hash = name.to_lowercase().encode_utf16().fold(0i32, |hash, unit| {
+        hash.wrapping_mul(31).wrapping_add(i32::from(unit))
+    });
+    TAG_PALETTE[hash.unsigned_abs() as usize % TAG_PALETTE.len()]
+}
+
+/// How a tag created without a color gets one
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TagColorStrategy {
+    /// Leave the color unset; responses derive one from the tag's name
+    #[default]
+    Hash,
+    /// Pick the palette color the fewest tags have, earliest in the palette
+    /// on a tie, so that colors are spread evenly
+    LeastUsed,
+}
+
+impl FromStr for TagColorStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "hash" => Ok(Self::Hash),
+            "least_used" => Ok(Self::LeastUsed),
+            other => Err(format!(
+                "Unknown tag color strategy '{}' (expected hash or least_used)",
+                other
+            )),
+        }
+    }
+}
+
 pub struct TagRepository;
 
 impl TagRepository {
-    /// List all tags ordered by name
+    /// List all tags ordered by name, leaving out deleted ones
     pub async fn list(pool: &SqlitePool) -> sqlx::Result<Vec<Tag>> {
         sqlx::query_as::<_, Tag>(
             r#"
-            SELECT id, name, color, created_at
+            SELECT id, name, color, muted, created_at
             FROM tags
+            WHERE deleted_at IS NULL
             ORDER BY name
             "#,
         )
@@ -22,22 +75,46 @@ impl TagRepository {
         .await
     }
 
-    /// Find a tag by exact name (case-insensitive)
-    pub async fn find_by_name(pool: &SqlitePool, name: &str) -> sqlx::Result<Option<Tag>> {
+    /// Find a tag by exact name (case-insensitive), leaving out deleted ones
+    pub async fn find_by_name<'e>(
+        executor: impl Executor<'e, Database = Sqlite>,
+        name: &str,
+    ) -> sqlx::Result<Option<Tag>> {
         sqlx::query_as::<_, Tag>(
             r#"
-            SELECT id, name, color, created_at
+            SELECT id, name, color, muted, created_at
             FROM tags
-            WHERE LOWER(name) = LOWER(?)
+            WHERE LOWER(name) = LOWER(?) AND deleted_at IS NULL
             "#,
         )
         .bind(name)
-        .fetch_optional(pool)
+        .fetch_optional(executor)
+        .await
+    }
+
+    /// Find a deleted tag by exact name (case-insensitive)
+    pub async fn find_deleted_by_name<'e>(
+        executor: impl Executor<'e, Database = Sqlite>,
+        name: &str,
+    ) -> sqlx::Result<Option<Tag>> {
+        sqlx::query_as::<_, Tag>(
+            r#"
+            SELECT id, name, color, muted, created_at
+            FROM tags
+            WHERE LOWER(name) = LOWER(?) AND deleted_at IS NOT NULL
+            "#,
+        )
+        .bind(name)
+        .fetch_optional(executor)
         .await
     }
 
     /// Create a new tag
-    pub async fn create(pool: &SqlitePool, name: &str, color: Option<&str>) -> sqlx::Result<Tag> {
+    pub async fn create<'e>(
+        executor: impl Executor<'e, Database = Sqlite>,
+        name: &str,
+        color: Option<&str>,
+    ) -> sqlx::Result<Tag> {
         let id = Uuid::new_v4();
         let now = Utc::now();
 
@@ -51,18 +128,47 @@ impl TagRepository {
         .bind(name)
         .bind(color)
         .bind(now)
-        .execute(pool)
+        .execute(executor)
         .await?;
 
         Ok(Tag {
             id,
             name: name.to_string(),
             color: color.map(|c| c.to_string()),
+            muted: false,
             created_at: now,
         })
     }
 
-    /// Update a tag (color and/or name)
+    /// Color for a new tag without one under `strategy`
+    pub async fn auto_color<'e>(
+        executor: impl Executor<'e, Database = Sqlite>,
+        strategy: TagColorStrategy,
+    ) -> sqlx::Result<Option<&'static str>> {
+        if strategy == TagColorStrategy::Hash {
+            return Ok(None);
+        }
+
+        let counts: HashMap<String, i64> = sqlx::query_as::<_, (String, i64)>(
+            r#"
+            SELECT color, COUNT(*)
+            FROM tags
+            WHERE color IS NOT NULL AND deleted_at IS NULL
+            GROUP BY color
+            "#,
+        )
+        .fetch_all(executor)
+        .await?
+        .into_iter()
+        .collect();
+
+        Ok(TAG_PALETTE
+            .iter()
+            .min_by_key(|color| counts.get(**color).copied().unwrap_or(0))
+            .copied())
+    }
+
+    /// Update a tag (color and/or name); deleted tags are not updated
     pub async fn update(
         pool: &SqlitePool,
         id: Uuid,
@@ -81,7 +187,7 @@ impl TagRepository {
         if sets.is_empty() {
             // Nothing to update, just fetch current
             return sqlx::query_as::<_, Tag>(
-                "SELECT id, name, color, created_at FROM tags WHERE id = ?",
+                "SELECT id, name, color, muted, created_at FROM tags WHERE id = ? AND deleted_at IS NULL",
             )
             .bind(id)
             .fetch_optional(pool)
@@ -89,7 +195,7 @@ impl TagRepository {
         }
 
         let query_str = format!(
-            "UPDATE tags SET {} WHERE id = ? RETURNING id, name, color, created_at",
+            "UPDATE tags SET {} WHERE id = ? AND deleted_at IS NULL RETURNING id, name, color, muted, created_at",
             sets.join(", ")
         );
 
@@ -107,8 +213,23 @@ impl TagRepository {
         query.fetch_optional(pool).await
     }
 
-    /// Delete a tag by ID
+    /// Soft-delete a tag by ID, hiding it while keeping its chore
+    /// associations. Returns `false` if there is no such tag or it is
+    /// already deleted.
     pub async fn delete(pool: &SqlitePool, id: Uuid) -> sqlx::Result<bool> {
+        let result =
+            sqlx::query("UPDATE tags SET deleted_at = ? WHERE id = ? AND deleted_at IS NULL")
+                .bind(Utc::now())
+                .bind(id)
+                .execute(pool)
+                .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Permanently delete a tag by ID, deleted or not, along with its chore
+    /// associations
+    pub async fn purge(pool: &SqlitePool, id: Uuid) -> sqlx::Result<bool> {
         let result = sqlx::query("DELETE FROM tags WHERE id = ?")
             .bind(id)
             .execute(pool)
@@ -117,14 +238,114 @@ impl TagRepository {
         Ok(result.rows_affected() > 0)
     }
 
+    /// Move every chore tagged `source_id` over to `target_id` and delete the
+    /// source tag for good, returning the target. Returns `None`, changing
+    /// nothing, if either tag doesn't exist or is deleted.
+    pub async fn merge(
+        pool: &SqlitePool,
+        source_id: Uuid,
+        target_id: Uuid,
+    ) -> sqlx::Result<Option<Tag>> {
+        let mut tx = pool.begin().await?;
+
+        let target = sqlx::query_as::<_, Tag>(
+            "SELECT id, name, color, muted, created_at FROM tags WHERE id = ? AND deleted_at IS NULL",
+        )
+        .bind(target_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let source_exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM tags WHERE id = ? AND deleted_at IS NULL)",
+        )
+        .bind(source_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        let (Some(target), true) = (target, source_exists) else {
+            return Ok(None);
+        };
+
+        // Chores carrying both tags already have the target
+        sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO chore_tags (chore_id, tag_id)
+            SELECT chore_id, ? FROM chore_tags WHERE tag_id = ?
+            "#,
+        )
+        .bind(target_id)
+        .bind(source_id)
+        .execute(&mut *tx)
+        .await?;
+
+        // Takes the source's chore associations with it
+        sqlx::query("DELETE FROM tags WHERE id = ?")
+            .bind(source_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(target))
+    }
+
+    /// Bring back a deleted tag with its color and chore associations.
+    /// Restoring a tag that isn't deleted returns it unchanged.
+    pub async fn restore<'e>(
+        executor: impl Executor<'e, Database = Sqlite>,
+        id: Uuid,
+    ) -> sqlx::Result<Option<Tag>> {
+        sqlx::query_as::<_, Tag>(
+            r#"
+            UPDATE tags SET deleted_at = NULL
+            WHERE id = ?
+            RETURNING id, name, color, muted, created_at
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(executor)
+        .await
+    }
+
+    /// Mute or unmute a tag. Returns `None` if there is no such tag or it is
+    /// deleted.
+    pub async fn set_muted(pool: &SqlitePool, id: Uuid, muted: bool) -> sqlx::Result<Option<Tag>> {
+        sqlx::query_as::<_, Tag>(
+            r#"
+            UPDATE tags SET muted = ?
+            WHERE id = ? AND deleted_at IS NULL
+            RETURNING id, name, color, muted, created_at
+            "#,
+        )
+        .bind(muted)
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Ids of chores carrying at least one muted tag. Deleted tags don't
+    /// mute their chores.
+    pub async fn muted_chore_ids(pool: &SqlitePool) -> sqlx::Result<HashSet<Uuid>> {
+        let ids = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            SELECT DISTINCT ct.chore_id
+            FROM chore_tags ct
+            INNER JOIN tags t ON t.id = ct.tag_id
+            WHERE t.muted AND t.deleted_at IS NULL
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(ids.into_iter().collect())
+    }
+
     /// Get all tags for a specific chore
     pub async fn get_tags_for_chore(pool: &SqlitePool, chore_id: Uuid) -> sqlx::Result<Vec<Tag>> {
         sqlx::query_as::<_, Tag>(
             r#"
-            SELECT t.id, t.name, t.color, t.created_at
+            SELECT t.id, t.name, t.color, t.muted, t.created_at
             FROM tags t
             INNER JOIN chore_tags ct ON ct.tag_id = t.id
-            WHERE ct.chore_id = ?
+            WHERE ct.chore_id = ? AND t.deleted_at IS NULL
             ORDER BY t.name
             "#,
         )
@@ -146,10 +367,10 @@ impl TagRepository {
         let placeholders: Vec<&str> = chore_ids.iter().map(|_| "?").collect();
         let query = format!(
             r#"
-            SELECT ct.chore_id, t.id, t.name, t.color, t.created_at
+            SELECT ct.chore_id, t.id, t.name, t.color, t.muted, t.created_at
             FROM tags t
             INNER JOIN chore_tags ct ON ct.tag_id = t.id
-            WHERE ct.chore_id IN ({})
+            WHERE ct.chore_id IN ({}) AND t.deleted_at IS NULL
             ORDER BY t.name
             "#,
             placeholders.join(", ")
@@ -168,6 +389,7 @@ impl TagRepository {
                 id: row.id,
                 name: row.name,
                 color: row.color,
+                muted: row.muted,
                 created_at: row.created_at,
             });
         }
@@ -175,11 +397,38 @@ impl TagRepository {
         Ok(map)
     }
 
-    /// Find existing tags or create new ones by name.
+    /// Find a tag by name (case-insensitive), restoring it if it was deleted
+    async fn find_or_restore(conn: &mut SqliteConnection, name: &str) -> sqlx::Result<Option<Tag>> {
+        if let Some(existing) = Self::find_by_name(&mut *conn, name).await? {
+            return Ok(Some(existing));
+        }
+        match Self::find_deleted_by_name(&mut *conn, name).await? {
+            Some(deleted) => Self::restore(&mut *conn, deleted.id).await,
+            None => Ok(None),
+        }
+    }
+
+    /// Find a tag by name (case-insensitive), creating it with `color` if it
+    /// doesn't exist. An existing tag keeps its own color; a deleted one is
+    /// restored.
+    pub async fn find_or_create(
+        conn: &mut SqliteConnection,
+        name: &str,
+        color: Option<&str>,
+    ) -> sqlx::Result<Tag> {
+        match Self::find_or_restore(&mut *conn, name).await? {
+            Some(existing) => Ok(existing),
+            None => Self::create(&mut *conn, name, color).await,
+        }
+    }
+
+    /// Find existing tags or create new ones by name, coloring new tags by
+    /// `colors`.
     /// Returns all tags matching the given names (case-insensitive matching for existing tags).
     pub async fn find_or_create_tags(
-        pool: &SqlitePool,
+        conn: &mut SqliteConnection,
         names: &[String],
+        colors: TagColorStrategy,
     ) -> sqlx::Result<Vec<Tag>> {
         let mut tags = Vec::with_capacity(names.len());
 
@@ -189,37 +438,47 @@ impl TagRepository {
                 continue;
             }
 
-            match Self::find_by_name(pool, trimmed).await? {
-                Some(existing) => tags.push(existing),
+            let tag = match Self::find_or_restore(&mut *conn, trimmed).await? {
+                Some(existing) => existing,
                 None => {
-                    let new_tag = Self::create(pool, trimmed, None).await?;
-                    tags.push(new_tag);
+                    let color = Self::auto_color(&mut *conn, colors).await?;
+                    Self::create(&mut *conn, trimmed, color).await?
                 }
-            }
+            };
+            tags.push(tag);
         }
 
         Ok(tags)
     }
 
     /// Set the tags for a chore, replacing any existing tag assignments.
-    /// Creates new tags as needed (on-the-fly). Returns the final set of tags.
+    /// Creates new tags as needed (on-the-fly), colored by `colors`. Returns
+    /// the final set of tags.
     pub async fn set_chore_tags(
-        pool: &SqlitePool,
+        conn: &mut SqliteConnection,
         chore_id: Uuid,
         tag_names: &[String],
+        colors: TagColorStrategy,
     ) -> sqlx::Result<Vec<Tag>> {
-        // Remove all existing tag associations for this chore
-        sqlx::query("DELETE FROM chore_tags WHERE chore_id = ?")
-            .bind(chore_id)
-            .execute(pool)
-            .await?;
+        // Remove the chore's existing associations, keeping those with
+        // deleted tags so that they come back if the tag is restored
+        sqlx::query(
+            r#"
+            DELETE FROM chore_tags
+            WHERE chore_id = ?
+              AND tag_id IN (SELECT id FROM tags WHERE deleted_at IS NULL)
+            "#,
+        )
+        .bind(chore_id)
+        .execute(&mut *conn)
+        .await?;
 
         if tag_names.is_empty() {
             return Ok(Vec::new());
         }
 
         // Find or create all tags
-        let tags = Self::find_or_create_tags(pool, tag_names).await?;
+        let tags = Self::find_or_create_tags(&mut *conn, tag_names, colors).await?;
 
         // Insert junction rows
         for tag in &tags {
@@ -231,7 +490,7 @@ impl TagRepository {
             )
             .bind(chore_id)
             .bind(tag.id)
-            .execute(pool)
+            .execute(&mut *conn)
             .await?;
         }
 
@@ -246,5 +505,21 @@ struct ChoreTagRow {
     id: Uuid,
     name: String,
     color: Option<String>,
+    muted: bool,
     created_at: chrono::DateTime<chrono::Utc>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_tag_color_is_stable_per_name() {
+        assert_eq!(auto_tag_color("kitchen"), auto_tag_color("kitchen"));
+        assert_eq!(auto_tag_color("Kitchen"), auto_tag_color("kitchen"));
+
+        let names = ["kitchen", "garden", "weekly", "bathroom", "laundry", "pets"];
+        let colors: HashSet<_> = names.iter().map(|name| auto_tag_color(name)).collect();
+        assert!(colors.len() > 1, "all names mapped to {colors:?}");
+    }
+}
@@ -1,8 +1,73 @@
-use axum::Router;
-use time::Duration;
-use tower_http::{cors::CorsLayer, trace::TraceLayer};
+use std::any::Any;
+
+use axum::{
+    Router,
+    body::Body,
+    http::{HeaderName, Request},
+    response::{IntoResponse, Response},
+};
+use color_eyre::eyre::eyre;
+use tower_http::{
+    catch_panic::CatchPanicLayer,
+    cors::CorsLayer,
+    request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer},
+    trace::TraceLayer,
+};
 use tower_sessions::{Expiry, MemoryStore, SessionManagerLayer, SessionStore};
 
+use crate::http::{SessionSettings, models::AppError};
+
+/// Header carrying the id of a request, set on the request when the client
+/// didn't send one and echoed on the response
+const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Span of a request, carrying its id so that everything logged while
+/// handling it (panics included) can be traced back to it
+fn request_span(request: &Request<Body>) -> tracing::Span {
+    let request_id = request
+        .headers()
+        .get(&REQUEST_ID_HEADER)
+        .and_then(|id| id.to_str().ok())
+        .unwrap_or_default();
+    tracing::info_span!(
+        "request",
+        method = %request.method(),
+        uri = %request.uri(),
+        request_id,
+    )
+}
+
+/// Turn a handler panic into a 500 problem details response
+fn handle_panic(panic: Box<dyn Any + Send + 'static>) -> Response {
+    let message = panic
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| panic.downcast_ref::<&str>().copied())
+        .unwrap_or("unknown panic");
+    AppError::Internal(eyre!("Handler panicked: {message}")).into_response()
+}
+
+/// Layers shared by the app and test middleware, outermost last
+fn with_common_layers(router: Router) -> Router {
+    router
+        .layer(CatchPanicLayer::custom(handle_panic))
+        .layer(TraceLayer::new_for_http().make_span_with(request_span))
+        .layer(PropagateRequestIdLayer::new(REQUEST_ID_HEADER))
+        .layer(SetRequestIdLayer::new(REQUEST_ID_HEADER, MakeRequestUuid))
+        .layer(CorsLayer::permissive())
+}
+
+/// Session layer over `store` with the cookie configured by `settings`
+fn session_layer<S: SessionStore + Clone>(
+    store: S,
+    settings: SessionSettings,
+) -> SessionManagerLayer<S> {
+    SessionManagerLayer::new(store)
+        .with_secure(settings.secure)
+        .with_same_site(settings.same_site)
+        .with_expiry(Expiry::OnInactivity(settings.ttl))
+}
+
 /// Apply middleware layers to the router.
 ///
 /// When `session_store` is `Some`, the provided store is used for session
@@ -11,38 +76,84 @@ use tower_sessions::{Expiry, MemoryStore, SessionManagerLayer, SessionStore};
 pub fn apply_middleware(
     router: Router,
     session_store: Option<impl SessionStore + Clone>,
+    session: SessionSettings,
 ) -> Router {
-    let common = |r: Router| {
-        r.layer(TraceLayer::new_for_http())
-            .layer(CorsLayer::permissive())
-    };
-
     match session_store {
-        Some(store) => {
-            let session_layer = SessionManagerLayer::new(store)
-                .with_secure(false)
-                .with_same_site(tower_sessions::cookie::SameSite::Lax)
-                .with_expiry(Expiry::OnInactivity(Duration::days(1)));
-            common(router.layer(session_layer))
-        }
-        None => {
-            let session_layer = SessionManagerLayer::new(MemoryStore::default())
-                .with_secure(false)
-                .with_expiry(Expiry::OnInactivity(Duration::days(1)));
-            common(router.layer(session_layer))
-        }
+        Some(store) => with_common_layers(router.layer(session_layer(store, session))),
+        None => with_common_layers(router.layer(session_layer(MemoryStore::default(), session))),
     }
 }
 
 /// Apply middleware for test mode (in-memory sessions, no auth enforced).
 pub fn apply_test_middleware(router: Router) -> Router {
-    let session_store = MemoryStore::default();
-    let session_layer = SessionManagerLayer::new(session_store)
-        .with_secure(false)
-        .with_expiry(Expiry::OnInactivity(Duration::days(1)));
+    let session_layer = session_layer(MemoryStore::default(), SessionSettings::default());
 
-    router
-        .layer(session_layer)
-        .layer(TraceLayer::new_for_http())
-        .layer(CorsLayer::permissive())
+    with_common_layers(router.layer(session_layer))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{http::StatusCode, routing::get};
+    use axum_test::TestServer;
+    use tower_sessions::cookie::SameSite;
+
+    use super::*;
+
+    async fn panicking_handler() -> &'static str {
+        panic!("deliberate test panic")
+    }
+
+    #[tokio::test]
+    async fn test_handler_panic_becomes_problem_details() {
+        let router = Router::new().route("/panic", get(panicking_handler));
+        let server = TestServer::new(apply_test_middleware(router)).expect("test server");
+
+        let response = server.get("/panic").await;
+        response.assert_status(StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(response.header("x-request-id").to_str().is_ok());
+        assert_eq!(response.header("content-type"), "application/problem+json");
+
+        let problem: serde_json::Value = response.json();
+        assert_eq!(problem["status"], 500);
+        assert_eq!(problem["detail"], "An unexpected error occurred");
+    }
+
+    #[tokio::test]
+    async fn test_session_cookie_follows_settings() {
+        async fn start_session(session: tower_sessions::Session) -> &'static str {
+            session.insert("seen", true).await.expect("insert");
+            "ok"
+        }
+        let settings = SessionSettings {
+            secure: true,
+            same_site: SameSite::Strict,
+            ttl: time::Duration::hours(8),
+        };
+        let router = Router::new().route("/session", get(start_session));
+        let server = TestServer::new(apply_middleware(
+            router,
+            Some(MemoryStore::default()),
+            settings,
+        ))
+        .expect("test server");
+
+        let response = server.get("/session").await;
+        let cookie = response.header("set-cookie");
+        let cookie = cookie.to_str().expect("ascii cookie");
+        assert!(cookie.contains("Secure"), "{cookie}");
+        assert!(cookie.contains("SameSite=Strict"), "{cookie}");
+        assert!(cookie.contains("Max-Age=28800"), "{cookie}");
+    }
+
+    #[tokio::test]
+    async fn test_request_id_is_kept_from_the_client() {
+        let router = Router::new().route("/panic", get(panicking_handler));
+        let server = TestServer::new(apply_test_middleware(router)).expect("test server");
+
+        let response = server
+            .get("/panic")
+            .add_header("x-request-id", "abc-123")
+            .await;
+        assert_eq!(response.header("x-request-id"), "abc-123");
+    }
 }
@@ -6,10 +6,41 @@ mod static_files;
 use std::sync::Arc;
 
 use axum::Router;
+use metrics_exporter_prometheus::PrometheusHandle;
 use sqlx::SqlitePool;
-use tower_sessions::SessionStore;
+use tower_sessions::{SessionStore, cookie::SameSite};
 
-use crate::services::OidcService;
+use crate::db::{completions::CompletionNoteTemplate, tags::TagColorStrategy};
+use crate::services::{DueSort, EventGeneratorSettings, OidcService, ScheduleLimits};
+
+/// Session cookie settings
+#[derive(Debug, Clone, Copy)]
+pub struct SessionSettings {
+    /// Mark the cookie `Secure`, so browsers only send it over HTTPS
+    pub secure: bool,
+    pub same_site: SameSite,
+    /// Sessions expire after this long without a request
+    pub ttl: time::Duration,
+}
+
+impl Default for SessionSettings {
+    fn default() -> Self {
+        Self {
+            secure: false,
+            same_site: SameSite::Lax,
+            ttl: time::Duration::days(1),
+        }
+    }
+}
+
+/// How long after recording a completion it may still be undone
+#[derive(Debug, Clone, Copy)]
+pub struct UndoWindow(pub chrono::Duration);
+
+/// PIN that `DELETE` requests, tag merges and completion undos must present in
+/// the `X-Nag-Pin` header, even when authentication is disabled
+#[derive(Debug, Clone)]
+pub struct DestructiveActionPin(pub String);
 
 /// Shared application state
 #[derive(Clone)]
@@ -17,6 +48,27 @@ pub struct AppState {
     pub pool: SqlitePool,
     /// `None` when `AUTH_ENABLED=false` — API routes are unprotected.
     pub oidc: Option<Arc<OidcService>>,
+    /// Ordering of `/api/chores/due` when the request has no `sort`
+    pub due_default_sort: DueSort,
+    /// Renders the `/metrics` scrape
+    pub metrics: PrometheusHandle,
+    /// Shared secret of the inbound email webhook (disabled when `None`)
+    pub email_inbound_secret: Option<String>,
+    /// Note given to API completions recorded without one
+    pub completion_note_template: Option<CompletionNoteTemplate>,
+    /// Settings of the running due event generator (`None` when
+    /// notifications are disabled)
+    pub notification_generator: Option<EventGeneratorSettings>,
+    /// How tags created without a color get one
+    pub tag_color_strategy: TagColorStrategy,
+    /// Limit on undoing the latest completion (unlimited when `None`)
+    pub undo_window: Option<UndoWindow>,
+    /// How frequent chore schedules may be
+    pub schedule_limits: ScheduleLimits,
+    /// PIN required for deletes (not required when `None`)
+    pub destructive_action_pin: Option<DestructiveActionPin>,
+    /// Session cookie settings
+    pub session: SessionSettings,
 }
 
 /// Build the complete application with routes and middleware.
@@ -25,8 +77,9 @@ pub struct AppState {
 /// OIDC auth). When `None`, a lightweight no-op session layer is used so that
 /// the `Session` extractor never panics (even though no auth routes exist).
 pub fn build_app(state: AppState, session_store: Option<impl SessionStore + Clone>) -> Router {
-    let router = routes::app_router(state.clone()).fallback(static_files::static_handler);
-    middleware::apply_middleware(router, session_store)
+    let session = state.session;
+    let router = routes::app_router(state).fallback(static_files::static_handler);
+    middleware::apply_middleware(router, session_store, session)
 }
 
 /// Build the app without OIDC (for integration tests).
@@ -34,7 +87,30 @@ pub fn build_app(state: AppState, session_store: Option<impl SessionStore + Clon
 /// This skips the OIDC client setup and session-based auth.
 /// All API routes are accessible without authentication.
 pub fn build_test_app(pool: SqlitePool) -> Router {
-    let state = AppState { pool, oidc: None };
+    build_test_app_with_state(test_state(pool))
+}
+
+/// Default settings of the test app over `pool`, to be adjusted for
+/// [`build_test_app_with_state`]
+pub fn test_state(pool: SqlitePool) -> AppState {
+    AppState {
+        pool,
+        oidc: None,
+        due_default_sort: DueSort::default(),
+        metrics: crate::metrics::install_recorder().expect("install metrics recorder"),
+        email_inbound_secret: None,
+        completion_note_template: None,
+        notification_generator: None,
+        tag_color_strategy: TagColorStrategy::default(),
+        undo_window: None,
+        schedule_limits: ScheduleLimits::default(),
+        destructive_action_pin: None,
+        session: SessionSettings::default(),
+    }
+}
+
+/// Build the app without OIDC from `state`, like [`build_test_app`]
+pub fn build_test_app_with_state(state: AppState) -> Router {
     let router = routes::test_app_router(state).fallback(static_files::static_handler);
     middleware::apply_test_middleware(router)
 }
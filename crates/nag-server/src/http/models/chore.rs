@@ -1,10 +1,23 @@
-use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+
+use axum::{
+    Json,
+    http::{HeaderValue, Uri, header},
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-use crate::db::models::{Chore, ChoreWithLastCompletion, Completion, ScheduleType, Tag};
-use crate::services::ChoreWithDueInfo;
+use crate::db::completions::CompletionStats;
+use crate::db::models::{
+    AuditAction, AuditLogEntry, Chore, ChoreAnchorMode, ChorePriority, ChoreWithLastCompletion,
+    Completion, CompletionSource, CompletionWithChore, ScheduleSnapshot, ScheduleType, Tag,
+};
+use crate::services::{
+    ChoreService, ChoreWithDueInfo, CompletionStreak, CompletionTiming, DueSort,
+};
 
 use super::tag::TagResponse;
 
@@ -22,18 +35,86 @@ pub enum ScheduleInput {
         cron_schedule: String,
     },
     /// Relative schedule based on interval from last completion
+    ///
+    /// Numbers are also accepted as numeric strings (e.g. `"7"`).
     Interval {
         /// Number of days between occurrences (1-365)
+        #[serde(deserialize_with = "deserialize_lenient_i32")]
         interval_days: i32,
         /// Hour of day for reminder (0-23, optional)
-        #[serde(default)]
+        #[serde(default, deserialize_with = "deserialize_lenient_optional_i32")]
         interval_time_hour: Option<i32>,
         /// Minute of hour for reminder (0-59, optional)
-        #[serde(default)]
+        #[serde(default, deserialize_with = "deserialize_lenient_optional_i32")]
         interval_time_minute: Option<i32>,
     },
     /// No fixed schedule — done whenever needed, completions are still logged
     OnceInAWhile {},
+    /// Fixed weekdays, e.g. every Tuesday and Friday
+    ///
+    /// Also accepted as `weekly` with `weekdays` in place of `days`.
+    #[serde(alias = "weekly")]
+    DaysOfWeek {
+        /// Weekdays to repeat on (0-6, 0 = Sunday)
+        #[serde(alias = "weekdays")]
+        days: Vec<u8>,
+        /// Hour of day for reminder (0-23, optional)
+        #[serde(default)]
+        time_hour: Option<i32>,
+        /// Minute of hour for reminder (0-59, optional)
+        #[serde(default)]
+        time_minute: Option<i32>,
+    },
+    /// A single occurrence; never due again once completed
+    OneTime {
+        /// When the chore is due (must be in the future)
+        due_at: DateTime<Utc>,
+    },
+}
+
+/// Accepts an `i32` as a JSON number or as a numeric string
+struct LenientI32Visitor;
+
+impl serde::de::Visitor<'_> for LenientI32Visitor {
+    type Value = i32;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("an integer or a numeric string")
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<i32, E> {
+        i32::try_from(v).map_err(|_| E::invalid_value(serde::de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<i32, E> {
+        i32::try_from(v).map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<i32, E> {
+        v.trim()
+            .parse()
+            .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+    }
+}
+
+/// Deserialize an `i32` sent either as a number or as a numeric string
+/// (`"7"`), as loosely-typed form libraries do
+fn deserialize_lenient_i32<'de, D>(deserializer: D) -> Result<i32, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserializer.deserialize_any(LenientI32Visitor)
+}
+
+/// [`deserialize_lenient_i32`] for optional fields; `null` is `None`
+fn deserialize_lenient_optional_i32<'de, D>(deserializer: D) -> Result<Option<i32>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Lenient(#[serde(deserialize_with = "deserialize_lenient_i32")] i32);
+
+    Ok(Option::<Lenient>::deserialize(deserializer)?.map(|Lenient(value)| value))
 }
 
 /// Request body for creating a new chore
@@ -46,9 +127,50 @@ pub struct CreateChoreRequest {
     /// Schedule specification (either cron or interval)
     #[serde(flatten)]
     pub schedule: ScheduleInput,
+    /// IANA timezone the cron schedule is evaluated in (defaults to UTC)
+    #[serde(default)]
+    pub timezone: Option<String>,
     /// Optional list of tag names to assign
     #[serde(default)]
     pub tags: Vec<String>,
+    /// Send the due notification this many minutes before the chore is due
+    #[serde(default)]
+    pub notify_lead_minutes: Option<i32>,
+    /// How often the current occurrence may be snoozed (unlimited if omitted)
+    #[serde(default)]
+    pub max_snoozes: Option<i32>,
+    /// Minutes the chore is expected to take (1-1440, optional)
+    #[serde(default)]
+    pub estimated_minutes: Option<i32>,
+    /// Prompt to review whether the chore is still needed every this many
+    /// days (never if omitted)
+    #[serde(default)]
+    pub review_interval_days: Option<i32>,
+    /// Shortest time between two due notifications, in minutes (defaults to
+    /// the server setting)
+    #[serde(default)]
+    pub min_notify_interval_minutes: Option<i32>,
+    /// Named notification recipient (defaults to the channel's default chat)
+    #[serde(default)]
+    pub recipient: Option<String>,
+    /// Chore whose completion also completes this one
+    #[serde(default)]
+    pub auto_complete_with: Option<Uuid>,
+    /// Sources allowed to complete the chore (any if omitted)
+    #[serde(default)]
+    pub allowed_completion_sources: Option<Vec<CompletionSource>>,
+    /// `low`, `normal` or `high` (defaults to `normal`)
+    #[serde(default)]
+    #[schema(value_type = Option<ChorePriority>)]
+    pub priority: Option<String>,
+    /// `from_completion` or `from_schedule` (defaults to `from_completion`;
+    /// `from_schedule` is only valid for cron and interval chores)
+    #[serde(default)]
+    #[schema(value_type = Option<ChoreAnchorMode>)]
+    pub anchor_mode: Option<String>,
+    /// Create the chore paused when `false` (defaults to `true`)
+    #[serde(default, alias = "enabled")]
+    pub is_active: Option<bool>,
 }
 
 /// Request body for updating a chore
@@ -62,21 +184,89 @@ pub struct UpdateChoreRequest {
     /// New schedule (optional, replaces the entire schedule)
     #[serde(default)]
     pub schedule: Option<ScheduleInput>,
+    /// New IANA timezone (optional, use null to reset to UTC)
+    #[serde(default, deserialize_with = "deserialize_optional_field")]
+    pub timezone: Option<Option<String>>,
     /// New set of tag names (optional, replaces all tags when present)
     pub tags: Option<Vec<String>>,
+    /// New notification lead time in minutes (optional, use null to clear)
+    #[serde(default, deserialize_with = "deserialize_optional_field")]
+    pub notify_lead_minutes: Option<Option<i32>>,
+    /// New snooze limit (optional, use null for unlimited)
+    #[serde(default, deserialize_with = "deserialize_optional_field")]
+    pub max_snoozes: Option<Option<i32>>,
+    /// New time estimate in minutes (optional, use null to clear)
+    #[serde(default, deserialize_with = "deserialize_optional_field")]
+    pub estimated_minutes: Option<Option<i32>>,
+    /// New review interval in days (optional, use null to stop review prompts)
+    #[serde(default, deserialize_with = "deserialize_optional_field")]
+    pub review_interval_days: Option<Option<i32>>,
+    /// New minimum time between due notifications in minutes (optional, use
+    /// null to fall back to the server setting)
+    #[serde(default, deserialize_with = "deserialize_optional_field")]
+    pub min_notify_interval_minutes: Option<Option<i32>>,
+    /// New notification recipient (optional, use null to reset to the default)
+    #[serde(default, deserialize_with = "deserialize_optional_field")]
+    pub recipient: Option<Option<String>>,
+    /// New auto-complete link (optional, use null to unlink)
+    #[serde(default, deserialize_with = "deserialize_optional_field")]
+    pub auto_complete_with: Option<Option<Uuid>>,
+    /// New allowed completion sources (optional, use null to allow any)
+    #[serde(default, deserialize_with = "deserialize_optional_field")]
+    pub allowed_completion_sources: Option<Option<Vec<CompletionSource>>>,
+    /// New priority: `low`, `normal` or `high` (optional)
+    #[serde(default)]
+    #[schema(value_type = Option<ChorePriority>)]
+    pub priority: Option<String>,
+    /// New anchor mode: `from_completion` or `from_schedule` (optional)
+    #[serde(default)]
+    #[schema(value_type = Option<ChoreAnchorMode>)]
+    pub anchor_mode: Option<String>,
+    /// Pause (`false`) or resume (`true`) the chore (optional)
+    #[serde(default, alias = "enabled")]
+    pub is_active: Option<bool>,
+}
+
+impl From<CreateChoreRequest> for UpdateChoreRequest {
+    /// Replace every field of a chore, resetting omitted ones to their
+    /// creation defaults
+    fn from(body: CreateChoreRequest) -> Self {
+        Self {
+            name: Some(body.name),
+            description: Some(body.description),
+            schedule: Some(body.schedule),
+            timezone: Some(body.timezone),
+            tags: Some(body.tags),
+            notify_lead_minutes: Some(body.notify_lead_minutes),
+            max_snoozes: Some(body.max_snoozes),
+            estimated_minutes: Some(body.estimated_minutes),
+            review_interval_days: Some(body.review_interval_days),
+            min_notify_interval_minutes: Some(body.min_notify_interval_minutes),
+            recipient: Some(body.recipient),
+            auto_complete_with: Some(body.auto_complete_with),
+            allowed_completion_sources: Some(body.allowed_completion_sources),
+            priority: Some(body.priority.unwrap_or_else(|| "normal".to_string())),
+            anchor_mode: Some(
+                body.anchor_mode
+                    .unwrap_or_else(|| "from_completion".to_string()),
+            ),
+            is_active: Some(body.is_active.unwrap_or(true)),
+        }
+    }
 }
 
 /// Custom deserializer that distinguishes between:
 /// - Field absent → None
 /// - Field present with null → Some(None)
 /// - Field present with value → Some(Some(value))
-fn deserialize_optional_field<'de, D>(deserializer: D) -> Result<Option<Option<String>>, D::Error>
+fn deserialize_optional_field<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
 where
+    T: Deserialize<'de>,
     D: serde::Deserializer<'de>,
 {
     // If we get here, the field was present in the JSON
     // Now we just need to check if it was null or a value
-    let value: Option<String> = Option::deserialize(deserializer)?;
+    let value: Option<T> = Option::deserialize(deserializer)?;
     Ok(Some(value))
 }
 
@@ -87,26 +277,140 @@ pub struct CompleteChoreRequest {
     pub notes: Option<String>,
     /// When the chore was completed (defaults to now)
     pub completed_at: Option<DateTime<Utc>>,
+    /// Postpone the next occurrence, and its due notifications, to this time
+    pub snooze_next_until: Option<DateTime<Utc>>,
+}
+
+/// Request body for editing a completion
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateCompletionRequest {
+    /// New completion time (optional)
+    pub completed_at: Option<DateTime<Utc>>,
+    /// New notes (optional, use null to clear)
+    #[serde(default, deserialize_with = "deserialize_optional_field")]
+    pub notes: Option<Option<String>>,
+}
+
+/// Request body for previewing a schedule's upcoming occurrences
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PreviewScheduleRequest {
+    /// Schedule to preview
+    #[serde(flatten)]
+    pub schedule: ScheduleInput,
+    /// Number of occurrences to return (1-50, defaults to 5)
+    pub count: Option<usize>,
+}
+
+/// Query parameters for a chore's upcoming occurrences
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct NextOccurrencesQuery {
+    /// Number of occurrences to return (1-50, defaults to 5)
+    pub count: Option<usize>,
+}
+
+/// One upcoming occurrence of a chore
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OccurrenceResponse {
+    /// When the occurrence is due
+    pub at: DateTime<Utc>,
+    /// The same instant as wall-clock time in the chore's timezone, with its
+    /// UTC offset (RFC 3339)
+    pub local: String,
+}
+
+/// Request body for completing every overdue chore at once
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BulkCompleteDueRequest {
+    /// Only report what would be completed, without recording anything
+    #[serde(default)]
+    pub dry_run: bool,
+    /// When the chores were completed (defaults to now)
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Optional notes recorded on every completion
+    pub notes: Option<String>,
 }
 
 /// Query parameters for listing chores
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct ListChoresQuery {
+    /// Cursor for pagination (`next_cursor` of the previous page)
+    pub cursor: Option<String>,
+    /// Maximum number of items to return
+    pub limit: Option<i64>,
+    /// Filter by tag names, comma-separated (case-insensitive)
+    pub tag: Option<String>,
+    /// With several tags, whether chores need `any` (default) or `all` of them
+    pub tag_match: Option<String>,
+    /// Include paused chores
+    #[serde(default)]
+    pub include_paused: bool,
+    /// Also return the total number of matching chores
+    #[serde(default)]
+    pub with_total: bool,
+    /// Sort key: `name`, `created_at`, `updated_at`, `last_completed_at` or
+    /// `neglect` (defaults to id order)
+    pub sort: Option<String>,
+    /// Sort direction: `asc` or `desc` (defaults to `desc` for `neglect`,
+    /// `asc` otherwise)
+    pub dir: Option<String>,
+}
+
+/// Query parameters for searching chores
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SearchChoresQuery {
+    /// Text to find in chore names and descriptions (case-insensitive)
+    pub q: String,
     /// Cursor for pagination (UUID of last item)
     pub cursor: Option<Uuid>,
     /// Maximum number of items to return
     pub limit: Option<i64>,
-    /// Filter by tag name
-    pub tag: Option<String>,
+    /// Include paused chores
+    #[serde(default)]
+    pub include_paused: bool,
 }
 
 /// Query parameters for listing completions
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct ListCompletionsQuery {
-    /// Cursor for pagination (UUID of last item)
+    /// Cursor for pagination (UUID of the last item of the previous page)
     pub cursor: Option<Uuid>,
     /// Maximum number of items to return
     pub limit: Option<i64>,
+    /// Classify each completion against the occurrence it satisfied
+    #[serde(default)]
+    pub classify: bool,
+    /// Comma-separated related objects to embed; only `chore` is supported
+    pub expand: Option<String>,
+    /// Earliest completion time (inclusive)
+    pub from: Option<DateTime<Utc>>,
+    /// Latest completion time (inclusive)
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// Query parameters for the completions feed
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CompletionFeedQuery {
+    /// Cursor for pagination (`next_cursor` of the previous page)
+    pub cursor: Option<String>,
+    /// Maximum number of items to return
+    pub limit: Option<i64>,
+    /// First day (UTC, inclusive)
+    pub from: Option<NaiveDate>,
+    /// Last day (UTC, inclusive)
+    pub to: Option<NaiveDate>,
+    /// Filter by tag name
+    pub tag: Option<String>,
+}
+
+/// Query parameters for the completion heatmap and calendar
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CompletionHeatmapQuery {
+    /// First day (UTC, inclusive). Defaults to one year before `to`.
+    pub from: Option<NaiveDate>,
+    /// Last day (UTC, inclusive). Defaults to today.
+    pub to: Option<NaiveDate>,
+    /// Filter by tag name
+    pub tag: Option<String>,
 }
 
 /// Query parameters for due chores endpoint
@@ -115,8 +419,28 @@ pub struct DueChoresQuery {
     /// Include upcoming chores (not yet overdue)
     #[serde(default)]
     pub include_upcoming: bool,
-    /// Filter by tag name
+    /// Filter by tag names, comma-separated (case-insensitive)
     pub tag: Option<String>,
+    /// With several tags, whether chores need `any` (default) or `all` of them
+    pub tag_match: Option<String>,
+    /// Ordering (defaults to the server's `DUE_DEFAULT_SORT`)
+    pub sort: Option<DueSort>,
+}
+
+/// Query parameters for the daily effort budget
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct DayBudgetQuery {
+    /// Minutes available for chores today
+    pub limit_minutes: Option<i64>,
+}
+
+/// Query parameters for deleting a chore
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct DeleteChoreQuery {
+    /// Delete the chore and its completions for good instead of moving it to
+    /// the trash
+    #[serde(default)]
+    pub purge: bool,
 }
 
 // ============================================================================
@@ -129,7 +453,7 @@ pub struct ChoreResponse {
     pub id: Uuid,
     pub name: String,
     pub description: Option<String>,
-    /// Schedule type: "cron", "interval", or "once_in_a_while"
+    /// Schedule type: "cron", "interval", "once_in_a_while", "days_of_week", or "one_time"
     pub schedule_type: ScheduleType,
     /// Cron expression (present when schedule_type is "cron")
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -137,12 +461,46 @@ pub struct ChoreResponse {
     /// Interval in days (present when schedule_type is "interval")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub interval_days: Option<i32>,
-    /// Hour of day for interval reminders (0-23)
+    /// Hour of day for interval and days-of-week reminders (0-23)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub interval_time_hour: Option<i32>,
-    /// Minute of hour for interval reminders (0-59)
+    /// Minute of hour for interval and days-of-week reminders (0-59)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub interval_time_minute: Option<i32>,
+    /// Weekdays (0-6, 0 = Sunday; present when schedule_type is "days_of_week")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_of_week: Option<Vec<u8>>,
+    /// Due time (present when schedule_type is "one_time")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_at: Option<DateTime<Utc>>,
+    /// IANA timezone the cron schedule is evaluated in (null means UTC)
+    pub timezone: Option<String>,
+    /// Minutes before the due time the notification is sent
+    pub notify_lead_minutes: Option<i32>,
+    /// How often the current occurrence may be snoozed (null means unlimited)
+    pub max_snoozes: Option<i32>,
+    /// Minutes the chore is expected to take
+    pub estimated_minutes: Option<i32>,
+    /// Days between prompts to review whether the chore is still needed
+    /// (null means never)
+    pub review_interval_days: Option<i32>,
+    /// Shortest time between two due notifications, in minutes (null means
+    /// the server setting)
+    pub min_notify_interval_minutes: Option<i32>,
+    /// When the chore was last marked reviewed
+    pub last_reviewed_at: Option<DateTime<Utc>>,
+    /// Named notification recipient (null means the default chat)
+    pub recipient: Option<String>,
+    /// Chore whose completion also completes this one
+    pub auto_complete_with: Option<Uuid>,
+    /// Sources allowed to complete the chore (null means any)
+    pub allowed_completion_sources: Option<Vec<CompletionSource>>,
+    /// Identifier assigned by an external system (null when not synced)
+    pub external_id: Option<String>,
+    pub priority: ChorePriority,
+    pub anchor_mode: ChoreAnchorMode,
+    /// `false` while the chore is paused
+    pub is_active: bool,
     pub last_completed_at: Option<DateTime<Utc>>,
     /// Tags assigned to this chore
     pub tags: Vec<TagResponse>,
@@ -161,6 +519,28 @@ impl ChoreResponse {
             interval_days: chore.interval_days,
             interval_time_hour: chore.interval_time_hour,
             interval_time_minute: chore.interval_time_minute,
+            days_of_week: chore
+                .days_of_week
+                .as_deref()
+                .map(ChoreService::parse_days_of_week),
+            due_at: chore.due_at,
+            timezone: chore.timezone,
+            notify_lead_minutes: chore.notify_lead_minutes,
+            max_snoozes: chore.max_snoozes,
+            estimated_minutes: chore.estimated_minutes,
+            review_interval_days: chore.review_interval_days,
+            min_notify_interval_minutes: chore.min_notify_interval_minutes,
+            last_reviewed_at: chore.last_reviewed_at,
+            recipient: chore.recipient,
+            auto_complete_with: chore.auto_complete_with,
+            allowed_completion_sources: chore
+                .allowed_completion_sources
+                .as_deref()
+                .map(ChoreService::parse_completion_sources),
+            external_id: chore.external_id,
+            priority: chore.priority,
+            anchor_mode: chore.anchor_mode,
+            is_active: chore.is_active,
             last_completed_at: None,
             tags: tags.into_iter().map(TagResponse::from).collect(),
             created_at: chore.created_at,
@@ -178,6 +558,28 @@ impl ChoreResponse {
             interval_days: chore.interval_days,
             interval_time_hour: chore.interval_time_hour,
             interval_time_minute: chore.interval_time_minute,
+            days_of_week: chore
+                .days_of_week
+                .as_deref()
+                .map(ChoreService::parse_days_of_week),
+            due_at: chore.due_at,
+            timezone: chore.timezone,
+            notify_lead_minutes: chore.notify_lead_minutes,
+            max_snoozes: chore.max_snoozes,
+            estimated_minutes: chore.estimated_minutes,
+            review_interval_days: chore.review_interval_days,
+            min_notify_interval_minutes: chore.min_notify_interval_minutes,
+            last_reviewed_at: chore.last_reviewed_at,
+            recipient: chore.recipient,
+            auto_complete_with: chore.auto_complete_with,
+            allowed_completion_sources: chore
+                .allowed_completion_sources
+                .as_deref()
+                .map(ChoreService::parse_completion_sources),
+            external_id: chore.external_id,
+            priority: chore.priority,
+            anchor_mode: chore.anchor_mode,
+            is_active: chore.is_active,
             last_completed_at: chore.last_completed_at,
             tags: tags.into_iter().map(TagResponse::from).collect(),
             created_at: chore.created_at,
@@ -186,13 +588,21 @@ impl ChoreResponse {
     }
 }
 
+/// A chore in the trash
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TrashedChoreResponse {
+    #[serde(flatten)]
+    pub chore: ChoreResponse,
+    pub deleted_at: DateTime<Utc>,
+}
+
 /// Response for a chore with due information
 #[derive(Debug, Serialize, ToSchema)]
 pub struct ChoreWithDueResponse {
     pub id: Uuid,
     pub name: String,
     pub description: Option<String>,
-    /// Schedule type: "cron", "interval", or "once_in_a_while"
+    /// Schedule type: "cron", "interval", "once_in_a_while", "days_of_week", or "one_time"
     pub schedule_type: ScheduleType,
     /// Cron expression (present when schedule_type is "cron")
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -200,15 +610,39 @@ pub struct ChoreWithDueResponse {
     /// Interval in days (present when schedule_type is "interval")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub interval_days: Option<i32>,
-    /// Hour of day for interval reminders (0-23)
+    /// Hour of day for interval and days-of-week reminders (0-23)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub interval_time_hour: Option<i32>,
-    /// Minute of hour for interval reminders (0-59)
+    /// Minute of hour for interval and days-of-week reminders (0-59)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub interval_time_minute: Option<i32>,
+    /// Weekdays (0-6, 0 = Sunday; present when schedule_type is "days_of_week")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_of_week: Option<Vec<u8>>,
+    /// Due time (present when schedule_type is "one_time")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_at: Option<DateTime<Utc>>,
+    /// IANA timezone the cron schedule is evaluated in (null means UTC)
+    pub timezone: Option<String>,
+    /// Minutes before the due time the notification is sent
+    pub notify_lead_minutes: Option<i32>,
+    /// How often the current occurrence may be snoozed (null means unlimited)
+    pub max_snoozes: Option<i32>,
+    /// Minutes the chore is expected to take
+    pub estimated_minutes: Option<i32>,
+    /// Named notification recipient (null means the default chat)
+    pub recipient: Option<String>,
+    /// Chore whose completion also completes this one
+    pub auto_complete_with: Option<Uuid>,
+    pub priority: ChorePriority,
+    pub anchor_mode: ChoreAnchorMode,
     pub last_completed_at: Option<DateTime<Utc>>,
     pub next_due: Option<DateTime<Utc>>,
     pub is_overdue: bool,
+    /// Consecutive on-time completions ending with the latest one
+    pub current_streak: i64,
+    /// Longest run of consecutive on-time completions
+    pub best_streak: i64,
     /// Tags assigned to this chore
     pub tags: Vec<TagResponse>,
     pub created_at: DateTime<Utc>,
@@ -216,7 +650,7 @@ pub struct ChoreWithDueResponse {
 }
 
 impl ChoreWithDueResponse {
-    pub fn from_due_info(info: ChoreWithDueInfo, tags: Vec<Tag>) -> Self {
+    pub fn from_due_info(info: ChoreWithDueInfo, tags: Vec<Tag>, streak: CompletionStreak) -> Self {
         Self {
             id: info.chore.id,
             name: info.chore.name,
@@ -226,9 +660,25 @@ impl ChoreWithDueResponse {
             interval_days: info.chore.interval_days,
             interval_time_hour: info.chore.interval_time_hour,
             interval_time_minute: info.chore.interval_time_minute,
+            days_of_week: info
+                .chore
+                .days_of_week
+                .as_deref()
+                .map(ChoreService::parse_days_of_week),
+            due_at: info.chore.due_at,
+            timezone: info.chore.timezone,
+            notify_lead_minutes: info.chore.notify_lead_minutes,
+            max_snoozes: info.chore.max_snoozes,
+            estimated_minutes: info.chore.estimated_minutes,
+            recipient: info.chore.recipient,
+            auto_complete_with: info.chore.auto_complete_with,
+            priority: info.chore.priority,
+            anchor_mode: info.chore.anchor_mode,
             last_completed_at: info.chore.last_completed_at,
             next_due: info.next_due,
             is_overdue: info.is_overdue,
+            current_streak: streak.current,
+            best_streak: streak.best,
             tags: tags.into_iter().map(TagResponse::from).collect(),
             created_at: info.chore.created_at,
             updated_at: info.chore.updated_at,
@@ -243,7 +693,45 @@ pub struct CompletionResponse {
     pub chore_id: Uuid,
     pub completed_at: DateTime<Utc>,
     pub notes: Option<String>,
+    /// Where the completion was recorded from
+    pub source: CompletionSource,
     pub created_at: DateTime<Utc>,
+    /// Set when the completion also snoozed the next occurrence
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snoozed_until: Option<DateTime<Utc>>,
+    /// Early/on-time/late relative to the occurrence it satisfied
+    /// (only with `classify=true`, and only for scheduled chores)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timing: Option<CompletionTiming>,
+    /// Seconds between the occurrence and the completion; negative when early
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lateness_seconds: Option<i64>,
+    /// The chore this completion belongs to (only with `expand=chore`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chore: Option<CompletionChore>,
+}
+
+/// Minimal chore embedded in a completion
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CompletionChore {
+    pub id: Uuid,
+    pub name: String,
+    pub schedule_type: ScheduleType,
+}
+
+impl From<CompletionWithChore> for CompletionResponse {
+    fn from(row: CompletionWithChore) -> Self {
+        let chore = CompletionChore {
+            id: row.completion.chore_id,
+            name: row.chore_name,
+            schedule_type: row.chore_schedule_type,
+        };
+
+        Self {
+            chore: Some(chore),
+            ..Self::from(row.completion)
+        }
+    }
 }
 
 impl From<Completion> for CompletionResponse {
@@ -253,20 +741,241 @@ impl From<Completion> for CompletionResponse {
             chore_id: completion.chore_id,
             completed_at: completion.completed_at,
             notes: completion.notes,
+            source: completion.source,
             created_at: completion.created_at,
+            snoozed_until: None,
+            timing: None,
+            lateness_seconds: None,
+            chore: None,
         }
     }
 }
 
+/// An overdue chore (to be) completed by a bulk completion
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BulkCompletedChore {
+    pub chore_id: Uuid,
+    pub name: String,
+    /// When the chore fell due
+    pub due_at: DateTime<Utc>,
+    pub completed_at: DateTime<Utc>,
+    /// Recorded completion (`null` on a dry run)
+    pub completion_id: Option<Uuid>,
+}
+
+/// Result of completing every overdue chore
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BulkCompleteDueResponse {
+    pub dry_run: bool,
+    pub chores: Vec<BulkCompletedChore>,
+}
+
+/// A chore counted against the daily effort budget
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BudgetChore {
+    pub id: Uuid,
+    pub name: String,
+    pub priority: ChorePriority,
+    /// `null` when the chore has no estimate; it then counts as no time
+    pub estimated_minutes: Option<i32>,
+    pub next_due: DateTime<Utc>,
+}
+
+impl From<&ChoreWithDueInfo> for BudgetChore {
+    fn from(info: &ChoreWithDueInfo) -> Self {
+        Self {
+            id: info.chore.id,
+            name: info.chore.name.clone(),
+            priority: info.chore.priority,
+            estimated_minutes: info.chore.estimated_minutes,
+            next_due: info.next_due.unwrap_or_default(),
+        }
+    }
+}
+
+/// How today's due chores fit the effort budget
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DayBudgetResponse {
+    pub limit_minutes: i64,
+    /// Estimated minutes of all chores due today, overdue ones included
+    pub total_minutes: i64,
+    /// Whether the chores fit within `limit_minutes`
+    pub fits: bool,
+    /// Minutes over the budget (0 when the chores fit)
+    pub overflow_minutes: i64,
+    /// Chores due today, by due time
+    pub chores: Vec<BudgetChore>,
+    /// Chores to put off until the rest fits, lowest priority first
+    pub defer: Vec<BudgetChore>,
+}
+
+/// Completion statistics for a chore
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ChoreStatsResponse {
+    pub chore_id: Uuid,
+    /// Total number of recorded completions
+    pub total_completions: i64,
+    /// Consecutive on-schedule completions up to now (0 if the streak is broken)
+    pub current_streak: i64,
+    /// Longest run of consecutive on-schedule completions
+    pub longest_streak: i64,
+    /// Completions recorded in the last 30 days
+    pub completions_last_30_days: i64,
+    /// Mean time between consecutive completions, in seconds
+    /// (absent with fewer than two completions)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_interval_seconds: Option<i64>,
+    /// Longest time between consecutive completions, in seconds
+    /// (absent with fewer than two completions)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longest_gap_seconds: Option<i64>,
+    /// Completions done on or before the day they were due
+    pub on_time_completions: i64,
+    /// Completions done after the day they were due
+    pub late_completions: i64,
+}
+
+impl ChoreStatsResponse {
+    pub fn from_stats(chore_id: Uuid, stats: CompletionStats) -> Self {
+        Self {
+            chore_id,
+            total_completions: stats.total_completions,
+            current_streak: stats.current_streak,
+            longest_streak: stats.longest_streak,
+            completions_last_30_days: stats.completions_last_30_days,
+            average_interval_seconds: stats.average_interval_seconds,
+            longest_gap_seconds: stats.longest_gap_seconds,
+            on_time_completions: 0,
+            late_completions: 0,
+        }
+    }
+}
+
+/// One recorded change to a chore
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuditLogEntryResponse {
+    pub id: Uuid,
+    pub chore_id: Uuid,
+    pub action: AuditAction,
+    /// User who made the change (absent when auth is disabled)
+    pub user_id: Option<Uuid>,
+    /// Schedule before the change (absent for creations)
+    pub schedule_before: Option<ScheduleSnapshot>,
+    /// Schedule after the change (absent for deletions)
+    pub schedule_after: Option<ScheduleSnapshot>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<AuditLogEntry> for AuditLogEntryResponse {
+    fn from(entry: AuditLogEntry) -> Self {
+        Self {
+            id: entry.id,
+            chore_id: entry.chore_id,
+            action: entry.action,
+            user_id: entry.user_id,
+            schedule_before: entry.schedule_before.map(|s| s.0),
+            schedule_after: entry.schedule_after.map(|s| s.0),
+            created_at: entry.created_at,
+        }
+    }
+}
+
+/// Completion counts per day across all chores
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CompletionHeatmapResponse {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    /// Map of UTC date to number of completions (days without any are omitted)
+    pub counts: BTreeMap<NaiveDate, i64>,
+}
+
+/// Number of completions on one UTC day
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CompletionDayCount {
+    pub date: NaiveDate,
+    pub count: i64,
+}
+
 /// Paginated response wrapper
 #[derive(Debug, Serialize, ToSchema)]
 pub struct PaginatedResponse<T> {
     pub items: Vec<T>,
-    pub next_cursor: Option<Uuid>,
+    /// Opaque cursor for the next page
+    pub next_cursor: Option<String>,
+    /// Total number of matching items (only when requested with `with_total`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i64>,
 }
 
 impl<T> PaginatedResponse<T> {
-    pub fn new(items: Vec<T>, next_cursor: Option<Uuid>) -> Self {
-        Self { items, next_cursor }
+    pub fn new(items: Vec<T>, next_cursor: Option<String>) -> Self {
+        Self {
+            items,
+            next_cursor,
+            total: None,
+        }
+    }
+
+    pub fn with_total(mut self, total: Option<i64>) -> Self {
+        self.total = total;
+        self
+    }
+
+    /// Answer with an RFC 8288 `Link` header to the next page of `uri`, the
+    /// URI this page was requested with
+    pub fn linked(self, uri: Uri) -> LinkedPage<T> {
+        LinkedPage { page: self, uri }
     }
 }
+
+/// A page whose response also carries `Link: <...>; rel="next"`, so clients
+/// can follow pagination without reading `next_cursor` from the body
+pub struct LinkedPage<T> {
+    page: PaginatedResponse<T>,
+    uri: Uri,
+}
+
+impl<T: Serialize> IntoResponse for LinkedPage<T> {
+    fn into_response(self) -> Response {
+        let link = self
+            .page
+            .next_cursor
+            .as_deref()
+            .map(|cursor| format!("<{}>; rel=\"next\"", next_page_uri(&self.uri, cursor)))
+            .and_then(|link| HeaderValue::from_str(&link).ok());
+
+        let mut response = Json(self.page).into_response();
+        if let Some(link) = link {
+            response.headers_mut().insert(header::LINK, link);
+        }
+        response
+    }
+}
+
+/// `uri` with its `cursor` query parameter replaced by `cursor`, keeping all
+/// other parameters as sent
+fn next_page_uri(uri: &Uri, cursor: &str) -> String {
+    let mut params: Vec<String> = uri
+        .query()
+        .into_iter()
+        .flat_map(|query| query.split('&'))
+        .filter(|param| !param.is_empty() && param.split('=').next() != Some("cursor"))
+        .map(str::to_string)
+        .collect();
+    params.push(format!("cursor={}", percent_encode(cursor)));
+
+    format!("{}?{}", uri.path(), params.join("&"))
+}
+
+/// Percent-encode everything but RFC 3986 unreserved characters
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
@@ -27,6 +27,9 @@ pub enum AppError {
     #[error("Unprocessable entity: {0}")]
     UnprocessableEntity(String),
 
+    #[error("Service unavailable: {0}")]
+    ServiceUnavailable(String),
+
     #[error("Internal server error")]
     Internal(#[from] color_eyre::eyre::Error),
 }
@@ -40,6 +43,7 @@ impl AppError {
             AppError::Forbidden(_) => StatusCode::FORBIDDEN,
             AppError::Conflict(_) => StatusCode::CONFLICT,
             AppError::UnprocessableEntity(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            AppError::ServiceUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
             AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -52,6 +56,7 @@ impl AppError {
             AppError::Forbidden(_) => "https://httpstatuses.io/403",
             AppError::Conflict(_) => "https://httpstatuses.io/409",
             AppError::UnprocessableEntity(_) => "https://httpstatuses.io/422",
+            AppError::ServiceUnavailable(_) => "https://httpstatuses.io/503",
             AppError::Internal(_) => "https://httpstatuses.io/500",
         }
     }
@@ -64,6 +69,7 @@ impl AppError {
             AppError::Forbidden(_) => "Forbidden",
             AppError::Conflict(_) => "Conflict",
             AppError::UnprocessableEntity(_) => "Unprocessable Entity",
+            AppError::ServiceUnavailable(_) => "Service Unavailable",
             AppError::Internal(_) => "Internal Server Error",
         }
     }
@@ -0,0 +1,138 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::db::models::{
+    Chore, ChorePriority, Completion, CompletionSource, CompletionWithChore, ScheduleSnapshot, Tag,
+};
+
+/// Backup of all chores, tags and completions, as returned by
+/// `GET /api/export` and accepted by `POST /api/import`
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ExportDocument {
+    #[serde(default)]
+    pub tags: Vec<ExportedTag>,
+    #[serde(default)]
+    pub chores: Vec<ExportedChore>,
+    #[serde(default)]
+    pub completions: Vec<ExportedCompletion>,
+}
+
+/// A tag in an export document
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ExportedTag {
+    pub name: String,
+    pub color: Option<String>,
+}
+
+impl From<Tag> for ExportedTag {
+    fn from(tag: Tag) -> Self {
+        Self {
+            name: tag.name,
+            color: tag.color,
+        }
+    }
+}
+
+/// A chore in an export document
+///
+/// Chores are matched to tags by name. `auto_complete_with` links are left
+/// out, as the linked chore may not be part of the import.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ExportedChore {
+    /// Kept on import; a chore with this id that already exists is skipped.
+    /// Without an id the chore gets a new one.
+    pub id: Option<Uuid>,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(flatten)]
+    pub schedule: ScheduleSnapshot,
+    pub notify_lead_minutes: Option<i32>,
+    pub max_snoozes: Option<i32>,
+    pub estimated_minutes: Option<i32>,
+    pub review_interval_days: Option<i32>,
+    pub min_notify_interval_minutes: Option<i32>,
+    pub recipient: Option<String>,
+    pub allowed_completion_sources: Option<String>,
+    pub external_id: Option<String>,
+    #[serde(default)]
+    pub priority: ChorePriority,
+    #[serde(default = "default_is_active")]
+    pub is_active: bool,
+    /// Names of the chore's tags
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+fn default_is_active() -> bool {
+    true
+}
+
+impl ExportedChore {
+    pub fn from_chore(chore: &Chore, tags: Vec<Tag>) -> Self {
+        Self {
+            id: Some(chore.id),
+            name: chore.name.clone(),
+            description: chore.description.clone(),
+            schedule: ScheduleSnapshot::from(chore),
+            notify_lead_minutes: chore.notify_lead_minutes,
+            max_snoozes: chore.max_snoozes,
+            estimated_minutes: chore.estimated_minutes,
+            review_interval_days: chore.review_interval_days,
+            min_notify_interval_minutes: chore.min_notify_interval_minutes,
+            recipient: chore.recipient.clone(),
+            allowed_completion_sources: chore.allowed_completion_sources.clone(),
+            external_id: chore.external_id.clone(),
+            priority: chore.priority,
+            is_active: chore.is_active,
+            tags: tags.into_iter().map(|t| t.name).collect(),
+        }
+    }
+}
+
+/// A completion in an export document
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ExportedCompletion {
+    /// Kept on import; a completion with this id that already exists is
+    /// skipped. Without an id the completion gets a new one.
+    pub id: Option<Uuid>,
+    /// Id of a chore in the same document
+    pub chore_id: Uuid,
+    pub completed_at: DateTime<Utc>,
+    pub notes: Option<String>,
+    pub source: CompletionSource,
+}
+
+impl From<CompletionWithChore> for ExportedCompletion {
+    fn from(row: CompletionWithChore) -> Self {
+        let Completion {
+            id,
+            chore_id,
+            completed_at,
+            notes,
+            source,
+            ..
+        } = row.completion;
+        Self {
+            id: Some(id),
+            chore_id,
+            completed_at,
+            notes,
+            source,
+        }
+    }
+}
+
+/// Result of an import
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ImportResponse {
+    pub chores_created: usize,
+    /// Chores whose id already exists
+    pub chores_skipped: usize,
+    pub completions_created: usize,
+    /// Completions whose id already exists
+    pub completions_skipped: usize,
+    /// Tags in the document, whether created or matched to an existing tag
+    pub tags_imported: usize,
+}
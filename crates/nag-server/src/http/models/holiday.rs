@@ -0,0 +1,40 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::db::models::Holiday;
+
+// ============================================================================
+// Request DTOs
+// ============================================================================
+
+/// Request body for adding a holiday
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateHolidayRequest {
+    /// Calendar date of the holiday in each chore's timezone, e.g. "2026-12-25"
+    pub date: NaiveDate,
+    /// Optional label (e.g. "Christmas")
+    pub name: Option<String>,
+}
+
+// ============================================================================
+// Response DTOs
+// ============================================================================
+
+/// Response for a single holiday
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HolidayResponse {
+    pub date: NaiveDate,
+    pub name: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Holiday> for HolidayResponse {
+    fn from(holiday: Holiday) -> Self {
+        Self {
+            date: holiday.date,
+            name: holiday.name,
+            created_at: holiday.created_at,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::CompletionResponse;
+
+// ============================================================================
+// Request DTOs
+// ============================================================================
+
+/// Inbound email as posted by the mail provider (Mailgun field names)
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct InboundEmailRequest {
+    /// Address the reply was sent to, e.g. `nag+<chore id>@example.com`
+    pub recipient: String,
+    /// Address the reply came from
+    #[serde(default)]
+    pub sender: Option<String>,
+    /// Reply text with quoted parts and signature removed
+    #[serde(default, rename = "stripped-text")]
+    pub stripped_text: Option<String>,
+    /// Full plain-text body, used when `stripped-text` is missing
+    #[serde(default, rename = "body-plain")]
+    pub body_plain: Option<String>,
+}
+
+/// Query parameters of the inbound email webhook
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct InboundEmailQuery {
+    /// Shared secret, for providers that cannot send custom headers
+    pub secret: Option<String>,
+}
+
+// ============================================================================
+// Response DTOs
+// ============================================================================
+
+/// Outcome of an inbound email
+#[derive(Debug, Serialize, ToSchema)]
+pub struct InboundEmailResponse {
+    /// Whether the reply completed the chore
+    pub completed: bool,
+    /// The recorded completion (absent when the reply was not "done")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completion: Option<CompletionResponse>,
+}
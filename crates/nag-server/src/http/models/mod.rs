@@ -1,8 +1,16 @@
 mod chore;
 mod error;
+mod export;
+mod holiday;
+mod integration;
+mod notification;
 mod tag;
 
 pub use chore::*;
 #[allow(unused_imports)]
 pub use error::{AppError, AppResult, ProblemDetailsSchema};
+pub use export::*;
+pub use holiday::*;
+pub use integration::*;
+pub use notification::*;
 pub use tag::*;
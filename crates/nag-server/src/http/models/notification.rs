@@ -0,0 +1,197 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::db::models::{
+    NotificationChannel, NotificationDelivery, NotificationDeliveryStatus, NotificationEvent,
+    NotificationEventType,
+};
+use crate::db::notifications::ChoreNotificationDelivery;
+use crate::services::notifications::SimulatedDueEvent;
+
+// ============================================================================
+// Request DTOs
+// ============================================================================
+
+/// Query parameters for listing notification deliveries
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ListDeliveriesQuery {
+    /// Only return deliveries with this status
+    pub status: Option<NotificationDeliveryStatus>,
+    /// Cursor for pagination (UUID of the last item of the previous page)
+    pub cursor: Option<Uuid>,
+    /// Maximum number of items to return
+    pub limit: Option<i64>,
+}
+
+/// Query parameters for listing notification events
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ListEventsQuery {
+    /// Only return events of this type
+    #[serde(rename = "type")]
+    pub event_type: Option<NotificationEventType>,
+    /// Earliest due time (inclusive)
+    pub from: Option<DateTime<Utc>>,
+    /// Latest due time (inclusive)
+    pub to: Option<DateTime<Utc>>,
+    /// Cursor for pagination (`next_cursor` of the previous page)
+    pub cursor: Option<String>,
+    /// Maximum number of items to return
+    pub limit: Option<i64>,
+}
+
+/// Request body for snoozing a chore's notifications
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SnoozeChoreRequest {
+    /// Suppress due notifications until this time
+    pub until: DateTime<Utc>,
+}
+
+/// Request body for simulating the due event generator
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct SimulateNotificationsRequest {
+    /// Days ahead to simulate (1-31, default 7)
+    pub window_days: Option<i64>,
+}
+
+// ============================================================================
+// Response DTOs
+// ============================================================================
+
+/// Delivery state of a notification on a single channel
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NotificationDeliveryResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub chore_id: Uuid,
+    pub event_type: NotificationEventType,
+    /// Due time the notification was sent for
+    pub due_at: DateTime<Utc>,
+    pub title: String,
+    pub channel: NotificationChannel,
+    pub status: NotificationDeliveryStatus,
+    /// Number of failed send attempts
+    pub attempt_count: i32,
+    /// Error from the most recent failed attempt
+    pub last_error: Option<String>,
+    pub last_attempted_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ChoreNotificationDelivery> for NotificationDeliveryResponse {
+    fn from(delivery: ChoreNotificationDelivery) -> Self {
+        Self {
+            id: delivery.delivery_id,
+            event_id: delivery.event_id,
+            chore_id: delivery.chore_id,
+            event_type: delivery.event_type,
+            due_at: delivery.due_at,
+            title: delivery.title,
+            channel: delivery.channel,
+            status: delivery.status,
+            attempt_count: delivery.attempt_count,
+            last_error: delivery.last_error,
+            last_attempted_at: delivery.last_attempted_at,
+            delivered_at: delivery.delivered_at,
+            created_at: delivery.created_at,
+        }
+    }
+}
+
+/// A notification event with the state of its deliveries
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NotificationEventResponse {
+    pub id: Uuid,
+    pub chore_id: Uuid,
+    pub event_type: NotificationEventType,
+    /// Due time the event was raised for
+    pub due_at: DateTime<Utc>,
+    pub title: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    /// One per channel the event was queued for
+    pub deliveries: Vec<EventDeliveryResponse>,
+}
+
+impl NotificationEventResponse {
+    pub fn from_event(event: NotificationEvent, deliveries: Vec<NotificationDelivery>) -> Self {
+        Self {
+            id: event.id,
+            chore_id: event.chore_id,
+            event_type: event.event_type,
+            due_at: event.due_at,
+            title: event.title,
+            body: event.body,
+            created_at: event.created_at,
+            deliveries: deliveries
+                .into_iter()
+                .map(EventDeliveryResponse::from)
+                .collect(),
+        }
+    }
+}
+
+/// Summary of an event's delivery on a single channel
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventDeliveryResponse {
+    pub id: Uuid,
+    pub channel: NotificationChannel,
+    pub status: NotificationDeliveryStatus,
+    /// Number of failed send attempts
+    pub attempt_count: i32,
+    /// Error from the most recent failed attempt
+    pub last_error: Option<String>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+impl From<NotificationDelivery> for EventDeliveryResponse {
+    fn from(delivery: NotificationDelivery) -> Self {
+        Self {
+            id: delivery.id,
+            channel: delivery.channel,
+            status: delivery.status,
+            attempt_count: delivery.attempt_count,
+            last_error: delivery.last_error,
+            delivered_at: delivery.delivered_at,
+        }
+    }
+}
+
+/// A due event the generator would raise
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SimulatedNotificationResponse {
+    pub chore_id: Uuid,
+    pub chore_name: String,
+    /// Occurrence the event is for
+    pub due_at: DateTime<Utc>,
+    /// When the event would be raised
+    pub notify_at: DateTime<Utc>,
+    pub title: String,
+    pub body: String,
+    /// Channels a delivery would be queued for
+    pub channels: Vec<NotificationChannel>,
+}
+
+impl SimulatedNotificationResponse {
+    pub fn from_event(event: SimulatedDueEvent, channels: &[NotificationChannel]) -> Self {
+        Self {
+            chore_id: event.chore_id,
+            chore_name: event.chore_name,
+            due_at: event.due_at,
+            notify_at: event.notify_at,
+            title: event.title,
+            body: event.body,
+            channels: channels.to_vec(),
+        }
+    }
+}
+
+/// Current snooze of a chore's notifications
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SnoozeResponse {
+    /// Notifications are suppressed until this time; an earlier request
+    /// never shortens an existing snooze
+    pub snoozed_until: DateTime<Utc>,
+}
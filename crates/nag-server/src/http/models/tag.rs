@@ -1,27 +1,26 @@
+use std::sync::LazyLock;
+
 use chrono::{DateTime, Utc};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-use crate::db::models::Tag;
+use crate::db::{
+    models::Tag,
+    tags::{TAG_PALETTE, auto_tag_color},
+};
 
 /// Valid tag color keys (earthy palette)
-pub const VALID_TAG_COLORS: &[&str] = &[
-    "terracotta",
-    "sage",
-    "ocean",
-    "amber",
-    "plum",
-    "clay",
-    "moss",
-    "slate",
-    "mauve",
-    "sand",
-];
+pub const VALID_TAG_COLORS: &[&str] = TAG_PALETTE;
+
+/// Custom tag colors: `#RRGGBB`
+static HEX_TAG_COLOR: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new("^#[0-9a-fA-F]{6}$").expect("valid hex color regex"));
 
-/// Check if a color key is valid
+/// Check if a color is a palette key or a `#RRGGBB` hex color
 pub fn is_valid_tag_color(color: &str) -> bool {
-    VALID_TAG_COLORS.contains(&color)
+    VALID_TAG_COLORS.contains(&color) || HEX_TAG_COLOR.is_match(color)
 }
 
 // ============================================================================
@@ -33,7 +32,8 @@ pub fn is_valid_tag_color(color: &str) -> bool {
 pub struct CreateTagRequest {
     /// Name of the tag
     pub name: String,
-    /// Optional color key from the palette (e.g. "sage", "terracotta")
+    /// Optional color: a palette key (e.g. "sage", "terracotta") or a
+    /// `#RRGGBB` hex color
     pub color: Option<String>,
 }
 
@@ -42,7 +42,7 @@ pub struct CreateTagRequest {
 pub struct UpdateTagRequest {
     /// New name (optional)
     pub name: Option<String>,
-    /// New color key (optional, use null to clear)
+    /// New palette key or `#RRGGBB` hex color (optional, use null to clear)
     #[serde(default, deserialize_with = "deserialize_optional_color")]
     pub color: Option<Option<String>>,
 }
@@ -59,6 +59,24 @@ where
     Ok(Some(value))
 }
 
+/// Request body for merging one tag into another
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MergeTagsRequest {
+    /// Tag to merge away; it is deleted afterwards
+    pub source_id: Uuid,
+    /// Tag that takes over the source's chores
+    pub target_id: Uuid,
+}
+
+/// Query parameters for deleting a tag
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct DeleteTagQuery {
+    /// Delete the tag and its chore associations for good instead of hiding
+    /// it
+    #[serde(default)]
+    pub purge: bool,
+}
+
 // ============================================================================
 // Response DTOs
 // ============================================================================
@@ -68,17 +86,24 @@ where
 pub struct TagResponse {
     pub id: Uuid,
     pub name: String,
-    /// Color key from the palette (null means auto-assign based on name hash)
+    /// Palette key or `#RRGGBB` hex color. Tags without an explicit color
+    /// get a palette key derived from a hash of their name.
     pub color: Option<String>,
+    /// Whether due notifications are silenced for chores carrying the tag
+    pub muted: bool,
     pub created_at: DateTime<Utc>,
 }
 
 impl From<Tag> for TagResponse {
     fn from(tag: Tag) -> Self {
+        let color = tag
+            .color
+            .unwrap_or_else(|| auto_tag_color(&tag.name).to_string());
         Self {
             id: tag.id,
             name: tag.name,
-            color: tag.color,
+            color: Some(color),
+            muted: tag.muted,
             created_at: tag.created_at,
         }
     }
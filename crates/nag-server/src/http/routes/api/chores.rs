@@ -1,73 +1,531 @@
 use axum::{
     Json,
-    extract::{Extension, Path, Query},
-    http::StatusCode,
+    extract::{Extension, OriginalUri, Path, Query},
+    http::{HeaderMap, HeaderName, StatusCode, header},
+    response::{IntoResponse, Response},
 };
+use chrono::{DateTime, Duration, Months, NaiveDate, NaiveTime, Utc};
+use sha2::{Digest, Sha256};
 use sqlx::SqlitePool;
 use uuid::Uuid;
 
-use crate::db::models::ScheduleType;
+use crate::db::models::{
+    ChoreAnchorMode, ChorePriority, ChoreWithLastCompletion, CompletionSource, ScheduleType,
+};
 use crate::db::{
-    ChoreRepository, CompletionRepository, TagRepository, chores::CreateChoreParams,
-    chores::UpdateScheduleParams,
+    AuditRepository, ChoreRepository, CompletionRepository, HolidayRepository,
+    NotificationRepository, TagRepository,
+    chores::{
+        ChoreCursor, ChoreOrder, ChoreSort, CreateChoreParams, DEFAULT_PAGE_SIZE, SortDirection,
+        TagFilter, UpdateChoreParams, UpdateScheduleParams,
+    },
+    completions::{
+        CompletedAtRange, CompletionCursor, CompletionFeedFilter, CompletionNoteTemplate,
+    },
+    tags::TagColorStrategy,
 };
+use crate::http::UndoWindow;
 use crate::http::models::{
-    AppError, AppResult, ChoreResponse, ChoreWithDueResponse, CompleteChoreRequest,
-    CompletionResponse, CreateChoreRequest, DueChoresQuery, ListChoresQuery, ListCompletionsQuery,
-    PaginatedResponse, ScheduleInput, UpdateChoreRequest,
+    AppError, AppResult, AuditLogEntryResponse, BudgetChore, BulkCompleteDueRequest,
+    BulkCompleteDueResponse, BulkCompletedChore, ChoreResponse, ChoreStatsResponse,
+    ChoreWithDueResponse, CompleteChoreRequest, CompletionDayCount, CompletionFeedQuery,
+    CompletionHeatmapQuery, CompletionHeatmapResponse, CompletionResponse, CreateChoreRequest,
+    DayBudgetQuery, DayBudgetResponse, DeleteChoreQuery, DueChoresQuery, LinkedPage,
+    ListChoresQuery, ListCompletionsQuery, NextOccurrencesQuery, OccurrenceResponse,
+    PaginatedResponse, PreviewScheduleRequest, ScheduleInput, SearchChoresQuery,
+    TrashedChoreResponse, UpdateChoreRequest, UpdateCompletionRequest,
 };
-use crate::services::ChoreService;
+use crate::http::routes::auth::CurrentUser;
+use crate::services::{ChoreService, ChoreWithDueInfo, DueSort, ScheduleLimits, calendar};
 
 const TAG: &str = "Chores";
 
+/// Default span of the completion heatmap when `from` is omitted
+const HEATMAP_DEFAULT_DAYS: i64 = 364;
+
+/// Longest range of the completion heatmap and calendar, in months
+const COMPLETION_RANGE_MAX_MONTHS: u32 = 24;
+
+/// Resolve the inclusive day range of a completion heatmap or calendar query
+fn completion_range(query: &CompletionHeatmapQuery) -> AppResult<(NaiveDate, NaiveDate)> {
+    let to = query.to.unwrap_or_else(|| Utc::now().date_naive());
+    let from = query
+        .from
+        .unwrap_or(to - Duration::days(HEATMAP_DEFAULT_DAYS));
+
+    if from > to {
+        return Err(AppError::BadRequest(
+            "'from' must not be after 'to'".to_string(),
+        ));
+    }
+    if to
+        .checked_sub_months(Months::new(COMPLETION_RANGE_MAX_MONTHS))
+        .is_some_and(|earliest| from < earliest)
+    {
+        return Err(AppError::BadRequest(format!(
+            "Date range must not exceed {} months",
+            COMPLETION_RANGE_MAX_MONTHS
+        )));
+    }
+
+    Ok((from, to))
+}
+
+/// Validate a notification lead time, mapping failures to 400
+fn validate_notify_lead(
+    lead_minutes: i32,
+    schedule_type: ScheduleType,
+    cron_schedule: Option<&str>,
+    interval_days: Option<i32>,
+    days_of_week: Option<&str>,
+) -> AppResult<()> {
+    ChoreService::validate_notify_lead(
+        lead_minutes,
+        schedule_type,
+        cron_schedule,
+        interval_days,
+        days_of_week,
+    )
+    .map_err(|e| AppError::BadRequest(format!("Invalid notification lead time: {}", e)))
+}
+
+/// Default and largest number of occurrences in a schedule preview
+const PREVIEW_DEFAULT_COUNT: usize = 5;
+const PREVIEW_MAX_COUNT: usize = 50;
+
+/// Validate a schedule the way create and update do, rejecting it with 400
+fn validate_schedule(schedule: &ScheduleInput, limits: ScheduleLimits) -> AppResult<()> {
+    match schedule {
+        ScheduleInput::Cron { cron_schedule } => {
+            ChoreService::validate_cron_schedule(cron_schedule, limits)
+                .map_err(|e| AppError::BadRequest(format!("Invalid cron schedule: {}", e)))
+        }
+        ScheduleInput::Interval {
+            interval_days,
+            interval_time_hour,
+            interval_time_minute,
+        } => ChoreService::validate_interval_schedule(
+            *interval_days,
+            *interval_time_hour,
+            *interval_time_minute,
+            limits,
+        )
+        .map_err(|e| AppError::BadRequest(format!("Invalid interval schedule: {}", e))),
+        ScheduleInput::DaysOfWeek {
+            days,
+            time_hour,
+            time_minute,
+        } => ChoreService::validate_days_of_week_schedule(days, *time_hour, *time_minute)
+            .map_err(|e| AppError::BadRequest(format!("Invalid days of week schedule: {}", e))),
+        ScheduleInput::OneTime { due_at } => validate_one_time_due(*due_at),
+        ScheduleInput::OnceInAWhile {} => Ok(()),
+    }
+}
+
+/// Reject one-time chores that would already be due when created
+fn validate_one_time_due(due_at: DateTime<Utc>) -> AppResult<()> {
+    if due_at <= Utc::now() {
+        return Err(AppError::BadRequest(
+            "due_at must be in the future".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Reject negative snooze limits with 400
+fn validate_max_snoozes(max_snoozes: Option<i32>) -> AppResult<()> {
+    match max_snoozes {
+        Some(max) if max < 0 => Err(AppError::BadRequest(
+            "max_snoozes cannot be negative".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Reject out-of-range time estimates with 400
+fn validate_estimated_minutes(minutes: Option<i32>) -> AppResult<()> {
+    match minutes {
+        Some(minutes) => {
+            ChoreService::validate_estimated_minutes(minutes).map_err(AppError::BadRequest)
+        }
+        None => Ok(()),
+    }
+}
+
+/// Reject review intervals shorter than a day with 400
+fn validate_review_interval_days(days: Option<i32>) -> AppResult<()> {
+    match days {
+        Some(days) => {
+            ChoreService::validate_review_interval_days(days).map_err(AppError::BadRequest)
+        }
+        None => Ok(()),
+    }
+}
+
+/// Reject minimum notification intervals shorter than a minute with 400
+fn validate_min_notify_interval_minutes(minutes: Option<i32>) -> AppResult<()> {
+    match minutes {
+        Some(minutes) => ChoreService::validate_min_notify_interval_minutes(minutes)
+            .map_err(AppError::BadRequest),
+        None => Ok(()),
+    }
+}
+
+/// Parse a priority name, rejecting unknown ones with 400
+fn parse_priority(priority: Option<&str>) -> AppResult<Option<ChorePriority>> {
+    priority
+        .map(str::parse)
+        .transpose()
+        .map_err(AppError::BadRequest)
+}
+
+/// Parse an anchor mode name, rejecting unknown ones with 400
+fn parse_anchor_mode(anchor_mode: Option<&str>) -> AppResult<Option<ChoreAnchorMode>> {
+    anchor_mode
+        .map(str::parse)
+        .transpose()
+        .map_err(AppError::BadRequest)
+}
+
+/// Reject `from_schedule` anchoring on schedules other than cron and interval
+fn validate_anchor_mode(
+    anchor_mode: ChoreAnchorMode,
+    schedule_type: ScheduleType,
+) -> AppResult<()> {
+    if anchor_mode == ChoreAnchorMode::FromSchedule
+        && !matches!(schedule_type, ScheduleType::Cron | ScheduleType::Interval)
+    {
+        return Err(AppError::BadRequest(
+            "anchor_mode 'from_schedule' is only supported for cron and interval chores"
+                .to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Trim and validate a recipient name
+fn normalize_recipient(recipient: &str) -> AppResult<String> {
+    let trimmed = recipient.trim();
+    if trimmed.is_empty() {
+        return Err(AppError::BadRequest(
+            "Recipient cannot be empty".to_string(),
+        ));
+    }
+    if trimmed.len() > 50 {
+        return Err(AppError::BadRequest(
+            "Recipient cannot exceed 50 characters".to_string(),
+        ));
+    }
+    Ok(trimmed.to_string())
+}
+
+/// Format allowed completion sources for storage; an empty list is rejected
+/// since it would make the chore impossible to complete
+fn format_completion_sources(sources: &[CompletionSource]) -> AppResult<String> {
+    if sources.is_empty() {
+        return Err(AppError::BadRequest(
+            "allowed_completion_sources cannot be empty; use null to allow any source".to_string(),
+        ));
+    }
+    Ok(ChoreService::format_completion_sources(sources))
+}
+
+/// Validate an IANA timezone name, returning its canonical spelling
+fn normalize_timezone(timezone: &str) -> AppResult<String> {
+    ChoreService::parse_timezone(timezone.trim())
+        .map(|tz| tz.name().to_string())
+        .map_err(|e| AppError::BadRequest(format!("Invalid timezone: {}", e)))
+}
+
+/// Check that `chore_id` (when already created) may auto-complete with
+/// `target_id`, which must be visible to `owner`
+async fn validate_auto_complete_with(
+    pool: &SqlitePool,
+    chore_id: Option<Uuid>,
+    target_id: Uuid,
+    owner: Option<Uuid>,
+) -> AppResult<()> {
+    if chore_id == Some(target_id) {
+        return Err(AppError::BadRequest(
+            "A chore cannot auto-complete with itself".to_string(),
+        ));
+    }
+
+    if !CompletionRepository::chore_exists(pool, target_id, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+    {
+        return Err(AppError::BadRequest(format!(
+            "Linked chore with id {} not found",
+            target_id
+        )));
+    }
+
+    if let Some(chore_id) = chore_id
+        && ChoreRepository::auto_complete_creates_cycle(pool, chore_id, target_id)
+            .await
+            .map_err(|e| AppError::Internal(e.into()))?
+    {
+        return Err(AppError::BadRequest(
+            "Auto-complete link would create a cycle".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Fail with 404 unless the chore exists and is visible to `owner`, in the
+/// trash or not
+async fn ensure_visible(pool: &SqlitePool, id: Uuid, owner: Option<Uuid>) -> AppResult<()> {
+    if ChoreRepository::visible_to(pool, id, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+    {
+        Ok(())
+    } else {
+        Err(AppError::NotFound(format!(
+            "Chore with id {} not found",
+            id
+        )))
+    }
+}
+
 /// List all chores with pagination
 #[utoipa::path(
     get,
     path = "/chores",
     params(
-        ("cursor" = Option<Uuid>, Query, description = "Cursor for pagination"),
+        ("cursor" = Option<String>, Query, description = "Cursor for pagination"),
         ("limit" = Option<i64>, Query, description = "Maximum items to return (default 20)"),
-        ("tag" = Option<String>, Query, description = "Filter by tag name")
+        ("tag" = Option<String>, Query, description = "Filter by tag names, comma-separated"),
+        ("tag_match" = Option<String>, Query, description = "With several tags: any (default) or all of them"),
+        ("include_paused" = Option<bool>, Query, description = "Include paused chores"),
+        ("with_total" = Option<bool>, Query, description = "Include the total number of matching chores"),
+        ("sort" = Option<String>, Query, description = "Sort by name, created_at, updated_at, last_completed_at or neglect (schedule periods since the last completion or skip)"),
+        ("dir" = Option<String>, Query, description = "Sort direction: asc or desc (default asc, desc for neglect)")
     ),
     tag = TAG,
     responses(
-        (status = 200, description = "List of chores", body = PaginatedResponse<ChoreResponse>)
+        (status = 200, description = "List of chores", body = PaginatedResponse<ChoreResponse>),
+        (status = 400, description = "Unknown sort or tag match, or invalid cursor")
     )
 )]
 pub async fn list_chores(
     Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    OriginalUri(uri): OriginalUri,
     Query(query): Query<ListChoresQuery>,
-) -> AppResult<Json<PaginatedResponse<ChoreResponse>>> {
-    let chores = ChoreRepository::list(&pool, query.cursor, query.limit)
-        .await
-        .map_err(|e| AppError::Internal(e.into()))?;
+) -> AppResult<LinkedPage<ChoreResponse>> {
+    let sort: ChoreSort = query
+        .sort
+        .as_deref()
+        .map(str::parse)
+        .transpose()
+        .map_err(AppError::BadRequest)?
+        .unwrap_or_default();
+    let order = ChoreOrder {
+        sort,
+        dir: query
+            .dir
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+            .map_err(AppError::BadRequest)?
+            .unwrap_or(sort.default_dir()),
+    };
+    let cursor = query
+        .cursor
+        .as_deref()
+        .map(str::parse::<ChoreCursor>)
+        .transpose()
+        .map_err(AppError::BadRequest)?;
+    if let Some(cursor) = &cursor
+        && cursor.sort_value.is_some() != (order.sort != ChoreSort::Id)
+    {
+        return Err(AppError::BadRequest(
+            "Cursor does not match the requested sort".to_string(),
+        ));
+    }
+    let tags = tag_filter(query.tag.as_deref(), query.tag_match.as_deref())?;
 
-    // Batch-load tags for all chores
-    let chore_ids: Vec<Uuid> = chores.iter().map(|c| c.id).collect();
-    let mut tags_map = TagRepository::get_tags_for_chores(&pool, &chore_ids)
+    let (chores, next_cursor) = if order.sort == ChoreSort::Neglect {
+        list_by_neglect(&pool, cursor.as_ref(), &query, &tags, owner, order.dir).await?
+    } else {
+        let chores = ChoreRepository::list(
+            &pool,
+            cursor.as_ref(),
+            query.limit,
+            &tags,
+            owner,
+            query.include_paused,
+            order,
+        )
         .await
         .map_err(|e| AppError::Internal(e.into()))?;
+        let next_cursor = chores.last().map(|c| ChoreCursor::after(c, order.sort));
+        (chores, next_cursor)
+    };
+    let next_cursor = next_cursor.map(|c| c.to_string());
+    let items = chores_with_tags(&pool, chores).await?;
 
-    // Build response items, optionally filtering by tag
-    let mut items: Vec<ChoreResponse> = Vec::new();
-    for chore in chores {
-        let chore_tags = tags_map.remove(&chore.id).unwrap_or_default();
-
-        // If tag filter is specified, skip chores that don't have the tag
-        if let Some(ref filter_tag) = query.tag
-            && !chore_tags
-                .iter()
-                .any(|t| t.name.eq_ignore_ascii_case(filter_tag))
-        {
-            continue;
+    let total = if query.with_total {
+        Some(
+            ChoreRepository::count(&pool, &tags, owner, query.include_paused)
+                .await
+                .map_err(|e| AppError::Internal(e.into()))?,
+        )
+    } else {
+        None
+    };
+
+    Ok(PaginatedResponse::new(items, next_cursor)
+        .with_total(total)
+        .linked(uri))
+}
+
+/// Tag filter from the `tag` (comma-separated names) and `tag_match` query
+/// parameters
+fn tag_filter(tag: Option<&str>, tag_match: Option<&str>) -> AppResult<TagFilter> {
+    let tag_match = tag_match
+        .map(str::parse)
+        .transpose()
+        .map_err(AppError::BadRequest)?
+        .unwrap_or_default();
+    Ok(TagFilter::parse(tag, tag_match))
+}
+
+/// Page of the chores matching `query`, ordered by [`ChoreService::neglect_ratio`]
+/// in `dir` with ties broken by id. Chores without a schedule period rank as
+/// least neglected.
+///
+/// The ratio grows with time, so every chore is loaded and ranked as of now;
+/// the cursor holds the last chore's ratio and resumes after that position.
+async fn list_by_neglect(
+    pool: &SqlitePool,
+    cursor: Option<&ChoreCursor>,
+    query: &ListChoresQuery,
+    tags: &TagFilter,
+    owner: Option<Uuid>,
+    dir: SortDirection,
+) -> AppResult<(Vec<ChoreWithLastCompletion>, Option<ChoreCursor>)> {
+    let after = match cursor {
+        Some(ChoreCursor {
+            id,
+            sort_value: Some(value),
+        }) => Some((
+            value.parse::<f64>().map_err(|_| {
+                AppError::BadRequest("Cursor does not match the requested sort".to_string())
+            })?,
+            *id,
+        )),
+        _ => None,
+    };
+
+    let chores = ChoreRepository::list(
+        pool,
+        None,
+        Some(-1),
+        tags,
+        owner,
+        query.include_paused,
+        ChoreOrder::default(),
+    )
+    .await
+    .map_err(|e| AppError::Internal(e.into()))?;
+
+    let now = Utc::now();
+    let in_order = |a: &(f64, Uuid), b: &(f64, Uuid)| {
+        let ordering = a.0.total_cmp(&b.0).then(a.1.cmp(&b.1));
+        match dir {
+            SortDirection::Asc => ordering,
+            SortDirection::Desc => ordering.reverse(),
         }
+    };
+    let mut ranked: Vec<((f64, Uuid), ChoreWithLastCompletion)> = chores
+        .into_iter()
+        .map(|chore| {
+            let ratio = ChoreService::neglect_ratio(&chore, now).unwrap_or(-1.0);
+            ((ratio, chore.id), chore)
+        })
+        .filter(|(key, _)| after.is_none_or(|after| in_order(&after, key).is_lt()))
+        .collect();
+    ranked.sort_by(|(a, _), (b, _)| in_order(a, b));
+    ranked.truncate(usize::try_from(query.limit.unwrap_or(DEFAULT_PAGE_SIZE)).unwrap_or(0));
 
-        items.push(ChoreResponse::from_chore_with_completion(chore, chore_tags));
+    let next_cursor = ranked.last().map(|((ratio, id), _)| ChoreCursor {
+        id: *id,
+        sort_value: Some(ratio.to_string()),
+    });
+    Ok((
+        ranked.into_iter().map(|(_, chore)| chore).collect(),
+        next_cursor,
+    ))
+}
+
+/// Search chores by name or description
+#[utoipa::path(
+    get,
+    path = "/chores/search",
+    params(
+        ("q" = String, Query, description = "Case-insensitive text to find in chore names and descriptions"),
+        ("cursor" = Option<Uuid>, Query, description = "Cursor for pagination"),
+        ("limit" = Option<i64>, Query, description = "Maximum items to return (default 20)"),
+        ("include_paused" = Option<bool>, Query, description = "Include paused chores")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "Matching chores", body = PaginatedResponse<ChoreResponse>),
+        (status = 400, description = "Empty search query")
+    )
+)]
+pub async fn search_chores(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<SearchChoresQuery>,
+) -> AppResult<LinkedPage<ChoreResponse>> {
+    let q = query.q.trim();
+    if q.is_empty() {
+        return Err(AppError::BadRequest(
+            "Search query cannot be empty".to_string(),
+        ));
     }
 
-    let next_cursor = items.last().map(|c| c.id);
+    let chores = ChoreRepository::search(
+        &pool,
+        q,
+        query.cursor,
+        query.limit,
+        owner,
+        query.include_paused,
+    )
+    .await
+    .map_err(|e| AppError::Internal(e.into()))?;
+
+    let items = chores_with_tags(&pool, chores).await?;
+    let next_cursor = items.last().map(|c| c.id.to_string());
+
+    Ok(PaginatedResponse::new(items, next_cursor).linked(uri))
+}
+
+/// Attach tags to chores
+async fn chores_with_tags(
+    pool: &SqlitePool,
+    chores: Vec<ChoreWithLastCompletion>,
+) -> AppResult<Vec<ChoreResponse>> {
+    // Batch-load tags for all chores
+    let chore_ids: Vec<Uuid> = chores.iter().map(|c| c.id).collect();
+    let mut tags_map = TagRepository::get_tags_for_chores(pool, &chore_ids)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
 
-    Ok(Json(PaginatedResponse::new(items, next_cursor)))
+    Ok(chores
+        .into_iter()
+        .map(|chore| {
+            let chore_tags = tags_map.remove(&chore.id).unwrap_or_default();
+            ChoreResponse::from_chore_with_completion(chore, chore_tags)
+        })
+        .collect())
 }
 
 /// Get chores that are due or overdue
@@ -76,44 +534,176 @@ pub async fn list_chores(
     path = "/chores/due",
     params(
         ("include_upcoming" = Option<bool>, Query, description = "Include upcoming chores"),
-        ("tag" = Option<String>, Query, description = "Filter by tag name")
+        ("tag" = Option<String>, Query, description = "Filter by tag names, comma-separated"),
+        ("tag_match" = Option<String>, Query, description = "With several tags: any (default) or all of them"),
+        ("sort" = Option<DueSort>, Query, description = "Ordering: due_asc, overdue_desc or priority, which ranks by lateness relative to the schedule period and uses the chore's priority only to break ties (defaults to DUE_DEFAULT_SORT)")
     ),
     tag = TAG,
     responses(
-        (status = 200, description = "List of due chores", body = Vec<ChoreWithDueResponse>)
+        (status = 200, description = "List of due chores", body = Vec<ChoreWithDueResponse>),
+        (status = 400, description = "Unknown tag match")
     )
 )]
 pub async fn get_due_chores(
     Extension(pool): Extension<SqlitePool>,
+    Extension(default_sort): Extension<DueSort>,
+    CurrentUser(owner): CurrentUser,
     Query(query): Query<DueChoresQuery>,
 ) -> AppResult<Json<Vec<ChoreWithDueResponse>>> {
-    let chores = ChoreService::get_due_chores(&pool, query.include_upcoming)
+    let sort = query.sort.unwrap_or(default_sort);
+    let tags = tag_filter(query.tag.as_deref(), query.tag_match.as_deref())?;
+    let chores = ChoreService::get_due_chores(&pool, owner, query.include_upcoming, sort, &tags)
         .await
         .map_err(AppError::Internal)?;
 
-    // Batch-load tags
+    let items = due_with_tags(&pool, chores).await?;
+
+    Ok(Json(items))
+}
+
+/// Check whether today's due chores fit in a time budget
+///
+/// Today is the current UTC day; overdue chores count towards it.
+#[utoipa::path(
+    get,
+    path = "/chores/today/budget",
+    params(
+        ("limit_minutes" = i64, Query, description = "Minutes available for chores today")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "Today's chores against the budget", body = DayBudgetResponse),
+        (status = 400, description = "Missing or negative limit")
+    )
+)]
+pub async fn get_day_budget(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    Query(query): Query<DayBudgetQuery>,
+) -> AppResult<Json<DayBudgetResponse>> {
+    let limit_minutes = match query.limit_minutes {
+        Some(limit) if limit >= 0 => limit,
+        Some(_) => {
+            return Err(AppError::BadRequest(
+                "limit_minutes must not be negative".to_string(),
+            ));
+        }
+        None => {
+            return Err(AppError::BadRequest(
+                "limit_minutes is required".to_string(),
+            ));
+        }
+    };
+
+    let end_of_today = (Utc::now().date_naive() + Duration::days(1))
+        .and_time(NaiveTime::MIN)
+        .and_utc();
+    let chores: Vec<ChoreWithDueInfo> =
+        ChoreService::get_due_chores(&pool, owner, true, DueSort::DueAsc, &TagFilter::default())
+            .await
+            .map_err(AppError::Internal)?
+            .into_iter()
+            .filter(|c| c.next_due.is_some_and(|due| due < end_of_today))
+            .collect();
+
+    let budget = ChoreService::plan_day_budget(&chores, limit_minutes);
+    let defer = budget
+        .deferrals
+        .iter()
+        .filter_map(|id| chores.iter().find(|c| c.chore.id == *id))
+        .map(BudgetChore::from)
+        .collect();
+
+    Ok(Json(DayBudgetResponse {
+        limit_minutes,
+        total_minutes: budget.total_minutes,
+        fits: budget.overflow_minutes == 0,
+        overflow_minutes: budget.overflow_minutes,
+        chores: chores.iter().map(BudgetChore::from).collect(),
+        defer,
+    }))
+}
+
+/// Get every active chore once, with its single next due time
+#[utoipa::path(
+    get,
+    path = "/chores/schedule",
+    tag = TAG,
+    responses(
+        (status = 200, description = "One entry per active chore, soonest due first", body = Vec<ChoreWithDueResponse>)
+    )
+)]
+pub async fn get_chore_schedule(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+) -> AppResult<Json<Vec<ChoreWithDueResponse>>> {
+    let chores =
+        ChoreService::get_due_chores(&pool, owner, true, DueSort::DueAsc, &TagFilter::default())
+            .await
+            .map_err(AppError::Internal)?;
+
+    let items = due_with_tags(&pool, chores).await?;
+
+    Ok(Json(items))
+}
+
+/// Attach tags and streaks to due chores
+async fn due_with_tags(
+    pool: &SqlitePool,
+    chores: Vec<ChoreWithDueInfo>,
+) -> AppResult<Vec<ChoreWithDueResponse>> {
+    // Batch-load tags and completion history
     let chore_ids: Vec<Uuid> = chores.iter().map(|c| c.chore.id).collect();
-    let mut tags_map = TagRepository::get_tags_for_chores(&pool, &chore_ids)
+    let mut tags_map = TagRepository::get_tags_for_chores(pool, &chore_ids)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+    let completed_map = CompletionRepository::completed_times_for_chores(pool, &chore_ids)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+    let holidays = HolidayRepository::list_dates(pool)
         .await
         .map_err(|e| AppError::Internal(e.into()))?;
 
     let mut items: Vec<ChoreWithDueResponse> = Vec::new();
     for info in chores {
         let chore_tags = tags_map.remove(&info.chore.id).unwrap_or_default();
+        let completed = completed_map
+            .get(&info.chore.id)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let streak = ChoreService::on_time_streak(&info.chore, completed, &holidays);
+        items.push(ChoreWithDueResponse::from_due_info(
+            info, chore_tags, streak,
+        ));
+    }
 
-        // If tag filter is specified, skip chores that don't have the tag
-        if let Some(ref filter_tag) = query.tag
-            && !chore_tags
-                .iter()
-                .any(|t| t.name.eq_ignore_ascii_case(filter_tag))
-        {
-            continue;
-        }
+    Ok(items)
+}
 
-        items.push(ChoreWithDueResponse::from_due_info(info, chore_tags));
-    }
+/// Get an iCalendar feed of upcoming chores
+#[utoipa::path(
+    get,
+    path = "/chores/calendar.ics",
+    tag = TAG,
+    responses(
+        (status = 200, description = "iCalendar feed of upcoming chores", content_type = "text/calendar", body = String)
+    )
+)]
+pub async fn get_chores_calendar(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+) -> AppResult<impl IntoResponse> {
+    let chores =
+        ChoreService::get_due_chores(&pool, owner, true, DueSort::DueAsc, &TagFilter::default())
+            .await
+            .map_err(AppError::Internal)?;
 
-    Ok(Json(items))
+    let body = calendar::render_due_calendar(&chores, Utc::now());
+
+    Ok((
+        [(header::CONTENT_TYPE, "text/calendar; charset=utf-8")],
+        body,
+    ))
 }
 
 /// Create a new chore
@@ -122,59 +712,123 @@ pub async fn get_due_chores(
     path = "/chores",
     request_body = CreateChoreRequest,
     responses(
-        (status = 201, description = "Chore created", body = ChoreResponse),
+        (status = 201, description = "Chore created", body = ChoreResponse,
+            headers(("Location" = String, description = "URL of the new chore"))),
         (status = 400, description = "Invalid request")
     ),
     tag = TAG,
 )]
 pub async fn create_chore(
     Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    Extension(tag_colors): Extension<TagColorStrategy>,
+    Extension(limits): Extension<ScheduleLimits>,
     Json(body): Json<CreateChoreRequest>,
-) -> AppResult<(StatusCode, Json<ChoreResponse>)> {
+) -> AppResult<(StatusCode, [(HeaderName, String); 1], Json<ChoreResponse>)> {
+    let chore = insert_chore(&pool, owner, &body, None, tag_colors, limits).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        [(header::LOCATION, format!("/api/chores/{}", chore.id))],
+        Json(chore),
+    ))
+}
+
+/// Validate and insert a new chore, then assign its tags
+async fn insert_chore(
+    pool: &SqlitePool,
+    owner: Option<Uuid>,
+    body: &CreateChoreRequest,
+    external_id: Option<&str>,
+    tag_colors: TagColorStrategy,
+    limits: ScheduleLimits,
+) -> AppResult<ChoreResponse> {
+    let recipient = body
+        .recipient
+        .as_deref()
+        .map(normalize_recipient)
+        .transpose()?;
+    validate_max_snoozes(body.max_snoozes)?;
+    validate_estimated_minutes(body.estimated_minutes)?;
+    validate_review_interval_days(body.review_interval_days)?;
+    validate_min_notify_interval_minutes(body.min_notify_interval_minutes)?;
+    let allowed_completion_sources = body
+        .allowed_completion_sources
+        .as_deref()
+        .map(format_completion_sources)
+        .transpose()?;
+    let timezone = body
+        .timezone
+        .as_deref()
+        .map(normalize_timezone)
+        .transpose()?;
+
+    let priority = parse_priority(body.priority.as_deref())?.unwrap_or_default();
+    let anchor_mode = parse_anchor_mode(body.anchor_mode.as_deref())?.unwrap_or_default();
+
+    validate_schedule(&body.schedule, limits)?;
+
+    let days_of_week = match &body.schedule {
+        ScheduleInput::DaysOfWeek { days, .. } => Some(ChoreService::format_days_of_week(days)),
+        _ => None,
+    };
+
     // Validate and extract schedule parameters
     let params = match &body.schedule {
-        ScheduleInput::Cron { cron_schedule } => {
-            if let Err(e) = ChoreService::validate_cron_schedule(cron_schedule) {
-                return Err(AppError::BadRequest(format!(
-                    "Invalid cron schedule: {}",
-                    e
-                )));
-            }
-            CreateChoreParams {
-                name: &body.name,
-                description: body.description.as_deref(),
-                schedule_type: ScheduleType::Cron,
-                cron_schedule: Some(cron_schedule.as_str()),
-                interval_days: None,
-                interval_time_hour: None,
-                interval_time_minute: None,
-            }
-        }
+        ScheduleInput::Cron { cron_schedule } => CreateChoreParams {
+            name: &body.name,
+            description: body.description.as_deref(),
+            schedule_type: ScheduleType::Cron,
+            cron_schedule: Some(cron_schedule.as_str()),
+            interval_days: None,
+            interval_time_hour: None,
+            interval_time_minute: None,
+            days_of_week: None,
+            due_at: None,
+            timezone: timezone.as_deref(),
+            notify_lead_minutes: body.notify_lead_minutes,
+            max_snoozes: body.max_snoozes,
+            estimated_minutes: body.estimated_minutes,
+            review_interval_days: body.review_interval_days,
+            min_notify_interval_minutes: body.min_notify_interval_minutes,
+            recipient: recipient.as_deref(),
+            auto_complete_with: body.auto_complete_with,
+            allowed_completion_sources: allowed_completion_sources.as_deref(),
+            external_id,
+            priority,
+            anchor_mode,
+            user_id: owner,
+            is_active: body.is_active.unwrap_or(true),
+        },
         ScheduleInput::Interval {
             interval_days,
             interval_time_hour,
             interval_time_minute,
-        } => {
-            if let Err(e) = ChoreService::validate_interval_schedule(
-                *interval_days,
-                *interval_time_hour,
-                *interval_time_minute,
-            ) {
-                return Err(AppError::BadRequest(format!(
-                    "Invalid interval schedule: {}",
-                    e
-                )));
-            }
-            CreateChoreParams {
-                name: &body.name,
-                description: body.description.as_deref(),
-                schedule_type: ScheduleType::Interval,
-                cron_schedule: None,
-                interval_days: Some(*interval_days),
-                interval_time_hour: *interval_time_hour,
-                interval_time_minute: *interval_time_minute,
-            }
-        }
+        } => CreateChoreParams {
+            name: &body.name,
+            description: body.description.as_deref(),
+            schedule_type: ScheduleType::Interval,
+            cron_schedule: None,
+            interval_days: Some(*interval_days),
+            interval_time_hour: *interval_time_hour,
+            interval_time_minute: *interval_time_minute,
+            days_of_week: None,
+            due_at: None,
+            timezone: timezone.as_deref(),
+            notify_lead_minutes: body.notify_lead_minutes,
+            max_snoozes: body.max_snoozes,
+            estimated_minutes: body.estimated_minutes,
+            review_interval_days: body.review_interval_days,
+            min_notify_interval_minutes: body.min_notify_interval_minutes,
+            recipient: recipient.as_deref(),
+            auto_complete_with: body.auto_complete_with,
+            allowed_completion_sources: allowed_completion_sources.as_deref(),
+            external_id,
+            priority,
+            anchor_mode,
+            user_id: owner,
+            is_active: body.is_active.unwrap_or(true),
+        },
         ScheduleInput::OnceInAWhile {} => CreateChoreParams {
             name: &body.name,
             description: body.description.as_deref(),
@@ -183,26 +837,315 @@ pub async fn create_chore(
             interval_days: None,
             interval_time_hour: None,
             interval_time_minute: None,
+            days_of_week: None,
+            due_at: None,
+            timezone: timezone.as_deref(),
+            notify_lead_minutes: body.notify_lead_minutes,
+            max_snoozes: body.max_snoozes,
+            estimated_minutes: body.estimated_minutes,
+            review_interval_days: body.review_interval_days,
+            min_notify_interval_minutes: body.min_notify_interval_minutes,
+            recipient: recipient.as_deref(),
+            auto_complete_with: body.auto_complete_with,
+            allowed_completion_sources: allowed_completion_sources.as_deref(),
+            external_id,
+            priority,
+            anchor_mode,
+            user_id: owner,
+            is_active: body.is_active.unwrap_or(true),
+        },
+        ScheduleInput::OneTime { due_at } => CreateChoreParams {
+            name: &body.name,
+            description: body.description.as_deref(),
+            schedule_type: ScheduleType::OneTime,
+            cron_schedule: None,
+            interval_days: None,
+            interval_time_hour: None,
+            interval_time_minute: None,
+            days_of_week: None,
+            due_at: Some(*due_at),
+            timezone: timezone.as_deref(),
+            notify_lead_minutes: body.notify_lead_minutes,
+            max_snoozes: body.max_snoozes,
+            estimated_minutes: body.estimated_minutes,
+            review_interval_days: body.review_interval_days,
+            min_notify_interval_minutes: body.min_notify_interval_minutes,
+            recipient: recipient.as_deref(),
+            auto_complete_with: body.auto_complete_with,
+            allowed_completion_sources: allowed_completion_sources.as_deref(),
+            external_id,
+            priority,
+            anchor_mode,
+            user_id: owner,
+            is_active: body.is_active.unwrap_or(true),
+        },
+        ScheduleInput::DaysOfWeek {
+            time_hour,
+            time_minute,
+            ..
+        } => CreateChoreParams {
+            name: &body.name,
+            description: body.description.as_deref(),
+            schedule_type: ScheduleType::DaysOfWeek,
+            cron_schedule: None,
+            interval_days: None,
+            interval_time_hour: *time_hour,
+            interval_time_minute: *time_minute,
+            days_of_week: days_of_week.as_deref(),
+            due_at: None,
+            timezone: timezone.as_deref(),
+            notify_lead_minutes: body.notify_lead_minutes,
+            max_snoozes: body.max_snoozes,
+            estimated_minutes: body.estimated_minutes,
+            review_interval_days: body.review_interval_days,
+            min_notify_interval_minutes: body.min_notify_interval_minutes,
+            recipient: recipient.as_deref(),
+            auto_complete_with: body.auto_complete_with,
+            allowed_completion_sources: allowed_completion_sources.as_deref(),
+            external_id,
+            priority,
+            anchor_mode,
+            user_id: owner,
+            is_active: body.is_active.unwrap_or(true),
         },
     };
 
-    let chore = ChoreRepository::create(&pool, params)
+    validate_anchor_mode(params.anchor_mode, params.schedule_type)?;
+
+    if let Some(lead) = params.notify_lead_minutes {
+        validate_notify_lead(
+            lead,
+            params.schedule_type,
+            params.cron_schedule,
+            params.interval_days,
+            params.days_of_week,
+        )?;
+    }
+
+    if let Some(target_id) = params.auto_complete_with {
+        validate_auto_complete_with(pool, None, target_id, owner).await?;
+    }
+
+    let chore = ChoreRepository::create(pool, params)
         .await
         .map_err(|e| AppError::Internal(e.into()))?;
 
     // Set tags if provided
     let tags = if !body.tags.is_empty() {
-        TagRepository::set_chore_tags(&pool, chore.id, &body.tags)
+        let mut conn = pool
+            .acquire()
+            .await
+            .map_err(|e| AppError::Internal(e.into()))?;
+        TagRepository::set_chore_tags(&mut conn, chore.id, &body.tags, tag_colors)
             .await
             .map_err(|e| AppError::Internal(e.into()))?
     } else {
         Vec::new()
     };
 
-    Ok((
-        StatusCode::CREATED,
-        Json(ChoreResponse::from_chore(chore, tags)),
-    ))
+    Ok(ChoreResponse::from_chore(chore, tags))
+}
+
+/// Create or replace the chore synced under an external id
+///
+/// Returns 201 when the chore is created and 200 when an existing one is
+/// updated, so a sync tool can replay the same request idempotently.
+#[utoipa::path(
+    put,
+    path = "/chores/by-external-id/{external_id}",
+    params(
+        ("external_id" = String, Path, description = "Identifier assigned by the external system")
+    ),
+    request_body = CreateChoreRequest,
+    responses(
+        (status = 200, description = "Chore updated", body = ChoreResponse),
+        (status = 201, description = "Chore created", body = ChoreResponse),
+        (status = 400, description = "Invalid request"),
+        (status = 409, description = "External id belongs to another user's chore or one in the trash")
+    ),
+    tag = TAG,
+)]
+pub async fn upsert_chore_by_external_id(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    Extension(tag_colors): Extension<TagColorStrategy>,
+    Extension(limits): Extension<ScheduleLimits>,
+    Path(external_id): Path<String>,
+    Json(body): Json<CreateChoreRequest>,
+) -> AppResult<(StatusCode, Json<ChoreResponse>)> {
+    let existing = ChoreRepository::get_by_external_id(&pool, &external_id, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    if let Some(existing) = existing {
+        let chore =
+            apply_chore_update(&pool, existing.id, &body.into(), owner, tag_colors, limits).await?;
+        return Ok((StatusCode::OK, Json(chore)));
+    }
+
+    let chore = insert_chore(&pool, owner, &body, Some(&external_id), tag_colors, limits)
+        .await
+        .map_err(|e| match e {
+            // The id is taken by a chore this user cannot see, or one in the trash
+            AppError::Internal(err)
+                if err
+                    .downcast_ref::<sqlx::Error>()
+                    .and_then(|e| e.as_database_error())
+                    .is_some_and(|e| e.is_unique_violation()) =>
+            {
+                AppError::Conflict(format!("External id '{}' is already in use", external_id))
+            }
+            e => e,
+        })?;
+
+    Ok((StatusCode::CREATED, Json(chore)))
+}
+
+/// Unsaved chore created at `now` with the given schedule, so its first
+/// occurrence is the next one due
+fn preview_chore(schedule: &ScheduleInput, now: DateTime<Utc>) -> ChoreWithLastCompletion {
+    let mut chore = ChoreWithLastCompletion {
+        id: Uuid::nil(),
+        name: String::new(),
+        description: None,
+        schedule_type: ScheduleType::OnceInAWhile,
+        cron_schedule: None,
+        interval_days: None,
+        interval_time_hour: None,
+        interval_time_minute: None,
+        days_of_week: None,
+        due_at: None,
+        timezone: None,
+        notify_lead_minutes: None,
+        max_snoozes: None,
+        estimated_minutes: None,
+        review_interval_days: None,
+        min_notify_interval_minutes: None,
+        last_reviewed_at: None,
+        recipient: None,
+        auto_complete_with: None,
+        allowed_completion_sources: None,
+        external_id: None,
+        priority: ChorePriority::Normal,
+        anchor_mode: ChoreAnchorMode::FromCompletion,
+        is_active: true,
+        created_at: now,
+        updated_at: now,
+        last_completed_at: None,
+        last_skipped_at: None,
+        next_snoozed_until: None,
+    };
+
+    match schedule {
+        ScheduleInput::Cron { cron_schedule } => {
+            chore.schedule_type = ScheduleType::Cron;
+            chore.cron_schedule = Some(cron_schedule.clone());
+        }
+        ScheduleInput::Interval {
+            interval_days,
+            interval_time_hour,
+            interval_time_minute,
+        } => {
+            chore.schedule_type = ScheduleType::Interval;
+            chore.interval_days = Some(*interval_days);
+            chore.interval_time_hour = *interval_time_hour;
+            chore.interval_time_minute = *interval_time_minute;
+        }
+        ScheduleInput::DaysOfWeek {
+            days,
+            time_hour,
+            time_minute,
+        } => {
+            chore.schedule_type = ScheduleType::DaysOfWeek;
+            chore.days_of_week = Some(ChoreService::format_days_of_week(days));
+            chore.interval_time_hour = *time_hour;
+            chore.interval_time_minute = *time_minute;
+        }
+        ScheduleInput::OneTime { due_at } => {
+            chore.schedule_type = ScheduleType::OneTime;
+            chore.due_at = Some(*due_at);
+        }
+        ScheduleInput::OnceInAWhile {} => {}
+    }
+
+    chore
+}
+
+/// Preview the next occurrences of a schedule without creating a chore
+#[utoipa::path(
+    post,
+    path = "/chores/preview-schedule",
+    request_body = PreviewScheduleRequest,
+    responses(
+        (status = 200, description = "Upcoming due times, soonest first", body = Vec<DateTime<Utc>>),
+        (status = 400, description = "Invalid schedule or count")
+    ),
+    tag = TAG,
+)]
+pub async fn preview_schedule(
+    Extension(limits): Extension<ScheduleLimits>,
+    Json(body): Json<PreviewScheduleRequest>,
+) -> AppResult<Json<Vec<DateTime<Utc>>>> {
+    let count = preview_count(body.count)?;
+    validate_schedule(&body.schedule, limits)?;
+
+    let now = Utc::now();
+    let chore = preview_chore(&body.schedule, now);
+
+    Ok(Json(ChoreService::preview_occurrences(&chore, count, now)))
+}
+
+/// Number of occurrences to preview, defaulting and bounds-checking `count`
+fn preview_count(count: Option<usize>) -> AppResult<usize> {
+    let count = count.unwrap_or(PREVIEW_DEFAULT_COUNT);
+    if !(1..=PREVIEW_MAX_COUNT).contains(&count) {
+        return Err(AppError::BadRequest(format!(
+            "count must be between 1 and {}",
+            PREVIEW_MAX_COUNT
+        )));
+    }
+    Ok(count)
+}
+
+/// Upcoming occurrences of a chore, each also given as wall-clock time in
+/// the chore's timezone
+#[utoipa::path(
+    get,
+    path = "/chores/{id}/next-occurrences",
+    params(
+        ("id" = Uuid, Path, description = "Chore ID"),
+        ("count" = Option<usize>, Query, description = "Number of occurrences to return (1-50, default 5)")
+    ),
+    responses(
+        (status = 200, description = "Upcoming occurrences, soonest first", body = Vec<OccurrenceResponse>),
+        (status = 400, description = "Invalid count"),
+        (status = 404, description = "Chore not found")
+    ),
+    tag = TAG,
+)]
+pub async fn get_next_occurrences(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    Path(id): Path<Uuid>,
+    Query(query): Query<NextOccurrencesQuery>,
+) -> AppResult<Json<Vec<OccurrenceResponse>>> {
+    let count = preview_count(query.count)?;
+
+    let chore = ChoreRepository::get_by_id(&pool, id, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .ok_or_else(|| AppError::NotFound(format!("Chore with id {} not found", id)))?;
+
+    let tz = ChoreService::chore_timezone(&chore);
+    let occurrences = ChoreService::preview_occurrences(&chore, count, Utc::now())
+        .into_iter()
+        .map(|at| OccurrenceResponse {
+            at,
+            local: at.with_timezone(&tz).to_rfc3339(),
+        })
+        .collect();
+
+    Ok(Json(occurrences))
 }
 
 /// Get a single chore by ID
@@ -213,16 +1156,20 @@ pub async fn create_chore(
         ("id" = Uuid, Path, description = "Chore ID")
     ),
     responses(
-        (status = 200, description = "Chore found", body = ChoreResponse),
+        (status = 200, description = "Chore found", body = ChoreResponse,
+            headers(("ETag" = String, description = "Version of the chore"))),
+        (status = 304, description = "Chore unchanged since the version in If-None-Match"),
         (status = 404, description = "Chore not found")
     ),
     tag = TAG,
 )]
 pub async fn get_chore(
     Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
     Path(id): Path<Uuid>,
-) -> AppResult<Json<ChoreResponse>> {
-    let chore = ChoreRepository::get_by_id(&pool, id)
+    headers: HeaderMap,
+) -> AppResult<Response> {
+    let chore = ChoreRepository::get_by_id(&pool, id, owner)
         .await
         .map_err(|e| AppError::Internal(e.into()))?
         .ok_or_else(|| AppError::NotFound(format!("Chore with id {} not found", id)))?;
@@ -231,7 +1178,42 @@ pub async fn get_chore(
         .await
         .map_err(|e| AppError::Internal(e.into()))?;
 
-    Ok(Json(ChoreResponse::from_chore_with_completion(chore, tags)))
+    let chore = ChoreResponse::from_chore_with_completion(chore, tags);
+    let etag = chore_etag(&chore);
+    if if_none_match(&headers, &etag) {
+        return Ok((StatusCode::NOT_MODIFIED, [(header::ETAG, etag)]).into_response());
+    }
+
+    Ok(([(header::ETAG, etag)], Json(chore)).into_response())
+}
+
+/// Strong ETag of a chore, changing whenever the chore is edited, completed
+/// or one of its tags changes
+fn chore_etag(chore: &ChoreResponse) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(chore.updated_at.to_rfc3339().as_bytes());
+    if let Some(last_completed_at) = chore.last_completed_at {
+        hasher.update(last_completed_at.to_rfc3339().as_bytes());
+    }
+    for tag in &chore.tags {
+        hasher.update(tag.id.as_bytes());
+        hasher.update(tag.name.as_bytes());
+        hasher.update(tag.color.as_deref().unwrap_or_default().as_bytes());
+        hasher.update([u8::from(tag.muted)]);
+    }
+    format!("\"{:x}\"", hasher.finalize())
+}
+
+/// Whether the request's `If-None-Match` header lists `etag` (or is `*`),
+/// comparing weakly as GET requests do
+fn if_none_match(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|tag| tag.trim())
+        .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
 }
 
 /// Update a chore
@@ -251,65 +1233,181 @@ pub async fn get_chore(
 )]
 pub async fn update_chore(
     Extension(pool): Extension<SqlitePool>,
+    CurrentUser(actor): CurrentUser,
+    Extension(tag_colors): Extension<TagColorStrategy>,
+    Extension(limits): Extension<ScheduleLimits>,
     Path(id): Path<Uuid>,
     Json(body): Json<UpdateChoreRequest>,
 ) -> AppResult<Json<ChoreResponse>> {
+    let chore = apply_chore_update(&pool, id, &body, actor, tag_colors, limits).await?;
+
+    Ok(Json(chore))
+}
+
+/// Validate and apply a partial update to a chore on behalf of `actor`, then
+/// update its tags
+async fn apply_chore_update(
+    pool: &SqlitePool,
+    id: Uuid,
+    body: &UpdateChoreRequest,
+    actor: Option<Uuid>,
+    tag_colors: TagColorStrategy,
+    limits: ScheduleLimits,
+) -> AppResult<ChoreResponse> {
+    let recipient = match &body.recipient {
+        Some(Some(r)) => Some(Some(normalize_recipient(r)?)),
+        Some(None) => Some(None),
+        None => None,
+    };
+    validate_max_snoozes(body.max_snoozes.flatten())?;
+    validate_estimated_minutes(body.estimated_minutes.flatten())?;
+    validate_review_interval_days(body.review_interval_days.flatten())?;
+    validate_min_notify_interval_minutes(body.min_notify_interval_minutes.flatten())?;
+    let allowed_completion_sources = match &body.allowed_completion_sources {
+        Some(Some(sources)) => Some(Some(format_completion_sources(sources)?)),
+        Some(None) => Some(None),
+        None => None,
+    };
+    let timezone = match &body.timezone {
+        Some(Some(tz)) => Some(Some(normalize_timezone(tz)?)),
+        Some(None) => Some(None),
+        None => None,
+    };
+    let priority = parse_priority(body.priority.as_deref())?;
+    let anchor_mode = parse_anchor_mode(body.anchor_mode.as_deref())?;
+
+    if let Some(schedule) = &body.schedule {
+        validate_schedule(schedule, limits)?;
+    }
+
+    let days_of_week = match &body.schedule {
+        Some(ScheduleInput::DaysOfWeek { days, .. }) => {
+            Some(ChoreService::format_days_of_week(days))
+        }
+        _ => None,
+    };
+
     // Validate and convert schedule if provided
     let schedule_params = match &body.schedule {
-        Some(ScheduleInput::Cron { cron_schedule }) => {
-            if let Err(e) = ChoreService::validate_cron_schedule(cron_schedule) {
-                return Err(AppError::BadRequest(format!(
-                    "Invalid cron schedule: {}",
-                    e
-                )));
-            }
-            Some(UpdateScheduleParams {
-                schedule_type: ScheduleType::Cron,
-                cron_schedule: Some(cron_schedule.as_str()),
-                interval_days: None,
-                interval_time_hour: None,
-                interval_time_minute: None,
-            })
-        }
+        Some(ScheduleInput::Cron { cron_schedule }) => Some(UpdateScheduleParams {
+            schedule_type: ScheduleType::Cron,
+            cron_schedule: Some(cron_schedule.as_str()),
+            interval_days: None,
+            interval_time_hour: None,
+            interval_time_minute: None,
+            days_of_week: None,
+            due_at: None,
+        }),
         Some(ScheduleInput::Interval {
             interval_days,
             interval_time_hour,
             interval_time_minute,
-        }) => {
-            if let Err(e) = ChoreService::validate_interval_schedule(
-                *interval_days,
-                *interval_time_hour,
-                *interval_time_minute,
-            ) {
-                return Err(AppError::BadRequest(format!(
-                    "Invalid interval schedule: {}",
-                    e
-                )));
-            }
-            Some(UpdateScheduleParams {
-                schedule_type: ScheduleType::Interval,
-                cron_schedule: None,
-                interval_days: Some(*interval_days),
-                interval_time_hour: *interval_time_hour,
-                interval_time_minute: *interval_time_minute,
-            })
-        }
+        }) => Some(UpdateScheduleParams {
+            schedule_type: ScheduleType::Interval,
+            cron_schedule: None,
+            interval_days: Some(*interval_days),
+            interval_time_hour: *interval_time_hour,
+            interval_time_minute: *interval_time_minute,
+            days_of_week: None,
+            due_at: None,
+        }),
         Some(ScheduleInput::OnceInAWhile {}) => Some(UpdateScheduleParams {
             schedule_type: ScheduleType::OnceInAWhile,
             cron_schedule: None,
             interval_days: None,
             interval_time_hour: None,
             interval_time_minute: None,
+            days_of_week: None,
+            due_at: None,
+        }),
+        Some(ScheduleInput::OneTime { due_at }) => Some(UpdateScheduleParams {
+            schedule_type: ScheduleType::OneTime,
+            cron_schedule: None,
+            interval_days: None,
+            interval_time_hour: None,
+            interval_time_minute: None,
+            days_of_week: None,
+            due_at: Some(*due_at),
+        }),
+        Some(ScheduleInput::DaysOfWeek {
+            time_hour,
+            time_minute,
+            ..
+        }) => Some(UpdateScheduleParams {
+            schedule_type: ScheduleType::DaysOfWeek,
+            cron_schedule: None,
+            interval_days: None,
+            interval_time_hour: *time_hour,
+            interval_time_minute: *time_minute,
+            days_of_week: days_of_week.as_deref(),
+            due_at: None,
         }),
         None => None,
     };
 
+    let existing = ChoreRepository::get_by_id(pool, id, actor)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .ok_or_else(|| AppError::NotFound(format!("Chore with id {} not found", id)))?;
+
+    // Validate the lead time and anchor mode against the schedule they will
+    // apply to
+    if schedule_params.is_some() || body.notify_lead_minutes.is_some() || anchor_mode.is_some() {
+        if let Some(anchor_mode) = anchor_mode {
+            let schedule_type = schedule_params
+                .as_ref()
+                .map_or(existing.schedule_type, |s| s.schedule_type);
+            validate_anchor_mode(anchor_mode, schedule_type)?;
+        }
+
+        let lead = body
+            .notify_lead_minutes
+            .unwrap_or(existing.notify_lead_minutes);
+        if let Some(lead) = lead {
+            match &schedule_params {
+                Some(s) => validate_notify_lead(
+                    lead,
+                    s.schedule_type,
+                    s.cron_schedule,
+                    s.interval_days,
+                    s.days_of_week,
+                )?,
+                None => validate_notify_lead(
+                    lead,
+                    existing.schedule_type,
+                    existing.cron_schedule.as_deref(),
+                    existing.interval_days,
+                    existing.days_of_week.as_deref(),
+                )?,
+            }
+        }
+    }
+
+    if let Some(Some(target_id)) = body.auto_complete_with {
+        validate_auto_complete_with(pool, Some(id), target_id, actor).await?;
+    }
+
     let chore = ChoreRepository::update(
-        &pool,
+        pool,
         id,
-        body.name.as_deref(),
-        body.description.as_ref().map(|d| d.as_deref()),
-        schedule_params,
+        UpdateChoreParams {
+            name: body.name.as_deref(),
+            description: body.description.as_ref().map(|d| d.as_deref()),
+            schedule: schedule_params,
+            timezone: timezone.as_ref().map(|tz| tz.as_deref()),
+            notify_lead_minutes: body.notify_lead_minutes,
+            max_snoozes: body.max_snoozes,
+            estimated_minutes: body.estimated_minutes,
+            review_interval_days: body.review_interval_days,
+            min_notify_interval_minutes: body.min_notify_interval_minutes,
+            recipient: recipient.as_ref().map(|r| r.as_deref()),
+            auto_complete_with: body.auto_complete_with,
+            allowed_completion_sources: allowed_completion_sources.as_ref().map(|s| s.as_deref()),
+            priority,
+            anchor_mode,
+            is_active: body.is_active,
+        },
+        actor,
     )
     .await
     .map_err(|e| AppError::Internal(e.into()))?
@@ -317,24 +1415,32 @@ pub async fn update_chore(
 
     // Update tags if provided
     let tags = if let Some(ref tag_names) = body.tags {
-        TagRepository::set_chore_tags(&pool, chore.id, tag_names)
+        let mut conn = pool
+            .acquire()
+            .await
+            .map_err(|e| AppError::Internal(e.into()))?;
+        TagRepository::set_chore_tags(&mut conn, chore.id, tag_names, tag_colors)
             .await
             .map_err(|e| AppError::Internal(e.into()))?
     } else {
-        TagRepository::get_tags_for_chore(&pool, chore.id)
+        TagRepository::get_tags_for_chore(pool, chore.id)
             .await
             .map_err(|e| AppError::Internal(e.into()))?
     };
 
-    Ok(Json(ChoreResponse::from_chore(chore, tags)))
+    Ok(ChoreResponse::from_chore(chore, tags))
 }
 
 /// Delete a chore
+///
+/// The chore moves to the trash with its completions and can be restored,
+/// unless `purge` is set.
 #[utoipa::path(
     delete,
     path = "/chores/{id}",
     params(
-        ("id" = Uuid, Path, description = "Chore ID")
+        ("id" = Uuid, Path, description = "Chore ID"),
+        ("purge" = Option<bool>, Query, description = "Delete permanently with its completions, also if already in the trash")
     ),
     tag = TAG,
     responses(
@@ -344,11 +1450,18 @@ pub async fn update_chore(
 )]
 pub async fn delete_chore(
     Extension(pool): Extension<SqlitePool>,
+    CurrentUser(actor): CurrentUser,
     Path(id): Path<Uuid>,
+    Query(query): Query<DeleteChoreQuery>,
 ) -> AppResult<StatusCode> {
-    let deleted = ChoreRepository::delete(&pool, id)
-        .await
-        .map_err(|e| AppError::Internal(e.into()))?;
+    ensure_visible(&pool, id, actor).await?;
+
+    let deleted = if query.purge {
+        ChoreRepository::purge(&pool, id).await
+    } else {
+        ChoreRepository::delete(&pool, id, actor).await
+    }
+    .map_err(|e| AppError::Internal(e.into()))?;
 
     if deleted {
         Ok(StatusCode::NO_CONTENT)
@@ -360,89 +1473,870 @@ pub async fn delete_chore(
     }
 }
 
-/// Mark a chore as complete
+/// List chores in the trash, most recently deleted first
+#[utoipa::path(
+    get,
+    path = "/chores/trash",
+    tag = TAG,
+    responses(
+        (status = 200, description = "Chores in the trash", body = Vec<TrashedChoreResponse>)
+    )
+)]
+pub async fn list_trashed_chores(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+) -> AppResult<Json<Vec<TrashedChoreResponse>>> {
+    let trashed = ChoreRepository::list_trash(&pool, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    let chore_ids: Vec<Uuid> = trashed.iter().map(|t| t.chore.id).collect();
+    let mut tags_map = TagRepository::get_tags_for_chores(&pool, &chore_ids)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    Ok(Json(
+        trashed
+            .into_iter()
+            .map(|t| {
+                let tags = tags_map.remove(&t.chore.id).unwrap_or_default();
+                TrashedChoreResponse {
+                    chore: ChoreResponse::from_chore_with_completion(t.chore, tags),
+                    deleted_at: t.deleted_at,
+                }
+            })
+            .collect(),
+    ))
+}
+
+/// Restore a chore from the trash with its completions
 #[utoipa::path(
     post,
-    path = "/chores/{id}/complete",
+    path = "/chores/{id}/restore",
     params(
         ("id" = Uuid, Path, description = "Chore ID")
     ),
     tag = TAG,
-    request_body = CompleteChoreRequest,
     responses(
-        (status = 201, description = "Completion recorded", body = CompletionResponse),
+        (status = 200, description = "Chore restored", body = ChoreResponse),
         (status = 404, description = "Chore not found")
     )
 )]
-pub async fn complete_chore(
+pub async fn restore_chore(
     Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
     Path(id): Path<Uuid>,
-    Json(body): Json<CompleteChoreRequest>,
-) -> AppResult<(StatusCode, Json<CompletionResponse>)> {
-    // Check if chore exists
-    if !CompletionRepository::chore_exists(&pool, id)
+) -> AppResult<Json<ChoreResponse>> {
+    let not_found = || AppError::NotFound(format!("Chore with id {} not found", id));
+    ensure_visible(&pool, id, owner).await?;
+
+    if !ChoreRepository::restore(&pool, id)
         .await
         .map_err(|e| AppError::Internal(e.into()))?
     {
-        return Err(AppError::NotFound(format!(
-            "Chore with id {} not found",
-            id
-        )));
+        return Err(not_found());
     }
 
-    let completion =
-        CompletionRepository::create(&pool, id, body.completed_at, body.notes.as_deref())
-            .await
-            .map_err(|e| AppError::Internal(e.into()))?;
+    let chore = ChoreRepository::get_by_id(&pool, id, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .ok_or_else(not_found)?;
+    let tags = TagRepository::get_tags_for_chore(&pool, id)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
 
-    Ok((
-        StatusCode::CREATED,
-        Json(CompletionResponse::from(completion)),
-    ))
+    Ok(Json(ChoreResponse::from_chore_with_completion(chore, tags)))
 }
 
-/// List completions for a chore
+/// Pause a chore: it keeps its history but is no longer due or notified
 #[utoipa::path(
-    get,
-    path = "/chores/{id}/completions",
+    post,
+    path = "/chores/{id}/pause",
     params(
-        ("id" = Uuid, Path, description = "Chore ID"),
-        ("cursor" = Option<Uuid>, Query, description = "Cursor for pagination"),
-        ("limit" = Option<i64>, Query, description = "Maximum items to return")
+        ("id" = Uuid, Path, description = "Chore ID")
     ),
     tag = TAG,
     responses(
-        (status = 200, description = "List of completions", body = PaginatedResponse<CompletionResponse>),
+        (status = 200, description = "Chore paused", body = ChoreResponse),
         (status = 404, description = "Chore not found")
     )
 )]
-pub async fn list_completions(
+pub async fn pause_chore(
     Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
     Path(id): Path<Uuid>,
-    Query(query): Query<ListCompletionsQuery>,
-) -> AppResult<Json<PaginatedResponse<CompletionResponse>>> {
-    // Check if chore exists
-    if !CompletionRepository::chore_exists(&pool, id)
+) -> AppResult<Json<ChoreResponse>> {
+    set_chore_active(&pool, id, owner, false).await.map(Json)
+}
+
+/// Resume a paused chore
+#[utoipa::path(
+    post,
+    path = "/chores/{id}/resume",
+    params(
+        ("id" = Uuid, Path, description = "Chore ID")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "Chore resumed", body = ChoreResponse),
+        (status = 404, description = "Chore not found")
+    )
+)]
+pub async fn resume_chore(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<ChoreResponse>> {
+    set_chore_active(&pool, id, owner, true).await.map(Json)
+}
+
+/// Skip a chore's pending occurrence without completing it
+///
+/// The next due time is then computed from the skipped occurrence, as if it
+/// had been completed on time.
+#[utoipa::path(
+    post,
+    path = "/chores/{id}/skip",
+    params(
+        ("id" = Uuid, Path, description = "Chore ID")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "Occurrence skipped", body = ChoreWithDueResponse),
+        (status = 404, description = "Chore not found"),
+        (status = 409, description = "Chore has no pending occurrence")
+    )
+)]
+pub async fn skip_chore(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<ChoreWithDueResponse>> {
+    let chore = ChoreRepository::get_by_id(&pool, id, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .ok_or_else(|| AppError::NotFound(format!("Chore with id {} not found", id)))?;
+    let holidays = HolidayRepository::list_dates(&pool)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+    let now = Utc::now();
+
+    let pending = ChoreService::compute_due_info(&chore, now, &holidays)
+        .and_then(|info| info.next_due)
+        .filter(|_| chore.is_active)
+        .ok_or_else(|| {
+            AppError::Conflict(format!(
+                "Chore with id {} has no pending occurrence to skip",
+                id
+            ))
+        })?;
+
+    ChoreRepository::skip(&pool, id, pending)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    let skipped = ChoreWithLastCompletion {
+        last_skipped_at: Some(pending),
+        ..chore
+    };
+    let info =
+        ChoreService::compute_due_info(&skipped, now, &holidays).unwrap_or(ChoreWithDueInfo {
+            chore: skipped,
+            next_due: None,
+            is_overdue: false,
+        });
+    let tags = TagRepository::get_tags_for_chore(&pool, id)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+    let completed = CompletionRepository::completed_times(&pool, id)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+    let streak = ChoreService::on_time_streak(&info.chore, &completed, &holidays);
+
+    Ok(Json(ChoreWithDueResponse::from_due_info(
+        info, tags, streak,
+    )))
+}
+
+/// Set the active flag of a chore visible to `owner` and return the updated
+/// chore
+async fn set_chore_active(
+    pool: &SqlitePool,
+    id: Uuid,
+    owner: Option<Uuid>,
+    is_active: bool,
+) -> AppResult<ChoreResponse> {
+    let not_found = || AppError::NotFound(format!("Chore with id {} not found", id));
+    ensure_visible(pool, id, owner).await?;
+
+    if !ChoreRepository::set_active(pool, id, is_active)
         .await
         .map_err(|e| AppError::Internal(e.into()))?
     {
-        return Err(AppError::NotFound(format!(
-            "Chore with id {} not found",
-            id
-        )));
+        return Err(not_found());
     }
 
-    let completions = CompletionRepository::list_for_chore(&pool, id, query.cursor, query.limit)
+    let chore = ChoreRepository::get_by_id(pool, id, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .ok_or_else(not_found)?;
+    let tags = TagRepository::get_tags_for_chore(pool, id)
         .await
         .map_err(|e| AppError::Internal(e.into()))?;
 
-    let next_cursor = completions.last().map(|c| c.id);
-    let items: Vec<CompletionResponse> = completions
-        .into_iter()
-        .map(CompletionResponse::from)
+    Ok(ChoreResponse::from_chore_with_completion(chore, tags))
+}
+
+/// Mark a chore as complete, optionally snoozing its next occurrence
+#[utoipa::path(
+    post,
+    path = "/chores/{id}/complete",
+    params(
+        ("id" = Uuid, Path, description = "Chore ID")
+    ),
+    tag = TAG,
+    request_body = CompleteChoreRequest,
+    responses(
+        (status = 201, description = "Completion recorded", body = CompletionResponse,
+            headers(("Location" = String, description = "URL of the new completion"))),
+        (status = 400, description = "Snooze ends before the completion"),
+        (status = 403, description = "Chore does not allow API completions"),
+        (status = 404, description = "Chore not found"),
+        (status = 409, description = "Chore cannot be snoozed")
+    )
+)]
+pub async fn complete_chore(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    note_template: Option<Extension<CompletionNoteTemplate>>,
+    Path(id): Path<Uuid>,
+    Json(body): Json<CompleteChoreRequest>,
+) -> AppResult<(
+    StatusCode,
+    [(HeaderName, String); 1],
+    Json<CompletionResponse>,
+)> {
+    let chore = ChoreRepository::get_by_id(&pool, id, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .ok_or_else(|| AppError::NotFound(format!("Chore with id {} not found", id)))?;
+
+    if !ChoreService::allows_completion_source(
+        chore.allowed_completion_sources.as_deref(),
+        CompletionSource::Api,
+    ) {
+        return Err(AppError::Forbidden(format!(
+            "Chore with id {} cannot be completed through the API",
+            id
+        )));
+    }
+
+    if let Some(until) = body.snooze_next_until {
+        if until <= body.completed_at.unwrap_or_else(Utc::now) {
+            return Err(AppError::BadRequest(
+                "snooze_next_until must be after the completion time".to_string(),
+            ));
+        }
+
+        if chore.max_snoozes == Some(0) {
+            return Err(AppError::Conflict(format!(
+                "Chore with id {} cannot be snoozed",
+                id
+            )));
+        }
+    }
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    let completion = CompletionRepository::insert(
+        &mut tx,
+        id,
+        body.completed_at,
+        body.notes.as_deref(),
+        CompletionSource::Api,
+        note_template.as_ref().map(|Extension(template)| template),
+        body.snooze_next_until,
+    )
+    .await
+    .map_err(|e| AppError::Internal(e.into()))?;
+
+    CompletionRepository::create_for_linked_chores(
+        &mut tx,
+        id,
+        completion.completed_at,
+        CompletionSource::Api,
+    )
+    .await
+    .map_err(|e| AppError::Internal(e.into()))?;
+
+    let snoozed_until = match body.snooze_next_until {
+        Some(until) => Some(
+            NotificationRepository::snooze_until(&mut *tx, id, until)
+                .await
+                .map_err(|e| AppError::Internal(e.into()))?
+                .ok_or_else(|| {
+                    AppError::Conflict(format!("Chore with id {} cannot be snoozed", id))
+                })?,
+        ),
+        None => None,
+    };
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    Ok((
+        StatusCode::CREATED,
+        [(
+            header::LOCATION,
+            format!("/api/completions/{}", completion.id),
+        )],
+        Json(CompletionResponse {
+            snoozed_until,
+            ..CompletionResponse::from(completion)
+        }),
+    ))
+}
+
+/// Undo a chore's latest completion
+///
+/// Removes the completion with the latest `completed_at`, so the chore's
+/// `last_completed_at` falls back to the one before it. With
+/// `UNDO_WINDOW_MINUTES` set, only a completion recorded within that many
+/// minutes can be undone. Completions of linked chores are kept.
+#[utoipa::path(
+    post,
+    path = "/chores/{id}/complete/undo",
+    params(
+        ("id" = Uuid, Path, description = "Chore ID")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "Completion removed", body = CompletionResponse),
+        (status = 404, description = "Chore not found or never completed"),
+        (status = 409, description = "Latest completion is outside the undo window")
+    )
+)]
+pub async fn undo_completion(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    undo_window: Option<Extension<UndoWindow>>,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<CompletionResponse>> {
+    if ChoreRepository::get_by_id(&pool, id, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .is_none()
+    {
+        return Err(AppError::NotFound(format!(
+            "Chore with id {} not found",
+            id
+        )));
+    }
+
+    let completion = CompletionRepository::latest_for_chore(&pool, id)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .ok_or_else(|| {
+            AppError::NotFound(format!("Chore with id {} has no completion to undo", id))
+        })?;
+
+    if let Some(Extension(UndoWindow(window))) = undo_window
+        && completion.created_at + window < Utc::now()
+    {
+        return Err(AppError::Conflict(format!(
+            "Completion with id {} was recorded more than {} minutes ago and can no longer be undone",
+            completion.id,
+            window.num_minutes()
+        )));
+    }
+
+    CompletionRepository::delete(&pool, completion.id, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    Ok(Json(CompletionResponse::from(completion)))
+}
+
+/// Undo a chore's latest completion
+///
+/// Alias of `/complete/undo`, undo window included.
+#[utoipa::path(
+    post,
+    path = "/chores/{id}/uncomplete",
+    params(
+        ("id" = Uuid, Path, description = "Chore ID")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "Completion removed", body = CompletionResponse),
+        (status = 404, description = "Chore not found or never completed"),
+        (status = 409, description = "Latest completion is outside the undo window")
+    )
+)]
+pub async fn uncomplete_chore(
+    pool: Extension<SqlitePool>,
+    user: CurrentUser,
+    undo_window: Option<Extension<UndoWindow>>,
+    id: Path<Uuid>,
+) -> AppResult<Json<CompletionResponse>> {
+    undo_completion(pool, user, undo_window, id).await
+}
+
+/// Mark a chore as reviewed, confirming it is still needed
+///
+/// The chore's next review prompt comes `review_interval_days` from now.
+#[utoipa::path(
+    post,
+    path = "/chores/{id}/reviewed",
+    params(
+        ("id" = Uuid, Path, description = "Chore ID")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "Chore marked reviewed", body = ChoreResponse),
+        (status = 404, description = "Chore not found")
+    )
+)]
+pub async fn mark_chore_reviewed(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<ChoreResponse>> {
+    ensure_visible(&pool, id, owner).await?;
+
+    if !ChoreRepository::mark_reviewed(&pool, id, Utc::now())
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+    {
+        return Err(AppError::NotFound(format!(
+            "Chore with id {} not found",
+            id
+        )));
+    }
+
+    let chore = ChoreRepository::get_by_id(&pool, id, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .ok_or_else(|| AppError::NotFound(format!("Chore with id {} not found", id)))?;
+    let tags = TagRepository::get_tags_for_chore(&pool, id)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    Ok(Json(ChoreResponse::from_chore_with_completion(chore, tags)))
+}
+
+/// Complete every overdue chore at once
+///
+/// With `dry_run`, returns the chores that would be completed without
+/// recording anything. Linked chores are not auto-completed, and chores that
+/// don't allow API completions are left out.
+#[utoipa::path(
+    post,
+    path = "/chores/due/complete",
+    tag = TAG,
+    request_body = BulkCompleteDueRequest,
+    responses(
+        (status = 200, description = "Overdue chores (to be) completed", body = BulkCompleteDueResponse)
+    )
+)]
+pub async fn complete_due_chores(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    note_template: Option<Extension<CompletionNoteTemplate>>,
+    Json(body): Json<BulkCompleteDueRequest>,
+) -> AppResult<Json<BulkCompleteDueResponse>> {
+    let overdue =
+        ChoreService::get_due_chores(&pool, owner, false, DueSort::DueAsc, &TagFilter::default())
+            .await
+            .map_err(AppError::Internal)?;
+    let completed_at = body.completed_at.unwrap_or_else(Utc::now);
+
+    let mut chores = Vec::with_capacity(overdue.len());
+    for info in overdue {
+        let Some(due_at) = info.next_due else {
+            continue;
+        };
+        if !ChoreService::allows_completion_source(
+            info.chore.allowed_completion_sources.as_deref(),
+            CompletionSource::Api,
+        ) {
+            continue;
+        }
+
+        let completion_id = if body.dry_run {
+            None
+        } else {
+            let completion = CompletionRepository::create(
+                &pool,
+                info.chore.id,
+                Some(completed_at),
+                body.notes.as_deref(),
+                CompletionSource::Api,
+                note_template.as_ref().map(|Extension(template)| template),
+            )
+            .await
+            .map_err(|e| AppError::Internal(e.into()))?;
+            Some(completion.id)
+        };
+
+        chores.push(BulkCompletedChore {
+            chore_id: info.chore.id,
+            name: info.chore.name,
+            due_at,
+            completed_at,
+            completion_id,
+        });
+    }
+
+    Ok(Json(BulkCompleteDueResponse {
+        dry_run: body.dry_run,
+        chores,
+    }))
+}
+
+/// List the recorded changes to a chore, oldest first
+///
+/// Entries are kept after the chore is deleted, so this still answers for
+/// deleted chores.
+#[utoipa::path(
+    get,
+    path = "/chores/{id}/audit",
+    params(
+        ("id" = Uuid, Path, description = "Chore ID")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "Audit log entries", body = Vec<AuditLogEntryResponse>),
+        (status = 404, description = "Chore not found")
+    )
+)]
+pub async fn get_chore_audit(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<Vec<AuditLogEntryResponse>>> {
+    ensure_visible(&pool, id, owner).await?;
+
+    let entries = AuditRepository::list_for_chore(&pool, id)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    Ok(Json(entries.into_iter().map(Into::into).collect()))
+}
+
+/// List completions for a chore
+#[utoipa::path(
+    get,
+    path = "/chores/{id}/completions",
+    params(
+        ("id" = Uuid, Path, description = "Chore ID"),
+        ("cursor" = Option<Uuid>, Query, description = "Cursor for pagination"),
+        ("limit" = Option<i64>, Query, description = "Maximum items to return"),
+        ("classify" = Option<bool>, Query, description = "Label each completion early, on time or late"),
+        ("expand" = Option<String>, Query, description = "Embed related objects; only `chore` is supported"),
+        ("from" = Option<DateTime<Utc>>, Query, description = "Earliest completion time, inclusive"),
+        ("to" = Option<DateTime<Utc>>, Query, description = "Latest completion time, inclusive")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "List of completions", body = PaginatedResponse<CompletionResponse>),
+        (status = 400, description = "Unknown expand value or `from` after `to`"),
+        (status = 404, description = "Chore not found")
+    )
+)]
+pub async fn list_completions(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    Path(id): Path<Uuid>,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<ListCompletionsQuery>,
+) -> AppResult<LinkedPage<CompletionResponse>> {
+    let chore = ChoreRepository::get_by_id(&pool, id, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .ok_or_else(|| AppError::NotFound(format!("Chore with id {} not found", id)))?;
+
+    let expand_chore = parse_completion_expand(query.expand.as_deref())?;
+
+    if let (Some(from), Some(to)) = (query.from, query.to)
+        && from > to
+    {
+        return Err(AppError::BadRequest(
+            "'from' must not be after 'to'".to_string(),
+        ));
+    }
+    let range = CompletedAtRange {
+        from: query.from,
+        to: query.to,
+    };
+
+    let mut items: Vec<CompletionResponse> = if expand_chore {
+        CompletionRepository::list_for_chore_with_chore(&pool, id, range, query.cursor, query.limit)
+            .await
+            .map_err(|e| AppError::Internal(e.into()))?
+            .into_iter()
+            .map(CompletionResponse::from)
+            .collect()
+    } else {
+        CompletionRepository::list_for_chore(&pool, id, range, query.cursor, query.limit)
+            .await
+            .map_err(|e| AppError::Internal(e.into()))?
+            .into_iter()
+            .map(CompletionResponse::from)
+            .collect()
+    };
+    let next_cursor = items.last().map(|c| c.id.to_string());
+
+    if query.classify {
+        // The occurrence a completion satisfied follows the one before it,
+        // which may be on another page
+        let history = CompletionRepository::completed_times(&pool, id)
+            .await
+            .map_err(|e| AppError::Internal(e.into()))?;
+        let holidays = HolidayRepository::list_dates(&pool)
+            .await
+            .map_err(|e| AppError::Internal(e.into()))?;
+
+        for item in &mut items {
+            let earlier = history.partition_point(|t| *t < item.completed_at);
+            let previous = earlier.checked_sub(1).map(|i| history[i]);
+            if let Some((timing, lateness)) =
+                ChoreService::classify_completion(&chore, previous, item.completed_at, &holidays)
+            {
+                item.timing = Some(timing);
+                item.lateness_seconds = Some(lateness);
+            }
+        }
+    }
+
+    Ok(PaginatedResponse::new(items, next_cursor).linked(uri))
+}
+
+/// Parse a completion `expand` list; returns whether the chore is embedded
+fn parse_completion_expand(expand: Option<&str>) -> AppResult<bool> {
+    let mut chore = false;
+    for field in expand.into_iter().flat_map(|e| e.split(',')) {
+        match field.trim() {
+            "chore" => chore = true,
+            "" => {}
+            other => {
+                return Err(AppError::BadRequest(format!(
+                    "Unknown expand value '{}', expected 'chore'",
+                    other
+                )));
+            }
+        }
+    }
+    Ok(chore)
+}
+
+/// Get completion statistics for a chore
+#[utoipa::path(
+    get,
+    path = "/chores/{id}/stats",
+    params(
+        ("id" = Uuid, Path, description = "Chore ID")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "Chore statistics", body = ChoreStatsResponse),
+        (status = 404, description = "Chore not found")
+    )
+)]
+pub async fn get_chore_stats(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<ChoreStatsResponse>> {
+    let chore = ChoreRepository::get_by_id(&pool, id, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .ok_or_else(|| AppError::NotFound(format!("Chore with id {} not found", id)))?;
+
+    let stats = CompletionRepository::stats(&pool, id, Utc::now())
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    let completed = CompletionRepository::completed_times(&pool, id)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+    let holidays = HolidayRepository::list_dates(&pool)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+    let (on_time_completions, late_completions) =
+        ChoreService::count_punctuality(&chore, &completed, &holidays);
+
+    Ok(Json(ChoreStatsResponse {
+        on_time_completions,
+        late_completions,
+        ..ChoreStatsResponse::from_stats(id, stats)
+    }))
+}
+
+/// List completions across all chores, newest first
+///
+/// Filters must stay the same while paging; the `Link` header's next page
+/// keeps them.
+#[utoipa::path(
+    get,
+    path = "/completions",
+    params(
+        ("cursor" = Option<String>, Query, description = "Cursor for pagination"),
+        ("limit" = Option<i64>, Query, description = "Maximum items to return (default 20)"),
+        ("from" = Option<NaiveDate>, Query, description = "First day, inclusive"),
+        ("to" = Option<NaiveDate>, Query, description = "Last day, inclusive"),
+        ("tag" = Option<String>, Query, description = "Filter by tag name")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "List of completions", body = PaginatedResponse<CompletionResponse>),
+        (status = 400, description = "Invalid cursor or date range")
+    )
+)]
+pub async fn list_all_completions(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<CompletionFeedQuery>,
+) -> AppResult<LinkedPage<CompletionResponse>> {
+    if let (Some(from), Some(to)) = (query.from, query.to)
+        && from > to
+    {
+        return Err(AppError::BadRequest(
+            "'from' must not be after 'to'".to_string(),
+        ));
+    }
+    let cursor = query
+        .cursor
+        .as_deref()
+        .map(str::parse::<CompletionCursor>)
+        .transpose()
+        .map_err(AppError::BadRequest)?;
+
+    let filter = CompletionFeedFilter {
+        from: query.from,
+        to: query.to,
+        tag: query.tag.as_deref(),
+        owner,
+    };
+    let completions = CompletionRepository::list_feed(&pool, filter, cursor.as_ref(), query.limit)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    let next_cursor = completions
+        .last()
+        .map(|c| CompletionCursor::after(&c.completion).to_string());
+    let items = completions
+        .into_iter()
+        .map(CompletionResponse::from)
         .collect();
 
-    Ok(Json(PaginatedResponse::new(items, next_cursor)))
+    Ok(PaginatedResponse::new(items, next_cursor).linked(uri))
+}
+
+/// Get completion counts per day for a heatmap
+#[utoipa::path(
+    get,
+    path = "/completions/heatmap",
+    params(
+        ("from" = Option<NaiveDate>, Query, description = "First day, inclusive (default: one year before `to`)"),
+        ("to" = Option<NaiveDate>, Query, description = "Last day, inclusive (default: today)"),
+        ("tag" = Option<String>, Query, description = "Filter by tag name")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "Completion counts per day", body = CompletionHeatmapResponse),
+        (status = 400, description = "Invalid date range")
+    )
+)]
+pub async fn get_completion_heatmap(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    Query(query): Query<CompletionHeatmapQuery>,
+) -> AppResult<Json<CompletionHeatmapResponse>> {
+    let (from, to) = completion_range(&query)?;
+
+    let counts = CompletionRepository::daily_counts(&pool, from, to, query.tag.as_deref(), owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    Ok(Json(CompletionHeatmapResponse {
+        from,
+        to,
+        counts: counts.into_iter().collect(),
+    }))
+}
+
+/// Get completion counts per day as a list, oldest first
+#[utoipa::path(
+    get,
+    path = "/completions/calendar",
+    params(
+        ("from" = Option<NaiveDate>, Query, description = "First day, inclusive (default: one year before `to`)"),
+        ("to" = Option<NaiveDate>, Query, description = "Last day, inclusive (default: today)"),
+        ("tag" = Option<String>, Query, description = "Filter by tag name")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "Days with completions", body = Vec<CompletionDayCount>),
+        (status = 400, description = "Invalid date range")
+    )
+)]
+pub async fn get_completion_calendar(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    Query(query): Query<CompletionHeatmapQuery>,
+) -> AppResult<Json<Vec<CompletionDayCount>>> {
+    let (from, to) = completion_range(&query)?;
+
+    let counts = CompletionRepository::daily_counts(&pool, from, to, query.tag.as_deref(), owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    Ok(Json(
+        counts
+            .into_iter()
+            .map(|(date, count)| CompletionDayCount { date, count })
+            .collect(),
+    ))
+}
+
+/// Edit a completion's time or notes
+#[utoipa::path(
+    put,
+    path = "/completions/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Completion ID")
+    ),
+    tag = TAG,
+    request_body = UpdateCompletionRequest,
+    responses(
+        (status = 200, description = "Completion updated", body = CompletionResponse),
+        (status = 404, description = "Completion not found")
+    )
+)]
+pub async fn update_completion(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    Path(id): Path<Uuid>,
+    Json(body): Json<UpdateCompletionRequest>,
+) -> AppResult<Json<CompletionResponse>> {
+    let completion = CompletionRepository::update(
+        &pool,
+        id,
+        owner,
+        body.completed_at,
+        body.notes.as_ref().map(|n| n.as_deref()),
+    )
+    .await
+    .map_err(|e| AppError::Internal(e.into()))?
+    .ok_or_else(|| AppError::NotFound(format!("Completion with id {} not found", id)))?;
+
+    Ok(Json(CompletionResponse::from(completion)))
 }
 
 /// Delete a completion record
@@ -460,9 +2354,10 @@ pub async fn list_completions(
 )]
 pub async fn delete_completion(
     Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
     Path(id): Path<Uuid>,
 ) -> AppResult<StatusCode> {
-    let deleted = CompletionRepository::delete(&pool, id)
+    let deleted = CompletionRepository::delete(&pool, id, owner)
         .await
         .map_err(|e| AppError::Internal(e.into()))?;
 
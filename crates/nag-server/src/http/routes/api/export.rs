@@ -0,0 +1,406 @@
+use std::collections::HashSet;
+
+use axum::{
+    Json,
+    extract::{Extension, Path},
+    http::header,
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use crate::db::{
+    ChoreRepository, CompletionRepository, TagRepository,
+    chores::{CreateChoreParams, TagFilter},
+    models::{Completion, ScheduleType},
+    tags::TagColorStrategy,
+};
+use crate::http::models::{
+    AppError, AppResult, ExportDocument, ExportedChore, ExportedCompletion, ExportedTag,
+    ImportResponse, is_valid_tag_color,
+};
+use crate::http::routes::auth::CurrentUser;
+use crate::services::{ChoreService, ScheduleLimits, export};
+
+const TAG: &str = "Export";
+
+/// Respond with a CSV attachment named `filename`
+fn csv_response(filename: &str, body: String) -> Response {
+    (
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{filename}\""),
+            ),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+/// Export every chore, including paused ones, as CSV
+#[utoipa::path(
+    get,
+    path = "/export/chores.csv",
+    tag = TAG,
+    responses(
+        (status = 200, description = "Chores as CSV", content_type = "text/csv", body = String)
+    )
+)]
+pub async fn export_chores_csv(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+) -> AppResult<Response> {
+    let chores =
+        ChoreRepository::list_all_with_last_completion(&pool, owner, true, &TagFilter::default())
+            .await
+            .map_err(|e| AppError::Internal(e.into()))?;
+    let chore_ids: Vec<Uuid> = chores.iter().map(|c| c.id).collect();
+    let tags = TagRepository::get_tags_for_chores(&pool, &chore_ids)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    let body =
+        export::render_chores_csv(&chores, &tags).map_err(|e| AppError::Internal(e.into()))?;
+
+    Ok(csv_response("chores.csv", body))
+}
+
+/// Export every completion, oldest first, as CSV
+#[utoipa::path(
+    get,
+    path = "/export/completions.csv",
+    tag = TAG,
+    responses(
+        (status = 200, description = "Completions as CSV", content_type = "text/csv", body = String)
+    )
+)]
+pub async fn export_completions_csv(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+) -> AppResult<Response> {
+    let completions = CompletionRepository::list_all(&pool, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    let body =
+        export::render_completions_csv(&completions).map_err(|e| AppError::Internal(e.into()))?;
+
+    Ok(csv_response("completions.csv", body))
+}
+
+/// Export every completion of a chore, oldest first, as CSV
+#[utoipa::path(
+    get,
+    path = "/chores/{id}/completions.csv",
+    params(
+        ("id" = Uuid, Path, description = "Chore ID")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "The chore's completions as CSV", content_type = "text/csv", body = String),
+        (status = 404, description = "Chore not found")
+    )
+)]
+pub async fn export_chore_completions_csv(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> AppResult<Response> {
+    if ChoreRepository::get_by_id(&pool, id, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .is_none()
+    {
+        return Err(AppError::NotFound(format!(
+            "Chore with id {} not found",
+            id
+        )));
+    }
+
+    let completions = CompletionRepository::list_all_for_chore(&pool, id)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    let body = export::render_chore_completions_csv(&completions)
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    Ok(csv_response(&format!("completions-{id}.csv"), body))
+}
+
+/// Export every chore, tag and completion as a JSON document for backups
+#[utoipa::path(
+    get,
+    path = "/export",
+    tag = TAG,
+    responses(
+        (status = 200, description = "All chores, tags and completions", body = ExportDocument)
+    )
+)]
+pub async fn export_json(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+) -> AppResult<Json<ExportDocument>> {
+    let chores = ChoreRepository::dump(&pool, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+    let chore_ids: Vec<Uuid> = chores.iter().map(|c| c.id).collect();
+    let mut chore_tags = TagRepository::get_tags_for_chores(&pool, &chore_ids)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+    let tags = TagRepository::list(&pool)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+    let completions = CompletionRepository::list_all(&pool, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    Ok(Json(ExportDocument {
+        tags: tags.into_iter().map(ExportedTag::from).collect(),
+        chores: chores
+            .iter()
+            .map(|chore| {
+                let tags = chore_tags.remove(&chore.id).unwrap_or_default();
+                ExportedChore::from_chore(chore, tags)
+            })
+            .collect(),
+        completions: completions
+            .into_iter()
+            .map(ExportedCompletion::from)
+            .collect(),
+    }))
+}
+
+/// Check that an imported chore has a complete, valid schedule
+fn validate_imported_schedule(chore: &ExportedChore, limits: ScheduleLimits) -> Result<(), String> {
+    let schedule = &chore.schedule;
+    match schedule.schedule_type {
+        ScheduleType::Cron => {
+            let cron = schedule
+                .cron_schedule
+                .as_deref()
+                .ok_or("cron_schedule is required for cron chores")?;
+            ChoreService::validate_cron_schedule(cron, limits)
+                .map_err(|e| format!("Invalid cron schedule: {}", e))
+        }
+        ScheduleType::Interval => {
+            let days = schedule
+                .interval_days
+                .ok_or("interval_days is required for interval chores")?;
+            ChoreService::validate_interval_schedule(
+                days,
+                schedule.interval_time_hour,
+                schedule.interval_time_minute,
+                limits,
+            )
+            .map_err(|e| format!("Invalid interval schedule: {}", e))
+        }
+        ScheduleType::DaysOfWeek => {
+            let days = schedule
+                .days_of_week
+                .as_deref()
+                .ok_or("days_of_week is required for days of week chores")?;
+            ChoreService::validate_days_of_week_schedule(
+                &ChoreService::parse_days_of_week(days),
+                schedule.interval_time_hour,
+                schedule.interval_time_minute,
+            )
+            .map_err(|e| format!("Invalid days of week schedule: {}", e))
+        }
+        // Past due times are kept: a backup may hold one-time chores that
+        // were already done
+        ScheduleType::OneTime => schedule
+            .due_at
+            .map(|_| ())
+            .ok_or_else(|| "due_at is required for one-time chores".to_string()),
+        ScheduleType::OnceInAWhile => Ok(()),
+    }
+}
+
+/// Validate an import document, rejecting it with 400 naming the first
+/// offending entry by its index
+fn validate_import(body: &ExportDocument, limits: ScheduleLimits) -> AppResult<()> {
+    for (index, tag) in body.tags.iter().enumerate() {
+        if let Some(color) = tag.color.as_deref()
+            && !is_valid_tag_color(color)
+        {
+            return Err(AppError::BadRequest(format!(
+                "tags[{}] (\"{}\"): invalid color \"{}\"",
+                index, tag.name, color
+            )));
+        }
+    }
+
+    for (index, chore) in body.chores.iter().enumerate() {
+        validate_imported_schedule(chore, limits)
+            .and_then(|()| {
+                chore
+                    .estimated_minutes
+                    .map_or(Ok(()), ChoreService::validate_estimated_minutes)
+            })
+            .and_then(|()| {
+                chore
+                    .review_interval_days
+                    .map_or(Ok(()), ChoreService::validate_review_interval_days)
+            })
+            .and_then(|()| {
+                chore
+                    .min_notify_interval_minutes
+                    .map_or(Ok(()), ChoreService::validate_min_notify_interval_minutes)
+            })
+            .map_err(|e| {
+                AppError::BadRequest(format!("chores[{}] (\"{}\"): {}", index, chore.name, e))
+            })?;
+    }
+
+    let chore_ids: HashSet<Uuid> = body.chores.iter().filter_map(|c| c.id).collect();
+    for (index, completion) in body.completions.iter().enumerate() {
+        if !chore_ids.contains(&completion.chore_id) {
+            return Err(AppError::BadRequest(format!(
+                "completions[{}]: chore {} is not part of the import",
+                index, completion.chore_id
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Restore chores, tags and completions from an export document
+///
+/// Tags are matched to existing ones by name. Chores and completions keep
+/// their ids; those whose id already exists are skipped, so importing the
+/// same backup twice is harmless. Every entry is validated before anything is
+/// written, and the import runs in one transaction, so a rejected document
+/// leaves the database untouched.
+#[utoipa::path(
+    post,
+    path = "/import",
+    request_body = ExportDocument,
+    tag = TAG,
+    responses(
+        (status = 200, description = "Document imported", body = ImportResponse),
+        (status = 400, description = "Invalid entry in the document"),
+        (status = 409, description = "An external id is already in use")
+    )
+)]
+pub async fn import_json(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    Extension(limits): Extension<ScheduleLimits>,
+    Json(body): Json<ExportDocument>,
+) -> AppResult<Json<ImportResponse>> {
+    validate_import(&body, limits)?;
+
+    let mut summary = ImportResponse {
+        chores_created: 0,
+        chores_skipped: 0,
+        completions_created: 0,
+        completions_skipped: 0,
+        tags_imported: body.tags.len(),
+    };
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    for tag in &body.tags {
+        TagRepository::find_or_create(&mut tx, tag.name.trim(), tag.color.as_deref())
+            .await
+            .map_err(|e| AppError::Internal(e.into()))?;
+    }
+
+    for (index, chore) in body.chores.iter().enumerate() {
+        let id = match chore.id {
+            Some(id) => {
+                let exists = ChoreRepository::exists(&mut tx, id)
+                    .await
+                    .map_err(|e| AppError::Internal(e.into()))?;
+                if exists {
+                    summary.chores_skipped += 1;
+                    continue;
+                }
+                id
+            }
+            None => Uuid::new_v4(),
+        };
+
+        let schedule = &chore.schedule;
+        ChoreRepository::insert(
+            &mut tx,
+            id,
+            CreateChoreParams {
+                name: &chore.name,
+                description: chore.description.as_deref(),
+                schedule_type: schedule.schedule_type,
+                cron_schedule: schedule.cron_schedule.as_deref(),
+                interval_days: schedule.interval_days,
+                interval_time_hour: schedule.interval_time_hour,
+                interval_time_minute: schedule.interval_time_minute,
+                days_of_week: schedule.days_of_week.as_deref(),
+                due_at: schedule.due_at,
+                timezone: schedule.timezone.as_deref(),
+                notify_lead_minutes: chore.notify_lead_minutes,
+                max_snoozes: chore.max_snoozes,
+                estimated_minutes: chore.estimated_minutes,
+                review_interval_days: chore.review_interval_days,
+                min_notify_interval_minutes: chore.min_notify_interval_minutes,
+                recipient: chore.recipient.as_deref(),
+                auto_complete_with: None,
+                allowed_completion_sources: chore.allowed_completion_sources.as_deref(),
+                external_id: chore.external_id.as_deref(),
+                priority: chore.priority,
+                anchor_mode: schedule.anchor_mode,
+                user_id: owner,
+                is_active: chore.is_active,
+            },
+        )
+        .await
+        .map_err(|e| match e.as_database_error() {
+            Some(db) if db.is_unique_violation() => AppError::Conflict(format!(
+                "chores[{}] (\"{}\"): external id is already in use",
+                index, chore.name
+            )),
+            _ => AppError::Internal(e.into()),
+        })?;
+
+        if !chore.tags.is_empty() {
+            TagRepository::set_chore_tags(&mut tx, id, &chore.tags, TagColorStrategy::Hash)
+                .await
+                .map_err(|e| AppError::Internal(e.into()))?;
+        }
+        summary.chores_created += 1;
+    }
+
+    let now = Utc::now();
+    for completion in &body.completions {
+        let created = CompletionRepository::restore(
+            &mut tx,
+            &Completion {
+                id: completion.id.unwrap_or_else(Uuid::new_v4),
+                chore_id: completion.chore_id,
+                completed_at: completion.completed_at,
+                notes: completion.notes.clone(),
+                source: completion.source,
+                created_at: now,
+            },
+        )
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+        if created {
+            summary.completions_created += 1;
+        } else {
+            summary.completions_skipped += 1;
+        }
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    Ok(Json(summary))
+}
@@ -0,0 +1,100 @@
+use axum::{
+    Json,
+    extract::{Extension, Path},
+    http::StatusCode,
+};
+use chrono::NaiveDate;
+use sqlx::SqlitePool;
+
+use crate::db::HolidayRepository;
+use crate::http::models::{AppError, AppResult, CreateHolidayRequest, HolidayResponse};
+
+const TAG: &str = "Holidays";
+
+/// List all holidays
+#[utoipa::path(
+    get,
+    path = "/holidays",
+    tag = TAG,
+    responses(
+        (status = 200, description = "List of all holidays", body = Vec<HolidayResponse>)
+    )
+)]
+pub async fn list_holidays(
+    Extension(pool): Extension<SqlitePool>,
+) -> AppResult<Json<Vec<HolidayResponse>>> {
+    let holidays = HolidayRepository::list(&pool)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    let items: Vec<HolidayResponse> = holidays.into_iter().map(HolidayResponse::from).collect();
+
+    Ok(Json(items))
+}
+
+/// Add a holiday
+#[utoipa::path(
+    post,
+    path = "/holidays",
+    request_body = CreateHolidayRequest,
+    tag = TAG,
+    responses(
+        (status = 201, description = "Holiday created", body = HolidayResponse),
+        (status = 400, description = "Invalid request"),
+        (status = 409, description = "Holiday for this date already exists")
+    )
+)]
+pub async fn create_holiday(
+    Extension(pool): Extension<SqlitePool>,
+    Json(body): Json<CreateHolidayRequest>,
+) -> AppResult<(StatusCode, Json<HolidayResponse>)> {
+    if HolidayRepository::exists(&pool, body.date)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+    {
+        return Err(AppError::Conflict(format!(
+            "Holiday on {} already exists",
+            body.date
+        )));
+    }
+
+    let name = body
+        .name
+        .as_deref()
+        .map(str::trim)
+        .filter(|n| !n.is_empty());
+
+    let holiday = HolidayRepository::create(&pool, body.date, name)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    Ok((StatusCode::CREATED, Json(HolidayResponse::from(holiday))))
+}
+
+/// Remove a holiday
+#[utoipa::path(
+    delete,
+    path = "/holidays/{date}",
+    params(
+        ("date" = NaiveDate, Path, description = "Holiday date (YYYY-MM-DD)")
+    ),
+    tag = TAG,
+    responses(
+        (status = 204, description = "Holiday deleted"),
+        (status = 404, description = "Holiday not found")
+    )
+)]
+pub async fn delete_holiday(
+    Extension(pool): Extension<SqlitePool>,
+    Path(date): Path<NaiveDate>,
+) -> AppResult<StatusCode> {
+    let deleted = HolidayRepository::delete(&pool, date)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    if deleted {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(AppError::NotFound(format!("Holiday on {} not found", date)))
+    }
+}
@@ -1,4 +1,7 @@
 mod chores;
+mod export;
+mod holidays;
+mod notifications;
 mod tags;
 
 use utoipa_axum::{router::OpenApiRouter, routes};
@@ -7,18 +10,61 @@ pub fn router() -> OpenApiRouter {
     OpenApiRouter::new()
         // Chore routes
         .routes(routes!(chores::list_chores))
+        .routes(routes!(chores::search_chores))
         .routes(routes!(chores::get_due_chores))
+        .routes(routes!(chores::get_day_budget))
+        .routes(routes!(chores::get_chore_schedule))
+        .routes(routes!(chores::get_chores_calendar))
         .routes(routes!(chores::create_chore))
+        .routes(routes!(chores::preview_schedule))
         .routes(routes!(chores::get_chore))
         .routes(routes!(chores::update_chore))
+        .routes(routes!(chores::upsert_chore_by_external_id))
         .routes(routes!(chores::delete_chore))
+        .routes(routes!(chores::list_trashed_chores))
+        .routes(routes!(chores::restore_chore))
+        .routes(routes!(chores::pause_chore))
+        .routes(routes!(chores::resume_chore))
+        .routes(routes!(chores::skip_chore))
         .routes(routes!(chores::complete_chore))
+        .routes(routes!(chores::undo_completion))
+        .routes(routes!(chores::uncomplete_chore))
+        .routes(routes!(chores::mark_chore_reviewed))
+        .routes(routes!(chores::complete_due_chores))
         .routes(routes!(chores::list_completions))
+        .routes(routes!(chores::get_chore_audit))
+        .routes(routes!(chores::get_chore_stats))
+        .routes(routes!(chores::get_next_occurrences))
         // Completion routes
+        .routes(routes!(chores::list_all_completions))
+        .routes(routes!(chores::update_completion))
         .routes(routes!(chores::delete_completion))
+        .routes(routes!(chores::get_completion_heatmap))
+        .routes(routes!(chores::get_completion_calendar))
         // Tag routes
         .routes(routes!(tags::list_tags))
         .routes(routes!(tags::create_tag))
         .routes(routes!(tags::update_tag))
         .routes(routes!(tags::delete_tag))
+        .routes(routes!(tags::restore_tag))
+        .routes(routes!(tags::merge_tags))
+        .routes(routes!(tags::mute_tag))
+        .routes(routes!(tags::unmute_tag))
+        // Holiday routes
+        .routes(routes!(holidays::list_holidays))
+        .routes(routes!(holidays::create_holiday))
+        .routes(routes!(holidays::delete_holiday))
+        // Export routes
+        .routes(routes!(export::export_chores_csv))
+        .routes(routes!(export::export_completions_csv))
+        .routes(routes!(export::export_chore_completions_csv))
+        .routes(routes!(export::export_json))
+        .routes(routes!(export::import_json))
+        // Notification routes
+        .routes(routes!(notifications::list_chore_notifications))
+        .routes(routes!(notifications::snooze_chore))
+        .routes(routes!(notifications::list_notification_deliveries))
+        .routes(routes!(notifications::list_notification_events))
+        .routes(routes!(notifications::retry_notification_delivery))
+        .routes(routes!(notifications::simulate_notifications))
 }
@@ -0,0 +1,316 @@
+use axum::{
+    Json,
+    extract::{Extension, OriginalUri, Path, Query},
+};
+use chrono::{Duration, Utc};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use crate::db::models::{NotificationDeliveryStatus, NotificationEventType};
+use crate::db::notifications::{NotificationEventCursor, NotificationEventFilter};
+use crate::db::{CompletionRepository, NotificationRepository};
+use crate::http::models::{
+    AppError, AppResult, LinkedPage, ListDeliveriesQuery, ListEventsQuery,
+    NotificationDeliveryResponse, NotificationEventResponse, PaginatedResponse,
+    SimulateNotificationsRequest, SimulatedNotificationResponse, SnoozeChoreRequest,
+    SnoozeResponse,
+};
+use crate::http::routes::auth::CurrentUser;
+use crate::services::notifications::{EventGeneratorSettings, simulate_due_events};
+
+const TAG: &str = "Notifications";
+
+/// Default and longest window of a notification simulation, in days
+const SIMULATION_DEFAULT_DAYS: i64 = 7;
+const SIMULATION_MAX_DAYS: i64 = 31;
+
+/// List notification deliveries for a chore
+#[utoipa::path(
+    get,
+    path = "/chores/{id}/notifications",
+    params(
+        ("id" = Uuid, Path, description = "Chore ID")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "Notification deliveries, newest first", body = Vec<NotificationDeliveryResponse>),
+        (status = 404, description = "Chore not found")
+    )
+)]
+pub async fn list_chore_notifications(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<Vec<NotificationDeliveryResponse>>> {
+    if !CompletionRepository::chore_exists(&pool, id, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+    {
+        return Err(AppError::NotFound(format!(
+            "Chore with id {} not found",
+            id
+        )));
+    }
+
+    let deliveries = NotificationRepository::list_for_chore(&pool, id)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    Ok(Json(
+        deliveries
+            .into_iter()
+            .map(NotificationDeliveryResponse::from)
+            .collect(),
+    ))
+}
+
+/// Snooze a chore's due notifications
+///
+/// Each snooze counts towards the chore's `max_snoozes`; the count resets
+/// when the chore is completed.
+#[utoipa::path(
+    post,
+    path = "/chores/{id}/snooze",
+    params(
+        ("id" = Uuid, Path, description = "Chore ID")
+    ),
+    request_body = SnoozeChoreRequest,
+    tag = TAG,
+    responses(
+        (status = 200, description = "Chore snoozed", body = SnoozeResponse),
+        (status = 400, description = "Snooze ends in the past"),
+        (status = 404, description = "Chore not found"),
+        (status = 409, description = "Snooze limit reached")
+    )
+)]
+pub async fn snooze_chore(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    Path(id): Path<Uuid>,
+    Json(body): Json<SnoozeChoreRequest>,
+) -> AppResult<Json<SnoozeResponse>> {
+    if body.until <= Utc::now() {
+        return Err(AppError::BadRequest(
+            "until must be in the future".to_string(),
+        ));
+    }
+
+    if !CompletionRepository::chore_exists(&pool, id, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+    {
+        return Err(AppError::NotFound(format!(
+            "Chore with id {} not found",
+            id
+        )));
+    }
+
+    let snoozed_until = NotificationRepository::snooze_until(&pool, id, body.until)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .ok_or_else(|| {
+            AppError::Conflict(format!("Chore with id {} has reached its snooze limit", id))
+        })?;
+
+    Ok(Json(SnoozeResponse { snoozed_until }))
+}
+
+/// List notification deliveries across all chores
+///
+/// Newest first; filter by `status=failed` to see why notifications are not
+/// arriving.
+#[utoipa::path(
+    get,
+    path = "/notifications/deliveries",
+    params(
+        ("status" = Option<NotificationDeliveryStatus>, Query, description = "Only return deliveries with this status"),
+        ("cursor" = Option<Uuid>, Query, description = "Cursor for pagination"),
+        ("limit" = Option<i64>, Query, description = "Maximum items to return (default 20)")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "Page of notification deliveries", body = PaginatedResponse<NotificationDeliveryResponse>)
+    )
+)]
+pub async fn list_notification_deliveries(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<ListDeliveriesQuery>,
+) -> AppResult<LinkedPage<NotificationDeliveryResponse>> {
+    let items: Vec<NotificationDeliveryResponse> = NotificationRepository::list_deliveries(
+        &pool,
+        query.status,
+        owner,
+        query.cursor,
+        query.limit,
+    )
+    .await
+    .map_err(|e| AppError::Internal(e.into()))?
+    .into_iter()
+    .map(NotificationDeliveryResponse::from)
+    .collect();
+    let next_cursor = items.last().map(|d| d.id.to_string());
+
+    Ok(PaginatedResponse::new(items, next_cursor).linked(uri))
+}
+
+/// List notification events with their deliveries
+///
+/// Latest due time first, for auditing what was sent and when.
+#[utoipa::path(
+    get,
+    path = "/notifications/events",
+    params(
+        ("type" = Option<NotificationEventType>, Query, description = "Only return events of this type"),
+        ("from" = Option<DateTime<Utc>>, Query, description = "Earliest due time, inclusive"),
+        ("to" = Option<DateTime<Utc>>, Query, description = "Latest due time, inclusive"),
+        ("cursor" = Option<String>, Query, description = "Cursor for pagination"),
+        ("limit" = Option<i64>, Query, description = "Maximum items to return (default 20)")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "Page of notification events", body = PaginatedResponse<NotificationEventResponse>),
+        (status = 400, description = "Invalid cursor or date range")
+    )
+)]
+pub async fn list_notification_events(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<ListEventsQuery>,
+) -> AppResult<LinkedPage<NotificationEventResponse>> {
+    if let (Some(from), Some(to)) = (query.from, query.to)
+        && from > to
+    {
+        return Err(AppError::BadRequest(
+            "'from' must not be after 'to'".to_string(),
+        ));
+    }
+    let cursor = query
+        .cursor
+        .as_deref()
+        .map(str::parse::<NotificationEventCursor>)
+        .transpose()
+        .map_err(AppError::BadRequest)?;
+
+    let filter = NotificationEventFilter {
+        event_type: query.event_type,
+        owner,
+        from: query.from,
+        to: query.to,
+    };
+    let events = NotificationRepository::list_events(&pool, filter, cursor.as_ref(), query.limit)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    let event_ids: Vec<Uuid> = events.iter().map(|e| e.id).collect();
+    let mut deliveries = NotificationRepository::deliveries_for_events(&pool, &event_ids)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    let next_cursor = events
+        .last()
+        .map(|e| NotificationEventCursor::after(e).to_string());
+    let items = events
+        .into_iter()
+        .map(|event| {
+            let event_deliveries = deliveries.remove(&event.id).unwrap_or_default();
+            NotificationEventResponse::from_event(event, event_deliveries)
+        })
+        .collect();
+
+    Ok(PaginatedResponse::new(items, next_cursor).linked(uri))
+}
+
+/// Requeue a notification delivery for another round of send attempts
+///
+/// Resets the attempt count so deliveries that exhausted their retries
+/// (e.g. after fixing a bad bot token) are sent on the next dispatch tick.
+#[utoipa::path(
+    post,
+    path = "/notifications/deliveries/{delivery_id}/retry",
+    params(
+        ("delivery_id" = Uuid, Path, description = "Notification delivery ID")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "Delivery requeued", body = NotificationDeliveryResponse),
+        (status = 404, description = "Delivery not found")
+    )
+)]
+pub async fn retry_notification_delivery(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    Path(delivery_id): Path<Uuid>,
+) -> AppResult<Json<NotificationDeliveryResponse>> {
+    let not_found = || {
+        AppError::NotFound(format!(
+            "Notification delivery with id {} not found",
+            delivery_id
+        ))
+    };
+
+    if !NotificationRepository::reset_delivery(&pool, delivery_id, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+    {
+        return Err(not_found());
+    }
+
+    let delivery = NotificationRepository::get_delivery(&pool, delivery_id, owner)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .ok_or_else(not_found)?;
+
+    Ok(Json(NotificationDeliveryResponse::from(delivery)))
+}
+
+/// Preview the due notifications the generator would raise
+///
+/// Runs the generator read-only over the coming window, assuming each
+/// occurrence is completed when due. Nothing is written. Without
+/// notifications enabled, events are listed with no channels.
+#[utoipa::path(
+    post,
+    path = "/admin/notifications/simulate",
+    request_body(content = Option<SimulateNotificationsRequest>),
+    tag = TAG,
+    responses(
+        (status = 200, description = "Events the generator would raise, soonest first", body = Vec<SimulatedNotificationResponse>),
+        (status = 400, description = "Window out of range")
+    )
+)]
+pub async fn simulate_notifications(
+    Extension(pool): Extension<SqlitePool>,
+    CurrentUser(owner): CurrentUser,
+    settings: Option<Extension<EventGeneratorSettings>>,
+    body: Option<Json<SimulateNotificationsRequest>>,
+) -> AppResult<Json<Vec<SimulatedNotificationResponse>>> {
+    let Json(body) = body.unwrap_or_default();
+    let days = body.window_days.unwrap_or(SIMULATION_DEFAULT_DAYS);
+    if !(1..=SIMULATION_MAX_DAYS).contains(&days) {
+        return Err(AppError::BadRequest(format!(
+            "window_days must be between 1 and {}",
+            SIMULATION_MAX_DAYS
+        )));
+    }
+
+    let settings = settings.map(|Extension(settings)| settings);
+    let (channels, config) = match &settings {
+        Some(settings) => (settings.channels.as_slice(), settings.config.clone()),
+        None => (&[][..], Default::default()),
+    };
+
+    let now = Utc::now();
+    let events = simulate_due_events(&pool, &config, owner, now, now + Duration::days(days))
+        .await
+        .map_err(AppError::Internal)?;
+
+    Ok(Json(
+        events
+            .into_iter()
+            .map(|event| SimulatedNotificationResponse::from_event(event, channels))
+            .collect(),
+    ))
+}
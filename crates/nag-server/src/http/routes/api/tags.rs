@@ -1,10 +1,15 @@
-use axum::{Json, extract::Extension, http::StatusCode};
+use axum::{
+    Json,
+    extract::{Extension, Query},
+    http::{HeaderName, StatusCode, header},
+};
 use sqlx::SqlitePool;
 use uuid::Uuid;
 
-use crate::db::TagRepository;
+use crate::db::{TagRepository, tags::TagColorStrategy};
 use crate::http::models::{
-    AppError, AppResult, CreateTagRequest, TagResponse, UpdateTagRequest, is_valid_tag_color,
+    AppError, AppResult, CreateTagRequest, DeleteTagQuery, MergeTagsRequest, TagResponse,
+    UpdateTagRequest, is_valid_tag_color,
 };
 
 const TAG: &str = "Tags";
@@ -37,15 +42,17 @@ pub async fn list_tags(
     request_body = CreateTagRequest,
     tag = TAG,
     responses(
-        (status = 201, description = "Tag created", body = TagResponse),
+        (status = 201, description = "Tag created", body = TagResponse,
+            headers(("Location" = String, description = "URL of the new tag"))),
         (status = 400, description = "Invalid request"),
-        (status = 409, description = "Tag with this name already exists")
+        (status = 409, description = "Tag with this name already exists, possibly deleted")
     )
 )]
 pub async fn create_tag(
     Extension(pool): Extension<SqlitePool>,
+    Extension(tag_colors): Extension<TagColorStrategy>,
     Json(body): Json<CreateTagRequest>,
-) -> AppResult<(StatusCode, Json<TagResponse>)> {
+) -> AppResult<(StatusCode, [(HeaderName, String); 1], Json<TagResponse>)> {
     let name = body.name.trim();
 
     if name.is_empty() {
@@ -63,7 +70,7 @@ pub async fn create_tag(
         && !is_valid_tag_color(color)
     {
         return Err(AppError::BadRequest(format!(
-            "Invalid tag color '{}'. Valid colors: terracotta, sage, ocean, amber, plum, clay, moss, slate, mauve, sand",
+            "Invalid tag color '{}'. Use a #RRGGBB hex color or one of: terracotta, sage, ocean, amber, plum, clay, moss, slate, mauve, sand",
             color
         )));
     }
@@ -79,11 +86,33 @@ pub async fn create_tag(
         )));
     }
 
-    let tag = TagRepository::create(&pool, name, body.color.as_deref())
+    if TagRepository::find_deleted_by_name(&pool, name)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .is_some()
+    {
+        return Err(AppError::Conflict(format!(
+            "A deleted tag named '{}' exists; restore or purge it first",
+            name
+        )));
+    }
+
+    let color = match body.color.as_deref() {
+        Some(color) => Some(color),
+        None => TagRepository::auto_color(&pool, tag_colors)
+            .await
+            .map_err(|e| AppError::Internal(e.into()))?,
+    };
+
+    let tag = TagRepository::create(&pool, name, color)
         .await
         .map_err(|e| AppError::Internal(e.into()))?;
 
-    Ok((StatusCode::CREATED, Json(TagResponse::from(tag))))
+    Ok((
+        StatusCode::CREATED,
+        [(header::LOCATION, format!("/api/tags/{}", tag.id))],
+        Json(TagResponse::from(tag)),
+    ))
 }
 
 /// Update a tag
@@ -98,7 +127,8 @@ pub async fn create_tag(
     responses(
         (status = 200, description = "Tag updated", body = TagResponse),
         (status = 400, description = "Invalid request"),
-        (status = 404, description = "Tag not found")
+        (status = 404, description = "Tag not found"),
+        (status = 409, description = "Another tag has this name, possibly deleted")
     )
 )]
 pub async fn update_tag(
@@ -124,7 +154,7 @@ pub async fn update_tag(
         && !is_valid_tag_color(color)
     {
         return Err(AppError::BadRequest(format!(
-            "Invalid tag color '{}'. Valid colors: terracotta, sage, ocean, amber, plum, clay, moss, slate, mauve, sand",
+            "Invalid tag color '{}'. Use a #RRGGBB hex color or one of: terracotta, sage, ocean, amber, plum, clay, moss, slate, mauve, sand",
             color
         )));
     }
@@ -132,6 +162,32 @@ pub async fn update_tag(
     let name_ref = body.name.as_deref().map(|n| n.trim());
     let color_ref = body.color.as_ref().map(|c| c.as_deref());
 
+    // Names are matched case-insensitively, so renaming a tag to a different
+    // case of its own name is fine
+    if let Some(name) = name_ref {
+        if TagRepository::find_by_name(&pool, name)
+            .await
+            .map_err(|e| AppError::Internal(e.into()))?
+            .is_some_and(|other| other.id != id)
+        {
+            return Err(AppError::Conflict(format!(
+                "Tag with name '{}' already exists",
+                name
+            )));
+        }
+
+        if TagRepository::find_deleted_by_name(&pool, name)
+            .await
+            .map_err(|e| AppError::Internal(e.into()))?
+            .is_some_and(|other| other.id != id)
+        {
+            return Err(AppError::Conflict(format!(
+                "A deleted tag named '{}' exists; restore or purge it first",
+                name
+            )));
+        }
+    }
+
     let tag = TagRepository::update(&pool, id, name_ref, color_ref)
         .await
         .map_err(|e| AppError::Internal(e.into()))?
@@ -141,11 +197,15 @@ pub async fn update_tag(
 }
 
 /// Delete a tag
+///
+/// The tag is hidden from listings and chores but can be restored with its
+/// associations, unless `purge` is set.
 #[utoipa::path(
     delete,
     path = "/tags/{id}",
     params(
-        ("id" = Uuid, Path, description = "Tag ID")
+        ("id" = Uuid, Path, description = "Tag ID"),
+        ("purge" = Option<bool>, Query, description = "Delete permanently, also if already deleted")
     ),
     tag = TAG,
     responses(
@@ -156,10 +216,14 @@ pub async fn update_tag(
 pub async fn delete_tag(
     Extension(pool): Extension<SqlitePool>,
     axum::extract::Path(id): axum::extract::Path<Uuid>,
+    Query(query): Query<DeleteTagQuery>,
 ) -> AppResult<StatusCode> {
-    let deleted = TagRepository::delete(&pool, id)
-        .await
-        .map_err(|e| AppError::Internal(e.into()))?;
+    let deleted = if query.purge {
+        TagRepository::purge(&pool, id).await
+    } else {
+        TagRepository::delete(&pool, id).await
+    }
+    .map_err(|e| AppError::Internal(e.into()))?;
 
     if deleted {
         Ok(StatusCode::NO_CONTENT)
@@ -167,3 +231,116 @@ pub async fn delete_tag(
         Err(AppError::NotFound(format!("Tag with id {} not found", id)))
     }
 }
+
+/// Restore a deleted tag
+#[utoipa::path(
+    post,
+    path = "/tags/{id}/restore",
+    params(
+        ("id" = Uuid, Path, description = "Tag ID")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "Tag restored with its chore associations", body = TagResponse),
+        (status = 404, description = "Tag not found")
+    )
+)]
+pub async fn restore_tag(
+    Extension(pool): Extension<SqlitePool>,
+    axum::extract::Path(id): axum::extract::Path<Uuid>,
+) -> AppResult<Json<TagResponse>> {
+    let tag = TagRepository::restore(&pool, id)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .ok_or_else(|| AppError::NotFound(format!("Tag with id {} not found", id)))?;
+
+    Ok(Json(TagResponse::from(tag)))
+}
+
+/// Merge one tag into another
+///
+/// Every chore tagged with the source gets the target instead, and the
+/// source tag is deleted for good.
+#[utoipa::path(
+    post,
+    path = "/tags/merge",
+    request_body = MergeTagsRequest,
+    tag = TAG,
+    responses(
+        (status = 200, description = "Tags merged; the target tag", body = TagResponse),
+        (status = 400, description = "Source and target are the same tag"),
+        (status = 404, description = "Source or target tag not found")
+    )
+)]
+pub async fn merge_tags(
+    Extension(pool): Extension<SqlitePool>,
+    Json(body): Json<MergeTagsRequest>,
+) -> AppResult<Json<TagResponse>> {
+    if body.source_id == body.target_id {
+        return Err(AppError::BadRequest(
+            "Cannot merge a tag into itself".to_string(),
+        ));
+    }
+
+    let tag = TagRepository::merge(&pool, body.source_id, body.target_id)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .ok_or_else(|| {
+            AppError::NotFound(format!(
+                "Tag with id {} or {} not found",
+                body.source_id, body.target_id
+            ))
+        })?;
+
+    Ok(Json(TagResponse::from(tag)))
+}
+
+/// Mute a tag: chores carrying it raise no due notifications until it is
+/// unmuted
+#[utoipa::path(
+    post,
+    path = "/tags/{id}/mute",
+    params(
+        ("id" = Uuid, Path, description = "Tag ID")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "Tag muted", body = TagResponse),
+        (status = 404, description = "Tag not found")
+    )
+)]
+pub async fn mute_tag(
+    Extension(pool): Extension<SqlitePool>,
+    axum::extract::Path(id): axum::extract::Path<Uuid>,
+) -> AppResult<Json<TagResponse>> {
+    set_tag_muted(&pool, id, true).await
+}
+
+/// Unmute a tag, letting its chores raise due notifications again
+#[utoipa::path(
+    post,
+    path = "/tags/{id}/unmute",
+    params(
+        ("id" = Uuid, Path, description = "Tag ID")
+    ),
+    tag = TAG,
+    responses(
+        (status = 200, description = "Tag unmuted", body = TagResponse),
+        (status = 404, description = "Tag not found")
+    )
+)]
+pub async fn unmute_tag(
+    Extension(pool): Extension<SqlitePool>,
+    axum::extract::Path(id): axum::extract::Path<Uuid>,
+) -> AppResult<Json<TagResponse>> {
+    set_tag_muted(&pool, id, false).await
+}
+
+async fn set_tag_muted(pool: &SqlitePool, id: Uuid, muted: bool) -> AppResult<Json<TagResponse>> {
+    let tag = TagRepository::set_muted(pool, id, muted)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .ok_or_else(|| AppError::NotFound(format!("Tag with id {} not found", id)))?;
+
+    Ok(Json(TagResponse::from(tag)))
+}
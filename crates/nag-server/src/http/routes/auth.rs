@@ -1,16 +1,22 @@
+use std::convert::Infallible;
+
 use axum::{
-    extract::Query,
-    extract::State,
+    extract::{FromRequestParts, Path, Query, State},
+    http::{HeaderMap, StatusCode, header, request::Parts},
     response::{IntoResponse, Json, Redirect, Response},
 };
+use chrono::{DateTime, Utc};
 use openidconnect::{
     AuthorizationCode, CsrfToken, EmptyAdditionalClaims, Nonce, OAuth2TokenResponse,
     PkceCodeChallenge, PkceCodeVerifier, Scope, TokenResponse, core::CoreGenderClaim, reqwest,
 };
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use tower_sessions::Session;
+use uuid::Uuid;
 
-use crate::db::UserRepository;
+use crate::db::models::ApiKey;
+use crate::db::{ApiKeyRepository, UserRepository};
 use crate::http::models::AppError;
 use crate::services::OidcService;
 
@@ -23,6 +29,30 @@ const SESSION_KEY_CSRF: &str = "oidc_csrf";
 const SESSION_KEY_NONCE: &str = "oidc_nonce";
 const SESSION_KEY_PKCE_VERIFIER: &str = "oidc_pkce_verifier";
 
+/// Prefix of every API key, so keys are recognisable in configs and logs
+const API_KEY_PREFIX: &str = "nag_";
+
+/// Number of leading key characters stored for display
+const API_KEY_DISPLAY_LEN: usize = 12;
+
+/// User a request is made on behalf of, as resolved by the auth guard
+///
+/// `None` when auth is disabled, in which case chores are not filtered by owner.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CurrentUser(pub Option<Uuid>);
+
+impl<S: Send + Sync> FromRequestParts<S> for CurrentUser {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts
+            .extensions
+            .get::<CurrentUser>()
+            .copied()
+            .unwrap_or_default())
+    }
+}
+
 /// Query params returned by the OIDC provider on callback
 #[derive(Debug, Deserialize)]
 pub struct CallbackQuery {
@@ -39,6 +69,45 @@ pub struct AuthMeResponse {
     pub picture: Option<String>,
 }
 
+/// Request body for POST /auth/api-keys
+#[derive(Debug, Deserialize)]
+pub struct CreateApiKeyRequest {
+    pub name: String,
+}
+
+/// An API key as listed by /auth/api-keys (never includes the key itself)
+#[derive(Debug, Serialize)]
+pub struct ApiKeyResponse {
+    pub id: Uuid,
+    pub name: String,
+    /// Leading characters of the key
+    pub prefix: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl From<ApiKey> for ApiKeyResponse {
+    fn from(key: ApiKey) -> Self {
+        Self {
+            id: key.id,
+            name: key.name,
+            prefix: key.prefix,
+            created_at: key.created_at,
+            last_used_at: key.last_used_at,
+            revoked_at: key.revoked_at,
+        }
+    }
+}
+
+/// Response for POST /auth/api-keys — the only time the raw key is returned
+#[derive(Debug, Serialize)]
+pub struct CreatedApiKeyResponse {
+    #[serde(flatten)]
+    pub api_key: ApiKeyResponse,
+    pub key: String,
+}
+
 /// Get the OIDC service from state, returning 500 if auth is not configured.
 ///
 /// This is safe because auth routes are only registered when OIDC is enabled,
@@ -290,3 +359,151 @@ pub async fn require_auth(session: &Session) -> Result<uuid::Uuid, AppError> {
         .parse()
         .map_err(|_| AppError::Internal(color_eyre::eyre::eyre!("Invalid user ID in session")))
 }
+
+/// GET /auth/api-keys — List the current user's API keys
+pub async fn list_api_keys(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<Json<Vec<ApiKeyResponse>>, AppError> {
+    let user_id = require_auth(&session).await?;
+
+    let keys = ApiKeyRepository::list_for_user(&state.pool, user_id)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    Ok(Json(keys.into_iter().map(ApiKeyResponse::from).collect()))
+}
+
+/// POST /auth/api-keys — Create an API key for the current user
+pub async fn create_api_key(
+    State(state): State<AppState>,
+    session: Session,
+    Json(body): Json<CreateApiKeyRequest>,
+) -> Result<(StatusCode, Json<CreatedApiKeyResponse>), AppError> {
+    let user_id = require_auth(&session).await?;
+
+    let name = body.name.trim();
+    if name.is_empty() {
+        return Err(AppError::BadRequest(
+            "API key name cannot be empty".to_string(),
+        ));
+    }
+    if name.len() > 100 {
+        return Err(AppError::BadRequest(
+            "API key name cannot exceed 100 characters".to_string(),
+        ));
+    }
+
+    let key = generate_api_key();
+    let api_key = ApiKeyRepository::create(
+        &state.pool,
+        user_id,
+        name,
+        &key[..API_KEY_DISPLAY_LEN],
+        &hash_api_key(&key),
+    )
+    .await
+    .map_err(|e| AppError::Internal(e.into()))?;
+
+    tracing::info!(user_id = %user_id, api_key_id = %api_key.id, "API key created");
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CreatedApiKeyResponse {
+            api_key: api_key.into(),
+            key,
+        }),
+    ))
+}
+
+/// DELETE /auth/api-keys/{id} — Revoke one of the current user's API keys
+pub async fn revoke_api_key(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let user_id = require_auth(&session).await?;
+
+    let revoked = ApiKeyRepository::revoke(&state.pool, id, user_id)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    if !revoked {
+        return Err(AppError::NotFound(format!(
+            "API key with id {} not found",
+            id
+        )));
+    }
+
+    tracing::info!(user_id = %user_id, api_key_id = %id, "API key revoked");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Generate a new random API key (`nag_` followed by 64 hex characters)
+pub fn generate_api_key() -> String {
+    format!(
+        "{API_KEY_PREFIX}{}{}",
+        Uuid::new_v4().simple(),
+        Uuid::new_v4().simple()
+    )
+}
+
+/// Hash an API key for storage and lookup (hex-encoded SHA-256)
+pub fn hash_api_key(key: &str) -> String {
+    format!("{:x}", Sha256::digest(key.as_bytes()))
+}
+
+/// Extract an API key from an `Authorization: Bearer nag_...` header
+pub fn bearer_api_key(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .map(str::trim)
+        .filter(|key| key.starts_with(API_KEY_PREFIX))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn test_generate_api_key_is_prefixed_and_unique() {
+        let key = generate_api_key();
+        assert!(key.starts_with(API_KEY_PREFIX));
+        assert_eq!(key.len(), API_KEY_PREFIX.len() + 64);
+        assert_ne!(key, generate_api_key());
+    }
+
+    #[test]
+    fn test_hash_api_key_is_stable_hex() {
+        let hash = hash_api_key("nag_example");
+        assert_eq!(hash, hash_api_key("nag_example"));
+        assert_ne!(hash, hash_api_key("nag_other"));
+        assert_eq!(hash.len(), 64);
+        assert!(hash.chars().all(|c| c.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn test_bearer_api_key() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(bearer_api_key(&headers), None);
+
+        headers.insert(
+            header::AUTHORIZATION,
+            HeaderValue::from_static("Bearer nag_abc"),
+        );
+        assert_eq!(bearer_api_key(&headers), Some("nag_abc"));
+
+        // Other bearer tokens are left for other schemes
+        headers.insert(
+            header::AUTHORIZATION,
+            HeaderValue::from_static("Bearer eyJhbGciOi"),
+        );
+        assert_eq!(bearer_api_key(&headers), None);
+    }
+}
@@ -0,0 +1,148 @@
+use axum::{
+    Json,
+    extract::{Extension, Query},
+    http::HeaderMap,
+};
+use sqlx::SqlitePool;
+
+use crate::db::models::CompletionSource;
+use crate::db::{ChoreRepository, CompletionRepository};
+use crate::http::models::{
+    AppError, AppResult, CompletionResponse, InboundEmailQuery, InboundEmailRequest,
+    InboundEmailResponse,
+};
+use crate::services::{ChoreService, inbound_email};
+
+const TAG: &str = "Integrations";
+
+/// Header carrying the inbound email webhook's shared secret
+pub const INBOUND_SECRET_HEADER: &str = "x-webhook-secret";
+
+/// Shared secret the mail provider must present to the inbound email webhook.
+///
+/// Only layered when `EMAIL_INBOUND_SECRET` is set; without it the webhook
+/// is disabled.
+#[derive(Debug, Clone)]
+pub struct EmailInboundSecret(pub String);
+
+/// Compare secrets in time independent of where they first differ
+pub(super) fn secrets_match(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// Complete a chore from a reply to its email reminder
+///
+/// The chore is identified by the id in the reply-to address
+/// (`nag+<chore id>@...`); replies whose first line is "done"-like record a
+/// completion, anything else is acknowledged and ignored. The shared secret
+/// is accepted in the `X-Webhook-Secret` header or the `secret` query
+/// parameter.
+#[utoipa::path(
+    post,
+    path = "/api/integrations/email-inbound",
+    params(
+        ("secret" = Option<String>, Query, description = "Shared secret, if not sent as X-Webhook-Secret")
+    ),
+    request_body = InboundEmailRequest,
+    responses(
+        (status = 200, description = "Email processed", body = InboundEmailResponse),
+        (status = 400, description = "No chore id in the recipient address"),
+        (status = 401, description = "Missing or wrong shared secret"),
+        (status = 403, description = "Chore does not allow email completions"),
+        (status = 404, description = "Webhook disabled or chore not found")
+    ),
+    tag = TAG,
+)]
+pub async fn email_inbound(
+    Extension(pool): Extension<SqlitePool>,
+    secret: Option<Extension<EmailInboundSecret>>,
+    headers: HeaderMap,
+    Query(query): Query<InboundEmailQuery>,
+    Json(body): Json<InboundEmailRequest>,
+) -> AppResult<Json<InboundEmailResponse>> {
+    let Some(Extension(EmailInboundSecret(expected))) = secret else {
+        return Err(AppError::NotFound(
+            "Inbound email is not configured".to_string(),
+        ));
+    };
+    let given = headers
+        .get(INBOUND_SECRET_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .or(query.secret.as_deref());
+    if !given.is_some_and(|given| secrets_match(given, &expected)) {
+        return Err(AppError::Unauthorized(
+            "Invalid inbound email secret".to_string(),
+        ));
+    }
+
+    let chore_id = inbound_email::reply_chore_id(&body.recipient).ok_or_else(|| {
+        AppError::BadRequest(format!(
+            "No chore id in recipient address '{}'",
+            body.recipient
+        ))
+    })?;
+    let chore = ChoreRepository::get_by_id(&pool, chore_id, None)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .ok_or_else(|| AppError::NotFound(format!("Chore with id {} not found", chore_id)))?;
+
+    let text = body
+        .stripped_text
+        .as_deref()
+        .or(body.body_plain.as_deref())
+        .unwrap_or_default();
+    if !inbound_email::is_done_reply(text) {
+        tracing::info!(%chore_id, sender = ?body.sender, "Ignoring inbound email that is not a done reply");
+        return Ok(Json(InboundEmailResponse {
+            completed: false,
+            completion: None,
+        }));
+    }
+
+    if !ChoreService::allows_completion_source(
+        chore.allowed_completion_sources.as_deref(),
+        CompletionSource::Email,
+    ) {
+        return Err(AppError::Forbidden(format!(
+            "Chore with id {} cannot be completed by email",
+            chore_id
+        )));
+    }
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+    let completion = CompletionRepository::insert(
+        &mut tx,
+        chore_id,
+        None,
+        Some("Completed via email"),
+        CompletionSource::Email,
+        None,
+        None,
+    )
+    .await
+    .map_err(|e| AppError::Internal(e.into()))?;
+    CompletionRepository::create_for_linked_chores(
+        &mut tx,
+        chore_id,
+        completion.completed_at,
+        CompletionSource::Email,
+    )
+    .await
+    .map_err(|e| AppError::Internal(e.into()))?;
+    tx.commit()
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    Ok(Json(InboundEmailResponse {
+        completed: true,
+        completion: Some(CompletionResponse::from(completion)),
+    }))
+}
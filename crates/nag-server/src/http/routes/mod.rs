@@ -1,19 +1,40 @@
 use axum::{
-    Extension, Json, Router, middleware as axum_middleware, response::IntoResponse, routing::get,
+    Extension, Json, Router,
+    http::{StatusCode, header},
+    middleware as axum_middleware,
+    response::IntoResponse,
+    routing::{delete, get, post},
 };
+use chrono::Utc;
+use metrics_exporter_prometheus::PrometheusHandle;
 use serde::Serialize;
+use sqlx::SqlitePool;
 use utoipa::{OpenApi, ToSchema};
 use utoipa_axum::{router::OpenApiRouter, routes};
 
-use super::AppState;
 use super::models::{
-    ChoreResponse, ChoreWithDueResponse, CompleteChoreRequest, CompletionResponse,
-    CreateChoreRequest, CreateTagRequest, DueChoresQuery, ListChoresQuery, ListCompletionsQuery,
-    PaginatedResponse, ProblemDetailsSchema, TagResponse, UpdateChoreRequest, UpdateTagRequest,
+    AppError, AppResult, AuditLogEntryResponse, BudgetChore, BulkCompleteDueRequest,
+    BulkCompleteDueResponse, BulkCompletedChore, ChoreResponse, ChoreStatsResponse,
+    ChoreWithDueResponse, CompleteChoreRequest, CompletionChore, CompletionDayCount,
+    CompletionFeedQuery, CompletionHeatmapQuery, CompletionHeatmapResponse, CompletionResponse,
+    CreateChoreRequest, CreateHolidayRequest, CreateTagRequest, DayBudgetQuery, DayBudgetResponse,
+    DeleteChoreQuery, DeleteTagQuery, DueChoresQuery, EventDeliveryResponse, ExportDocument,
+    ExportedChore, ExportedCompletion, ExportedTag, HolidayResponse, ImportResponse,
+    InboundEmailRequest, InboundEmailResponse, ListChoresQuery, ListCompletionsQuery,
+    ListDeliveriesQuery, ListEventsQuery, MergeTagsRequest, NextOccurrencesQuery,
+    NotificationDeliveryResponse, NotificationEventResponse, OccurrenceResponse, PaginatedResponse,
+    PreviewScheduleRequest, ProblemDetailsSchema, SearchChoresQuery, SimulateNotificationsRequest,
+    SimulatedNotificationResponse, SnoozeChoreRequest, SnoozeResponse, TagResponse,
+    TrashedChoreResponse, UpdateChoreRequest, UpdateCompletionRequest, UpdateTagRequest,
 };
+use super::{AppState, DestructiveActionPin};
+use crate::db::models::{AuditAction, ScheduleSnapshot};
+use crate::db::{ApiKeyRepository, MigrationRepository, NotificationRepository, chores::TagFilter};
+use crate::services::{ChoreService, CompletionTiming, DueSort};
 
 mod api;
 pub mod auth;
+pub mod integrations;
 mod scalar;
 
 /// Base OpenAPI spec with shared schemas
@@ -21,24 +42,76 @@ mod scalar;
 #[openapi(
     components(schemas(
         HealthResponse,
+        ReadinessResponse,
+        PoolStats,
         AppConfigResponse,
         ProblemDetailsSchema,
         // Chore schemas
         ChoreResponse,
         ChoreWithDueResponse,
+        ChoreStatsResponse,
+        AuditLogEntryResponse,
+        AuditAction,
+        ScheduleSnapshot,
         CompletionResponse,
+        CompletionChore,
+        CompletionHeatmapResponse,
+        CompletionDayCount,
         CreateChoreRequest,
         UpdateChoreRequest,
         CompleteChoreRequest,
+        UpdateCompletionRequest,
+        PreviewScheduleRequest,
+        NextOccurrencesQuery,
+        OccurrenceResponse,
+        BulkCompleteDueRequest,
+        BulkCompleteDueResponse,
+        BulkCompletedChore,
+        DayBudgetQuery,
+        DayBudgetResponse,
+        DeleteChoreQuery,
+        BudgetChore,
+        TrashedChoreResponse,
         ListChoresQuery,
+        SearchChoresQuery,
         ListCompletionsQuery,
+        CompletionFeedQuery,
         DueChoresQuery,
+        DueSort,
+        CompletionTiming,
+        CompletionHeatmapQuery,
         PaginatedResponse<ChoreResponse>,
         PaginatedResponse<CompletionResponse>,
+        PaginatedResponse<NotificationDeliveryResponse>,
+        PaginatedResponse<NotificationEventResponse>,
+        // Export schemas
+        ExportDocument,
+        ExportedChore,
+        ExportedCompletion,
+        ExportedTag,
+        ImportResponse,
         // Tag schemas
         TagResponse,
         CreateTagRequest,
         UpdateTagRequest,
+        MergeTagsRequest,
+        DeleteTagQuery,
+        // Holiday schemas
+        HolidayResponse,
+        CreateHolidayRequest,
+        // Integration schemas
+        InboundEmailRequest,
+        InboundEmailResponse,
+        // Notification schemas
+        NotificationDeliveryResponse,
+        ListDeliveriesQuery,
+        NotificationEventResponse,
+        EventDeliveryResponse,
+        ListEventsQuery,
+        SnoozeChoreRequest,
+        SnoozeResponse,
+        SimulateNotificationsRequest,
+        SimulatedNotificationResponse,
     )),
     info(title = "Nag API", description = "Nag server API")
 )]
@@ -57,6 +130,130 @@ pub async fn health() -> Json<HealthResponse> {
     Json(HealthResponse { status: "ok" })
 }
 
+/// Readiness check response
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReadinessResponse {
+    /// Service status
+    pub status: &'static str,
+    pub pool: PoolStats,
+}
+
+/// Database connection pool usage
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PoolStats {
+    /// Open connections, idle or in use
+    pub size: u32,
+    /// Open connections not currently in use
+    pub idle: usize,
+}
+
+/// Readiness check endpoint
+///
+/// Unlike `/health`, fails with 503 while the database can't be queried, so
+/// traffic isn't routed to the instance during an outage.
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    responses(
+        (status = 200, description = "Database reachable", body = ReadinessResponse),
+        (status = 503, description = "Database unreachable", body = ProblemDetailsSchema)
+    )
+)]
+pub async fn health_ready(
+    Extension(pool): Extension<SqlitePool>,
+) -> AppResult<Json<ReadinessResponse>> {
+    crate::db::ping(&pool).await.map_err(|e| {
+        tracing::warn!(error = %e, "Readiness check failed");
+        AppError::ServiceUnavailable("Database is unreachable".to_string())
+    })?;
+
+    Ok(Json(ReadinessResponse {
+        status: "ok",
+        pool: PoolStats {
+            size: pool.size(),
+            idle: pool.num_idle(),
+        },
+    }))
+}
+
+/// Migration status response
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MigrationHealthResponse {
+    /// "ok", or "dirty" when a migration was left partially applied
+    pub status: &'static str,
+    /// Highest successfully applied migration version
+    pub current_version: Option<i64>,
+    /// Number of applied migrations
+    pub applied: usize,
+    /// Embedded migration versions not yet applied
+    pub pending: Vec<i64>,
+    pub dirty: bool,
+}
+
+/// Migration status endpoint
+#[utoipa::path(
+    get,
+    path = "/health/migrations",
+    responses(
+        (status = 200, description = "Migrations applied cleanly", body = MigrationHealthResponse),
+        (status = 503, description = "Migration state is dirty", body = MigrationHealthResponse)
+    )
+)]
+pub async fn health_migrations(
+    Extension(pool): Extension<SqlitePool>,
+) -> AppResult<(StatusCode, Json<MigrationHealthResponse>)> {
+    let migrations = MigrationRepository::status(&pool)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    let code = if migrations.dirty {
+        StatusCode::SERVICE_UNAVAILABLE
+    } else {
+        StatusCode::OK
+    };
+
+    Ok((
+        code,
+        Json(MigrationHealthResponse {
+            status: if migrations.dirty { "dirty" } else { "ok" },
+            current_version: migrations.current_version,
+            applied: migrations.applied,
+            pending: migrations.pending,
+            dirty: migrations.dirty,
+        }),
+    ))
+}
+
+/// Prometheus metrics endpoint
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    responses(
+        (status = 200, description = "Metrics in the Prometheus text format", body = String, content_type = "text/plain")
+    )
+)]
+pub async fn metrics(
+    Extension(pool): Extension<SqlitePool>,
+    Extension(handle): Extension<PrometheusHandle>,
+) -> AppResult<impl IntoResponse> {
+    let pending = NotificationRepository::count_pending(&pool)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+    let overdue =
+        ChoreService::get_due_chores(&pool, None, false, DueSort::DueAsc, &TagFilter::default())
+            .await
+            .map_err(AppError::Internal)?
+            .len();
+
+    ::metrics::gauge!(crate::metrics::NOTIFICATIONS_PENDING).set(pending as f64);
+    ::metrics::gauge!(crate::metrics::CHORES_OVERDUE).set(overdue as f64);
+
+    Ok((
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        handle.render(),
+    ))
+}
+
 /// Application configuration exposed to the frontend
 #[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct AppConfigResponse {
@@ -71,17 +268,34 @@ pub struct AppConfigResponse {
 /// When `state.oidc` is `None`, all API routes are publicly accessible.
 pub fn app_router(state: AppState) -> Router {
     let pool = state.pool.clone();
+    let due_default_sort = state.due_default_sort;
+    let metrics_handle = state.metrics.clone();
+    let email_inbound_secret = state.email_inbound_secret.clone();
+    let completion_note_template = state.completion_note_template.clone();
+    let notification_generator = state.notification_generator.clone();
+    let undo_window = state.undo_window;
+    let destructive_action_pin = state.destructive_action_pin.clone();
+    let tag_color_strategy = state.tag_color_strategy;
+    let schedule_limits = state.schedule_limits;
     let auth_enabled = state.oidc.is_some();
 
     // Build the OpenAPI schema from the combined router (for docs generation only)
     let (_combined_router, openapi) = OpenApiRouter::with_openapi(ApiDoc::openapi())
         .routes(routes!(health))
+        .routes(routes!(health_ready))
+        .routes(routes!(health_migrations))
+        .routes(routes!(metrics))
+        .routes(routes!(integrations::email_inbound))
         .nest("/api", api::router())
         .split_for_parts();
 
     let api_routes: Router = api::router().into();
 
-    let mut router = Router::new().route("/health", get(health));
+    let mut router = Router::new()
+        .route("/health", get(health))
+        .route("/health/ready", get(health_ready))
+        .route("/health/migrations", get(health_migrations))
+        .route("/metrics", get(metrics));
 
     if auth_enabled {
         // Auth routes (require AppState for OIDC client, but no auth guard)
@@ -90,6 +304,11 @@ pub fn app_router(state: AppState) -> Router {
             .route("/callback", get(auth::callback))
             .route("/logout", get(auth::logout))
             .route("/me", get(auth::me))
+            .route(
+                "/api-keys",
+                get(auth::list_api_keys).post(auth::create_api_key),
+            )
+            .route("/api-keys/{id}", delete(auth::revoke_api_key))
             .with_state(state.clone());
 
         // Protected /api/* routes with auth guard
@@ -113,6 +332,28 @@ pub fn app_router(state: AppState) -> Router {
         }),
     );
 
+    // Inbound email webhook (authenticated by its shared secret, outside auth guard)
+    router = router.route(
+        "/api/integrations/email-inbound",
+        post(integrations::email_inbound),
+    );
+    if let Some(secret) = email_inbound_secret {
+        router = router.layer(Extension(integrations::EmailInboundSecret(secret)));
+    }
+    if let Some(template) = completion_note_template {
+        router = router.layer(Extension(template));
+    }
+    if let Some(settings) = notification_generator {
+        router = router.layer(Extension(settings));
+    }
+    if let Some(window) = undo_window {
+        router = router.layer(Extension(window));
+    }
+    router = router.layer(axum_middleware::from_fn(destructive_action_guard));
+    if let Some(pin) = destructive_action_pin {
+        router = router.layer(Extension(pin));
+    }
+
     let openapi_clone = openapi.clone();
     router
         .route(
@@ -121,14 +362,26 @@ pub fn app_router(state: AppState) -> Router {
         )
         .nest("/docs", scalar::router())
         .layer(Extension(pool))
+        .layer(Extension(due_default_sort))
+        .layer(Extension(tag_color_strategy))
+        .layer(Extension(schedule_limits))
+        .layer(Extension(metrics_handle))
 }
 
 /// Create the application router without auth (for integration tests).
 pub fn test_app_router(state: AppState) -> Router {
     let pool = state.pool.clone();
+    let due_default_sort = state.due_default_sort;
+    let tag_color_strategy = state.tag_color_strategy;
+    let schedule_limits = state.schedule_limits;
+    let metrics_handle = state.metrics.clone();
 
     let (router, openapi) = OpenApiRouter::with_openapi(ApiDoc::openapi())
         .routes(routes!(health))
+        .routes(routes!(health_ready))
+        .routes(routes!(health_migrations))
+        .routes(routes!(metrics))
+        .routes(routes!(integrations::email_inbound))
         .nest("/api", api::router())
         .split_for_parts();
 
@@ -150,17 +403,89 @@ pub fn test_app_router(state: AppState) -> Router {
             get(move || async move { Json(openapi.clone()) }),
         )
         .nest("/docs", scalar::router())
+        .layer(axum_middleware::from_fn(destructive_action_guard))
         .layer(Extension(pool))
+        .layer(Extension(due_default_sort))
+        .layer(Extension(tag_color_strategy))
+        .layer(Extension(schedule_limits))
+        .layer(Extension(metrics_handle))
+}
+
+/// Header carrying the PIN for destructive actions
+const DESTRUCTIVE_ACTION_PIN_HEADER: &str = "x-nag-pin";
+
+/// Whether a request discards data: every `DELETE`, plus the `POST` routes
+/// that merge tags or undo a completion
+fn is_destructive(method: &axum::http::Method, path: &str) -> bool {
+    if method == axum::http::Method::DELETE {
+        return true;
+    }
+    if method != axum::http::Method::POST {
+        return false;
+    }
+
+    let segments: Vec<&str> = path.trim_end_matches('/').split('/').collect();
+    matches!(
+        segments.as_slice(),
+        ["", "api", "tags", "merge"]
+            | ["", "api", "chores", _, "uncomplete"]
+            | ["", "api", "chores", _, "complete", "undo"]
+    )
 }
 
-/// Middleware function that checks for an authenticated session on protected routes.
+/// Middleware function that rejects destructive requests (see
+/// [`is_destructive`]) without the configured [`DestructiveActionPin`];
+/// everything passes when no PIN is configured.
+async fn destructive_action_guard(
+    pin: Option<Extension<DestructiveActionPin>>,
+    request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    if let Some(Extension(DestructiveActionPin(expected))) = pin
+        && is_destructive(request.method(), request.uri().path())
+    {
+        let given = request
+            .headers()
+            .get(DESTRUCTIVE_ACTION_PIN_HEADER)
+            .and_then(|v| v.to_str().ok());
+        if !given.is_some_and(|given| integrations::secrets_match(given, &expected)) {
+            return AppError::Forbidden(
+                "This action requires a valid X-Nag-Pin header".to_string(),
+            )
+            .into_response();
+        }
+    }
+    next.run(request).await
+}
+
+/// Middleware function that checks for an API key or an authenticated session
+/// on protected routes, exposing the user to handlers as [`auth::CurrentUser`].
 async fn auth_guard(
+    Extension(pool): Extension<SqlitePool>,
     session: tower_sessions::Session,
-    request: axum::extract::Request,
+    mut request: axum::extract::Request,
     next: axum::middleware::Next,
 ) -> axum::response::Response {
+    if let Some(key) = auth::bearer_api_key(request.headers()) {
+        match ApiKeyRepository::authenticate(&pool, &auth::hash_api_key(key), Utc::now()).await {
+            Ok(Some(user_id)) => {
+                request
+                    .extensions_mut()
+                    .insert(auth::CurrentUser(Some(user_id)));
+                return next.run(request).await;
+            }
+            Ok(None) => {}
+            Err(e) => return AppError::Internal(e.into()).into_response(),
+        }
+    }
+
     match auth::require_auth(&session).await {
-        Ok(_user_id) => next.run(request).await,
+        Ok(user_id) => {
+            request
+                .extensions_mut()
+                .insert(auth::CurrentUser(Some(user_id)));
+            next.run(request).await
+        }
         Err(e) => e.into_response(),
     }
 }
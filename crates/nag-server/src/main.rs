@@ -5,11 +5,14 @@ mod config;
 use std::sync::Arc;
 
 use envconfig::Envconfig;
+use nag_server::db::completions::CompletionNoteTemplate;
 use nag_server::db::models::NotificationChannel;
 use nag_server::services::{
-    NotificationRuntimeConfig, OidcService, TelegramChannel, run_dispatcher, run_event_generator,
+    DiscordChannel, EventGeneratorSettings, NotificationChannelSender, NotificationRuntimeConfig,
+    OidcService, OperatorAlerter, SlackChannel, TelegramChannel, run_dispatcher,
+    run_event_generator, verify_channels,
 };
-use nag_server::{db, http};
+use nag_server::{db, http, metrics};
 use tokio::net::TcpListener;
 use tower_sessions_sqlx_store::SqliteStore;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -46,6 +49,8 @@ async fn main() -> color_eyre::Result<()> {
     // Initialize tracing
     init_tracing(config.json_logs);
 
+    let metrics = metrics::install_recorder()?;
+
     // Initialize database
     let pool = db::create_pool(&config.database_url).await?;
     tracing::info!("Database connected and migrations applied");
@@ -70,7 +75,28 @@ async fn main() -> color_eyre::Result<()> {
     };
 
     // Build the application state
-    let state = http::AppState { pool, oidc };
+    let mut state = http::AppState {
+        pool,
+        oidc,
+        due_default_sort: config.due_default_sort,
+        metrics,
+        email_inbound_secret: config.email_inbound_secret.clone(),
+        completion_note_template: config
+            .completion_note_template
+            .clone()
+            .map(CompletionNoteTemplate),
+        notification_generator: None,
+        tag_color_strategy: config.tag_color_strategy,
+        schedule_limits: config.schedule_limits()?,
+        undo_window: config
+            .undo_window_minutes
+            .map(|minutes| http::UndoWindow(chrono::Duration::minutes(minutes.into()))),
+        destructive_action_pin: config
+            .destructive_action_pin
+            .clone()
+            .map(http::DestructiveActionPin),
+        session: config.session_settings()?,
+    };
 
     if config.notifications_enabled {
         let runtime_config = NotificationRuntimeConfig {
@@ -78,16 +104,49 @@ async fn main() -> color_eyre::Result<()> {
             dispatch_interval_seconds: config.notification_dispatch_interval_seconds,
             max_attempts: config.notification_max_attempts,
             batch_size: config.notification_batch_size,
+            retry_base_delay_seconds: config.notification_retry_base_delay_seconds,
+            quiet_hours: config.notification_quiet_hours()?,
+            max_body_chars: config.notification_max_body_chars,
+            reminder_repeat_hours: config.notification_reminder_repeat_hours,
+            min_notify_interval_minutes: config.notification_min_interval_minutes,
         };
 
-        let channels = vec![NotificationChannel::Telegram];
+        let mut channels = vec![NotificationChannel::Telegram];
         let telegram = Arc::new(
             TelegramChannel::new(
                 config.telegram_bot_token.clone().unwrap_or_default(),
                 config.telegram_chat_id.clone().unwrap_or_default(),
+                state.pool.clone(),
             )
+            .and_then(|t| t.with_recipients(config.telegram_recipients.as_deref().unwrap_or("")))
             .map_err(|e| color_eyre::eyre::eyre!(e))?,
         );
+        let mut senders: Vec<Arc<dyn NotificationChannelSender>> = vec![telegram.clone()];
+
+        if let Some(webhook_url) = &config.discord_webhook_url {
+            let discord =
+                DiscordChannel::new(webhook_url).map_err(|e| color_eyre::eyre::eyre!(e))?;
+            channels.push(NotificationChannel::Discord);
+            senders.push(Arc::new(discord));
+        }
+
+        if let Some(webhook_url) = &config.slack_webhook_url {
+            let slack = SlackChannel::new(webhook_url).map_err(|e| color_eyre::eyre::eyre!(e))?;
+            channels.push(NotificationChannel::Slack);
+            senders.push(Arc::new(slack));
+        }
+
+        if config.notification_verify_on_start {
+            let verify_senders = senders.clone();
+            tokio::spawn(async move {
+                verify_channels(&verify_senders).await;
+            });
+        }
+
+        state.notification_generator = Some(EventGeneratorSettings {
+            channels: channels.clone(),
+            config: runtime_config.clone(),
+        });
 
         let generator_pool = state.pool.clone();
         let generator_config = runtime_config.clone();
@@ -96,17 +155,20 @@ async fn main() -> color_eyre::Result<()> {
             run_event_generator(generator_pool, channels, generator_config).await;
         });
 
+        let alerter = config.operator_alert_channel.and_then(|channel| {
+            let sender = senders.iter().find(|s| s.channel() == channel)?.clone();
+            Some(Arc::new(OperatorAlerter::new(
+                sender,
+                config.operator_alert_recipient.clone(),
+                chrono::Duration::seconds(config.operator_alert_cooldown_seconds),
+            )))
+        });
+
         let dispatcher_pool = state.pool.clone();
         let dispatcher_config = runtime_config;
-        let dispatcher_telegram = telegram.clone();
         tokio::spawn(async move {
             tracing::info!("Notification dispatcher started");
-            run_dispatcher(
-                dispatcher_pool,
-                vec![dispatcher_telegram],
-                dispatcher_config,
-            )
-            .await;
+            run_dispatcher(dispatcher_pool, senders, dispatcher_config, alerter).await;
         });
 
         let callback_pool = state.pool.clone();
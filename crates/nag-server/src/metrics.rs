@@ -0,0 +1,76 @@
+//! Prometheus metrics exposed on `/metrics`
+
+use std::sync::{Mutex, PoisonError};
+
+use metrics::{Unit, describe_counter, describe_gauge};
+use metrics_exporter_prometheus::{BuildError, PrometheusBuilder, PrometheusHandle};
+
+/// Deliveries handed to a notification sender
+pub const NOTIFICATIONS_DISPATCHED: &str = "nag_notifications_dispatched_total";
+/// Deliveries a sender accepted
+pub const NOTIFICATIONS_DELIVERED: &str = "nag_notifications_delivered_total";
+/// Deliveries a sender rejected
+pub const NOTIFICATIONS_FAILED: &str = "nag_notifications_failed_total";
+/// Deliveries waiting to be attempted, refreshed on every scrape; failed and
+/// skipped deliveries are not counted
+pub const NOTIFICATIONS_PENDING: &str = "nag_notifications_pending";
+/// Active chores past their due time, refreshed on every scrape
+pub const CHORES_OVERDUE: &str = "nag_chores_overdue";
+
+static HANDLE: Mutex<Option<PrometheusHandle>> = Mutex::new(None);
+
+/// Install the global Prometheus recorder, or return the handle of the one
+/// already installed.
+///
+/// Every metric is registered up front so scrapes report zeros instead of
+/// missing series, e.g. when notifications are disabled.
+pub fn install_recorder() -> Result<PrometheusHandle, BuildError> {
+    let mut installed = HANDLE.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(handle) = installed.as_ref() {
+        return Ok(handle.clone());
+    }
+
+    let handle = PrometheusBuilder::new().install_recorder()?;
+    register();
+    *installed = Some(handle.clone());
+    Ok(handle)
+}
+
+fn register() {
+    describe_counter!(
+        NOTIFICATIONS_DISPATCHED,
+        Unit::Count,
+        "Notification deliveries handed to a sender"
+    );
+    describe_counter!(
+        NOTIFICATIONS_DELIVERED,
+        Unit::Count,
+        "Notification deliveries accepted by their channel"
+    );
+    describe_counter!(
+        NOTIFICATIONS_FAILED,
+        Unit::Count,
+        "Notification deliveries rejected by their channel"
+    );
+    describe_gauge!(
+        NOTIFICATIONS_PENDING,
+        Unit::Count,
+        "Notification deliveries waiting to be attempted"
+    );
+    describe_gauge!(
+        CHORES_OVERDUE,
+        Unit::Count,
+        "Active chores past their due time"
+    );
+
+    for counter in [
+        NOTIFICATIONS_DISPATCHED,
+        NOTIFICATIONS_DELIVERED,
+        NOTIFICATIONS_FAILED,
+    ] {
+        metrics::counter!(counter).absolute(0);
+    }
+    for gauge in [NOTIFICATIONS_PENDING, CHORES_OVERDUE] {
+        metrics::gauge!(gauge).set(0.0);
+    }
+}
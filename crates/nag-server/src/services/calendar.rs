@@ -0,0 +1,114 @@
+use chrono::{DateTime, Utc};
+
+use crate::services::ChoreWithDueInfo;
+
+/// Timestamp format for UTC date-times (RFC 5545 §3.3.5)
+const ICAL_DATETIME_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+/// Maximum content line length in octets, excluding the line break
+const ICAL_MAX_LINE_OCTETS: usize = 75;
+
+/// Render due chores as an iCalendar feed with one event per dated chore.
+///
+/// Chores without a due time (e.g. `once_in_a_while`) are skipped. Each
+/// occurrence gets its own UID, so calendars keep a completed occurrence
+/// instead of moving it to the next due time.
+pub fn render_due_calendar(chores: &[ChoreWithDueInfo], now: DateTime<Utc>) -> String {
+    let stamp = now.format(ICAL_DATETIME_FORMAT).to_string();
+
+    let mut out = String::new();
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, "PRODID:-//nag//chores//EN");
+    push_line(&mut out, "CALSCALE:GREGORIAN");
+    push_line(&mut out, "X-WR-CALNAME:Chores");
+
+    for info in chores {
+        let Some(next_due) = info.next_due else {
+            continue;
+        };
+
+        let start = next_due.format(ICAL_DATETIME_FORMAT);
+        push_line(&mut out, "BEGIN:VEVENT");
+        push_line(&mut out, &format!("UID:{}-{start}@nag", info.chore.id));
+        push_line(&mut out, &format!("DTSTAMP:{stamp}"));
+        push_line(&mut out, &format!("DTSTART:{start}"));
+        push_line(
+            &mut out,
+            &format!("SUMMARY:{}", escape_text(&info.chore.name)),
+        );
+        if let Some(description) = &info.chore.description {
+            push_line(
+                &mut out,
+                &format!("DESCRIPTION:{}", escape_text(description)),
+            );
+        }
+        push_line(&mut out, "END:VEVENT");
+    }
+
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+/// Escape a TEXT value (RFC 5545 §3.3.11)
+fn escape_text(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '\\' => out.push_str("\\\\"),
+            ';' => out.push_str("\\;"),
+            ',' => out.push_str("\\,"),
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+/// Append a content line, folding it at 75 octets without splitting characters
+fn push_line(out: &mut String, line: &str) {
+    let mut width = 0;
+    for ch in line.chars() {
+        let len = ch.len_utf8();
+        if width + len > ICAL_MAX_LINE_OCTETS {
+            out.push_str("\r\n ");
+            // The leading space of a continuation line counts towards its length
+            width = 1;
+        }
+        out.push(ch);
+        width += len;
+    }
+    out.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_text() {
+        assert_eq!(escape_text("a,b;c\\d\ne"), "a\\,b\\;c\\\\d\\ne");
+    }
+
+    #[test]
+    fn test_push_line_folds_long_lines() {
+        let mut out = String::new();
+        push_line(&mut out, &"x".repeat(100));
+
+        let lines: Vec<&str> = out.trim_end_matches("\r\n").split("\r\n").collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].len(), 75);
+        assert_eq!(lines[1], format!(" {}", "x".repeat(25)));
+    }
+
+    #[test]
+    fn test_push_line_does_not_split_multibyte_chars() {
+        let mut out = String::new();
+        push_line(&mut out, &"é".repeat(50));
+
+        for line in out.trim_end_matches("\r\n").split("\r\n") {
+            assert!(line.len() <= ICAL_MAX_LINE_OCTETS);
+        }
+    }
+}
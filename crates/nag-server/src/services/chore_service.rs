@@ -1,18 +1,66 @@
-use chrono::{DateTime, Duration, NaiveTime, Utc};
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use chrono::{
+    DateTime, Datelike, Duration, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
+};
+use chrono_tz::Tz;
 use croner::Cron;
+use serde::{Deserialize, Serialize};
 use sqlx::SqlitePool;
+use utoipa::ToSchema;
+use uuid::Uuid;
 
 use crate::db::{
-    ChoreRepository,
-    models::{ChoreWithLastCompletion, ScheduleType},
+    ChoreRepository, HolidayRepository,
+    chores::TagFilter,
+    models::{ChoreAnchorMode, ChoreWithLastCompletion, CompletionSource, ScheduleType},
 };
 
-/// Minimum interval for interval-based schedules (1 day)
+/// Name and allowed values of each field of a five-field cron expression;
+/// croner accepts 7 as well as 0 for Sunday
+const CRON_FIELDS: [(&str, u32, u32); 5] = [
+    ("minute", 0, 59),
+    ("hour", 0, 23),
+    ("day-of-month", 1, 31),
+    ("month", 1, 12),
+    ("day-of-week", 0, 7),
+];
+
+/// Default minimum interval for interval-based schedules (1 day)
 pub const MIN_INTERVAL_DAYS: i32 = 1;
 
+/// Default minimum time between two occurrences of a cron schedule (1 hour)
+pub const MIN_CRON_INTERVAL_MINUTES: i64 = 60;
+
 /// Maximum interval for interval-based schedules (1 year)
 pub const MAX_INTERVAL_DAYS: i32 = 365;
 
+/// Maximum notification lead time (1 week)
+pub const MAX_NOTIFY_LEAD_MINUTES: i32 = 7 * 24 * 60;
+
+/// Maximum time estimate of a chore (1 day)
+pub const MAX_ESTIMATED_MINUTES: i32 = 24 * 60;
+
+/// How frequent chore schedules may be
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScheduleLimits {
+    /// Shortest time between two occurrences of a cron schedule
+    pub min_cron_interval: Duration,
+    /// Shortest interval of an interval schedule, in days
+    pub min_interval_days: i32,
+}
+
+impl Default for ScheduleLimits {
+    fn default() -> Self {
+        Self {
+            min_cron_interval: Duration::minutes(MIN_CRON_INTERVAL_MINUTES),
+            min_interval_days: MIN_INTERVAL_DAYS,
+        }
+    }
+}
+
 /// A chore with computed due information
 #[derive(Debug, Clone)]
 pub struct ChoreWithDueInfo {
@@ -21,21 +69,95 @@ pub struct ChoreWithDueInfo {
     pub is_overdue: bool,
 }
 
+/// Runs of consecutive on-time completions of a chore
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompletionStreak {
+    /// Run ending with the latest completion
+    pub current: i64,
+    /// Longest run so far
+    pub best: i64,
+}
+
+/// How a day's due chores fit a time budget
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DayBudget {
+    /// Estimated minutes of all the day's chores
+    pub total_minutes: i64,
+    /// Minutes over the budget (0 when the chores fit)
+    pub overflow_minutes: i64,
+    /// Ids of the chores to put off until the rest fits, in the order they
+    /// should be put off
+    pub deferrals: Vec<Uuid>,
+}
+
+/// Ordering of the due chores list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DueSort {
+    /// Soonest due first, counting overdue chores by whole days overdue so
+    /// that higher priority comes first within a day
+    #[default]
+    DueAsc,
+    /// Overdue chores first, longest overdue to the second, then upcoming
+    /// chores soonest first
+    OverdueDesc,
+    /// Most overdue relative to the schedule period first, so a daily chore
+    /// two days late outranks a monthly chore two days late. This ranks by
+    /// urgency; the chore's own `priority` only breaks ties.
+    Priority,
+}
+
+/// How a completion relates to the occurrence it satisfied, by calendar day
+/// in the chore's timezone
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CompletionTiming {
+    /// Done on a day before the occurrence
+    Early,
+    /// Done on the day of the occurrence
+    OnTime,
+    /// Done on a day after the occurrence
+    Late,
+}
+
+impl FromStr for DueSort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "due_asc" => Ok(Self::DueAsc),
+            "overdue_desc" => Ok(Self::OverdueDesc),
+            "priority" => Ok(Self::Priority),
+            other => Err(format!(
+                "Unknown due sort '{}' (expected due_asc, overdue_desc or priority)",
+                other
+            )),
+        }
+    }
+}
+
 pub struct ChoreService;
 
 impl ChoreService {
     /// Get all chores that are due or overdue
+    ///
+    /// With an `owner`, only that user's chores and unowned chores are included.
     pub async fn get_due_chores(
         pool: &SqlitePool,
+        owner: Option<Uuid>,
         include_upcoming: bool,
+        sort: DueSort,
+        tags: &TagFilter,
     ) -> color_eyre::Result<Vec<ChoreWithDueInfo>> {
-        let chores = ChoreRepository::list_all_with_last_completion(pool).await?;
+        let chores =
+            ChoreRepository::list_all_with_last_completion(pool, owner, false, tags).await?;
+        let holidays = HolidayRepository::list_dates(pool).await?;
         let now = Utc::now();
 
         let mut result = Vec::new();
 
         for chore in chores {
-            if let Some(due_info) = Self::compute_due_info(&chore, now) {
+            if let Some(due_info) = Self::compute_due_info(&chore, now, &holidays) {
                 // Include if overdue, or if include_upcoming is true
                 if due_info.is_overdue || include_upcoming {
                     result.push(due_info);
@@ -43,31 +165,273 @@ impl ChoreService {
             }
         }
 
-        // Sort by next_due (overdue first, then by soonest due)
-        result.sort_by(|a, b| match (&a.next_due, &b.next_due) {
-            (Some(a_due), Some(b_due)) => a_due.cmp(b_due),
-            (Some(_), None) => std::cmp::Ordering::Less,
-            (None, Some(_)) => std::cmp::Ordering::Greater,
-            (None, None) => std::cmp::Ordering::Equal,
-        });
+        Self::sort_due_chores(&mut result, sort, now);
 
         Ok(result)
     }
 
-    /// Compute the next due time for a single chore
+    /// Order due chores; chores without a due time always sort last.
+    ///
+    /// Chores that tie on the sort (overdue by the same number of whole days,
+    /// due at the same time, or equally urgent) are ordered high priority
+    /// first.
+    pub fn sort_due_chores(chores: &mut [ChoreWithDueInfo], sort: DueSort, now: DateTime<Utc>) {
+        match sort {
+            DueSort::DueAsc => {
+                chores.sort_by_key(|c| {
+                    let overdue_days = c
+                        .next_due
+                        .filter(|_| c.is_overdue)
+                        .map(|due| (now - due).num_days());
+                    (
+                        c.next_due.is_none(),
+                        overdue_days.is_none(),
+                        Reverse(overdue_days),
+                        c.next_due.filter(|_| overdue_days.is_none()),
+                        Reverse(c.chore.priority),
+                        c.next_due,
+                    )
+                });
+            }
+            DueSort::OverdueDesc => {
+                chores.sort_by_key(|c| {
+                    let overdue = c.next_due.filter(|_| c.is_overdue).map(|due| now - due);
+                    (
+                        c.next_due.is_none(),
+                        overdue.is_none(),
+                        Reverse(overdue),
+                        c.next_due,
+                        Reverse(c.chore.priority),
+                    )
+                });
+            }
+            DueSort::Priority => {
+                chores.sort_by(|a, b| {
+                    let urgency = |c: &ChoreWithDueInfo| {
+                        c.next_due.map(|due| {
+                            let period = Self::schedule_period(
+                                c.chore.schedule_type,
+                                c.chore.cron_schedule.as_deref(),
+                                c.chore.interval_days,
+                                c.chore.days_of_week.as_deref(),
+                            )
+                            .unwrap_or(Duration::days(1));
+                            (now - due).num_seconds() as f64 / period.num_seconds().max(1) as f64
+                        })
+                    };
+                    match (urgency(a), urgency(b)) {
+                        (Some(a), Some(b)) => b.total_cmp(&a),
+                        (Some(_), None) => std::cmp::Ordering::Less,
+                        (None, Some(_)) => std::cmp::Ordering::Greater,
+                        (None, None) => std::cmp::Ordering::Equal,
+                    }
+                    .then_with(|| b.chore.priority.cmp(&a.chore.priority))
+                });
+            }
+        }
+    }
+
+    /// Fit a day's due chores into `limit_minutes`.
+    ///
+    /// Chores without an estimate count as no time and are never deferred.
+    /// Deferrals are picked lowest priority first, and among equal priorities
+    /// the chore due last first, until the remaining chores fit.
+    pub fn plan_day_budget(chores: &[ChoreWithDueInfo], limit_minutes: i64) -> DayBudget {
+        let minutes = |c: &ChoreWithDueInfo| i64::from(c.chore.estimated_minutes.unwrap_or(0));
+        let total_minutes: i64 = chores.iter().map(minutes).sum();
+
+        let mut candidates: Vec<&ChoreWithDueInfo> =
+            chores.iter().filter(|c| minutes(c) > 0).collect();
+        candidates.sort_by_key(|c| (c.chore.priority, Reverse(c.next_due)));
+
+        let mut remaining = total_minutes;
+        let mut deferrals = Vec::new();
+        for chore in candidates {
+            if remaining <= limit_minutes {
+                break;
+            }
+            remaining -= minutes(chore);
+            deferrals.push(chore.chore.id);
+        }
+
+        DayBudget {
+            total_minutes,
+            overflow_minutes: (total_minutes - limit_minutes).max(0),
+            deferrals,
+        }
+    }
+
+    /// Compute the next due time for a single chore.
+    ///
+    /// A due time falling on one of `holidays` (dates in the chore's timezone)
+    /// is shifted forward day by day, keeping its local time of day, until it
+    /// lands on a non-holiday.
+    /// One-time chores keep their fixed due time and yield `None` once
+    /// completed or skipped, so they drop out of the due list for good.
     pub fn compute_due_info(
         chore: &ChoreWithLastCompletion,
         now: DateTime<Utc>,
+        holidays: &HashSet<NaiveDate>,
     ) -> Option<ChoreWithDueInfo> {
-        match chore.schedule_type {
-            ScheduleType::Cron => Self::compute_cron_due(chore, now),
-            ScheduleType::Interval => Self::compute_interval_due(chore, now),
-            ScheduleType::OnceInAWhile => Some(ChoreWithDueInfo {
+        let mut info = match chore.schedule_type {
+            ScheduleType::Cron => Self::compute_cron_due(chore, now)?,
+            ScheduleType::Interval => Self::compute_interval_due(chore, now)?,
+            ScheduleType::DaysOfWeek => Self::compute_days_of_week_due(chore, now)?,
+            ScheduleType::OnceInAWhile => ChoreWithDueInfo {
                 chore: chore.clone(),
                 next_due: None,
                 is_overdue: false,
-            }),
+            },
+            ScheduleType::OneTime => {
+                if chore.last_completed_at.is_some() || chore.last_skipped_at.is_some() {
+                    return None;
+                }
+                let due_at = chore.due_at?;
+                return Some(ChoreWithDueInfo {
+                    chore: chore.clone(),
+                    next_due: Some(due_at),
+                    is_overdue: due_at <= now,
+                });
+            }
+        };
+
+        if let Some(next_due) = info.next_due {
+            let mut shifted = Self::skip_holidays(next_due, Self::chore_timezone(chore), holidays);
+            // A snooze set when completing holds until a later skip supersedes it
+            let snoozed = chore
+                .next_snoozed_until
+                .filter(|_| chore.last_skipped_at <= chore.last_completed_at);
+            if let Some(until) = snoozed {
+                shifted = shifted.max(until);
+            }
+            info.next_due = Some(shifted);
+            info.is_overdue = shifted <= now;
         }
+
+        Some(info)
+    }
+
+    /// The next `count` due times of a chore, as if each occurrence were
+    /// completed exactly when due.
+    ///
+    /// Chores without a due time yield an empty list; one-time chores yield
+    /// at most one occurrence. Holidays are not applied.
+    pub fn preview_occurrences(
+        chore: &ChoreWithLastCompletion,
+        count: usize,
+        now: DateTime<Utc>,
+    ) -> Vec<DateTime<Utc>> {
+        let holidays = HashSet::new();
+        let mut chore = chore.clone();
+        let mut occurrences: Vec<DateTime<Utc>> = Vec::with_capacity(count);
+
+        while occurrences.len() < count {
+            let Some(next_due) =
+                Self::compute_due_info(&chore, now, &holidays).and_then(|info| info.next_due)
+            else {
+                break;
+            };
+            if occurrences.last().is_some_and(|&last| next_due <= last) {
+                break;
+            }
+            occurrences.push(next_due);
+            chore.last_completed_at = Some(next_due);
+            chore.next_snoozed_until = None;
+        }
+
+        occurrences
+    }
+
+    /// Classify a completion against the occurrence it satisfied: the one due
+    /// after the `previous` completion (or the chore's creation).
+    ///
+    /// Returns the timing and the signed lateness in seconds (negative when
+    /// early), or `None` for chores without a due time.
+    pub fn classify_completion(
+        chore: &ChoreWithLastCompletion,
+        previous: Option<DateTime<Utc>>,
+        completed_at: DateTime<Utc>,
+        holidays: &HashSet<NaiveDate>,
+    ) -> Option<(CompletionTiming, i64)> {
+        // Only the latest skip is known; it counts if it fell in this window
+        let satisfied = ChoreWithLastCompletion {
+            last_completed_at: previous,
+            last_skipped_at: chore
+                .last_skipped_at
+                .filter(|skipped| *skipped < completed_at && previous < Some(*skipped)),
+            next_snoozed_until: None,
+            ..chore.clone()
+        };
+        let due = Self::compute_due_info(&satisfied, completed_at, holidays)?.next_due?;
+
+        let tz = Self::chore_timezone(chore);
+        let due_day = due.with_timezone(&tz).date_naive();
+        let completed_day = completed_at.with_timezone(&tz).date_naive();
+        let timing = match completed_day.cmp(&due_day) {
+            std::cmp::Ordering::Less => CompletionTiming::Early,
+            std::cmp::Ordering::Equal => CompletionTiming::OnTime,
+            std::cmp::Ordering::Greater => CompletionTiming::Late,
+        };
+
+        Some((timing, (completed_at - due).num_seconds()))
+    }
+
+    /// Count completions (oldest first) done on time or early, and late.
+    ///
+    /// Chores without a due time count neither.
+    pub fn count_punctuality(
+        chore: &ChoreWithLastCompletion,
+        completed: &[DateTime<Utc>],
+        holidays: &HashSet<NaiveDate>,
+    ) -> (i64, i64) {
+        let mut on_time = 0;
+        let mut late = 0;
+        let mut previous = None;
+        for completed_at in completed {
+            match Self::classify_completion(chore, previous, *completed_at, holidays) {
+                Some((CompletionTiming::Late, _)) => late += 1,
+                Some(_) => on_time += 1,
+                None => {}
+            }
+            previous = Some(*completed_at);
+        }
+        (on_time, late)
+    }
+
+    /// Walk completions (oldest first) and count runs done on or before the
+    /// day they were due; a late completion ends the run.
+    ///
+    /// Chores without a due time (e.g. `once_in_a_while`) are always on time.
+    pub fn on_time_streak(
+        chore: &ChoreWithLastCompletion,
+        completed: &[DateTime<Utc>],
+        holidays: &HashSet<NaiveDate>,
+    ) -> CompletionStreak {
+        let mut streak = CompletionStreak::default();
+        let mut previous = None;
+        for completed_at in completed {
+            let late = matches!(
+                Self::classify_completion(chore, previous, *completed_at, holidays),
+                Some((CompletionTiming::Late, _))
+            );
+            streak.current = if late { 0 } else { streak.current + 1 };
+            streak.best = streak.best.max(streak.current);
+            previous = Some(*completed_at);
+        }
+        streak
+    }
+
+    /// Move a due time past any consecutive holidays, keeping its local time
+    /// of day. Holidays are dates in the chore's timezone `tz`.
+    fn skip_holidays(due: DateTime<Utc>, tz: Tz, holidays: &HashSet<NaiveDate>) -> DateTime<Utc> {
+        let mut local = due.with_timezone(&tz).naive_local();
+        if !holidays.contains(&local.date()) {
+            return due;
+        }
+        while holidays.contains(&local.date()) {
+            local += Duration::days(1);
+        }
+        Self::resolve_local(tz, local).unwrap_or(due)
     }
 
     /// Compute due info for a cron-based chore
@@ -90,11 +454,20 @@ impl ChoreService {
             }
         };
 
-        // Base time is either last completion or chore creation
-        let base_time = chore.last_completed_at.unwrap_or(chore.created_at);
-
-        // Find the next occurrence after base_time
-        let next_due = cron.find_next_occurrence(&base_time, false).ok()?;
+        // Find the next occurrence in the chore's timezone
+        let tz = Self::chore_timezone(chore);
+        let next_due = match chore.anchor_mode {
+            ChoreAnchorMode::FromCompletion => {
+                Self::next_cron_occurrence(&cron, tz, Self::base_time(chore))?
+            }
+            ChoreAnchorMode::FromSchedule => {
+                let period =
+                    Self::schedule_period(ScheduleType::Cron, Some(cron_schedule), None, None)?;
+                Self::next_scheduled_occurrence(chore, period, |after| {
+                    Self::next_cron_occurrence(&cron, tz, after)
+                })?
+            }
+        };
         let is_overdue = next_due <= now;
 
         Some(ChoreWithDueInfo {
@@ -104,26 +477,110 @@ impl ChoreService {
         })
     }
 
+    /// Time the next occurrence is computed from: the last completion or
+    /// skipped occurrence, whichever is later, or the chore's creation
+    fn base_time(chore: &ChoreWithLastCompletion) -> DateTime<Utc> {
+        chore
+            .last_completed_at
+            .max(chore.last_skipped_at)
+            .unwrap_or(chore.created_at)
+    }
+
+    /// With [`ChoreAnchorMode::FromSchedule`], the occurrence after the one
+    /// the latest completion or skip counted for.
+    ///
+    /// `first_after` walks the occurrences laid out from the chore's creation.
+    /// A completion counts for whichever occurrence it is nearest to, so
+    /// completing early doesn't bring the following one forward and
+    /// completing late doesn't push it back.
+    fn next_scheduled_occurrence(
+        chore: &ChoreWithLastCompletion,
+        period: Duration,
+        first_after: impl Fn(DateTime<Utc>) -> Option<DateTime<Utc>>,
+    ) -> Option<DateTime<Utc>> {
+        let Some(done) = chore.last_completed_at.max(chore.last_skipped_at) else {
+            return first_after(chore.created_at);
+        };
+
+        // Occurrences more than a couple of periods back can't be the nearest
+        let mut previous = None;
+        let mut next = first_after(chore.created_at.max(done - period * 2))?;
+        while next <= done {
+            previous = Some(next);
+            next = first_after(next)?;
+        }
+
+        match previous {
+            Some(previous) if done - previous <= next - done => Some(next),
+            _ => first_after(next),
+        }
+    }
+
     /// Compute due info for an interval-based chore
     fn compute_interval_due(
         chore: &ChoreWithLastCompletion,
         now: DateTime<Utc>,
     ) -> Option<ChoreWithDueInfo> {
-        let interval_days = chore.interval_days?;
-
-        // Base time is either last completion or chore creation
-        let base_time = chore.last_completed_at.unwrap_or(chore.created_at);
+        let interval_days = i64::from(chore.interval_days?);
+        let tz = Self::chore_timezone(chore);
 
-        // Calculate the due date by adding interval days
-        let due_date = base_time.date_naive() + Duration::days(i64::from(interval_days));
-
-        // Apply configured time of day (default to midnight UTC)
+        // Apply configured time of day in the chore's timezone (default to
+        // midnight)
         let hour = chore.interval_time_hour.unwrap_or(0) as u32;
         let minute = chore.interval_time_minute.unwrap_or(0) as u32;
-
         let time = NaiveTime::from_hms_opt(hour, minute, 0)?;
-        let next_due = due_date.and_time(time).and_utc();
 
+        let next_due = match chore.anchor_mode {
+            ChoreAnchorMode::FromCompletion => {
+                // Calculate the due date by adding interval days
+                let base_date = Self::base_time(chore).with_timezone(&tz).date_naive();
+                let due_date = base_date + Duration::days(interval_days);
+                Self::resolve_local(tz, due_date.and_time(time))?
+            }
+            ChoreAnchorMode::FromSchedule => {
+                // Occurrences fall every `interval_days` after the creation day
+                let created = chore.created_at.with_timezone(&tz).date_naive();
+                let step = interval_days.max(1);
+                let occurrence = |n: i64| {
+                    let due_date = created + Duration::days(n * step);
+                    Self::resolve_local(tz, due_date.and_time(time))
+                };
+                Self::next_scheduled_occurrence(chore, Duration::days(step), |after| {
+                    let elapsed = (after.with_timezone(&tz).date_naive() - created)
+                        .num_days()
+                        .max(0);
+                    ((elapsed / step).max(1)..)
+                        .map_while(occurrence)
+                        .find(|due| *due > after)
+                })?
+            }
+        };
+
+        let is_overdue = next_due <= now;
+
+        Some(ChoreWithDueInfo {
+            chore: chore.clone(),
+            next_due: Some(next_due),
+            is_overdue,
+        })
+    }
+
+    /// Compute due info for a days-of-week chore
+    fn compute_days_of_week_due(
+        chore: &ChoreWithLastCompletion,
+        now: DateTime<Utc>,
+    ) -> Option<ChoreWithDueInfo> {
+        let days = Self::parse_days_of_week(chore.days_of_week.as_deref()?);
+
+        let base_time = Self::base_time(chore);
+
+        let next_due = Self::next_weekday_occurrence(
+            &days,
+            chore.interval_time_hour,
+            chore.interval_time_minute,
+            Self::chore_timezone(chore),
+            base_time,
+        )?;
         let is_overdue = next_due <= now;
 
         Some(ChoreWithDueInfo {
@@ -133,12 +590,122 @@ impl ChoreService {
         })
     }
 
+    /// First selected weekday strictly after the local date of `after`, at
+    /// the given wall-clock time in `tz` (default midnight).
+    pub fn next_weekday_occurrence(
+        days: &[u8],
+        hour: Option<i32>,
+        minute: Option<i32>,
+        tz: Tz,
+        after: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        let time =
+            NaiveTime::from_hms_opt(hour.unwrap_or(0) as u32, minute.unwrap_or(0) as u32, 0)?;
+        let after = after.with_timezone(&tz).date_naive();
+        let date = (1..=7)
+            .map(|offset| after + Duration::days(offset))
+            .find(|date| days.contains(&(date.weekday().num_days_from_sunday() as u8)))?;
+        Self::resolve_local(tz, date.and_time(time))
+    }
+
+    /// Parse a stored days-of-week list (e.g. "2,5"), ignoring malformed entries
+    pub fn parse_days_of_week(days: &str) -> Vec<u8> {
+        days.split(',')
+            .filter_map(|d| d.trim().parse().ok())
+            .collect()
+    }
+
+    /// Format weekdays for storage, sorted ascending
+    pub fn format_days_of_week(days: &[u8]) -> String {
+        let mut sorted = days.to_vec();
+        sorted.sort_unstable();
+        sorted
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Parse a stored completion source list (e.g. "api,telegram"), ignoring
+    /// malformed entries
+    pub fn parse_completion_sources(sources: &str) -> Vec<CompletionSource> {
+        sources
+            .split(',')
+            .filter_map(|s| s.trim().parse().ok())
+            .collect()
+    }
+
+    /// Format completion sources for storage, without duplicates
+    pub fn format_completion_sources(sources: &[CompletionSource]) -> String {
+        [
+            CompletionSource::Api,
+            CompletionSource::Telegram,
+            CompletionSource::Email,
+        ]
+        .into_iter()
+        .filter(|source| sources.contains(source))
+        .map(CompletionSource::as_str)
+        .collect::<Vec<_>>()
+        .join(",")
+    }
+
+    /// Whether a chore with the stored `allowed` list accepts completions
+    /// from `source`
+    pub fn allows_completion_source(allowed: Option<&str>, source: CompletionSource) -> bool {
+        allowed.is_none_or(|allowed| Self::parse_completion_sources(allowed).contains(&source))
+    }
+
+    /// Parse an IANA timezone name such as `Europe/Budapest`
+    pub fn parse_timezone(name: &str) -> Result<Tz, String> {
+        name.parse::<Tz>()
+            .map_err(|_| format!("Unknown timezone '{}'", name))
+    }
+
+    /// Timezone a chore's schedule is evaluated in, falling back to UTC
+    pub fn chore_timezone(chore: &ChoreWithLastCompletion) -> Tz {
+        chore
+            .timezone
+            .as_deref()
+            .and_then(|name| Self::parse_timezone(name).ok())
+            .unwrap_or(Tz::UTC)
+    }
+
+    /// Next cron occurrence strictly after `after`, matching fields against
+    /// wall-clock time in `tz`
+    pub fn next_cron_occurrence(
+        cron: &Cron,
+        tz: Tz,
+        after: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        cron.find_next_occurrence(&after.with_timezone(&tz), false)
+            .ok()
+            .map(|next| next.with_timezone(&Utc))
+    }
+
+    /// Resolve a wall-clock time in `tz` to a UTC instant.
+    ///
+    /// Times that fall in a DST gap are shifted forward to the first valid
+    /// local minute after the gap; times repeated by a DST overlap resolve
+    /// to the earlier instant.
+    pub fn resolve_local(tz: Tz, local: NaiveDateTime) -> Option<DateTime<Utc>> {
+        // DST gaps are at most a few hours; a day is a generous upper bound.
+        (0..=24 * 60).find_map(|offset| {
+            match tz.from_local_datetime(&(local + Duration::minutes(offset))) {
+                LocalResult::Single(dt) => Some(dt.with_timezone(&Utc)),
+                LocalResult::Ambiguous(earliest, _) => Some(earliest.with_timezone(&Utc)),
+                LocalResult::None => None,
+            }
+        })
+    }
+
     /// Validate a cron schedule string.
     ///
     /// Returns an error if:
-    /// - The schedule is not valid cron syntax
-    /// - The schedule would fire more frequently than once per hour
-    pub fn validate_cron_schedule(schedule: &str) -> Result<(), String> {
+    /// - The schedule is not valid cron syntax; out-of-range values name the
+    ///   offending field
+    /// - The schedule would fire more frequently than `limits` allow
+    pub fn validate_cron_schedule(schedule: &str, limits: ScheduleLimits) -> Result<(), String> {
+        Self::validate_cron_fields(schedule)?;
         let cron = Cron::new(schedule).parse().map_err(|e| e.to_string())?;
 
         // Check frequency by finding two consecutive occurrences
@@ -151,8 +718,138 @@ impl ChoreService {
             .map_err(|e| e.to_string())?;
 
         let interval = second - first;
-        if interval < Duration::hours(1) {
-            return Err("Schedule is too frequent. Minimum interval is 1 hour.".to_string());
+        if interval < limits.min_cron_interval {
+            return Err(format!(
+                "Schedule is too frequent. Minimum interval is {} minutes.",
+                limits.min_cron_interval.num_minutes()
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Check the field count and the numeric values of each field of a cron
+    /// expression, leaving names, `L`, `W` and nicknames to croner
+    fn validate_cron_fields(schedule: &str) -> Result<(), String> {
+        if schedule.trim_start().starts_with('@') {
+            return Ok(());
+        }
+
+        let fields: Vec<&str> = schedule.split_whitespace().collect();
+        if fields.len() != CRON_FIELDS.len() {
+            return Err(format!(
+                "expected 5 fields (minute, hour, day of month, month, day of week), got {}",
+                fields.len()
+            ));
+        }
+
+        for (field, (name, min, max)) in fields.iter().zip(CRON_FIELDS) {
+            for item in field.split(',') {
+                // Only the range part of `a-b/step` or `day#nth` holds field values
+                let range = item.split(['/', '#']).next().unwrap_or_default();
+                for value in range.split('-') {
+                    let Ok(number) = value.parse::<u32>() else {
+                        continue;
+                    };
+                    if !(min..=max).contains(&number) {
+                        return Err(format!("{name} field '{field}' out of range {min}-{max}"));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Time between two consecutive occurrences of a schedule, if it has one.
+    ///
+    /// Cron periods are measured between the next two occurrences from now.
+    /// Days-of-week periods are the shortest gap between two selected days.
+    pub fn schedule_period(
+        schedule_type: ScheduleType,
+        cron_schedule: Option<&str>,
+        interval_days: Option<i32>,
+        days_of_week: Option<&str>,
+    ) -> Option<Duration> {
+        match schedule_type {
+            ScheduleType::Cron => {
+                let cron = Cron::new(cron_schedule?).parse().ok()?;
+                let first = cron.find_next_occurrence(&Utc::now(), false).ok()?;
+                let second = cron.find_next_occurrence(&first, false).ok()?;
+                Some(second - first)
+            }
+            ScheduleType::Interval => Some(Duration::days(i64::from(interval_days?))),
+            ScheduleType::DaysOfWeek => {
+                let mut days = Self::parse_days_of_week(days_of_week?);
+                days.sort_unstable();
+                let first = *days.first()?;
+                let wrap = i64::from(first) + 7 - i64::from(*days.last()?);
+                let gap = days
+                    .windows(2)
+                    .map(|w| i64::from(w[1] - w[0]))
+                    .fold(wrap, i64::min);
+                Some(Duration::days(gap))
+            }
+            ScheduleType::OnceInAWhile | ScheduleType::OneTime => None,
+        }
+    }
+
+    /// How many schedule periods have passed since the chore was last
+    /// completed or skipped, or created if it never was. `None` for chores
+    /// without a period.
+    pub fn neglect_ratio(chore: &ChoreWithLastCompletion, now: DateTime<Utc>) -> Option<f64> {
+        let period = Self::schedule_period(
+            chore.schedule_type,
+            chore.cron_schedule.as_deref(),
+            chore.interval_days,
+            chore.days_of_week.as_deref(),
+        )
+        .filter(|period| *period > Duration::zero())?;
+        let elapsed = (now - Self::base_time(chore)).max(Duration::zero());
+
+        Some(elapsed.num_seconds() as f64 / period.num_seconds() as f64)
+    }
+
+    /// When the chore is next due for a review: `review_interval_days` after
+    /// it was last reviewed, or created if it never was. `None` for chores
+    /// without a review interval.
+    pub fn review_due_at(chore: &ChoreWithLastCompletion) -> Option<DateTime<Utc>> {
+        let days = chore.review_interval_days?;
+        let since = chore.last_reviewed_at.unwrap_or(chore.created_at);
+        Some(since + Duration::days(i64::from(days)))
+    }
+
+    /// Validate a notification lead time against the chore's schedule.
+    ///
+    /// Returns an error if:
+    /// - The lead time is negative
+    /// - The lead time is longer than one week
+    /// - The lead time is longer than the schedule period
+    pub fn validate_notify_lead(
+        lead_minutes: i32,
+        schedule_type: ScheduleType,
+        cron_schedule: Option<&str>,
+        interval_days: Option<i32>,
+        days_of_week: Option<&str>,
+    ) -> Result<(), String> {
+        if lead_minutes < 0 {
+            return Err("Lead time cannot be negative".to_string());
+        }
+        if lead_minutes > MAX_NOTIFY_LEAD_MINUTES {
+            return Err(format!(
+                "Lead time cannot exceed {} minutes (1 week)",
+                MAX_NOTIFY_LEAD_MINUTES
+            ));
+        }
+
+        if let Some(period) =
+            Self::schedule_period(schedule_type, cron_schedule, interval_days, days_of_week)
+            && Duration::minutes(i64::from(lead_minutes)) > period
+        {
+            return Err(format!(
+                "Lead time cannot exceed the schedule interval ({} minutes)",
+                period.num_minutes()
+            ));
         }
 
         Ok(())
@@ -161,18 +858,26 @@ impl ChoreService {
     /// Validate an interval schedule.
     ///
     /// Returns an error if:
-    /// - The interval is less than 1 day
+    /// - The interval is shorter than `limits` allow
     /// - The interval is greater than 365 days (1 year)
     /// - The time values are out of range
+    ///
+    /// The lower bound on the interval is what keeps interval chores from
+    /// firing too often, like the minimum gap does for cron. The time of day
+    /// needs no check against the creation time: the first occurrence falls
+    /// at least a whole day after the local creation date, so even
+    /// `days = 1` at midnight is first due at the next midnight in the
+    /// chore's timezone, never overdue on creation.
     pub fn validate_interval_schedule(
         days: i32,
         hour: Option<i32>,
         minute: Option<i32>,
+        limits: ScheduleLimits,
     ) -> Result<(), String> {
-        if days < MIN_INTERVAL_DAYS {
+        if days < limits.min_interval_days {
             return Err(format!(
                 "Interval must be at least {} day(s)",
-                MIN_INTERVAL_DAYS
+                limits.min_interval_days
             ));
         }
         if days > MAX_INTERVAL_DAYS {
@@ -182,6 +887,65 @@ impl ChoreService {
             ));
         }
 
+        Self::validate_time_of_day(hour, minute)
+    }
+
+    /// Validate a chore's review interval: at least a day.
+    pub fn validate_review_interval_days(days: i32) -> Result<(), String> {
+        if days < 1 {
+            return Err("review_interval_days must be at least 1".to_string());
+        }
+        Ok(())
+    }
+
+    /// Validate a chore's minimum time between due notifications: at least a
+    /// minute.
+    pub fn validate_min_notify_interval_minutes(minutes: i32) -> Result<(), String> {
+        if minutes < 1 {
+            return Err("min_notify_interval_minutes must be at least 1".to_string());
+        }
+        Ok(())
+    }
+
+    /// Validate a chore's time estimate: 1 minute up to a day.
+    pub fn validate_estimated_minutes(minutes: i32) -> Result<(), String> {
+        if !(1..=MAX_ESTIMATED_MINUTES).contains(&minutes) {
+            return Err(format!(
+                "estimated_minutes must be between 1 and {}",
+                MAX_ESTIMATED_MINUTES
+            ));
+        }
+        Ok(())
+    }
+
+    /// Validate a days-of-week schedule.
+    ///
+    /// Returns an error if:
+    /// - No days are selected
+    /// - A day is outside 0-6 (0 = Sunday)
+    /// - A day is selected more than once
+    /// - The time values are out of range
+    pub fn validate_days_of_week_schedule(
+        days: &[u8],
+        hour: Option<i32>,
+        minute: Option<i32>,
+    ) -> Result<(), String> {
+        if days.is_empty() {
+            return Err("At least one day must be selected".to_string());
+        }
+        if let Some(d) = days.iter().find(|&&d| d > 6) {
+            return Err(format!("Day {} is out of range (0-6, 0 = Sunday)", d));
+        }
+        let unique: HashSet<u8> = days.iter().copied().collect();
+        if unique.len() != days.len() {
+            return Err("Days must not contain duplicates".to_string());
+        }
+
+        Self::validate_time_of_day(hour, minute)
+    }
+
+    /// Validate an optional hour (0-23) and minute (0-59)
+    fn validate_time_of_day(hour: Option<i32>, minute: Option<i32>) -> Result<(), String> {
         if let Some(h) = hour
             && !(0..=23).contains(&h)
         {
@@ -197,3 +961,602 @@ impl ChoreService {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::models::{ChoreAnchorMode, ChorePriority};
+
+    #[test]
+    fn test_validate_cron_reports_minute_field() {
+        let error = ChoreService::validate_cron_schedule("99 9 * * *", ScheduleLimits::default())
+            .unwrap_err();
+        assert_eq!(error, "minute field '99' out of range 0-59");
+    }
+
+    #[test]
+    fn test_validate_cron_reports_day_of_week_field() {
+        let error = ChoreService::validate_cron_schedule("0 9 * * 8", ScheduleLimits::default())
+            .unwrap_err();
+        assert_eq!(error, "day-of-week field '8' out of range 0-7");
+    }
+
+    #[test]
+    fn test_validate_cron_reports_values_inside_ranges_and_steps() {
+        let error =
+            ChoreService::validate_cron_schedule("0 9-25/2 * * *", ScheduleLimits::default())
+                .unwrap_err();
+        assert_eq!(error, "hour field '9-25/2' out of range 0-23");
+    }
+
+    #[test]
+    fn test_validate_cron_reports_field_count() {
+        let error =
+            ChoreService::validate_cron_schedule("0 9 * *", ScheduleLimits::default()).unwrap_err();
+        assert!(error.contains("got 4"), "unexpected error: {error}");
+    }
+
+    #[test]
+    fn test_validate_cron_accepts_names_and_nicknames() {
+        assert_eq!(
+            ChoreService::validate_cron_schedule("0 9 * JAN MON-FRI", ScheduleLimits::default()),
+            Ok(())
+        );
+        assert_eq!(
+            ChoreService::validate_cron_schedule("0 9 L * *", ScheduleLimits::default()),
+            Ok(())
+        );
+        assert_eq!(
+            ChoreService::validate_cron_schedule("@daily", ScheduleLimits::default()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_validate_cron_respects_minimum_interval() {
+        let error = ChoreService::validate_cron_schedule("*/15 * * * *", ScheduleLimits::default())
+            .unwrap_err();
+        assert!(error.contains("60 minutes"), "unexpected error: {error}");
+
+        let limits = ScheduleLimits {
+            min_cron_interval: Duration::minutes(15),
+            ..ScheduleLimits::default()
+        };
+        assert_eq!(
+            ChoreService::validate_cron_schedule("*/15 * * * *", limits),
+            Ok(())
+        );
+        assert!(ChoreService::validate_cron_schedule("*/5 * * * *", limits).is_err());
+    }
+
+    fn local(date: (i32, u32, u32), hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(date.0, date.1, date.2)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    fn utc(date: (i32, u32, u32), hour: u32, minute: u32) -> DateTime<Utc> {
+        local(date, hour, minute).and_utc()
+    }
+
+    #[test]
+    fn resolve_local_regular_time() {
+        let tz = chrono_tz::America::New_York;
+        assert_eq!(
+            ChoreService::resolve_local(tz, local((2025, 6, 1), 9, 0)),
+            Some(utc((2025, 6, 1), 13, 0))
+        );
+    }
+
+    #[test]
+    fn resolve_local_spring_forward_gap_shifts_forward() {
+        // 2025-03-09 02:00-03:00 does not exist in New York.
+        let tz = chrono_tz::America::New_York;
+        assert_eq!(
+            ChoreService::resolve_local(tz, local((2025, 3, 9), 2, 30)),
+            Some(utc((2025, 3, 9), 7, 0))
+        );
+    }
+
+    #[test]
+    fn resolve_local_fall_back_overlap_picks_earlier() {
+        // 2025-11-02 01:30 happens twice in New York (EDT, then EST).
+        let tz = chrono_tz::America::New_York;
+        assert_eq!(
+            ChoreService::resolve_local(tz, local((2025, 11, 2), 1, 30)),
+            Some(utc((2025, 11, 2), 5, 30))
+        );
+    }
+
+    #[test]
+    fn resolve_local_utc_is_identity() {
+        assert_eq!(
+            ChoreService::resolve_local(Tz::UTC, local((2025, 3, 9), 2, 30)),
+            Some(utc((2025, 3, 9), 2, 30))
+        );
+    }
+
+    fn cron(expr: &str) -> Cron {
+        Cron::new(expr).parse().expect("valid cron")
+    }
+
+    #[test]
+    fn next_cron_occurrence_follows_local_time_across_dst() {
+        // 9am New York is 14:00 UTC before the 2025-03-09 switch, 13:00 after.
+        let tz = chrono_tz::America::New_York;
+        let daily = cron("0 9 * * *");
+        assert_eq!(
+            ChoreService::next_cron_occurrence(&daily, tz, utc((2025, 3, 7), 15, 0)),
+            Some(utc((2025, 3, 8), 14, 0))
+        );
+        assert_eq!(
+            ChoreService::next_cron_occurrence(&daily, tz, utc((2025, 3, 8), 15, 0)),
+            Some(utc((2025, 3, 9), 13, 0))
+        );
+    }
+
+    #[test]
+    fn next_cron_occurrence_in_spring_forward_gap() {
+        // 02:30 does not exist in New York on 2025-03-09; it fires at the
+        // end of the gap (03:00 EDT) instead of skipping the day.
+        let tz = chrono_tz::America::New_York;
+        assert_eq!(
+            ChoreService::next_cron_occurrence(&cron("30 2 * * *"), tz, utc((2025, 3, 8), 12, 0)),
+            Some(utc((2025, 3, 9), 7, 0))
+        );
+    }
+
+    #[test]
+    fn parse_timezone_rejects_unknown_names() {
+        assert_eq!(
+            ChoreService::parse_timezone("Europe/Budapest"),
+            Ok(chrono_tz::Europe::Budapest)
+        );
+        assert!(ChoreService::parse_timezone("Mars/Olympus_Mons").is_err());
+    }
+
+    #[test]
+    fn next_weekday_occurrence_skips_base_date() {
+        // 2025-06-03 is a Tuesday
+        let after = utc((2025, 6, 3), 6, 0);
+        assert_eq!(
+            ChoreService::next_weekday_occurrence(&[2, 5], Some(9), Some(0), Tz::UTC, after),
+            Some(utc((2025, 6, 6), 9, 0))
+        );
+    }
+
+    #[test]
+    fn next_weekday_occurrence_wraps_week() {
+        // 2025-06-07 is a Saturday; next Tuesday is 2025-06-10
+        let after = utc((2025, 6, 7), 12, 0);
+        assert_eq!(
+            ChoreService::next_weekday_occurrence(&[2], None, None, Tz::UTC, after),
+            Some(utc((2025, 6, 10), 0, 0))
+        );
+    }
+
+    /// Daily chore due at `hour:minute` New York time, last completed at
+    /// `completed_at`
+    fn new_york_chore(
+        schedule_type: ScheduleType,
+        hour: i32,
+        minute: i32,
+        completed_at: DateTime<Utc>,
+    ) -> ChoreWithLastCompletion {
+        let mut chore = one_time_chore(completed_at, Some(completed_at));
+        chore.schedule_type = schedule_type;
+        chore.due_at = None;
+        chore.timezone = Some("America/New_York".to_string());
+        chore.interval_time_hour = Some(hour);
+        chore.interval_time_minute = Some(minute);
+        match schedule_type {
+            ScheduleType::Interval => chore.interval_days = Some(1),
+            _ => chore.days_of_week = Some("0,1,2,3,4,5,6".to_string()),
+        }
+        chore
+    }
+
+    fn next_due(chore: &ChoreWithLastCompletion) -> Option<DateTime<Utc>> {
+        let now = chore.last_completed_at?;
+        ChoreService::compute_due_info(chore, now, &HashSet::new())?.next_due
+    }
+
+    #[test]
+    fn compute_due_info_in_spring_forward_gap() {
+        // 02:30 does not exist in New York on 2024-03-10; the chore falls due
+        // at the end of the gap (03:00 EDT).
+        for schedule_type in [ScheduleType::Interval, ScheduleType::DaysOfWeek] {
+            let chore = new_york_chore(schedule_type, 2, 30, utc((2024, 3, 9), 12, 0));
+            assert_eq!(
+                next_due(&chore),
+                Some(utc((2024, 3, 10), 7, 0)),
+                "{schedule_type:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn compute_due_info_in_fall_back_overlap() {
+        // 01:30 happens twice in New York on 2024-11-03; the chore falls due
+        // at the first one (01:30 EDT).
+        for schedule_type in [ScheduleType::Interval, ScheduleType::DaysOfWeek] {
+            let chore = new_york_chore(schedule_type, 1, 30, utc((2024, 11, 2), 12, 0));
+            assert_eq!(
+                next_due(&chore),
+                Some(utc((2024, 11, 3), 5, 30)),
+                "{schedule_type:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn compute_due_info_uses_local_date() {
+        // 22:00 on 2024-06-01 in New York is already 06-02 in UTC; the next
+        // day's 09:00 is still 06-02 locally.
+        for schedule_type in [ScheduleType::Interval, ScheduleType::DaysOfWeek] {
+            let chore = new_york_chore(schedule_type, 9, 0, utc((2024, 6, 2), 2, 0));
+            assert_eq!(
+                next_due(&chore),
+                Some(utc((2024, 6, 2), 13, 0)),
+                "{schedule_type:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn compute_due_info_skips_local_holidays() {
+        // Due 22:00 on 2024-06-03 in New York, which is 06-04 in UTC
+        let chore = new_york_chore(ScheduleType::Interval, 22, 0, utc((2024, 6, 3), 1, 0));
+        let now = chore.last_completed_at.unwrap();
+        let next_due = |holiday| {
+            let holidays = HashSet::from([holiday]);
+            ChoreService::compute_due_info(&chore, now, &holidays)?.next_due
+        };
+
+        assert_eq!(
+            next_due(NaiveDate::from_ymd_opt(2024, 6, 3).unwrap()),
+            Some(utc((2024, 6, 5), 2, 0))
+        );
+        assert_eq!(
+            next_due(NaiveDate::from_ymd_opt(2024, 6, 4).unwrap()),
+            Some(utc((2024, 6, 4), 2, 0))
+        );
+    }
+
+    #[test]
+    fn schedule_period_days_of_week_is_shortest_gap() {
+        let period =
+            |days| ChoreService::schedule_period(ScheduleType::DaysOfWeek, None, None, Some(days));
+        assert_eq!(period("2,5"), Some(Duration::days(3)));
+        assert_eq!(period("0,6"), Some(Duration::days(1)));
+        assert_eq!(period("3"), Some(Duration::days(7)));
+    }
+
+    fn one_time_chore(
+        due_at: DateTime<Utc>,
+        last_completed_at: Option<DateTime<Utc>>,
+    ) -> ChoreWithLastCompletion {
+        ChoreWithLastCompletion {
+            id: uuid::Uuid::new_v4(),
+            name: "Renew passport".to_string(),
+            description: None,
+            schedule_type: ScheduleType::OneTime,
+            cron_schedule: None,
+            interval_days: None,
+            interval_time_hour: None,
+            interval_time_minute: None,
+            days_of_week: None,
+            due_at: Some(due_at),
+            timezone: None,
+            notify_lead_minutes: None,
+            max_snoozes: None,
+            estimated_minutes: None,
+            review_interval_days: None,
+            min_notify_interval_minutes: None,
+            last_reviewed_at: None,
+            recipient: None,
+            auto_complete_with: None,
+            allowed_completion_sources: None,
+            external_id: None,
+            priority: ChorePriority::Normal,
+            anchor_mode: ChoreAnchorMode::FromCompletion,
+            is_active: true,
+            created_at: utc((2025, 6, 1), 8, 0),
+            updated_at: utc((2025, 6, 1), 8, 0),
+            last_completed_at,
+            last_skipped_at: None,
+            next_snoozed_until: None,
+        }
+    }
+
+    #[test]
+    fn test_neglect_ratio_counts_periods_since_last_completion() {
+        let now = utc((2025, 6, 21), 8, 0);
+        let mut chore = one_time_chore(now, None);
+        assert_eq!(ChoreService::neglect_ratio(&chore, now), None);
+
+        chore.schedule_type = ScheduleType::Interval;
+        chore.interval_days = Some(4);
+        chore.last_completed_at = Some(now - Duration::days(10));
+        assert_eq!(ChoreService::neglect_ratio(&chore, now), Some(2.5));
+
+        // A later skip counts like a completion
+        chore.last_skipped_at = Some(now - Duration::days(2));
+        assert_eq!(ChoreService::neglect_ratio(&chore, now), Some(0.5));
+
+        // Never completed: measured from creation (20 days ago)
+        chore.last_completed_at = None;
+        chore.last_skipped_at = None;
+        assert_eq!(ChoreService::neglect_ratio(&chore, now), Some(5.0));
+    }
+
+    /// A one-time chore due at `due_at` with `priority`, as listed at `now`
+    fn due_with_priority(
+        name: &str,
+        due_at: DateTime<Utc>,
+        priority: ChorePriority,
+        now: DateTime<Utc>,
+    ) -> ChoreWithDueInfo {
+        let mut chore = one_time_chore(due_at, None);
+        chore.name = name.to_string();
+        chore.priority = priority;
+        ChoreWithDueInfo {
+            chore,
+            next_due: Some(due_at),
+            is_overdue: due_at <= now,
+        }
+    }
+
+    fn names(chores: &[ChoreWithDueInfo]) -> Vec<&str> {
+        chores.iter().map(|c| c.chore.name.as_str()).collect()
+    }
+
+    #[test]
+    fn sort_due_chores_prefers_high_priority_within_overdue_day() {
+        let now = utc((2025, 6, 10), 12, 0);
+        let mut chores = vec![
+            due_with_priority("low", utc((2025, 6, 10), 8, 0), ChorePriority::Low, now),
+            due_with_priority(
+                "normal",
+                utc((2025, 6, 10), 9, 0),
+                ChorePriority::Normal,
+                now,
+            ),
+            due_with_priority("high", utc((2025, 6, 10), 10, 0), ChorePriority::High, now),
+            // A day further overdue still comes first
+            due_with_priority("old", utc((2025, 6, 8), 12, 0), ChorePriority::Low, now),
+        ];
+
+        ChoreService::sort_due_chores(&mut chores, DueSort::DueAsc, now);
+
+        assert_eq!(names(&chores), ["old", "high", "normal", "low"]);
+    }
+
+    #[test]
+    fn sort_due_chores_overdue_desc_ranks_by_time_overdue() {
+        let now = utc((2025, 6, 10), 12, 0);
+        let mut chores = vec![
+            due_with_priority("later", utc((2025, 6, 11), 9, 0), ChorePriority::High, now),
+            due_with_priority("high", utc((2025, 6, 10), 10, 0), ChorePriority::High, now),
+            due_with_priority("low", utc((2025, 6, 10), 8, 0), ChorePriority::Low, now),
+            due_with_priority("soon", utc((2025, 6, 10), 18, 0), ChorePriority::Low, now),
+        ];
+
+        // Overdue by the same whole days, so priority decides
+        ChoreService::sort_due_chores(&mut chores, DueSort::DueAsc, now);
+        assert_eq!(names(&chores), ["high", "low", "soon", "later"]);
+
+        ChoreService::sort_due_chores(&mut chores, DueSort::OverdueDesc, now);
+        assert_eq!(names(&chores), ["low", "high", "soon", "later"]);
+    }
+
+    #[test]
+    fn sort_due_chores_breaks_upcoming_ties_by_priority() {
+        let now = utc((2025, 6, 10), 12, 0);
+        let soon = utc((2025, 6, 10), 18, 0);
+        let mut chores = vec![
+            due_with_priority("later", utc((2025, 6, 11), 9, 0), ChorePriority::High, now),
+            due_with_priority("low", soon, ChorePriority::Low, now),
+            due_with_priority("high", soon, ChorePriority::High, now),
+        ];
+
+        ChoreService::sort_due_chores(&mut chores, DueSort::DueAsc, now);
+        assert_eq!(names(&chores), ["high", "low", "later"]);
+
+        ChoreService::sort_due_chores(&mut chores, DueSort::Priority, now);
+        assert_eq!(names(&chores), ["high", "low", "later"]);
+    }
+
+    #[test]
+    fn plan_day_budget_defers_lowest_priority_first() {
+        let now = utc((2025, 6, 10), 12, 0);
+        let estimated = |name, due_at, priority, minutes| {
+            let mut chore = due_with_priority(name, due_at, priority, now);
+            chore.chore.estimated_minutes = minutes;
+            chore
+        };
+        let chores = [
+            estimated(
+                "dishes",
+                utc((2025, 6, 10), 9, 0),
+                ChorePriority::High,
+                Some(30),
+            ),
+            estimated(
+                "windows",
+                utc((2025, 6, 10), 9, 0),
+                ChorePriority::Low,
+                Some(20),
+            ),
+            estimated(
+                "laundry",
+                utc((2025, 6, 10), 8, 0),
+                ChorePriority::Normal,
+                Some(25),
+            ),
+            estimated(
+                "plants",
+                utc((2025, 6, 10), 10, 0),
+                ChorePriority::Normal,
+                Some(10),
+            ),
+            estimated("mail", utc((2025, 6, 10), 7, 0), ChorePriority::Low, None),
+        ];
+        let id = |name| {
+            chores
+                .iter()
+                .find(|c| c.chore.name == name)
+                .unwrap()
+                .chore
+                .id
+        };
+
+        let budget = ChoreService::plan_day_budget(&chores, 40);
+        assert_eq!(budget.total_minutes, 85);
+        assert_eq!(budget.overflow_minutes, 45);
+        // Low before normal; the normal chore due last goes first
+        assert_eq!(
+            budget.deferrals,
+            [id("windows"), id("plants"), id("laundry")]
+        );
+
+        let budget = ChoreService::plan_day_budget(&chores, 85);
+        assert_eq!(budget.overflow_minutes, 0);
+        assert!(budget.deferrals.is_empty());
+    }
+
+    #[test]
+    fn compute_due_info_one_time_is_due_once() {
+        let due_at = utc((2025, 6, 10), 9, 0);
+        let holidays = HashSet::from([due_at.date_naive()]);
+
+        // The fixed due time is kept, even on a holiday
+        let upcoming = ChoreService::compute_due_info(
+            &one_time_chore(due_at, None),
+            utc((2025, 6, 9), 9, 0),
+            &holidays,
+        )
+        .expect("due info");
+        assert_eq!(upcoming.next_due, Some(due_at));
+        assert!(!upcoming.is_overdue);
+
+        let overdue = ChoreService::compute_due_info(
+            &one_time_chore(due_at, None),
+            utc((2025, 6, 11), 9, 0),
+            &holidays,
+        )
+        .expect("due info");
+        assert!(overdue.is_overdue);
+
+        let completed = one_time_chore(due_at, Some(utc((2025, 6, 10), 10, 0)));
+        assert!(
+            ChoreService::compute_due_info(&completed, utc((2025, 6, 11), 9, 0), &holidays)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn preview_occurrences_one_time_yields_its_due_time_once() {
+        let due_at = utc((2025, 6, 10), 9, 0);
+        let now = utc((2025, 6, 1), 8, 0);
+
+        assert_eq!(
+            ChoreService::preview_occurrences(&one_time_chore(due_at, None), 5, now),
+            vec![due_at]
+        );
+    }
+
+    #[test]
+    fn preview_occurrences_days_of_week_cycles_through_days() {
+        let mut chore = one_time_chore(utc((2025, 6, 10), 9, 0), None);
+        chore.schedule_type = ScheduleType::DaysOfWeek;
+        chore.due_at = None;
+        // Tuesdays and Fridays at 07:30, from Sunday 2025-06-01
+        chore.days_of_week = Some("2,5".to_string());
+        chore.interval_time_hour = Some(7);
+        chore.interval_time_minute = Some(30);
+
+        assert_eq!(
+            ChoreService::preview_occurrences(&chore, 3, utc((2025, 6, 1), 8, 0)),
+            vec![
+                utc((2025, 6, 3), 7, 30),
+                utc((2025, 6, 6), 7, 30),
+                utc((2025, 6, 10), 7, 30),
+            ]
+        );
+    }
+
+    /// Next due time of `chore` after its latest completion at `completed_at`
+    fn next_due_after(
+        chore: &ChoreWithLastCompletion,
+        completed_at: Option<DateTime<Utc>>,
+    ) -> Option<DateTime<Utc>> {
+        let chore = ChoreWithLastCompletion {
+            last_completed_at: completed_at,
+            ..chore.clone()
+        };
+        ChoreService::compute_due_info(&chore, utc((2025, 6, 1), 8, 0), &HashSet::new())?.next_due
+    }
+
+    #[test]
+    fn compute_due_info_interval_anchor_modes() {
+        // Every 3 days at 09:00 from 2025-06-01: due 06-04, 06-07, 06-10, ...
+        let mut chore = one_time_chore(utc((2025, 6, 10), 9, 0), None);
+        chore.schedule_type = ScheduleType::Interval;
+        chore.due_at = None;
+        chore.interval_days = Some(3);
+        chore.interval_time_hour = Some(9);
+        let early = Some(utc((2025, 6, 3), 10, 0));
+        let late = Some(utc((2025, 6, 5), 8, 0));
+
+        assert_eq!(next_due_after(&chore, early), Some(utc((2025, 6, 6), 9, 0)));
+        assert_eq!(next_due_after(&chore, late), Some(utc((2025, 6, 8), 9, 0)));
+
+        chore.anchor_mode = ChoreAnchorMode::FromSchedule;
+        assert_eq!(next_due_after(&chore, None), Some(utc((2025, 6, 4), 9, 0)));
+        // Both count for 06-04, the occurrence they are nearest to
+        assert_eq!(next_due_after(&chore, early), Some(utc((2025, 6, 7), 9, 0)));
+        assert_eq!(next_due_after(&chore, late), Some(utc((2025, 6, 7), 9, 0)));
+        // Completed exactly when due, the schedule carries on unchanged
+        assert_eq!(
+            ChoreService::preview_occurrences(&chore, 3, utc((2025, 6, 1), 8, 0)),
+            vec![
+                utc((2025, 6, 4), 9, 0),
+                utc((2025, 6, 7), 9, 0),
+                utc((2025, 6, 10), 9, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn compute_due_info_cron_from_schedule_skips_occurrence_completed_early() {
+        let mut chore = one_time_chore(utc((2025, 6, 10), 9, 0), None);
+        chore.schedule_type = ScheduleType::Cron;
+        chore.due_at = None;
+        chore.cron_schedule = Some("0 9 * * *".to_string());
+        let early = Some(utc((2025, 6, 3), 8, 0));
+
+        assert_eq!(next_due_after(&chore, early), Some(utc((2025, 6, 3), 9, 0)));
+
+        chore.anchor_mode = ChoreAnchorMode::FromSchedule;
+        assert_eq!(next_due_after(&chore, early), Some(utc((2025, 6, 4), 9, 0)));
+    }
+
+    #[test]
+    fn validate_days_of_week_schedule_rejects_bad_days() {
+        assert!(ChoreService::validate_days_of_week_schedule(&[1, 4], Some(8), None).is_ok());
+        assert!(ChoreService::validate_days_of_week_schedule(&[], None, None).is_err());
+        assert!(ChoreService::validate_days_of_week_schedule(&[7], None, None).is_err());
+        assert!(ChoreService::validate_days_of_week_schedule(&[2, 2], None, None).is_err());
+        assert!(ChoreService::validate_days_of_week_schedule(&[2], Some(24), None).is_err());
+    }
+
+    #[test]
+    fn due_sort_parses_config_values() {
+        assert_eq!("due_asc".parse(), Ok(DueSort::DueAsc));
+        assert_eq!("overdue_desc".parse(), Ok(DueSort::OverdueDesc));
+        assert_eq!("priority".parse(), Ok(DueSort::Priority));
+        assert!("newest".parse::<DueSort>().is_err());
+    }
+}
@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::db::models::{
+    ChoreAnchorMode, ChorePriority, ChoreWithLastCompletion, Completion, CompletionSource,
+    CompletionWithChore, ScheduleType, Tag,
+};
+
+/// Header of the chores export, in [`ChoreRecord`] field order
+const CHORE_COLUMNS: [&str; 25] = [
+    "id",
+    "name",
+    "description",
+    "schedule_type",
+    "cron_schedule",
+    "interval_days",
+    "interval_time_hour",
+    "interval_time_minute",
+    "days_of_week",
+    "due_at",
+    "timezone",
+    "anchor_mode",
+    "notify_lead_minutes",
+    "max_snoozes",
+    "estimated_minutes",
+    "recipient",
+    "auto_complete_with",
+    "allowed_completion_sources",
+    "external_id",
+    "priority",
+    "is_active",
+    "tags",
+    "last_completed_at",
+    "created_at",
+    "updated_at",
+];
+
+/// Header of the completions export, in [`CompletionRecord`] field order
+const COMPLETION_COLUMNS: [&str; 7] = [
+    "id",
+    "chore_id",
+    "chore_name",
+    "completed_at",
+    "notes",
+    "source",
+    "created_at",
+];
+
+/// Header of a single chore's completion log, in [`ChoreCompletionRecord`]
+/// field order
+const CHORE_COMPLETION_COLUMNS: [&str; 3] = ["completed_at", "notes", "created_at"];
+
+/// One row of the chores export, with every column needed to recreate the chore
+#[derive(Serialize)]
+struct ChoreRecord<'a> {
+    id: Uuid,
+    name: &'a str,
+    description: Option<&'a str>,
+    schedule_type: ScheduleType,
+    cron_schedule: Option<&'a str>,
+    interval_days: Option<i32>,
+    interval_time_hour: Option<i32>,
+    interval_time_minute: Option<i32>,
+    days_of_week: Option<&'a str>,
+    due_at: Option<DateTime<Utc>>,
+    timezone: Option<&'a str>,
+    anchor_mode: ChoreAnchorMode,
+    notify_lead_minutes: Option<i32>,
+    max_snoozes: Option<i32>,
+    estimated_minutes: Option<i32>,
+    recipient: Option<&'a str>,
+    auto_complete_with: Option<Uuid>,
+    allowed_completion_sources: Option<&'a str>,
+    external_id: Option<&'a str>,
+    priority: ChorePriority,
+    is_active: bool,
+    /// Comma-separated tag names
+    tags: String,
+    last_completed_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+/// One row of the completions export
+#[derive(Serialize)]
+struct CompletionRecord<'a> {
+    id: Uuid,
+    chore_id: Uuid,
+    chore_name: &'a str,
+    completed_at: DateTime<Utc>,
+    notes: Option<&'a str>,
+    source: CompletionSource,
+    created_at: DateTime<Utc>,
+}
+
+/// One row of a single chore's completion log
+#[derive(Serialize)]
+struct ChoreCompletionRecord<'a> {
+    completed_at: DateTime<Utc>,
+    notes: Option<&'a str>,
+    created_at: DateTime<Utc>,
+}
+
+/// Render chores as CSV with a header row, one row per chore.
+///
+/// `tags` maps chore ids to their tags; chores without an entry get none.
+pub fn render_chores_csv(
+    chores: &[ChoreWithLastCompletion],
+    tags: &HashMap<Uuid, Vec<Tag>>,
+) -> csv::Result<String> {
+    let mut writer = writer_with_header(&CHORE_COLUMNS)?;
+    for chore in chores {
+        let tags = tags
+            .get(&chore.id)
+            .map(|tags| {
+                tags.iter()
+                    .map(|tag| tag.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .unwrap_or_default();
+
+        writer.serialize(ChoreRecord {
+            id: chore.id,
+            name: &chore.name,
+            description: chore.description.as_deref(),
+            schedule_type: chore.schedule_type,
+            cron_schedule: chore.cron_schedule.as_deref(),
+            interval_days: chore.interval_days,
+            interval_time_hour: chore.interval_time_hour,
+            interval_time_minute: chore.interval_time_minute,
+            days_of_week: chore.days_of_week.as_deref(),
+            due_at: chore.due_at,
+            timezone: chore.timezone.as_deref(),
+            anchor_mode: chore.anchor_mode,
+            notify_lead_minutes: chore.notify_lead_minutes,
+            max_snoozes: chore.max_snoozes,
+            estimated_minutes: chore.estimated_minutes,
+            recipient: chore.recipient.as_deref(),
+            auto_complete_with: chore.auto_complete_with,
+            allowed_completion_sources: chore.allowed_completion_sources.as_deref(),
+            external_id: chore.external_id.as_deref(),
+            priority: chore.priority,
+            is_active: chore.is_active,
+            tags,
+            last_completed_at: chore.last_completed_at,
+            created_at: chore.created_at,
+            updated_at: chore.updated_at,
+        })?;
+    }
+    into_string(writer)
+}
+
+/// Render completions as CSV with a header row, one row per completion
+pub fn render_completions_csv(completions: &[CompletionWithChore]) -> csv::Result<String> {
+    let mut writer = writer_with_header(&COMPLETION_COLUMNS)?;
+    for row in completions {
+        let completion = &row.completion;
+        writer.serialize(CompletionRecord {
+            id: completion.id,
+            chore_id: completion.chore_id,
+            chore_name: &row.chore_name,
+            completed_at: completion.completed_at,
+            notes: completion.notes.as_deref(),
+            source: completion.source,
+            created_at: completion.created_at,
+        })?;
+    }
+    into_string(writer)
+}
+
+/// Render one chore's completions as CSV with a header row, one row per
+/// completion
+pub fn render_chore_completions_csv(completions: &[Completion]) -> csv::Result<String> {
+    let mut writer = writer_with_header(&CHORE_COMPLETION_COLUMNS)?;
+    for completion in completions {
+        writer.serialize(ChoreCompletionRecord {
+            completed_at: completion.completed_at,
+            notes: completion.notes.as_deref(),
+            created_at: completion.created_at,
+        })?;
+    }
+    into_string(writer)
+}
+
+/// A CSV writer that has written `columns` as the header row, so empty
+/// exports still name their columns
+fn writer_with_header(columns: &[&str]) -> csv::Result<csv::Writer<Vec<u8>>> {
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_writer(Vec::new());
+    writer.write_record(columns)?;
+    Ok(writer)
+}
+
+fn into_string(writer: csv::Writer<Vec<u8>>) -> csv::Result<String> {
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| csv::Error::from(e.into_error()))?;
+    // Every field written was a `str`, so the output is valid UTF-8
+    Ok(String::from_utf8(bytes).expect("CSV output is UTF-8"))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_render_completions_csv_quotes_commas_and_newlines() {
+        let at = Utc.with_ymd_and_hms(2026, 3, 1, 9, 30, 0).unwrap();
+        let row = CompletionWithChore {
+            completion: Completion {
+                id: Uuid::nil(),
+                chore_id: Uuid::nil(),
+                completed_at: at,
+                notes: Some("Fed the cat\nand the fish".to_string()),
+                source: CompletionSource::Api,
+                created_at: at,
+            },
+            chore_name: "Feed pets, water plants".to_string(),
+            chore_schedule_type: ScheduleType::Interval,
+        };
+
+        let csv = render_completions_csv(&[row]).unwrap();
+        let mut lines = csv.lines();
+
+        assert_eq!(lines.next(), Some(COMPLETION_COLUMNS.join(",").as_str()));
+        assert!(csv.contains("\"Feed pets, water plants\""));
+        assert!(csv.contains("\"Fed the cat\nand the fish\""));
+        assert!(csv.contains(",api,"));
+    }
+
+    #[test]
+    fn test_render_chores_csv_without_chores_has_header() {
+        let csv = render_chores_csv(&[], &HashMap::new()).unwrap();
+
+        assert_eq!(csv, format!("{}\n", CHORE_COLUMNS.join(",")));
+    }
+}
@@ -0,0 +1,84 @@
+use uuid::Uuid;
+
+/// Replies that mark a chore as done, compared case-insensitively against the
+/// first line of the reply
+const DONE_REPLIES: &[&str] = &["done", "did it", "complete", "completed", "finished", "yes"];
+
+/// Extract the chore id embedded in a reply-to address such as
+/// `nag+<chore id>@example.com`.
+///
+/// `recipient` may hold several comma-separated addresses, optionally with
+/// display names (`Nag <nag+...@example.com>`); the first one carrying a
+/// valid chore id wins.
+pub fn reply_chore_id(recipient: &str) -> Option<Uuid> {
+    recipient.split(',').find_map(|address| {
+        let address = address.trim();
+        let address = match (address.rfind('<'), address.rfind('>')) {
+            (Some(start), Some(end)) if start < end => &address[start + 1..end],
+            _ => address,
+        };
+        let (local, _domain) = address.split_once('@')?;
+        let (_mailbox, token) = local.split_once('+')?;
+        Uuid::parse_str(token).ok()
+    })
+}
+
+/// Whether a reply body asks to mark the chore as done.
+///
+/// Only the first non-empty line that is not quoted (`>`) counts, so the
+/// reminder quoted below the reply is ignored. Trailing punctuation is
+/// dropped, e.g. "Done!" matches.
+pub fn is_done_reply(body: &str) -> bool {
+    let Some(line) = body
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with('>'))
+    else {
+        return false;
+    };
+
+    let reply = line
+        .trim_end_matches(|c: char| c.is_ascii_punctuation())
+        .to_lowercase();
+    DONE_REPLIES.contains(&reply.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHORE_ID: &str = "0195c2a4-6b1e-7c3d-9f00-1a2b3c4d5e6f";
+
+    #[test]
+    fn test_reply_chore_id_from_plain_address() {
+        let expected = Uuid::parse_str(CHORE_ID).unwrap();
+        assert_eq!(
+            reply_chore_id(&format!("nag+{CHORE_ID}@example.com")),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn test_reply_chore_id_from_display_name_list() {
+        let expected = Uuid::parse_str(CHORE_ID).unwrap();
+        let recipient = format!("someone@example.com, Nag <nag+{CHORE_ID}@example.com>");
+        assert_eq!(reply_chore_id(&recipient), Some(expected));
+    }
+
+    #[test]
+    fn test_reply_chore_id_rejects_missing_or_invalid_token() {
+        assert_eq!(reply_chore_id("nag@example.com"), None);
+        assert_eq!(reply_chore_id("nag+not-a-uuid@example.com"), None);
+        assert_eq!(reply_chore_id(""), None);
+    }
+
+    #[test]
+    fn test_is_done_reply() {
+        assert!(is_done_reply("Done!"));
+        assert!(is_done_reply("\n  did it.\n\nSent from my phone"));
+        assert!(is_done_reply("yes\n> Reminder: Vacuum is due"));
+        assert!(!is_done_reply("> done\nnot yet"));
+        assert!(!is_done_reply("done tomorrow"));
+        assert!(!is_done_reply(""));
+    }
+}
@@ -1,10 +1,17 @@
+pub mod calendar;
 pub mod chore_service;
+pub mod export;
+pub mod inbound_email;
 pub mod notifications;
 pub mod oidc;
 
-pub use chore_service::{ChoreService, ChoreWithDueInfo};
+pub use chore_service::{
+    ChoreService, ChoreWithDueInfo, CompletionStreak, CompletionTiming, DayBudget, DueSort,
+    ScheduleLimits,
+};
 pub use notifications::{
-    NotificationChannelSender, NotificationRuntimeConfig, TelegramChannel, run_dispatcher,
-    run_event_generator,
+    DiscordChannel, EventGeneratorSettings, NotificationChannelSender, NotificationRuntimeConfig,
+    OperatorAlerter, QuietHours, SlackChannel, TelegramChannel, run_dispatcher,
+    run_event_generator, verify_channels,
 };
 pub use oidc::OidcService;
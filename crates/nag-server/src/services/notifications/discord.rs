@@ -0,0 +1,163 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use reqwest::{Client, Url};
+use serde_json::{Value, json};
+
+use crate::db::models::{NotificationChannel, NotificationEventType};
+use crate::db::notifications::PendingNotification;
+
+use super::NotificationChannelSender;
+
+/// Accent colour of notification embeds (`#F59E0B`)
+const EMBED_COLOR: u32 = 0xF59E0B;
+
+/// Sends notifications to a Discord channel through an incoming webhook
+#[derive(Debug, Clone)]
+pub struct DiscordChannel {
+    client: Client,
+    webhook_url: Url,
+}
+
+impl DiscordChannel {
+    pub fn new(webhook_url: &str) -> Result<Self, String> {
+        let webhook_url = Url::parse(webhook_url)
+            .map_err(|e| format!("Invalid DISCORD_WEBHOOK_URL '{webhook_url}': {e}"))?;
+
+        Ok(Self {
+            client: Client::new(),
+            webhook_url,
+        })
+    }
+
+    /// Build the webhook payload: a single embed with the due time (or the
+    /// time an operator alert was raised) in the footer
+    fn payload(notification: &PendingNotification) -> Value {
+        let footer = match notification.event_type {
+            NotificationEventType::Due => "Due",
+            NotificationEventType::Review => "Review due",
+            NotificationEventType::OperatorAlert => "Raised",
+        };
+        json!({
+            "embeds": [{
+                "title": notification.title,
+                "description": notification.body,
+                "color": EMBED_COLOR,
+                "footer": {
+                    "text": format!("{footer} {}", notification.due_at.format("%Y-%m-%d %H:%M UTC")),
+                },
+                "timestamp": notification.due_at.to_rfc3339(),
+            }],
+        })
+    }
+}
+
+impl NotificationChannelSender for DiscordChannel {
+    fn channel(&self) -> NotificationChannel {
+        NotificationChannel::Discord
+    }
+
+    fn send<'a>(
+        &'a self,
+        notification: &'a PendingNotification,
+    ) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send + 'a>> {
+        Box::pin(async move {
+            let response = self
+                .client
+                .post(self.webhook_url.clone())
+                .json(&Self::payload(notification))
+                .send()
+                .await
+                .map_err(|e| format!("Discord send failed: {e}"))?;
+
+            let status = response.status();
+            if !status.is_success() {
+                let body = response.text().await.unwrap_or_default();
+                return Err(format!("Discord webhook returned {status}: {body}"));
+            }
+
+            Ok(())
+        })
+    }
+
+    fn verify<'a>(&'a self) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send + 'a>> {
+        Box::pin(async move {
+            // GET on a webhook URL returns the webhook object when the token is valid
+            self.client
+                .get(self.webhook_url.clone())
+                .send()
+                .await
+                .and_then(|r| r.error_for_status())
+                .map(|_| ())
+                .map_err(|e| format!("Discord webhook check failed: {e}"))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{Router, http::StatusCode, routing::post};
+    use chrono::{TimeZone, Utc};
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn notification() -> PendingNotification {
+        PendingNotification {
+            delivery_id: Uuid::new_v4(),
+            event_id: Uuid::new_v4(),
+            channel: NotificationChannel::Discord,
+            attempt_count: 0,
+            chore_id: Uuid::new_v4(),
+            event_type: NotificationEventType::Due,
+            due_at: Utc.with_ymd_and_hms(2026, 3, 1, 9, 30, 0).unwrap(),
+            title: "Chore due: Vacuum".to_string(),
+            body: "Vacuum is due".to_string(),
+            recipient: None,
+        }
+    }
+
+    /// Serve `status` for every webhook POST on a local port
+    async fn webhook_returning(status: StatusCode) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind");
+        let addr = listener.local_addr().expect("local addr");
+        let app = Router::new().route("/webhook", post(move || async move { status }));
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        format!("http://{addr}/webhook")
+    }
+
+    #[test]
+    fn test_payload_embeds_notification() {
+        let payload = DiscordChannel::payload(&notification());
+        let embed = &payload["embeds"][0];
+
+        assert_eq!(embed["title"], "Chore due: Vacuum");
+        assert_eq!(embed["description"], "Vacuum is due");
+        assert_eq!(embed["footer"]["text"], "Due 2026-03-01 09:30 UTC");
+        assert_eq!(embed["timestamp"], "2026-03-01T09:30:00+00:00");
+    }
+
+    #[test]
+    fn test_new_rejects_invalid_url() {
+        assert!(DiscordChannel::new("not a url").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_send_succeeds_on_2xx() {
+        let url = webhook_returning(StatusCode::NO_CONTENT).await;
+        let channel = DiscordChannel::new(&url).expect("build channel");
+
+        assert_eq!(channel.send(&notification()).await, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn test_send_fails_on_non_2xx() {
+        let url = webhook_returning(StatusCode::TOO_MANY_REQUESTS).await;
+        let channel = DiscordChannel::new(&url).expect("build channel");
+
+        let error = channel.send(&notification()).await.unwrap_err();
+        assert!(error.contains("429"), "unexpected error: {error}");
+    }
+}
@@ -1,17 +1,31 @@
+use std::collections::HashSet;
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
 
+use chrono::{DateTime, NaiveDate, Utc};
 use sqlx::SqlitePool;
 use tokio::time::{self, Duration};
+use uuid::Uuid;
 
 use crate::db::{
-    NotificationRepository, models::NotificationChannel, notifications::PendingNotification,
+    ChoreRepository, HolidayRepository, NotificationRepository, TagRepository,
+    chores::TagFilter,
+    models::{ChoreWithLastCompletion, NotificationChannel, NotificationEventType},
+    notifications::PendingNotification,
 };
-use crate::services::ChoreService;
+use crate::services::{ChoreService, DueSort};
 
+mod discord;
+mod operator;
+mod quiet_hours;
+mod slack;
 mod telegram;
 
+pub use discord::DiscordChannel;
+pub use operator::OperatorAlerter;
+pub use quiet_hours::QuietHours;
+pub use slack::SlackChannel;
 pub use telegram::TelegramChannel;
 
 #[derive(Debug, Clone)]
@@ -20,6 +34,22 @@ pub struct NotificationRuntimeConfig {
     pub dispatch_interval_seconds: u64,
     pub max_attempts: i32,
     pub batch_size: i64,
+    /// Delay before the first retry; quadruples with every further failed attempt.
+    pub retry_base_delay_seconds: i64,
+    /// Daily window in which due events are held back until it ends
+    pub quiet_hours: Option<QuietHours>,
+    /// Longest due event body, in characters; longer bodies end in an ellipsis.
+    pub max_body_chars: Option<usize>,
+    /// Raise a fresh due event every this many hours while a chore stays
+    /// overdue; a chore is notified once per occurrence when unset.
+    ///
+    /// Each reminder is a separate event with its own deliveries, so every
+    /// reminder gets a full `max_attempts` of its own. A delivery that ran out
+    /// of attempts stays failed, but the next reminder tries again.
+    pub reminder_repeat_hours: Option<u32>,
+    /// Shortest time between two due events of a chore, in minutes, however
+    /// many occurrences or reminders fall in between; chores can override it.
+    pub min_notify_interval_minutes: Option<u32>,
 }
 
 impl Default for NotificationRuntimeConfig {
@@ -29,10 +59,22 @@ impl Default for NotificationRuntimeConfig {
             dispatch_interval_seconds: 15,
             max_attempts: 5,
             batch_size: 50,
+            retry_base_delay_seconds: 30,
+            quiet_hours: None,
+            max_body_chars: None,
+            reminder_repeat_hours: None,
+            min_notify_interval_minutes: None,
         }
     }
 }
 
+/// Channels and settings the due event generator runs with
+#[derive(Debug, Clone)]
+pub struct EventGeneratorSettings {
+    pub channels: Vec<NotificationChannel>,
+    pub config: NotificationRuntimeConfig,
+}
+
 pub trait NotificationChannelSender: Send + Sync {
     fn channel(&self) -> NotificationChannel;
 
@@ -40,6 +82,33 @@ pub trait NotificationChannelSender: Send + Sync {
         &'a self,
         notification: &'a PendingNotification,
     ) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send + 'a>>;
+
+    /// Check that the channel is reachable and its credentials are accepted.
+    fn verify<'a>(&'a self) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send + 'a>> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Verify every sender, logging a warning for each that fails.
+///
+/// Returns the channels that failed along with their errors.
+pub async fn verify_channels(
+    senders: &[Arc<dyn NotificationChannelSender>],
+) -> Vec<(NotificationChannel, String)> {
+    let mut failures = Vec::new();
+
+    for sender in senders {
+        let channel = sender.channel();
+        match sender.verify().await {
+            Ok(()) => tracing::info!(?channel, "Notification channel verified"),
+            Err(error) => {
+                tracing::warn!(?channel, %error, "Notification channel failed verification");
+                failures.push((channel, error));
+            }
+        }
+    }
+
+    failures
 }
 
 pub async fn run_event_generator(
@@ -52,7 +121,7 @@ pub async fn run_event_generator(
 
     loop {
         interval.tick().await;
-        generate_due_events_once(&pool, &channels).await;
+        generate_due_events_once(&pool, &channels, &config).await;
     }
 }
 
@@ -60,35 +129,152 @@ pub async fn run_dispatcher(
     pool: SqlitePool,
     senders: Vec<Arc<dyn NotificationChannelSender>>,
     config: NotificationRuntimeConfig,
+    alerter: Option<Arc<OperatorAlerter>>,
 ) {
     let mut interval = time::interval(Duration::from_secs(config.dispatch_interval_seconds));
     interval.set_missed_tick_behavior(time::MissedTickBehavior::Skip);
 
     loop {
         interval.tick().await;
-        dispatch_pending_once(&pool, &senders, config.batch_size, config.max_attempts).await;
+        dispatch_pending_once(
+            &pool,
+            &senders,
+            config.batch_size,
+            config.max_attempts,
+            config.retry_base_delay_seconds,
+            alerter.as_deref(),
+        )
+        .await;
     }
 }
 
-pub async fn generate_due_events_once(pool: &SqlitePool, channels: &[NotificationChannel]) {
-    match ChoreService::get_due_chores(pool, false).await {
+/// Name the due event generator's runs are recorded under
+const DUE_EVENT_GENERATOR: &str = "due_events";
+
+/// Raise due events for chores that have fallen due.
+///
+/// Each run covers the window since the last completed run, which is
+/// persisted: besides a chore's pending occurrence, every later occurrence
+/// whose notify time falls in that window raises its own event, so
+/// occurrences missed under load or downtime are still notified. The pending
+/// occurrence is raised whenever its notify time has passed, not just inside
+/// the window, so an event a failed run couldn't raise is retried.
+///
+/// Inside quiet hours nothing is raised; chores that fell due meanwhile are
+/// picked up by the first run after the window ends, still keyed by their
+/// actual due time.
+///
+/// Review prompts for chores whose review interval ran out are raised in the
+/// same run, see [`raise_review_events`].
+pub async fn generate_due_events_once(
+    pool: &SqlitePool,
+    channels: &[NotificationChannel],
+    config: &NotificationRuntimeConfig,
+) {
+    let now = Utc::now();
+
+    if let Some(until) = config.quiet_hours.and_then(|quiet| quiet.ends_after(now)) {
+        tracing::debug!(%until, "Quiet hours, deferring due notifications");
+        return;
+    }
+
+    let since = match NotificationRepository::last_generator_run(pool, DUE_EVENT_GENERATOR).await {
+        Ok(since) => since,
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to fetch last due event generator run");
+            None
+        }
+    };
+    if let Some(since) = since {
+        let missed_after = chrono::Duration::seconds(2 * config.poll_interval_seconds as i64);
+        if now - since > missed_after {
+            tracing::info!(%since, "Due event generator missed runs, catching up");
+        }
+    }
+
+    let holidays = match HolidayRepository::list_dates(pool).await {
+        Ok(holidays) => holidays,
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to fetch holidays");
+            return;
+        }
+    };
+
+    let muted = match TagRepository::muted_chore_ids(pool).await {
+        Ok(muted) => muted,
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to fetch chores with muted tags");
+            return;
+        }
+    };
+
+    raise_review_events(pool, channels, config).await;
+
+    match ChoreService::get_due_chores(pool, None, true, DueSort::DueAsc, &TagFilter::default())
+        .await
+    {
         Ok(due_chores) => {
             for item in due_chores {
                 let Some(due_at) = item.next_due else {
                     continue;
                 };
+                if muted.contains(&item.chore.id) {
+                    continue;
+                }
+
+                // Notify once the lead time before the due moment has been reached
+                let lead = chrono::Duration::minutes(i64::from(
+                    item.chore.notify_lead_minutes.unwrap_or(0),
+                ));
+                if due_at - lead > now {
+                    continue;
+                }
+
+                match NotificationRepository::is_snoozed(pool, item.chore.id, now).await {
+                    Ok(true) => continue,
+                    Ok(false) => {}
+                    Err(e) => {
+                        tracing::error!(error = %e, "Failed to check notification snooze");
+                        continue;
+                    }
+                }
+
+                // A snooze that expired after the chore fell due re-arms the
+                // reminder: dedup the event by the snooze expiry so it is not
+                // deduplicated against the one already sent.
+                let dedup_at =
+                    match NotificationRepository::snoozed_until(pool, item.chore.id).await {
+                        Ok(Some(until)) if until > due_at => until,
+                        Ok(_) => due_at,
+                        Err(e) => {
+                            tracing::error!(error = %e, "Failed to fetch notification snooze");
+                            continue;
+                        }
+                    };
+
+                let dedup_at = reminder_dedup_at(dedup_at, now, config);
+
+                // Hold the event back while the chore's previous one is more
+                // recent than its minimum interval
+                let min_interval = min_notify_interval(&item.chore, config);
+                if let Some(interval) = min_interval {
+                    match NotificationRepository::last_due_event_at(pool, item.chore.id).await {
+                        Ok(Some(last)) if now - last < interval => continue,
+                        Ok(_) => {}
+                        Err(e) => {
+                            tracing::error!(error = %e, "Failed to fetch last due event");
+                            continue;
+                        }
+                    }
+                }
 
-                let title = format!("Chore due: {}", item.chore.name);
-                let body = format!(
-                    "{} is due at {} UTC.",
-                    item.chore.name,
-                    due_at.format("%Y-%m-%d %H:%M")
-                );
+                let (title, body) = due_event_text(&item.chore.name, due_at, config);
 
-                if let Err(e) = NotificationRepository::upsert_due_event_with_deliveries(
+                if let Err(e) = NotificationRepository::upsert_due_reminder_with_deliveries(
                     pool,
                     item.chore.id,
                     due_at,
+                    dedup_at,
                     &title,
                     &body,
                     channels,
@@ -97,6 +283,33 @@ pub async fn generate_due_events_once(pool: &SqlitePool, channels: &[Notificatio
                 {
                     tracing::error!(error = %e, "Failed to upsert notification event");
                 }
+
+                // Catching up would put the events closer together than the
+                // minimum interval allows
+                let Some(since) = since.filter(|_| min_interval.is_none()) else {
+                    continue;
+                };
+                for missed in missed_occurrences(&item.chore, due_at, lead, since, now, &holidays) {
+                    let (title, body) = due_event_text(&item.chore.name, missed, config);
+                    if let Err(e) = NotificationRepository::upsert_due_event_with_deliveries(
+                        pool,
+                        item.chore.id,
+                        missed,
+                        &title,
+                        &body,
+                        channels,
+                    )
+                    .await
+                    {
+                        tracing::error!(error = %e, "Failed to upsert notification event");
+                    }
+                }
+            }
+
+            if let Err(e) =
+                NotificationRepository::record_generator_run(pool, DUE_EVENT_GENERATOR, now).await
+            {
+                tracing::error!(error = %e, "Failed to record due event generator run");
             }
         }
         Err(e) => {
@@ -105,13 +318,275 @@ pub async fn generate_due_events_once(pool: &SqlitePool, channels: &[Notificatio
     }
 }
 
+/// Occurrences of `chore` after the pending one at `due_at` whose notify time
+/// falls in `(since, now]`, each assumed completed when due
+fn missed_occurrences(
+    chore: &ChoreWithLastCompletion,
+    due_at: DateTime<Utc>,
+    lead: chrono::Duration,
+    since: DateTime<Utc>,
+    now: DateTime<Utc>,
+    holidays: &HashSet<NaiveDate>,
+) -> Vec<DateTime<Utc>> {
+    let mut chore = chore.clone();
+    let mut previous = due_at;
+    let mut missed = Vec::new();
+
+    loop {
+        chore.last_completed_at = Some(previous);
+        let Some(next) =
+            ChoreService::compute_due_info(&chore, now, holidays).and_then(|info| info.next_due)
+        else {
+            break;
+        };
+        if next <= previous || next - lead > now {
+            break;
+        }
+        if next - lead > since {
+            missed.push(next);
+        }
+        previous = next;
+    }
+
+    missed
+}
+
+/// Raise a review event for every chore, paused ones included, whose review
+/// interval ran out, keyed by the time the review fell due.
+///
+/// Marking the chore reviewed moves that time forward, so the next prompt
+/// comes a full interval later.
+async fn raise_review_events(
+    pool: &SqlitePool,
+    channels: &[NotificationChannel],
+    config: &NotificationRuntimeConfig,
+) {
+    let now = Utc::now();
+    let chores = match ChoreRepository::list_all_with_last_completion(
+        pool,
+        None,
+        true,
+        &TagFilter::default(),
+    )
+    .await
+    {
+        Ok(chores) => chores,
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to collect chores for review prompts");
+            return;
+        }
+    };
+
+    for chore in chores {
+        let Some(review_due_at) = ChoreService::review_due_at(&chore).filter(|at| *at <= now)
+        else {
+            continue;
+        };
+
+        let title = format!("Review chore: {}", chore.name);
+        let mut body = format!(
+            "Is {} still needed? Mark it reviewed, or pause or delete it.",
+            chore.name
+        );
+        if let Some(max_chars) = config.max_body_chars {
+            body = truncate_chars(body, max_chars);
+        }
+
+        if let Err(e) = NotificationRepository::upsert_event_with_deliveries(
+            pool,
+            chore.id,
+            NotificationEventType::Review,
+            review_due_at,
+            &title,
+            &body,
+            channels,
+        )
+        .await
+        {
+            tracing::error!(error = %e, "Failed to upsert review event");
+        }
+    }
+}
+
+/// Shortest time between two due events of `chore`: its own minimum
+/// interval, or else the configured one
+fn min_notify_interval(
+    chore: &ChoreWithLastCompletion,
+    config: &NotificationRuntimeConfig,
+) -> Option<chrono::Duration> {
+    chore
+        .min_notify_interval_minutes
+        .map(i64::from)
+        .or(config.min_notify_interval_minutes.map(i64::from))
+        .filter(|minutes| *minutes > 0)
+        .map(chrono::Duration::minutes)
+}
+
+/// Dedup key of the reminder for an event keyed by `due_at` that is current
+/// at `now`.
+///
+/// With `reminder_repeat_hours` set, the key moves forward by that many hours
+/// at a time, so a chore that stays overdue is raised again once per period
+/// instead of being deduplicated against its first event.
+fn reminder_dedup_at(
+    due_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+    config: &NotificationRuntimeConfig,
+) -> DateTime<Utc> {
+    let Some(hours) = config.reminder_repeat_hours.filter(|hours| *hours > 0) else {
+        return due_at;
+    };
+    let period = i64::from(hours) * 3600;
+    let periods = (now - due_at).num_seconds().max(0) / period;
+    due_at + chrono::Duration::seconds(periods * period)
+}
+
+/// Title and body of the due event for the occurrence of `chore_name` at
+/// `due_at`
+fn due_event_text(
+    chore_name: &str,
+    due_at: DateTime<Utc>,
+    config: &NotificationRuntimeConfig,
+) -> (String, String) {
+    let title = format!("Chore due: {}", chore_name);
+    let mut body = format!(
+        "{} is due at {} UTC.",
+        chore_name,
+        due_at.format("%Y-%m-%d %H:%M")
+    );
+    if let Some(max_chars) = config.max_body_chars {
+        body = truncate_chars(body, max_chars);
+    }
+    (title, body)
+}
+
+/// A due event [`simulate_due_events`] expects the generator to raise
+#[derive(Debug, Clone)]
+pub struct SimulatedDueEvent {
+    pub chore_id: Uuid,
+    pub chore_name: String,
+    /// Due time of the occurrence the event reminds of
+    pub due_at: DateTime<Utc>,
+    /// When the generator would raise the event
+    pub notify_at: DateTime<Utc>,
+    pub title: String,
+    pub body: String,
+}
+
+/// List the due events the generator would raise between `now` and `until`,
+/// without writing anything.
+///
+/// Each occurrence is assumed to be completed when due, so recurring chores
+/// yield one event per occurrence in the window. Lead times, snoozes, muted
+/// tags, quiet hours, minimum notification intervals and holidays are applied
+/// as the generator does, and events that were already raised are left out.
+/// With an `owner`, only that user's chores and unowned chores are simulated.
+/// Events are ordered by when they'd be raised.
+pub async fn simulate_due_events(
+    pool: &SqlitePool,
+    config: &NotificationRuntimeConfig,
+    owner: Option<Uuid>,
+    now: DateTime<Utc>,
+    until: DateTime<Utc>,
+) -> color_eyre::Result<Vec<SimulatedDueEvent>> {
+    let chores =
+        ChoreRepository::list_all_with_last_completion(pool, owner, false, &TagFilter::default())
+            .await?;
+    let holidays = HolidayRepository::list_dates(pool).await?;
+    let muted = TagRepository::muted_chore_ids(pool).await?;
+    let mut events = Vec::new();
+
+    for mut chore in chores {
+        if muted.contains(&chore.id) {
+            continue;
+        }
+        let lead = chrono::Duration::minutes(i64::from(chore.notify_lead_minutes.unwrap_or(0)));
+        let snoozed_until = NotificationRepository::snoozed_until(pool, chore.id).await?;
+        let min_interval = min_notify_interval(&chore, config);
+        let mut last_notified = NotificationRepository::last_due_event_at(pool, chore.id).await?;
+        let mut previous: Option<DateTime<Utc>> = None;
+
+        loop {
+            let Some(due_at) = ChoreService::compute_due_info(&chore, now, &holidays)
+                .and_then(|info| info.next_due)
+            else {
+                break;
+            };
+            if previous.is_some_and(|previous| due_at <= previous) {
+                break;
+            }
+
+            let mut notify_at = (due_at - lead).max(now);
+            let mut dedup_at = due_at;
+            if let Some(snoozed_until) = snoozed_until
+                && snoozed_until > notify_at
+            {
+                notify_at = snoozed_until;
+                dedup_at = dedup_at.max(snoozed_until);
+            }
+            if let Some(end) = config.quiet_hours.and_then(|q| q.ends_after(notify_at)) {
+                notify_at = end;
+            }
+            if notify_at > until {
+                break;
+            }
+            let dedup_at = reminder_dedup_at(dedup_at, notify_at, config);
+
+            let held_back = min_interval
+                .zip(last_notified)
+                .is_some_and(|(interval, last)| notify_at - last < interval);
+
+            if !held_back
+                && !NotificationRepository::due_event_exists(pool, chore.id, dedup_at).await?
+            {
+                last_notified = Some(notify_at);
+                let (title, body) = due_event_text(&chore.name, due_at, config);
+                events.push(SimulatedDueEvent {
+                    chore_id: chore.id,
+                    chore_name: chore.name.clone(),
+                    due_at,
+                    notify_at,
+                    title,
+                    body,
+                });
+            }
+
+            previous = Some(due_at);
+            chore.last_completed_at = Some(due_at);
+        }
+    }
+
+    events.sort_by_key(|event| (event.notify_at, event.due_at));
+    Ok(events)
+}
+
+/// Cut `text` down to `max_chars` characters, ending in an ellipsis if
+/// anything was dropped
+fn truncate_chars(text: String, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text;
+    }
+    let mut truncated: String = text.chars().take(max_chars.saturating_sub(1)).collect();
+    truncated.push('…');
+    truncated
+}
+
 pub async fn dispatch_pending_once(
     pool: &SqlitePool,
     senders: &[Arc<dyn NotificationChannelSender>],
     batch_size: i64,
     max_attempts: i32,
+    retry_base_delay_seconds: i64,
+    alerter: Option<&OperatorAlerter>,
 ) {
-    let pending = match NotificationRepository::list_pending(pool, batch_size, max_attempts).await {
+    let pending = match NotificationRepository::list_pending(
+        pool,
+        batch_size,
+        max_attempts,
+        Utc::now(),
+    )
+    .await
+    {
         Ok(items) => items,
         Err(e) => {
             tracing::error!(error = %e, "Failed to fetch pending notification deliveries");
@@ -126,17 +601,25 @@ pub async fn dispatch_pending_once(
             .cloned();
 
         let Some(sender) = sender else {
-            let error = format!("No sender configured for channel: {:?}", delivery.channel);
-            if let Err(e) =
-                NotificationRepository::mark_failed(pool, delivery.delivery_id, &error).await
+            // Not the delivery's fault: keep it retryable without using up attempts
+            let reason = format!("No sender configured for channel: {:?}", delivery.channel);
+            if let Err(e) = NotificationRepository::mark_skipped(
+                pool,
+                delivery.delivery_id,
+                &reason,
+                retry_base_delay_seconds,
+            )
+            .await
             {
-                tracing::error!(error = %e, "Failed to mark delivery as failed");
+                tracing::error!(error = %e, "Failed to mark delivery as skipped");
             }
             continue;
         };
 
+        metrics::counter!(crate::metrics::NOTIFICATIONS_DISPATCHED).increment(1);
         match sender.send(&delivery).await {
             Ok(()) => {
+                metrics::counter!(crate::metrics::NOTIFICATIONS_DELIVERED).increment(1);
                 if let Err(e) =
                     NotificationRepository::mark_delivered(pool, delivery.delivery_id).await
                 {
@@ -144,11 +627,35 @@ pub async fn dispatch_pending_once(
                 }
             }
             Err(error) => {
-                if let Err(e) =
-                    NotificationRepository::mark_failed(pool, delivery.delivery_id, &error).await
+                metrics::counter!(crate::metrics::NOTIFICATIONS_FAILED).increment(1);
+                if let Err(e) = NotificationRepository::mark_failed(
+                    pool,
+                    delivery.delivery_id,
+                    &error,
+                    retry_base_delay_seconds,
+                )
+                .await
                 {
                     tracing::error!(error = %e, "Failed to mark delivery as failed");
                 }
+                // Out of attempts: there's no retry left to skip chats for
+                if delivery.attempt_count + 1 >= max_attempts
+                    && let Err(e) =
+                        NotificationRepository::clear_delivered_chats(pool, delivery.delivery_id)
+                            .await
+                {
+                    tracing::error!(error = %e, "Failed to clear delivered chats");
+                }
+                if let Some(alerter) = alerter {
+                    let channel = delivery.channel;
+                    alerter
+                        .report(
+                            &format!("{channel:?}: {error}"),
+                            &format!("Delivering a notification via {channel:?} failed: {error}"),
+                            Utc::now(),
+                        )
+                        .await;
+                }
             }
         }
     }
@@ -158,11 +665,12 @@ pub async fn dispatch_pending_once(
 mod tests {
     use std::sync::Mutex;
 
-    use chrono::Utc;
+    use chrono::{Duration as ChronoDuration, DurationRound};
 
     use crate::db::{
-        self, ChoreRepository, NotificationRepository, chores::CreateChoreParams,
-        models::ScheduleType,
+        self, ChoreRepository, CompletionRepository, NotificationRepository, TagRepository,
+        chores::{CreateChoreParams, UpdateChoreParams},
+        models::{ChoreAnchorMode, ChorePriority, CompletionSource, ScheduleType},
     };
 
     use super::*;
@@ -170,6 +678,7 @@ mod tests {
     struct FakeSender {
         channel: NotificationChannel,
         fail: bool,
+        verify_fail: bool,
         calls: Mutex<Vec<String>>,
     }
 
@@ -178,6 +687,7 @@ mod tests {
             Self {
                 channel,
                 fail,
+                verify_fail: false,
                 calls: Mutex::new(Vec::new()),
             }
         }
@@ -204,6 +714,41 @@ mod tests {
                 }
             })
         }
+
+        fn verify<'a>(&'a self) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send + 'a>> {
+            Box::pin(async move {
+                if self.verify_fail {
+                    Err("simulated auth failure".to_string())
+                } else {
+                    Ok(())
+                }
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_channels_reports_failures() {
+        let healthy: Arc<dyn NotificationChannelSender> =
+            Arc::new(FakeSender::new(NotificationChannel::Telegram, false));
+        let broken: Arc<dyn NotificationChannelSender> = Arc::new(FakeSender {
+            verify_fail: true,
+            ..FakeSender::new(NotificationChannel::Telegram, false)
+        });
+
+        assert!(
+            verify_channels(std::slice::from_ref(&healthy))
+                .await
+                .is_empty()
+        );
+
+        let failures = verify_channels(&[healthy, broken]).await;
+        assert_eq!(
+            failures,
+            vec![(
+                NotificationChannel::Telegram,
+                "simulated auth failure".to_string()
+            )]
+        );
     }
 
     async fn seed_delivery(pool: &SqlitePool) -> uuid::Uuid {
@@ -217,6 +762,22 @@ mod tests {
                 interval_days: Some(1),
                 interval_time_hour: Some(9),
                 interval_time_minute: Some(0),
+                days_of_week: None,
+                due_at: None,
+                timezone: None,
+                notify_lead_minutes: None,
+                max_snoozes: None,
+                estimated_minutes: None,
+                review_interval_days: None,
+                min_notify_interval_minutes: None,
+                recipient: None,
+                auto_complete_with: None,
+                allowed_completion_sources: None,
+                external_id: None,
+                priority: ChorePriority::Normal,
+                anchor_mode: ChoreAnchorMode::FromCompletion,
+                user_id: None,
+                is_active: true,
             },
         )
         .await
@@ -253,7 +814,7 @@ mod tests {
         let sender = Arc::new(FakeSender::new(NotificationChannel::Telegram, false));
         let senders: Vec<Arc<dyn NotificationChannelSender>> = vec![sender];
 
-        dispatch_pending_once(&pool, &senders, 50, 5).await;
+        dispatch_pending_once(&pool, &senders, 50, 5, 30, None).await;
 
         let status: String =
             sqlx::query_scalar("SELECT status FROM notification_deliveries WHERE id = ?")
@@ -265,13 +826,109 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_dispatch_pending_once_marks_failed_when_sender_missing() {
+    async fn test_list_pending_backs_off_failed_deliveries() {
+        let pool = db::create_pool("sqlite::memory:")
+            .await
+            .expect("create pool");
+        let delivery_id = seed_delivery(&pool).await;
+
+        let before = Utc::now();
+        NotificationRepository::mark_failed(&pool, delivery_id, "temporary", 30)
+            .await
+            .expect("mark failed");
+        let after = Utc::now();
+
+        // The first failure with a 30s base delay waits 30s
+        let too_soon = NotificationRepository::list_pending(
+            &pool,
+            50,
+            5,
+            before + ChronoDuration::seconds(29),
+        )
+        .await
+        .expect("list pending");
+        assert!(too_soon.is_empty());
+
+        let ready =
+            NotificationRepository::list_pending(&pool, 50, 5, after + ChronoDuration::seconds(30))
+                .await
+                .expect("list pending");
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].delivery_id, delivery_id);
+
+        // The second waits four times as long
+        let before = Utc::now();
+        NotificationRepository::mark_failed(&pool, delivery_id, "temporary", 30)
+            .await
+            .expect("mark failed");
+        let after = Utc::now();
+
+        let too_soon = NotificationRepository::list_pending(
+            &pool,
+            50,
+            5,
+            before + ChronoDuration::seconds(119),
+        )
+        .await
+        .expect("list pending");
+        assert!(too_soon.is_empty());
+
+        let ready = NotificationRepository::list_pending(
+            &pool,
+            50,
+            5,
+            after + ChronoDuration::seconds(120),
+        )
+        .await
+        .expect("list pending");
+        assert_eq!(ready.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_pending_once_does_not_retry_immediately() {
+        let pool = db::create_pool("sqlite::memory:")
+            .await
+            .expect("create pool");
+        seed_delivery(&pool).await;
+
+        let sender = Arc::new(FakeSender::new(NotificationChannel::Telegram, true));
+        let senders: Vec<Arc<dyn NotificationChannelSender>> = vec![sender.clone()];
+
+        dispatch_pending_once(&pool, &senders, 50, 5, 30, None).await;
+        dispatch_pending_once(&pool, &senders, 50, 5, 30, None).await;
+
+        assert_eq!(sender.calls.lock().expect("lock").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_pending_once_forgets_chats_after_last_attempt() {
+        let pool = db::create_pool("sqlite::memory:")
+            .await
+            .expect("create pool");
+        let delivery_id = seed_delivery(&pool).await;
+        NotificationRepository::mark_chat_delivered(&pool, delivery_id, 100)
+            .await
+            .expect("mark chat delivered");
+
+        let sender = Arc::new(FakeSender::new(NotificationChannel::Telegram, true));
+        let senders: Vec<Arc<dyn NotificationChannelSender>> = vec![sender];
+
+        dispatch_pending_once(&pool, &senders, 50, 1, 30, None).await;
+
+        let reached = NotificationRepository::delivered_chats(&pool, delivery_id)
+            .await
+            .expect("delivered chats");
+        assert!(reached.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_pending_once_skips_when_sender_missing() {
         let pool = db::create_pool("sqlite::memory:")
             .await
             .expect("create pool");
         let delivery_id = seed_delivery(&pool).await;
 
-        dispatch_pending_once(&pool, &[], 50, 5).await;
+        dispatch_pending_once(&pool, &[], 50, 5, 30, None).await;
 
         let row: (String, i32, Option<String>) = sqlx::query_as(
             "SELECT status, attempt_count, last_error FROM notification_deliveries WHERE id = ?",
@@ -281,8 +938,686 @@ mod tests {
         .await
         .expect("fetch delivery row");
 
-        assert_eq!(row.0, "failed");
-        assert_eq!(row.1, 1);
+        assert_eq!(row.0, "skipped");
+        assert_eq!(row.1, 0);
         assert!(row.2.unwrap_or_default().contains("No sender configured"));
     }
+
+    #[tokio::test]
+    async fn test_dispatch_pending_once_delivers_skipped_once_sender_registered() {
+        let pool = db::create_pool("sqlite::memory:")
+            .await
+            .expect("create pool");
+        let delivery_id = seed_delivery(&pool).await;
+
+        // More dispatch rounds than max_attempts without a sender
+        for _ in 0..3 {
+            dispatch_pending_once(&pool, &[], 50, 2, 0, None).await;
+        }
+
+        let sender = Arc::new(FakeSender::new(NotificationChannel::Telegram, false));
+        let senders: Vec<Arc<dyn NotificationChannelSender>> = vec![sender.clone()];
+        dispatch_pending_once(&pool, &senders, 50, 2, 0, None).await;
+
+        assert_eq!(sender.calls.lock().expect("lock").len(), 1);
+        let status: String =
+            sqlx::query_scalar("SELECT status FROM notification_deliveries WHERE id = ?")
+                .bind(delivery_id)
+                .fetch_one(&pool)
+                .await
+                .expect("fetch delivery status");
+        assert_eq!(status, "delivered");
+    }
+
+    #[tokio::test]
+    async fn test_operator_alerter_coalesces_identical_errors() {
+        let sender = Arc::new(FakeSender::new(NotificationChannel::Discord, false));
+        let alerter = OperatorAlerter::new(sender.clone(), None, ChronoDuration::hours(1));
+        let now = Utc::now();
+
+        for i in 0..10 {
+            alerter
+                .report(
+                    "Telegram: chat not found",
+                    "chat not found",
+                    now + ChronoDuration::minutes(i),
+                )
+                .await;
+        }
+        assert_eq!(sender.calls.lock().expect("lock").len(), 1);
+
+        assert!(
+            alerter
+                .report("Telegram: Unauthorized", "Unauthorized", now)
+                .await
+        );
+        assert_eq!(sender.calls.lock().expect("lock").len(), 2);
+
+        // Once the cooldown has passed the next alert reports what was swallowed
+        assert!(
+            alerter
+                .report(
+                    "Telegram: chat not found",
+                    "chat not found",
+                    now + ChronoDuration::hours(1),
+                )
+                .await
+        );
+        let calls = sender.calls.lock().expect("lock");
+        assert_eq!(calls.len(), 3);
+        assert!(calls[2].ends_with("(9 similar errors suppressed)"));
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_pending_once_alerts_operators_once_per_error() {
+        let pool = db::create_pool("sqlite::memory:")
+            .await
+            .expect("create pool");
+        for _ in 0..10 {
+            seed_delivery(&pool).await;
+        }
+
+        let telegram = Arc::new(FakeSender::new(NotificationChannel::Telegram, true));
+        let senders: Vec<Arc<dyn NotificationChannelSender>> = vec![telegram.clone()];
+        let operator = Arc::new(FakeSender::new(NotificationChannel::Discord, false));
+        let alerter = OperatorAlerter::new(operator.clone(), None, ChronoDuration::hours(1));
+
+        dispatch_pending_once(&pool, &senders, 50, 5, 0, Some(&alerter)).await;
+
+        assert_eq!(telegram.calls.lock().expect("lock").len(), 10);
+        let alerts = operator.calls.lock().expect("lock");
+        assert_eq!(alerts.len(), 1);
+        assert!(alerts[0].contains("simulated send failure"));
+    }
+
+    async fn seed_overdue_chore(pool: &SqlitePool) -> uuid::Uuid {
+        let chore = ChoreRepository::create(
+            pool,
+            CreateChoreParams {
+                name: "Overdue chore",
+                description: None,
+                schedule_type: ScheduleType::Interval,
+                cron_schedule: None,
+                interval_days: Some(1),
+                interval_time_hour: None,
+                interval_time_minute: None,
+                days_of_week: None,
+                due_at: None,
+                timezone: None,
+                notify_lead_minutes: None,
+                max_snoozes: None,
+                estimated_minutes: None,
+                review_interval_days: None,
+                min_notify_interval_minutes: None,
+                recipient: None,
+                auto_complete_with: None,
+                allowed_completion_sources: None,
+                external_id: None,
+                priority: ChorePriority::Normal,
+                anchor_mode: ChoreAnchorMode::FromCompletion,
+                user_id: None,
+                is_active: true,
+            },
+        )
+        .await
+        .expect("create chore");
+
+        CompletionRepository::create(
+            pool,
+            chore.id,
+            Some(Utc::now() - ChronoDuration::days(3)),
+            None,
+            CompletionSource::Api,
+            None,
+        )
+        .await
+        .expect("create completion");
+
+        chore.id
+    }
+
+    async fn count_events(pool: &SqlitePool) -> i64 {
+        sqlx::query_scalar("SELECT COUNT(*) FROM notification_events")
+            .fetch_one(pool)
+            .await
+            .expect("count events")
+    }
+
+    #[tokio::test]
+    async fn test_generate_due_events_once_skips_snoozed_chore() {
+        let pool = db::create_pool("sqlite::memory:")
+            .await
+            .expect("create pool");
+        let chore_id = seed_overdue_chore(&pool).await;
+
+        NotificationRepository::snooze_until(
+            &pool,
+            chore_id,
+            Utc::now() + ChronoDuration::hours(1),
+        )
+        .await
+        .expect("snooze");
+
+        generate_due_events_once(
+            &pool,
+            &[NotificationChannel::Telegram],
+            &NotificationRuntimeConfig::default(),
+        )
+        .await;
+
+        assert_eq!(count_events(&pool).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_generate_due_events_once_defers_during_quiet_hours() {
+        let pool = db::create_pool("sqlite::memory:")
+            .await
+            .expect("create pool");
+        let chore_id = seed_overdue_chore(&pool).await;
+
+        let now = Utc::now().time();
+        let around_now = NotificationRuntimeConfig {
+            quiet_hours: Some(QuietHours::new(
+                now - ChronoDuration::hours(1),
+                now + ChronoDuration::hours(1),
+            )),
+            ..Default::default()
+        };
+        generate_due_events_once(&pool, &[NotificationChannel::Telegram], &around_now).await;
+        assert_eq!(count_events(&pool).await, 0);
+        // The next run's window still starts before the quiet hours
+        assert!(
+            NotificationRepository::last_generator_run(&pool, DUE_EVENT_GENERATOR)
+                .await
+                .expect("fetch run")
+                .is_none()
+        );
+
+        let elsewhere = NotificationRuntimeConfig {
+            quiet_hours: Some(QuietHours::new(
+                now + ChronoDuration::hours(2),
+                now + ChronoDuration::hours(3),
+            )),
+            ..Default::default()
+        };
+        generate_due_events_once(&pool, &[NotificationChannel::Telegram], &elsewhere).await;
+        assert_eq!(count_events(&pool).await, 1);
+
+        let due_at: chrono::DateTime<Utc> =
+            sqlx::query_scalar("SELECT due_at FROM notification_events WHERE chore_id = ?")
+                .bind(chore_id)
+                .fetch_one(&pool)
+                .await
+                .expect("fetch due_at");
+        assert!(due_at < Utc::now() - ChronoDuration::days(1));
+    }
+
+    #[tokio::test]
+    async fn test_generate_due_events_once_truncates_long_bodies() {
+        let pool = db::create_pool("sqlite::memory:")
+            .await
+            .expect("create pool");
+        let chore_id = seed_overdue_chore(&pool).await;
+        sqlx::query("UPDATE chores SET name = ? WHERE id = ?")
+            .bind("Descale the kettle ".repeat(20))
+            .bind(chore_id)
+            .execute(&pool)
+            .await
+            .expect("rename chore");
+
+        let config = NotificationRuntimeConfig {
+            max_body_chars: Some(100),
+            ..Default::default()
+        };
+        generate_due_events_once(&pool, &[NotificationChannel::Telegram], &config).await;
+
+        let body: String = sqlx::query_scalar("SELECT body FROM notification_events")
+            .fetch_one(&pool)
+            .await
+            .expect("fetch body");
+        assert_eq!(body.chars().count(), 100);
+        assert!(body.starts_with("Descale the kettle"));
+        assert!(body.ends_with('…'));
+    }
+
+    #[test]
+    fn test_reminder_dedup_at_buckets_by_repeat_period() {
+        let due_at = Utc::now() - ChronoDuration::days(2);
+        let repeat = NotificationRuntimeConfig {
+            reminder_repeat_hours: Some(6),
+            ..Default::default()
+        };
+
+        let within_first = due_at + ChronoDuration::hours(5);
+        assert_eq!(reminder_dedup_at(due_at, within_first, &repeat), due_at);
+
+        let third_period = due_at + ChronoDuration::hours(13);
+        assert_eq!(
+            reminder_dedup_at(due_at, third_period, &repeat),
+            due_at + ChronoDuration::hours(12)
+        );
+
+        // Opt-in: without a repeat the event stays keyed by the due time
+        let once = NotificationRuntimeConfig::default();
+        assert_eq!(reminder_dedup_at(due_at, third_period, &once), due_at);
+    }
+
+    #[tokio::test]
+    async fn test_generate_due_events_once_repeats_reminders_for_overdue_chore() {
+        let pool = db::create_pool("sqlite::memory:")
+            .await
+            .expect("create pool");
+        seed_overdue_chore(&pool).await;
+
+        generate_due_events_once(
+            &pool,
+            &[NotificationChannel::Telegram],
+            &NotificationRuntimeConfig::default(),
+        )
+        .await;
+        assert_eq!(count_events(&pool).await, 1);
+
+        // The chore has been overdue for well over an hour, so its reminder
+        // falls in a later period than the first event
+        let repeat = NotificationRuntimeConfig {
+            reminder_repeat_hours: Some(1),
+            ..Default::default()
+        };
+        generate_due_events_once(&pool, &[NotificationChannel::Telegram], &repeat).await;
+        generate_due_events_once(&pool, &[NotificationChannel::Telegram], &repeat).await;
+        assert_eq!(count_events(&pool).await, 2);
+
+        // Both remind of the same occurrence
+        let due_ats: Vec<DateTime<Utc>> =
+            sqlx::query_scalar("SELECT DISTINCT due_at FROM notification_events")
+                .fetch_all(&pool)
+                .await
+                .expect("fetch due times");
+        assert_eq!(due_ats.len(), 1);
+
+        let deliveries: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM notification_deliveries")
+            .fetch_one(&pool)
+            .await
+            .expect("count deliveries");
+        assert_eq!(deliveries, 2);
+    }
+
+    #[tokio::test]
+    async fn test_generate_due_events_once_respects_min_notify_interval() {
+        let pool = db::create_pool("sqlite::memory:")
+            .await
+            .expect("create pool");
+        let chore_id = seed_overdue_chore(&pool).await;
+
+        // Hourly reminders, but at most one notification a day
+        let config = NotificationRuntimeConfig {
+            reminder_repeat_hours: Some(1),
+            min_notify_interval_minutes: Some(24 * 60),
+            ..Default::default()
+        };
+        generate_due_events_once(&pool, &[NotificationChannel::Telegram], &config).await;
+        assert_eq!(count_events(&pool).await, 1);
+
+        // Move the event back two hours: without the limit the current hour
+        // would get a reminder of its own
+        sqlx::query(
+            "UPDATE notification_events SET dedup_at = ?, created_at = ? WHERE chore_id = ?",
+        )
+        .bind(Utc::now() - ChronoDuration::hours(2))
+        .bind(Utc::now() - ChronoDuration::hours(2))
+        .bind(chore_id)
+        .execute(&pool)
+        .await
+        .expect("backdate event");
+        generate_due_events_once(&pool, &[NotificationChannel::Telegram], &config).await;
+        assert_eq!(count_events(&pool).await, 1);
+
+        // A chore's own interval takes precedence over the configured one
+        sqlx::query("UPDATE chores SET min_notify_interval_minutes = 60 WHERE id = ?")
+            .bind(chore_id)
+            .execute(&pool)
+            .await
+            .expect("set chore interval");
+        generate_due_events_once(&pool, &[NotificationChannel::Telegram], &config).await;
+        assert_eq!(count_events(&pool).await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_generate_due_events_once_notifies_again_after_min_interval() {
+        let pool = db::create_pool("sqlite::memory:")
+            .await
+            .expect("create pool");
+        let chore_id = seed_overdue_chore(&pool).await;
+
+        let config = NotificationRuntimeConfig {
+            reminder_repeat_hours: Some(1),
+            min_notify_interval_minutes: Some(24 * 60),
+            ..Default::default()
+        };
+        generate_due_events_once(&pool, &[NotificationChannel::Telegram], &config).await;
+
+        sqlx::query(
+            "UPDATE notification_events SET dedup_at = ?, created_at = ? WHERE chore_id = ?",
+        )
+        .bind(Utc::now() - ChronoDuration::hours(25))
+        .bind(Utc::now() - ChronoDuration::hours(25))
+        .bind(chore_id)
+        .execute(&pool)
+        .await
+        .expect("backdate event");
+        generate_due_events_once(&pool, &[NotificationChannel::Telegram], &config).await;
+        assert_eq!(count_events(&pool).await, 2);
+    }
+
+    #[test]
+    fn test_truncate_chars_keeps_short_text() {
+        assert_eq!(truncate_chars("Vacuum".to_string(), 6), "Vacuum");
+        assert_eq!(truncate_chars("Vacuum".to_string(), 4), "Vac…");
+        assert_eq!(truncate_chars("Küche putzen".to_string(), 3), "Kü…");
+    }
+
+    #[tokio::test]
+    async fn test_generate_due_events_once_catches_up_after_missed_runs() {
+        let pool = db::create_pool("sqlite::memory:")
+            .await
+            .expect("create pool");
+        let config = NotificationRuntimeConfig::default();
+
+        // An hourly chore, never completed, first due at `top - 3h`
+        let top = Utc::now()
+            .duration_trunc(ChronoDuration::hours(1))
+            .expect("truncate to hour");
+        let hour = |n: i64| top - ChronoDuration::hours(n);
+
+        // The last run was at `top - 2h`, after which the ticks were skipped
+        let last_run = hour(2);
+        NotificationRepository::record_generator_run(&pool, DUE_EVENT_GENERATOR, last_run)
+            .await
+            .expect("record run");
+
+        let chore = ChoreRepository::create(
+            &pool,
+            CreateChoreParams {
+                name: "Water plants",
+                description: None,
+                schedule_type: ScheduleType::Cron,
+                cron_schedule: Some("0 * * * *"),
+                interval_days: None,
+                interval_time_hour: None,
+                interval_time_minute: None,
+                days_of_week: None,
+                due_at: None,
+                timezone: None,
+                notify_lead_minutes: None,
+                max_snoozes: None,
+                estimated_minutes: None,
+                review_interval_days: None,
+                min_notify_interval_minutes: None,
+                recipient: None,
+                auto_complete_with: None,
+                allowed_completion_sources: None,
+                external_id: None,
+                priority: ChorePriority::Normal,
+                anchor_mode: ChoreAnchorMode::FromCompletion,
+                user_id: None,
+                is_active: true,
+            },
+        )
+        .await
+        .expect("create chore");
+        sqlx::query("UPDATE chores SET created_at = ? WHERE id = ?")
+            .bind(hour(3) - ChronoDuration::minutes(30))
+            .bind(chore.id)
+            .execute(&pool)
+            .await
+            .expect("backdate chore");
+
+        generate_due_events_once(&pool, &[NotificationChannel::Telegram], &config).await;
+
+        // The pending occurrence, and the two that fell due after the last run
+        for (n, raised) in [(3, true), (2, false), (1, true), (0, true)] {
+            assert_eq!(
+                NotificationRepository::due_event_exists(&pool, chore.id, hour(n))
+                    .await
+                    .expect("check event"),
+                raised,
+                "occurrence at top - {n}h"
+            );
+        }
+        let recorded = NotificationRepository::last_generator_run(&pool, DUE_EVENT_GENERATOR)
+            .await
+            .expect("fetch run")
+            .expect("run recorded");
+        assert!(recorded > last_run);
+    }
+
+    #[tokio::test]
+    async fn test_generate_due_events_once_skips_paused_chore() {
+        let pool = db::create_pool("sqlite::memory:")
+            .await
+            .expect("create pool");
+        let chore_id = seed_overdue_chore(&pool).await;
+
+        ChoreRepository::set_active(&pool, chore_id, false)
+            .await
+            .expect("pause");
+
+        generate_due_events_once(
+            &pool,
+            &[NotificationChannel::Telegram],
+            &NotificationRuntimeConfig::default(),
+        )
+        .await;
+
+        assert_eq!(count_events(&pool).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_generate_due_events_once_skips_chores_with_muted_tag() {
+        let pool = db::create_pool("sqlite::memory:")
+            .await
+            .expect("create pool");
+        let chore_id = seed_overdue_chore(&pool).await;
+
+        let mut conn = pool.acquire().await.expect("acquire");
+        let tags = TagRepository::set_chore_tags(
+            &mut conn,
+            chore_id,
+            &["kitchen".to_string()],
+            Default::default(),
+        )
+        .await
+        .expect("tag chore");
+        drop(conn);
+        TagRepository::set_muted(&pool, tags[0].id, true)
+            .await
+            .expect("mute");
+
+        let config = NotificationRuntimeConfig::default();
+        generate_due_events_once(&pool, &[NotificationChannel::Telegram], &config).await;
+        assert_eq!(count_events(&pool).await, 0);
+
+        TagRepository::set_muted(&pool, tags[0].id, false)
+            .await
+            .expect("unmute");
+        generate_due_events_once(&pool, &[NotificationChannel::Telegram], &config).await;
+        assert_eq!(count_events(&pool).await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_generate_due_events_once_prompts_review_until_reviewed() {
+        let pool = db::create_pool("sqlite::memory:")
+            .await
+            .expect("create pool");
+        let chore_id = seed_overdue_chore(&pool).await;
+
+        // Paused, so only the review prompt is raised
+        ChoreRepository::update(
+            &pool,
+            chore_id,
+            UpdateChoreParams {
+                review_interval_days: Some(Some(30)),
+                is_active: Some(false),
+                ..Default::default()
+            },
+            None,
+        )
+        .await
+        .expect("update chore");
+        let config = NotificationRuntimeConfig::default();
+
+        generate_due_events_once(&pool, &[NotificationChannel::Telegram], &config).await;
+        assert_eq!(count_events(&pool).await, 0);
+
+        let created_at = Utc::now() - ChronoDuration::days(31);
+        sqlx::query("UPDATE chores SET created_at = ? WHERE id = ?")
+            .bind(created_at)
+            .bind(chore_id)
+            .execute(&pool)
+            .await
+            .expect("backdate chore");
+
+        generate_due_events_once(&pool, &[NotificationChannel::Telegram], &config).await;
+        generate_due_events_once(&pool, &[NotificationChannel::Telegram], &config).await;
+        let events: Vec<(NotificationEventType, DateTime<Utc>)> =
+            sqlx::query_as("SELECT event_type, due_at FROM notification_events")
+                .fetch_all(&pool)
+                .await
+                .expect("list events");
+        assert_eq!(
+            events,
+            vec![(
+                NotificationEventType::Review,
+                created_at + ChronoDuration::days(30)
+            )]
+        );
+
+        ChoreRepository::mark_reviewed(&pool, chore_id, Utc::now())
+            .await
+            .expect("mark reviewed");
+        generate_due_events_once(&pool, &[NotificationChannel::Telegram], &config).await;
+        assert_eq!(count_events(&pool).await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_generate_due_events_once_renotifies_after_snooze_expires() {
+        let pool = db::create_pool("sqlite::memory:")
+            .await
+            .expect("create pool");
+        let chore_id = seed_overdue_chore(&pool).await;
+
+        generate_due_events_once(
+            &pool,
+            &[NotificationChannel::Telegram],
+            &NotificationRuntimeConfig::default(),
+        )
+        .await;
+        assert_eq!(count_events(&pool).await, 1);
+
+        // Snooze that has already run out
+        let expired = Utc::now() - ChronoDuration::minutes(1);
+        NotificationRepository::snooze_until(&pool, chore_id, expired)
+            .await
+            .expect("snooze");
+
+        generate_due_events_once(
+            &pool,
+            &[NotificationChannel::Telegram],
+            &NotificationRuntimeConfig::default(),
+        )
+        .await;
+        generate_due_events_once(
+            &pool,
+            &[NotificationChannel::Telegram],
+            &NotificationRuntimeConfig::default(),
+        )
+        .await;
+        assert_eq!(count_events(&pool).await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_generate_due_events_once_respects_notify_lead() {
+        let pool = db::create_pool("sqlite::memory:")
+            .await
+            .expect("create pool");
+
+        // Daily chore completed just now falls due at the next midnight UTC,
+        // which is less than a day away but not yet overdue.
+        let chore = ChoreRepository::create(
+            &pool,
+            CreateChoreParams {
+                name: "Early reminder",
+                description: None,
+                schedule_type: ScheduleType::Interval,
+                cron_schedule: None,
+                interval_days: Some(1),
+                interval_time_hour: None,
+                interval_time_minute: None,
+                days_of_week: None,
+                due_at: None,
+                timezone: None,
+                notify_lead_minutes: None,
+                max_snoozes: None,
+                estimated_minutes: None,
+                review_interval_days: None,
+                min_notify_interval_minutes: None,
+                recipient: None,
+                auto_complete_with: None,
+                allowed_completion_sources: None,
+                external_id: None,
+                priority: ChorePriority::Normal,
+                anchor_mode: ChoreAnchorMode::FromCompletion,
+                user_id: None,
+                is_active: true,
+            },
+        )
+        .await
+        .expect("create chore");
+        CompletionRepository::create(
+            &pool,
+            chore.id,
+            Some(Utc::now()),
+            None,
+            CompletionSource::Api,
+            None,
+        )
+        .await
+        .expect("create completion");
+
+        generate_due_events_once(
+            &pool,
+            &[NotificationChannel::Telegram],
+            &NotificationRuntimeConfig::default(),
+        )
+        .await;
+        assert_eq!(count_events(&pool).await, 0);
+
+        ChoreRepository::update(
+            &pool,
+            chore.id,
+            db::chores::UpdateChoreParams {
+                notify_lead_minutes: Some(Some(24 * 60)),
+                ..Default::default()
+            },
+            None,
+        )
+        .await
+        .expect("update chore");
+
+        generate_due_events_once(
+            &pool,
+            &[NotificationChannel::Telegram],
+            &NotificationRuntimeConfig::default(),
+        )
+        .await;
+        assert_eq!(count_events(&pool).await, 1);
+
+        // The event still carries the real due time
+        let due_at: chrono::DateTime<Utc> =
+            sqlx::query_scalar("SELECT due_at FROM notification_events")
+                .fetch_one(&pool)
+                .await
+                .expect("fetch due_at");
+        assert!(due_at > Utc::now());
+    }
 }
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::db::models::NotificationEventType;
+use crate::db::notifications::PendingNotification;
+
+use super::NotificationChannelSender;
+
+/// Alert state of one error signature
+#[derive(Debug, Clone, Copy)]
+struct SignatureState {
+    last_alerted_at: DateTime<Utc>,
+    /// Reports swallowed since the last alert
+    suppressed: u32,
+}
+
+/// Alerts operators about dispatcher errors through a designated channel.
+///
+/// Identical errors are coalesced: each error signature is alerted at most
+/// once per `cooldown`, and the next alert mentions how many were swallowed
+/// in between.
+pub struct OperatorAlerter {
+    sender: Arc<dyn NotificationChannelSender>,
+    recipient: Option<String>,
+    cooldown: Duration,
+    signatures: Mutex<HashMap<String, SignatureState>>,
+}
+
+impl OperatorAlerter {
+    pub fn new(
+        sender: Arc<dyn NotificationChannelSender>,
+        recipient: Option<String>,
+        cooldown: Duration,
+    ) -> Self {
+        Self {
+            sender,
+            recipient,
+            cooldown,
+            signatures: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Report an error, alerting operators unless `signature` was already
+    /// alerted within the cooldown.
+    ///
+    /// Returns whether an alert was sent. An alert the channel rejects is
+    /// only logged and still starts the cooldown, so a broken alert channel
+    /// is not hammered either.
+    pub async fn report(&self, signature: &str, message: &str, now: DateTime<Utc>) -> bool {
+        let suppressed = {
+            let mut signatures = self
+                .signatures
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            match signatures.get_mut(signature) {
+                Some(state) if now - state.last_alerted_at < self.cooldown => {
+                    state.suppressed += 1;
+                    return false;
+                }
+                Some(state) => {
+                    let suppressed = state.suppressed;
+                    *state = SignatureState {
+                        last_alerted_at: now,
+                        suppressed: 0,
+                    };
+                    suppressed
+                }
+                None => {
+                    signatures.insert(
+                        signature.to_string(),
+                        SignatureState {
+                            last_alerted_at: now,
+                            suppressed: 0,
+                        },
+                    );
+                    0
+                }
+            }
+        };
+
+        let alert = self.alert(message, suppressed, now);
+        if let Err(error) = self.sender.send(&alert).await {
+            tracing::warn!(%error, signature, "Failed to send operator alert");
+        }
+        true
+    }
+
+    /// Build the alert as a notification for the designated channel
+    fn alert(&self, message: &str, suppressed: u32, now: DateTime<Utc>) -> PendingNotification {
+        let body = if suppressed > 0 {
+            format!("{message}\n\n({suppressed} similar errors suppressed)")
+        } else {
+            message.to_string()
+        };
+
+        PendingNotification {
+            delivery_id: Uuid::nil(),
+            event_id: Uuid::nil(),
+            channel: self.sender.channel(),
+            attempt_count: 0,
+            chore_id: Uuid::nil(),
+            event_type: NotificationEventType::OperatorAlert,
+            due_at: now,
+            title: "nag: notification delivery failing".to_string(),
+            body,
+            recipient: self.recipient.clone(),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use chrono::{DateTime, Duration, NaiveTime, Utc};
+
+/// Daily window (UTC) during which no due notifications are raised.
+///
+/// A window whose start is after its end wraps past midnight, e.g.
+/// 22:00–07:00. The start is inclusive and the end exclusive; equal times
+/// make an empty window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuietHours {
+    start: NaiveTime,
+    end: NaiveTime,
+}
+
+impl QuietHours {
+    pub fn new(start: NaiveTime, end: NaiveTime) -> Self {
+        Self { start, end }
+    }
+
+    /// Parse a window from `HH:MM` start and end times
+    pub fn parse(start: &str, end: &str) -> Result<Self, String> {
+        let parse = |time: &str| {
+            NaiveTime::parse_from_str(time.trim(), "%H:%M")
+                .map_err(|_| format!("invalid time '{time}', expected HH:MM"))
+        };
+        Ok(Self::new(parse(start)?, parse(end)?))
+    }
+
+    /// Whether a time of day falls inside the window
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+
+    /// When the window `now` falls in ends, or `None` outside the window
+    pub fn ends_after(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let time = now.time();
+        if !self.contains(time) {
+            return None;
+        }
+
+        let end = now.date_naive().and_time(self.end).and_utc();
+        Some(if time < self.end {
+            end
+        } else {
+            end + Duration::days(1)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(time: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(&format!("2026-03-10T{time}:00Z"))
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_quiet_hours_within_a_day() {
+        let quiet = QuietHours::parse("13:00", "15:30").unwrap();
+
+        assert_eq!(quiet.ends_after(at("12:59")), None);
+        assert_eq!(quiet.ends_after(at("13:00")), Some(at("15:30")));
+        assert_eq!(quiet.ends_after(at("15:29")), Some(at("15:30")));
+        assert_eq!(quiet.ends_after(at("15:30")), None);
+    }
+
+    #[test]
+    fn test_quiet_hours_wrapping_midnight() {
+        let quiet = QuietHours::parse("22:00", "07:00").unwrap();
+
+        assert_eq!(
+            quiet.ends_after(at("23:15")),
+            Some(at("07:00") + Duration::days(1))
+        );
+        assert_eq!(quiet.ends_after(at("03:00")), Some(at("07:00")));
+        assert_eq!(quiet.ends_after(at("07:00")), None);
+        assert_eq!(quiet.ends_after(at("12:00")), None);
+    }
+
+    #[test]
+    fn test_quiet_hours_parse_rejects_bad_times() {
+        assert!(QuietHours::parse("25:00", "07:00").is_err());
+        assert!(QuietHours::parse("22:00", "7am").is_err());
+        assert!(QuietHours::parse("22:00", "07:00").is_ok());
+        assert!(
+            QuietHours::parse("08:00", "08:00")
+                .unwrap()
+                .ends_after(at("08:00"))
+                .is_none()
+        );
+    }
+}
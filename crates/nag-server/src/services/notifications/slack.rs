@@ -0,0 +1,148 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use reqwest::{Client, Url};
+use serde_json::{Value, json};
+
+use crate::db::models::NotificationChannel;
+use crate::db::notifications::PendingNotification;
+
+use super::NotificationChannelSender;
+
+/// Sends notifications to a Slack channel through an incoming webhook
+///
+/// Incoming webhooks cannot be inspected without posting to them, so the
+/// channel is not verified on start.
+#[derive(Debug, Clone)]
+pub struct SlackChannel {
+    client: Client,
+    webhook_url: Url,
+}
+
+impl SlackChannel {
+    pub fn new(webhook_url: &str) -> Result<Self, String> {
+        let webhook_url = Url::parse(webhook_url)
+            .map_err(|e| format!("Invalid SLACK_WEBHOOK_URL '{webhook_url}': {e}"))?;
+
+        Ok(Self {
+            client: Client::new(),
+            webhook_url,
+        })
+    }
+
+    /// Build the webhook payload: the title in bold with the body below
+    fn payload(notification: &PendingNotification) -> Value {
+        json!({
+            "text": format!(
+                "*{}*\n{}",
+                escape_mrkdwn(&notification.title),
+                escape_mrkdwn(&notification.body)
+            ),
+        })
+    }
+}
+
+/// Escape the characters Slack treats as control sequences in message text
+fn escape_mrkdwn(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+impl NotificationChannelSender for SlackChannel {
+    fn channel(&self) -> NotificationChannel {
+        NotificationChannel::Slack
+    }
+
+    fn send<'a>(
+        &'a self,
+        notification: &'a PendingNotification,
+    ) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send + 'a>> {
+        Box::pin(async move {
+            let response = self
+                .client
+                .post(self.webhook_url.clone())
+                .json(&Self::payload(notification))
+                .send()
+                .await
+                .map_err(|e| format!("Slack send failed: {e}"))?;
+
+            let status = response.status();
+            if !status.is_success() {
+                let body = response.text().await.unwrap_or_default();
+                return Err(format!("Slack webhook returned {status}: {body}"));
+            }
+
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{Router, http::StatusCode, routing::post};
+    use chrono::{TimeZone, Utc};
+    use uuid::Uuid;
+
+    use crate::db::models::NotificationEventType;
+
+    use super::*;
+
+    fn notification() -> PendingNotification {
+        PendingNotification {
+            delivery_id: Uuid::new_v4(),
+            event_id: Uuid::new_v4(),
+            channel: NotificationChannel::Slack,
+            attempt_count: 0,
+            chore_id: Uuid::new_v4(),
+            event_type: NotificationEventType::Due,
+            due_at: Utc.with_ymd_and_hms(2026, 3, 1, 9, 30, 0).unwrap(),
+            title: "Chore due: Water <plants>".to_string(),
+            body: "Water <plants> & herbs is due".to_string(),
+            recipient: None,
+        }
+    }
+
+    /// Serve `status` for every webhook POST on a local port
+    async fn webhook_returning(status: StatusCode) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind");
+        let addr = listener.local_addr().expect("local addr");
+        let app = Router::new().route("/webhook", post(move || async move { status }));
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        format!("http://{addr}/webhook")
+    }
+
+    #[test]
+    fn test_payload_bolds_title_above_body() {
+        let payload = SlackChannel::payload(&notification());
+
+        assert_eq!(
+            payload["text"],
+            "*Chore due: Water &lt;plants&gt;*\nWater &lt;plants&gt; &amp; herbs is due"
+        );
+    }
+
+    #[test]
+    fn test_new_rejects_invalid_url() {
+        assert!(SlackChannel::new("not a url").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_send_succeeds_on_2xx() {
+        let url = webhook_returning(StatusCode::OK).await;
+        let channel = SlackChannel::new(&url).expect("build channel");
+
+        assert_eq!(channel.send(&notification()).await, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn test_send_fails_on_non_2xx() {
+        let url = webhook_returning(StatusCode::NOT_FOUND).await;
+        let channel = SlackChannel::new(&url).expect("build channel");
+
+        let error = channel.send(&notification()).await.unwrap_err();
+        assert!(error.contains("404"), "unexpected error: {error}");
+    }
+}
@@ -1,7 +1,9 @@
+use std::collections::{HashMap, HashSet};
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
 
+use chrono::{Duration, Utc};
 use sqlx::SqlitePool;
 use teloxide::dispatching::UpdateFilterExt;
 use teloxide::dptree;
@@ -14,30 +16,187 @@ use teloxide::prelude::{
 use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup, Message};
 use uuid::Uuid;
 
-use crate::db::CompletionRepository;
-use crate::db::models::NotificationChannel;
+use crate::db::models::{CompletionSource, NotificationChannel, NotificationEventType};
 use crate::db::notifications::PendingNotification;
+use crate::db::{ChoreRepository, CompletionRepository, NotificationRepository};
+use crate::services::ChoreService;
 
 use super::NotificationChannelSender;
 
+/// Snooze duration of the inline "Snooze" button (and of `snooze:{id}`)
+const SNOOZE_BUTTON_MINUTES: i64 = 60;
+
+/// Longest snooze accepted from callback data (one day)
+const MAX_SNOOZE_MINUTES: i64 = 24 * 60;
+
+/// Callback answer (and error) when a chore has used up its snoozes
+const SNOOZE_LIMIT_REACHED: &str = "Snooze limit reached";
+
+/// Callback answer (and error) when a chore can't be completed from Telegram
+const COMPLETION_NOT_ALLOWED: &str = "This chore can't be marked done from Telegram";
+
+/// Action requested by an inline keyboard button
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CallbackAction {
+    /// `done:{chore_id}`
+    Done(Uuid),
+    /// `snooze:{chore_id}` (one hour) or `snooze:{chore_id}:{minutes}`
+    Snooze { chore_id: Uuid, minutes: i64 },
+}
+
+impl CallbackAction {
+    /// Parse callback data, returning the text to answer with on failure.
+    fn parse(data: &str) -> Result<Self, &'static str> {
+        if let Some(chore_id) = data.strip_prefix("done:") {
+            let chore_id = Uuid::parse_str(chore_id).map_err(|_| "Invalid chore id")?;
+            return Ok(Self::Done(chore_id));
+        }
+
+        if let Some(rest) = data.strip_prefix("snooze:") {
+            let (chore_id, minutes) = match rest.split_once(':') {
+                Some((chore_id, minutes)) => (
+                    chore_id,
+                    minutes
+                        .parse::<i64>()
+                        .ok()
+                        .filter(|m| (1..=MAX_SNOOZE_MINUTES).contains(m))
+                        .ok_or("Invalid snooze duration")?,
+                ),
+                None => (rest, SNOOZE_BUTTON_MINUTES),
+            };
+            let chore_id = Uuid::parse_str(chore_id).map_err(|_| "Invalid chore id")?;
+            return Ok(Self::Snooze { chore_id, minutes });
+        }
+
+        Err("Unsupported action")
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TelegramChannel {
     bot: Bot,
-    chat_id: i64,
+    /// Default chats, each sent every notification without a recipient
+    chat_ids: Vec<i64>,
+    /// Named recipients (chore `recipient` → chat id)
+    recipients: HashMap<String, i64>,
+    /// Where the chats each delivery already reached are recorded
+    pool: SqlitePool,
 }
 
 impl TelegramChannel {
-    pub fn new(bot_token: String, chat_id: String) -> Result<Self, String> {
-        let parsed_chat_id = chat_id.parse::<i64>().map_err(|_| {
-            format!("Invalid TELEGRAM_CHAT_ID '{chat_id}': expected numeric chat id")
-        })?;
+    /// Create a channel sending to the comma-separated chat ids in `chat_id`
+    pub fn new(bot_token: String, chat_id: String, pool: SqlitePool) -> Result<Self, String> {
+        let chat_ids = chat_id
+            .split(',')
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(|id| {
+                id.parse::<i64>().map_err(|_| {
+                    format!("Invalid TELEGRAM_CHAT_ID '{chat_id}': expected numeric chat ids")
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if chat_ids.is_empty() {
+            return Err(format!(
+                "Invalid TELEGRAM_CHAT_ID '{chat_id}': expected numeric chat ids"
+            ));
+        }
 
         Ok(Self {
             bot: Bot::new(bot_token),
-            chat_id: parsed_chat_id,
+            chat_ids,
+            recipients: HashMap::new(),
+            pool,
         })
     }
 
+    /// Register named recipients from comma-separated `name=chat_id` pairs.
+    pub fn with_recipients(mut self, spec: &str) -> Result<Self, String> {
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (name, chat_id) = entry.split_once('=').ok_or_else(|| {
+                format!("Invalid TELEGRAM_RECIPIENTS entry '{entry}': expected name=chat_id")
+            })?;
+            let chat_id = chat_id.trim().parse::<i64>().map_err(|_| {
+                format!("Invalid TELEGRAM_RECIPIENTS entry '{entry}': expected numeric chat id")
+            })?;
+            self.recipients.insert(name.trim().to_string(), chat_id);
+        }
+
+        Ok(self)
+    }
+
+    /// Resolve the chats for a notification, falling back to the default
+    /// chats when no recipient is set or the name is not configured.
+    fn chat_ids_for(&self, recipient: Option<&str>) -> &[i64] {
+        let Some(name) = recipient else {
+            return &self.chat_ids;
+        };
+
+        match self.recipients.get(name) {
+            Some(chat_id) => std::slice::from_ref(chat_id),
+            None => {
+                tracing::warn!(recipient = %name, "Unknown Telegram recipient, using default chat");
+                &self.chat_ids
+            }
+        }
+    }
+
+    /// Send delivery `delivery_id` with `send` to each of `chat_ids` it
+    /// hasn't reached yet. Fails with every chat's error if any send fails;
+    /// the chats that succeeded are recorded for the retry.
+    ///
+    /// Operator alerts carry a nil delivery id and are not tracked.
+    async fn send_to_chats<Fut>(
+        &self,
+        delivery_id: Uuid,
+        chat_ids: &[i64],
+        mut send: impl FnMut(i64) -> Fut,
+    ) -> Result<(), String>
+    where
+        Fut: Future<Output = Result<(), String>>,
+    {
+        let tracked = !delivery_id.is_nil();
+        let reached = if tracked {
+            NotificationRepository::delivered_chats(&self.pool, delivery_id)
+                .await
+                .map_err(|e| format!("Failed to load delivered chats: {e}"))?
+        } else {
+            HashSet::new()
+        };
+
+        let mut errors = Vec::new();
+        for &chat_id in chat_ids.iter().filter(|id| !reached.contains(id)) {
+            match send(chat_id).await {
+                Ok(()) if tracked => {
+                    // Worst case the chat gets the message again on the retry
+                    if let Err(e) = NotificationRepository::mark_chat_delivered(
+                        &self.pool,
+                        delivery_id,
+                        chat_id,
+                    )
+                    .await
+                    {
+                        tracing::error!(error = %e, chat_id, "Failed to record delivered chat");
+                    }
+                }
+                Ok(()) => {}
+                Err(e) => errors.push(format!("chat {chat_id}: {e}")),
+            }
+        }
+
+        if errors.is_empty() {
+            if tracked
+                && let Err(e) =
+                    NotificationRepository::clear_delivered_chats(&self.pool, delivery_id).await
+            {
+                tracing::error!(error = %e, "Failed to clear delivered chats");
+            }
+            Ok(())
+        } else {
+            Err(errors.join("; "))
+        }
+    }
+
     pub async fn run_callback_handler(self: Arc<Self>, pool: SqlitePool) {
         let bot = self.bot.clone();
         let handler = Update::filter_callback_query().endpoint(handle_callback_query);
@@ -49,28 +208,39 @@ impl TelegramChannel {
             .await;
     }
 
+    /// Send a notification; due notifications get "Mark done" and "Snooze"
+    /// buttons, review prompts and operator alerts are plain messages
     async fn send_message_with_inline_done(
         &self,
         notification: &PendingNotification,
     ) -> Result<(), String> {
-        let callback_data = format!("done:{}", notification.chore_id);
-        let keyboard = InlineKeyboardMarkup::new(vec![vec![InlineKeyboardButton::callback(
-            "Mark done",
-            callback_data,
-        )]]);
-
-        let _message: Message = self
-            .bot
-            .send_message(
-                ChatId(self.chat_id),
-                format!("{}\n{}", notification.title, notification.body),
-            )
-            .reply_markup(keyboard)
-            .send()
-            .await
-            .map_err(|e| format!("Telegram send failed: {e}"))?;
+        let text = format!("{}\n{}", notification.title, notification.body);
+        let chat_ids = self.chat_ids_for(notification.recipient.as_deref());
 
-        Ok(())
+        self.send_to_chats(notification.delivery_id, chat_ids, |chat_id| {
+            let mut request = self.bot.send_message(ChatId(chat_id), text.clone());
+            if notification.event_type == NotificationEventType::Due {
+                request = request.reply_markup(InlineKeyboardMarkup::new(vec![vec![
+                    InlineKeyboardButton::callback(
+                        "Mark done",
+                        format!("done:{}", notification.chore_id),
+                    ),
+                    InlineKeyboardButton::callback(
+                        "Snooze 1h",
+                        format!("snooze:{}", notification.chore_id),
+                    ),
+                ]]));
+            }
+
+            async move {
+                let _message: Message = request
+                    .send()
+                    .await
+                    .map_err(|e| format!("Telegram send failed: {e}"))?;
+                Ok(())
+            }
+        })
+        .await
     }
 }
 
@@ -87,44 +257,28 @@ async fn handle_callback_query(
         return Ok(());
     };
 
-    let Some(chore_id_str) = data.strip_prefix("done:") else {
-        bot.answer_callback_query(query.id)
-            .text("Unsupported action")
-            .send()
-            .await?;
-        return Ok(());
-    };
-
-    let chore_id = match Uuid::parse_str(chore_id_str) {
-        Ok(id) => id,
-        Err(_) => {
+    let action = match CallbackAction::parse(&data) {
+        Ok(action) => action,
+        Err(reason) => {
             bot.answer_callback_query(query.id)
-                .text("Invalid chore id")
+                .text(reason)
                 .send()
                 .await?;
             return Ok(());
         }
     };
 
-    let completion_result = async {
-        let exists = CompletionRepository::chore_exists(&pool, chore_id)
-            .await
-            .map_err(|e| e.to_string())?;
-        if !exists {
-            return Err("Chore not found".to_string());
+    let (chore_id, result) = match action {
+        CallbackAction::Done(chore_id) => (chore_id, complete_chore(&pool, chore_id).await),
+        CallbackAction::Snooze { chore_id, minutes } => {
+            (chore_id, snooze_chore(&pool, chore_id, minutes).await)
         }
+    };
 
-        CompletionRepository::create(&pool, chore_id, None, Some("Completed via Telegram"))
-            .await
-            .map_err(|e| e.to_string())?;
-        Ok::<(), String>(())
-    }
-    .await;
-
-    match completion_result {
-        Ok(()) => {
+    match result {
+        Ok(answer) => {
             bot.answer_callback_query(query.id)
-                .text("Marked done")
+                .text(answer)
                 .send()
                 .await?;
 
@@ -137,17 +291,84 @@ async fn handle_callback_query(
             }
         }
         Err(error) => {
+            let answer = match action {
+                CallbackAction::Done(_) if error == COMPLETION_NOT_ALLOWED => {
+                    COMPLETION_NOT_ALLOWED
+                }
+                CallbackAction::Done(_) => "Failed to mark done",
+                CallbackAction::Snooze { .. } if error == SNOOZE_LIMIT_REACHED => {
+                    SNOOZE_LIMIT_REACHED
+                }
+                CallbackAction::Snooze { .. } => "Failed to snooze",
+            };
             bot.answer_callback_query(query.id)
-                .text("Failed to mark done")
+                .text(answer)
                 .send()
                 .await?;
-            tracing::error!(error = %error, chore_id = %chore_id, "Failed to complete chore from Telegram callback");
+            tracing::error!(error = %error, chore_id = %chore_id, "Failed to handle Telegram callback");
         }
     }
 
     Ok(())
 }
 
+/// Record a completion for the chore, returning the callback answer text.
+async fn complete_chore(pool: &SqlitePool, chore_id: Uuid) -> Result<String, String> {
+    let chore = ChoreRepository::get_by_id(pool, chore_id, None)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Chore not found".to_string())?;
+    if !ChoreService::allows_completion_source(
+        chore.allowed_completion_sources.as_deref(),
+        CompletionSource::Telegram,
+    ) {
+        return Err(COMPLETION_NOT_ALLOWED.to_string());
+    }
+
+    let mut tx = pool.begin().await.map_err(|e| e.to_string())?;
+    let completion = CompletionRepository::insert(
+        &mut tx,
+        chore_id,
+        None,
+        Some("Completed via Telegram"),
+        CompletionSource::Telegram,
+        None,
+        None,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+    CompletionRepository::create_for_linked_chores(
+        &mut tx,
+        chore_id,
+        completion.completed_at,
+        CompletionSource::Telegram,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+    tx.commit().await.map_err(|e| e.to_string())?;
+    Ok("Marked done".to_string())
+}
+
+/// Snooze the chore's notifications, returning the callback answer text.
+async fn snooze_chore(pool: &SqlitePool, chore_id: Uuid, minutes: i64) -> Result<String, String> {
+    let exists = CompletionRepository::chore_exists(pool, chore_id, None)
+        .await
+        .map_err(|e| e.to_string())?;
+    if !exists {
+        return Err("Chore not found".to_string());
+    }
+
+    let until = NotificationRepository::snooze_until(
+        pool,
+        chore_id,
+        Utc::now() + Duration::minutes(minutes),
+    )
+    .await
+    .map_err(|e| e.to_string())?
+    .ok_or_else(|| SNOOZE_LIMIT_REACHED.to_string())?;
+    Ok(format!("Snoozed until {} UTC", until.format("%H:%M")))
+}
+
 impl NotificationChannelSender for TelegramChannel {
     fn channel(&self) -> NotificationChannel {
         NotificationChannel::Telegram
@@ -159,4 +380,258 @@ impl NotificationChannelSender for TelegramChannel {
     ) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send + 'a>> {
         Box::pin(async move { self.send_message_with_inline_done(notification).await })
     }
+
+    fn verify<'a>(&'a self) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send + 'a>> {
+        Box::pin(async move {
+            self.bot
+                .get_me()
+                .send()
+                .await
+                .map(|_| ())
+                .map_err(|e| format!("Telegram getMe failed: {e}"))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::db::{
+        self,
+        chores::CreateChoreParams,
+        completions::CompletedAtRange,
+        models::{ChoreAnchorMode, ChorePriority, ScheduleType},
+    };
+
+    /// Pool for tests that never touch the database
+    fn lazy_pool() -> SqlitePool {
+        SqlitePool::connect_lazy("sqlite::memory:").expect("create pool")
+    }
+
+    fn channel(pool: SqlitePool) -> TelegramChannel {
+        TelegramChannel::new("token".to_string(), "100".to_string(), pool)
+            .and_then(|t| t.with_recipients("partner=200, me = 300"))
+            .expect("build channel")
+    }
+
+    #[tokio::test]
+    async fn test_chat_ids_for_mapped_recipient() {
+        assert_eq!(channel(lazy_pool()).chat_ids_for(Some("partner")), [200]);
+        assert_eq!(channel(lazy_pool()).chat_ids_for(Some("me")), [300]);
+    }
+
+    #[tokio::test]
+    async fn test_chat_ids_for_defaults_without_recipient() {
+        assert_eq!(channel(lazy_pool()).chat_ids_for(None), [100]);
+        assert_eq!(channel(lazy_pool()).chat_ids_for(Some("stranger")), [100]);
+    }
+
+    #[tokio::test]
+    async fn test_new_parses_comma_separated_chat_ids() {
+        let new = |chat_id: &str| {
+            TelegramChannel::new("token".to_string(), chat_id.to_string(), lazy_pool())
+        };
+        let channel = new("100, 101,").expect("build channel");
+        assert_eq!(channel.chat_ids_for(None), [100, 101]);
+
+        assert!(new("100,abc").is_err());
+        assert!(new(" ").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_send_to_chats_retries_only_failed_chats() {
+        let pool = db::create_pool("sqlite::memory:")
+            .await
+            .expect("create pool");
+        let chore_id = seed_chore(&pool, None).await;
+        let event_id = NotificationRepository::upsert_due_event_with_deliveries(
+            &pool,
+            chore_id,
+            Utc::now(),
+            "Chore due",
+            "Vacuum is due",
+            &[NotificationChannel::Telegram],
+        )
+        .await
+        .expect("upsert event");
+        let delivery_id = sqlx::query_scalar::<_, Uuid>(
+            "SELECT id FROM notification_deliveries WHERE event_id = ?",
+        )
+        .bind(event_id)
+        .fetch_one(&pool)
+        .await
+        .expect("fetch delivery id");
+
+        // A fresh channel, as after a restart, still knows the reached chats
+        let send_once = |send| {
+            let channel = channel(pool.clone());
+            async move {
+                channel
+                    .send_to_chats(delivery_id, &[100, 101, 102], send)
+                    .await
+            }
+        };
+        let sent = Mutex::new(Vec::new());
+        let send = |fail: Option<i64>| {
+            let sent = &sent;
+            move |chat_id: i64| {
+                sent.lock().expect("lock").push(chat_id);
+                async move {
+                    if Some(chat_id) == fail {
+                        Err("boom".to_string())
+                    } else {
+                        Ok(())
+                    }
+                }
+            }
+        };
+
+        let result = send_once(send(Some(101))).await;
+        assert_eq!(result, Err("chat 101: boom".to_string()));
+
+        // The retry only reaches the chat that failed
+        let result = send_once(send(None)).await;
+        assert_eq!(result, Ok(()));
+        assert_eq!(*sent.lock().expect("lock"), [100, 101, 102, 101]);
+        let reached = NotificationRepository::delivered_chats(&pool, delivery_id)
+            .await
+            .expect("delivered chats");
+        assert!(reached.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_with_recipients_rejects_malformed_entries() {
+        let base =
+            || TelegramChannel::new("token".to_string(), "100".to_string(), lazy_pool()).unwrap();
+        assert!(base().with_recipients("partner").is_err());
+        assert!(base().with_recipients("partner=abc").is_err());
+        assert!(base().with_recipients("").is_ok());
+    }
+
+    #[test]
+    fn test_parse_callback_done() {
+        let id = Uuid::new_v4();
+        assert_eq!(
+            CallbackAction::parse(&format!("done:{id}")),
+            Ok(CallbackAction::Done(id))
+        );
+    }
+
+    #[test]
+    fn test_parse_callback_snooze() {
+        let id = Uuid::new_v4();
+        assert_eq!(
+            CallbackAction::parse(&format!("snooze:{id}:60")),
+            Ok(CallbackAction::Snooze {
+                chore_id: id,
+                minutes: 60
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_callback_snooze_defaults_to_button_duration() {
+        let id = Uuid::new_v4();
+        assert_eq!(
+            CallbackAction::parse(&format!("snooze:{id}")),
+            Ok(CallbackAction::Snooze {
+                chore_id: id,
+                minutes: SNOOZE_BUTTON_MINUTES
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_callback_rejects_bad_snooze_duration() {
+        let id = Uuid::new_v4();
+        assert!(CallbackAction::parse(&format!("snooze:{id}:0")).is_err());
+        assert!(CallbackAction::parse(&format!("snooze:{id}:abc")).is_err());
+        assert!(CallbackAction::parse("snooze:not-a-uuid").is_err());
+        assert!(CallbackAction::parse("unknown:123").is_err());
+    }
+
+    async fn seed_chore(pool: &SqlitePool, allowed_completion_sources: Option<&str>) -> Uuid {
+        ChoreRepository::create(
+            pool,
+            CreateChoreParams {
+                name: "Vacuum",
+                description: None,
+                schedule_type: ScheduleType::Interval,
+                cron_schedule: None,
+                interval_days: Some(7),
+                interval_time_hour: None,
+                interval_time_minute: None,
+                days_of_week: None,
+                due_at: None,
+                timezone: None,
+                notify_lead_minutes: None,
+                max_snoozes: None,
+                estimated_minutes: None,
+                review_interval_days: None,
+                min_notify_interval_minutes: None,
+                recipient: None,
+                auto_complete_with: None,
+                allowed_completion_sources,
+                external_id: None,
+                priority: ChorePriority::Normal,
+                anchor_mode: ChoreAnchorMode::FromCompletion,
+                user_id: None,
+                is_active: true,
+            },
+        )
+        .await
+        .expect("create chore")
+        .id
+    }
+
+    #[tokio::test]
+    async fn test_complete_chore_records_telegram_source() {
+        let pool = db::create_pool("sqlite::memory:")
+            .await
+            .expect("create pool");
+        let chore_id = seed_chore(&pool, None).await;
+
+        assert_eq!(
+            complete_chore(&pool, chore_id).await,
+            Ok("Marked done".to_string())
+        );
+
+        let completions = CompletionRepository::list_for_chore(
+            &pool,
+            chore_id,
+            CompletedAtRange::default(),
+            None,
+            None,
+        )
+        .await
+        .expect("list completions");
+        assert_eq!(completions.len(), 1);
+        assert_eq!(completions[0].source, CompletionSource::Telegram);
+    }
+
+    #[tokio::test]
+    async fn test_complete_chore_rejects_disallowed_telegram() {
+        let pool = db::create_pool("sqlite::memory:")
+            .await
+            .expect("create pool");
+        let chore_id = seed_chore(&pool, Some("api")).await;
+
+        assert_eq!(
+            complete_chore(&pool, chore_id).await,
+            Err(COMPLETION_NOT_ALLOWED.to_string())
+        );
+
+        let completions = CompletionRepository::list_for_chore(
+            &pool,
+            chore_id,
+            CompletedAtRange::default(),
+            None,
+            None,
+        )
+        .await
+        .expect("list completions");
+        assert!(completions.is_empty());
+    }
 }
@@ -2,14 +2,28 @@
 //!
 //! Tests cover:
 //! - CRUD operations (create, read, update, delete)
-//! - Pagination
+//! - Schedule preview, and a chore's next occurrences in its timezone
+//! - Upsert by external id
+//! - Completion note template
+//! - Pagination, including `Link` headers
+//! - Priority
+//! - Anchor mode
 //! - Due chores endpoint
+//! - Daily effort budget
+//! - Undoing the latest completion
+//! - PIN for deletes (`DESTRUCTIVE_ACTION_PIN`)
+//! - Review intervals and marking chores reviewed
+//! - Minimum time between due notifications
 //! - Error cases (404, 400)
 
 mod common;
 
 use axum::http::StatusCode;
-use common::{ChoreResponse, ChoreWithDueResponse, PaginatedResponse, ProblemDetails};
+use common::{
+    ChoreResponse, ChoreWithDueResponse, CompletionResponse, PaginatedResponse, ProblemDetails,
+};
+use nag_server::db::completions::CompletionNoteTemplate;
+use nag_server::services::ScheduleLimits;
 use uuid::Uuid;
 
 // ============================================================================
@@ -94,6 +108,252 @@ async fn test_list_chores_pagination_cursor() {
     }
 }
 
+/// Target of a response's `Link: <...>; rel="next"` header
+fn next_link(response: &axum_test::TestResponse) -> Option<String> {
+    let link = response.maybe_header("link")?;
+    let link = link.to_str().expect("ascii link header");
+    let target = link
+        .strip_prefix('<')
+        .and_then(|l| l.strip_suffix(">; rel=\"next\""))
+        .unwrap_or_else(|| panic!("malformed link header: {link}"));
+    Some(target.to_string())
+}
+
+#[tokio::test]
+async fn test_list_chores_link_header_follows_pages() {
+    let server = common::create_test_app().await;
+
+    for name in ["Alpha", "Bravo", "Charlie", "Delta", "Echo"] {
+        common::create_chore(&server, name, "0 9 * * *").await;
+    }
+
+    let mut url = "/api/chores?sort=name&dir=desc&limit=2&include_paused=true".to_string();
+    let mut names = Vec::new();
+    for _ in 0..3 {
+        let response = server.get(&url).await;
+        response.assert_status_ok();
+        let page: PaginatedResponse<ChoreResponse> = response.json();
+        names.extend(page.items.into_iter().map(|c| c.name));
+
+        let link = next_link(&response).expect("link header");
+        assert!(
+            link.starts_with("/api/chores?sort=name&dir=desc&limit=2&include_paused=true&cursor="),
+            "unexpected link: {link}"
+        );
+        assert_eq!(link.matches("cursor=").count(), 1);
+        url = link;
+    }
+
+    assert_eq!(names, ["Echo", "Delta", "Charlie", "Bravo", "Alpha"]);
+}
+
+#[tokio::test]
+async fn test_list_chores_link_header_absent_without_next_cursor() {
+    let server = common::create_test_app().await;
+
+    let response = server.get("/api/chores").await;
+
+    response.assert_status_ok();
+    assert!(next_link(&response).is_none());
+}
+
+/// Names of every chore listed with `query`, following cursors two at a time
+async fn list_names_paged(server: &axum_test::TestServer, query: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let url = match &cursor {
+            Some(cursor) => format!("/api/chores?limit=2&{}&cursor={}", query, cursor),
+            None => format!("/api/chores?limit=2&{}", query),
+        };
+        let response = server.get(&url).await;
+        response.assert_status_ok();
+        let page: PaginatedResponse<ChoreResponse> = response.json();
+        if page.items.is_empty() {
+            return names;
+        }
+        names.extend(page.items.into_iter().map(|c| c.name));
+        cursor = page.next_cursor;
+    }
+}
+
+#[tokio::test]
+async fn test_list_chores_sorted_by_name() {
+    let server = common::create_test_app().await;
+
+    for name in ["dust", "Bake", "apples", "Clean", "bake"] {
+        common::create_interval_chore(&server, name, 7).await;
+    }
+
+    let names = list_names_paged(&server, "sort=name").await;
+    assert_eq!(names.len(), 5);
+    let lowered: Vec<String> = names.iter().map(|n| n.to_lowercase()).collect();
+    assert_eq!(lowered, ["apples", "bake", "bake", "clean", "dust"]);
+
+    let names = list_names_paged(&server, "sort=name&dir=desc").await;
+    let lowered: Vec<String> = names.iter().map(|n| n.to_lowercase()).collect();
+    assert_eq!(lowered, ["dust", "clean", "bake", "bake", "apples"]);
+}
+
+#[tokio::test]
+async fn test_list_chores_sorted_by_last_completed_at() {
+    let server = common::create_test_app().await;
+
+    let now = chrono::Utc::now();
+    for (name, days_ago) in [("Week", Some(7)), ("Never", None), ("Today", Some(0))] {
+        let chore = common::create_interval_chore(&server, name, 30).await;
+        if let Some(days) = days_ago {
+            common::complete_chore_at(&server, chore.id, now - chrono::Duration::days(days)).await;
+        }
+    }
+
+    assert_eq!(
+        list_names_paged(&server, "sort=last_completed_at").await,
+        ["Never", "Week", "Today"]
+    );
+    assert_eq!(
+        list_names_paged(&server, "sort=last_completed_at&dir=desc").await,
+        ["Today", "Week", "Never"]
+    );
+}
+
+#[tokio::test]
+async fn test_list_chores_sorted_by_neglect() {
+    let server = common::create_test_app().await;
+
+    let now = chrono::Utc::now();
+    // (name, interval in days, days since the last completion)
+    for (name, interval, days_ago) in [
+        ("Barely overdue", 7, 8),
+        ("Fresh", 30, 0),
+        ("Long neglected", 1, 10),
+        ("Due now", 2, 2),
+    ] {
+        let chore = common::create_interval_chore(&server, name, interval).await;
+        common::complete_chore_at(&server, chore.id, now - chrono::Duration::days(days_ago)).await;
+    }
+
+    assert_eq!(
+        list_names_paged(&server, "sort=neglect").await,
+        ["Long neglected", "Barely overdue", "Due now", "Fresh"]
+    );
+    assert_eq!(
+        list_names_paged(&server, "sort=neglect&dir=asc").await,
+        ["Fresh", "Due now", "Barely overdue", "Long neglected"]
+    );
+}
+
+#[tokio::test]
+async fn test_list_chores_sorted_by_created_at() {
+    let server = common::create_test_app().await;
+
+    for name in ["First", "Second", "Third"] {
+        common::create_interval_chore(&server, name, 7).await;
+    }
+
+    assert_eq!(
+        list_names_paged(&server, "sort=created_at&dir=desc").await,
+        ["Third", "Second", "First"]
+    );
+}
+
+#[tokio::test]
+async fn test_list_chores_rejects_unknown_sort() {
+    let server = common::create_test_app().await;
+
+    let response = server.get("/api/chores?sort=priority").await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    let response = server.get("/api/chores?sort=name&dir=sideways").await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_list_chores_rejects_cursor_from_other_sort() {
+    let server = common::create_test_app().await;
+
+    for i in 1..=3 {
+        common::create_interval_chore(&server, &format!("Chore {}", i), 7).await;
+    }
+
+    let page: PaginatedResponse<ChoreResponse> = server.get("/api/chores?limit=2").await.json();
+    let response = server
+        .get(&format!(
+            "/api/chores?limit=2&sort=name&cursor={}",
+            page.next_cursor.unwrap()
+        ))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_list_chores_tag_filter_paginates_over_matches() {
+    let server = common::create_test_app().await;
+
+    let mut tagged = Vec::new();
+    for i in 1..=5 {
+        let chore =
+            common::create_chore_with_tags(&server, &format!("Tagged {}", i), "0 9 * * *", &["x"])
+                .await;
+        tagged.push(chore.id);
+        common::create_chore(&server, &format!("Untagged {}", i), "0 9 * * *").await;
+    }
+
+    let mut seen = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let url = match &cursor {
+            Some(cursor) => format!("/api/chores?limit=2&tag=x&cursor={}", cursor),
+            None => "/api/chores?limit=2&tag=x".to_string(),
+        };
+        let page: PaginatedResponse<ChoreResponse> = server.get(&url).await.json();
+        if page.items.is_empty() {
+            break;
+        }
+        // Every page except the last is full
+        assert!(page.items.len() == 2 || seen.len() + page.items.len() == 5);
+        seen.extend(page.items.iter().map(|c| c.id));
+        cursor = page.next_cursor;
+    }
+
+    seen.sort();
+    tagged.sort();
+    assert_eq!(seen, tagged);
+}
+
+#[tokio::test]
+async fn test_list_chores_with_total() {
+    let server = common::create_test_app().await;
+
+    for i in 1..=3 {
+        common::create_chore(&server, &format!("Chore {}", i), "0 9 * * *").await;
+    }
+
+    let response = server.get("/api/chores?limit=2&with_total=true").await;
+    response.assert_status_ok();
+    let body: PaginatedResponse<ChoreResponse> = response.json();
+    assert_eq!(body.items.len(), 2);
+    assert_eq!(body.total, Some(3));
+
+    // Omitted unless requested
+    let response = server.get("/api/chores?limit=2").await;
+    let body: serde_json::Value = response.json();
+    assert!(body.get("total").is_none());
+}
+
+#[tokio::test]
+async fn test_list_chores_total_respects_tag_filter() {
+    let server = common::create_test_app().await;
+
+    common::create_chore_with_tags(&server, "Vacuum", "0 9 * * *", &["Kitchen"]).await;
+    common::create_chore_with_tags(&server, "Dishes", "0 9 * * *", &["Kitchen", "Daily"]).await;
+    common::create_chore(&server, "Laundry", "0 9 * * *").await;
+
+    let response = server.get("/api/chores?tag=KITCHEN&with_total=true").await;
+    let body: PaginatedResponse<ChoreResponse> = response.json();
+    assert_eq!(body.total, Some(2));
+}
+
 // ============================================================================
 // Create Chore (POST /api/chores)
 // ============================================================================
@@ -126,6 +386,10 @@ async fn test_create_chore_success() {
     assert!(chore.created_at <= chrono::Utc::now());
     assert!(chore.updated_at <= chrono::Utc::now());
     assert_eq!(chore.created_at, chore.updated_at); // Should be equal on creation
+    assert_eq!(
+        response.header("location"),
+        format!("/api/chores/{}", chore.id)
+    );
 }
 
 #[tokio::test]
@@ -168,6 +432,29 @@ async fn test_create_chore_invalid_cron_schedule() {
     assert!(problem.problem_type.is_some());
 }
 
+#[tokio::test]
+async fn test_create_chore_cron_reports_invalid_field() {
+    let server = common::create_test_app().await;
+
+    for (cron_schedule, expected) in [
+        ("99 9 * * *", "minute field '99' out of range 0-59"),
+        ("0 9 * * 8", "day-of-week field '8' out of range 0-7"),
+    ] {
+        let body = serde_json::json!({
+            "name": "Vacuum",
+            "schedule_type": "cron",
+            "cron_schedule": cron_schedule
+        });
+
+        let response = server.post("/api/chores").json(&body).await;
+
+        response.assert_status(StatusCode::BAD_REQUEST);
+        let problem: ProblemDetails = response.json();
+        let detail = problem.detail.unwrap();
+        assert!(detail.ends_with(expected), "unexpected detail: {detail}");
+    }
+}
+
 #[tokio::test]
 async fn test_create_chore_too_frequent_schedule() {
     let server = common::create_test_app().await;
@@ -189,6 +476,41 @@ async fn test_create_chore_too_frequent_schedule() {
     assert!(problem.title.is_some());
 }
 
+#[tokio::test]
+async fn test_create_chore_respects_configured_minimum_interval() {
+    let pool = nag_server::db::create_pool("sqlite::memory:")
+        .await
+        .expect("Failed to create test database pool");
+    let app = nag_server::http::build_test_app_with_state(nag_server::http::AppState {
+        schedule_limits: ScheduleLimits {
+            min_cron_interval: chrono::Duration::minutes(15),
+            ..ScheduleLimits::default()
+        },
+        ..nag_server::http::test_state(pool)
+    });
+    let server =
+        axum_test::TestServer::new(app.into_make_service()).expect("Failed to create test server");
+
+    let body = |cron: &str| {
+        serde_json::json!({
+            "name": "Check the oven",
+            "schedule_type": "cron",
+            "cron_schedule": cron
+        })
+    };
+
+    server
+        .post("/api/chores")
+        .json(&body("*/15 * * * *"))
+        .await
+        .assert_status(StatusCode::CREATED);
+
+    let response = server.post("/api/chores").json(&body("*/5 * * * *")).await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+    let problem: ProblemDetails = response.json();
+    assert!(problem.detail.unwrap().contains("15 minutes"));
+}
+
 #[tokio::test]
 async fn test_create_interval_chore_success() {
     let server = common::create_test_app().await;
@@ -240,6 +562,35 @@ async fn test_create_interval_chore_with_time() {
     assert_eq!(chore.interval_time_minute, Some(30));
 }
 
+#[tokio::test]
+async fn test_create_daily_midnight_interval_chore_is_not_overdue() {
+    let server = common::create_test_app().await;
+
+    let body = serde_json::json!({
+        "name": "Take out trash",
+        "schedule_type": "interval",
+        "interval_days": 1,
+        "interval_time_hour": 0
+    });
+    let response = server.post("/api/chores").json(&body).await;
+    response.assert_status(StatusCode::CREATED);
+    let chore: ChoreResponse = response.json();
+
+    // Today's midnight has already passed; the first occurrence is the next one
+    let due: Vec<ChoreWithDueResponse> = server
+        .get("/api/chores/due?include_upcoming=true")
+        .await
+        .json();
+    let next_midnight = (chore.created_at.date_naive() + chrono::Duration::days(1))
+        .and_time(chrono::NaiveTime::MIN)
+        .and_utc();
+    assert_eq!(due[0].next_due, Some(next_midnight));
+    assert!(!due[0].is_overdue);
+
+    let overdue: Vec<ChoreWithDueResponse> = server.get("/api/chores/due").await.json();
+    assert!(overdue.is_empty());
+}
+
 #[tokio::test]
 async fn test_create_interval_chore_invalid_days() {
     let server = common::create_test_app().await;
@@ -295,132 +646,396 @@ async fn test_create_chore_missing_required_fields() {
     response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
 }
 
-// ============================================================================
-// Get Chore (GET /api/chores/{id})
-// ============================================================================
-
 #[tokio::test]
-async fn test_get_chore_success() {
+async fn test_create_interval_chore_accepts_numeric_strings() {
     let server = common::create_test_app().await;
 
-    let created = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
-
-    let response = server.get(&format!("/api/chores/{}", created.id)).await;
-
-    response.assert_status_ok();
+    let response = server
+        .post("/api/chores")
+        .json(&serde_json::json!({
+            "name": "Water plants",
+            "schedule_type": "interval",
+            "interval_days": "7",
+            "interval_time_hour": "8",
+            "interval_time_minute": null
+        }))
+        .await;
 
+    response.assert_status(StatusCode::CREATED);
     let chore: ChoreResponse = response.json();
-    assert_eq!(chore.id, created.id);
-    assert_eq!(chore.name, "Vacuum");
-}
-
-#[tokio::test]
-async fn test_get_chore_not_found() {
-    let server = common::create_test_app().await;
-
-    let fake_id = Uuid::new_v4();
-    let response = server.get(&format!("/api/chores/{}", fake_id)).await;
-
-    response.assert_status(StatusCode::NOT_FOUND);
-
-    let problem: ProblemDetails = response.json();
-    assert_eq!(problem.status, Some(404));
-    assert!(problem.detail.unwrap().contains("not found"));
-    assert!(problem.title.is_some());
+    assert_eq!(chore.interval_days, Some(7));
+    assert_eq!(chore.interval_time_hour, Some(8));
+    assert!(chore.interval_time_minute.is_none());
 }
 
 #[tokio::test]
-async fn test_get_chore_invalid_uuid() {
+async fn test_create_interval_chore_rejects_non_numeric_string() {
     let server = common::create_test_app().await;
 
-    let response = server.get("/api/chores/not-a-uuid").await;
+    let response = server
+        .post("/api/chores")
+        .json(&serde_json::json!({
+            "name": "Water plants",
+            "schedule_type": "interval",
+            "interval_days": "abc"
+        }))
+        .await;
 
-    // Axum returns 400 for path parsing failures
-    response.assert_status(StatusCode::BAD_REQUEST);
+    response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    let body = response.text();
+    assert!(
+        body.contains(r#"invalid value: string "abc", expected an integer or a numeric string"#),
+        "unexpected error: {body}"
+    );
 }
 
 // ============================================================================
-// Update Chore (PUT /api/chores/{id})
+// Preview Schedule (POST /api/chores/preview-schedule)
 // ============================================================================
 
-#[tokio::test]
-async fn test_update_chore_all_fields() {
-    let server = common::create_test_app().await;
-
-    let created = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
-
-    let body = serde_json::json!({
-        "name": "Vacuum Thoroughly",
-        "description": "Including under furniture",
-        "schedule": {
-            "schedule_type": "cron",
-            "cron_schedule": "0 10 * * 0"
-        }
-    });
-
+async fn preview(
+    server: &axum_test::TestServer,
+    body: serde_json::Value,
+) -> Vec<chrono::DateTime<chrono::Utc>> {
     let response = server
-        .put(&format!("/api/chores/{}", created.id))
+        .post("/api/chores/preview-schedule")
         .json(&body)
         .await;
-
     response.assert_status_ok();
-
-    let chore: ChoreResponse = response.json();
-    assert_eq!(chore.name, "Vacuum Thoroughly");
-    assert_eq!(
-        chore.description,
-        Some("Including under furniture".to_string())
-    );
-    assert_eq!(chore.cron_schedule, Some("0 10 * * 0".to_string()));
+    response.json()
 }
 
 #[tokio::test]
-async fn test_update_chore_partial() {
+async fn test_preview_schedule_cron_defaults_to_five() {
     let server = common::create_test_app().await;
 
-    let created = common::create_cron_chore_with_description(
+    let times = preview(
         &server,
-        "Vacuum",
-        Some("Original"),
-        "0 9 * * 1",
+        serde_json::json!({"schedule_type": "cron", "cron_schedule": "0 9 * * *"}),
     )
     .await;
 
-    // Only update name
-    let body = serde_json::json!({
-        "name": "Vacuum Thoroughly"
-    });
-
-    let response = server
-        .put(&format!("/api/chores/{}", created.id))
-        .json(&body)
-        .await;
-
-    response.assert_status_ok();
-
-    let chore: ChoreResponse = response.json();
-    assert_eq!(chore.name, "Vacuum Thoroughly");
-    // Other fields should be unchanged
-    assert_eq!(chore.description, Some("Original".to_string()));
-    assert_eq!(chore.cron_schedule, Some("0 9 * * 1".to_string()));
+    assert_eq!(times.len(), 5);
+    assert!(times[0] > chrono::Utc::now());
+    for pair in times.windows(2) {
+        assert_eq!(pair[1] - pair[0], chrono::Duration::days(1));
+    }
+    assert!(
+        times
+            .iter()
+            .all(|t| t.format("%H:%M").to_string() == "09:00")
+    );
 }
 
 #[tokio::test]
-async fn test_update_chore_clear_description() {
+async fn test_preview_schedule_interval_uses_count() {
     let server = common::create_test_app().await;
 
-    let created = common::create_cron_chore_with_description(
+    let times = preview(
         &server,
-        "Vacuum",
-        Some("Original"),
-        "0 9 * * 1",
+        serde_json::json!({
+            "schedule_type": "interval",
+            "interval_days": 3,
+            "interval_time_hour": 18,
+            "count": 3
+        }),
     )
     .await;
 
-    // Set description to null to clear it
-    let body = serde_json::json!({
-        "description": null
-    });
+    assert_eq!(times.len(), 3);
+    for pair in times.windows(2) {
+        assert_eq!(pair[1] - pair[0], chrono::Duration::days(3));
+    }
+    assert!(
+        times
+            .iter()
+            .all(|t| t.format("%H:%M").to_string() == "18:00")
+    );
+}
+
+#[tokio::test]
+async fn test_preview_schedule_once_in_a_while_is_empty() {
+    let server = common::create_test_app().await;
+
+    let times = preview(
+        &server,
+        serde_json::json!({"schedule_type": "once_in_a_while"}),
+    )
+    .await;
+
+    assert!(times.is_empty());
+}
+
+#[tokio::test]
+async fn test_preview_schedule_does_not_create_chore() {
+    let server = common::create_test_app().await;
+
+    preview(
+        &server,
+        serde_json::json!({"schedule_type": "cron", "cron_schedule": "0 9 * * *"}),
+    )
+    .await;
+
+    let chores: PaginatedResponse<ChoreResponse> = server.get("/api/chores").await.json();
+    assert!(chores.items.is_empty());
+}
+
+#[tokio::test]
+async fn test_preview_schedule_rejects_invalid_schedules() {
+    let server = common::create_test_app().await;
+
+    for (body, expected) in [
+        (
+            serde_json::json!({"schedule_type": "cron", "cron_schedule": "not a valid cron"}),
+            "Invalid cron schedule",
+        ),
+        (
+            serde_json::json!({"schedule_type": "interval", "interval_days": 0}),
+            "Invalid interval schedule",
+        ),
+        (
+            serde_json::json!({"schedule_type": "cron", "cron_schedule": "0 9 * * *", "count": 0}),
+            "count must be between",
+        ),
+    ] {
+        let response = server
+            .post("/api/chores/preview-schedule")
+            .json(&body)
+            .await;
+
+        response.assert_status(StatusCode::BAD_REQUEST);
+        let problem: ProblemDetails = response.json();
+        let detail = problem.detail.unwrap();
+        assert!(detail.contains(expected), "unexpected error: {detail}");
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct Occurrence {
+    at: chrono::DateTime<chrono::Utc>,
+    local: String,
+}
+
+#[tokio::test]
+async fn test_next_occurrences_in_chore_timezone() {
+    let server = common::create_test_app().await;
+
+    let body = serde_json::json!({
+        "name": "Morning walk",
+        "schedule_type": "cron",
+        "cron_schedule": "0 9 * * *",
+        "timezone": "Asia/Kolkata"
+    });
+    let response = server.post("/api/chores").json(&body).await;
+    response.assert_status(StatusCode::CREATED);
+    let chore: ChoreResponse = response.json();
+
+    let response = server
+        .get(&format!(
+            "/api/chores/{}/next-occurrences?count=3",
+            chore.id
+        ))
+        .await;
+    response.assert_status_ok();
+    let occurrences: Vec<Occurrence> = response.json();
+
+    assert_eq!(occurrences.len(), 3);
+    for occurrence in &occurrences {
+        // 9am in Kolkata (UTC+05:30) is 03:30 UTC
+        assert_eq!(occurrence.at.format("%H:%M").to_string(), "03:30");
+        let local = chrono::DateTime::parse_from_rfc3339(&occurrence.local).unwrap();
+        assert_eq!(local.format("%H:%M%:z").to_string(), "09:00+05:30");
+        assert_eq!(local, occurrence.at);
+    }
+}
+
+#[tokio::test]
+async fn test_next_occurrences_errors() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    server
+        .get(&format!(
+            "/api/chores/{}/next-occurrences?count=0",
+            chore.id
+        ))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+    server
+        .get(&format!("/api/chores/{}/next-occurrences", Uuid::new_v4()))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+}
+
+// ============================================================================
+// Get Chore (GET /api/chores/{id})
+// ============================================================================
+
+#[tokio::test]
+async fn test_get_chore_success() {
+    let server = common::create_test_app().await;
+
+    let created = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+
+    let response = server.get(&format!("/api/chores/{}", created.id)).await;
+
+    response.assert_status_ok();
+
+    let chore: ChoreResponse = response.json();
+    assert_eq!(chore.id, created.id);
+    assert_eq!(chore.name, "Vacuum");
+}
+
+#[tokio::test]
+async fn test_get_chore_not_found() {
+    let server = common::create_test_app().await;
+
+    let fake_id = Uuid::new_v4();
+    let response = server.get(&format!("/api/chores/{}", fake_id)).await;
+
+    response.assert_status(StatusCode::NOT_FOUND);
+
+    let problem: ProblemDetails = response.json();
+    assert_eq!(problem.status, Some(404));
+    assert!(problem.detail.unwrap().contains("not found"));
+    assert!(problem.title.is_some());
+}
+
+#[tokio::test]
+async fn test_get_chore_invalid_uuid() {
+    let server = common::create_test_app().await;
+
+    let response = server.get("/api/chores/not-a-uuid").await;
+
+    // Axum returns 400 for path parsing failures
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_get_chore_not_modified_until_changed() {
+    let server = common::create_test_app().await;
+
+    let created = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    let url = format!("/api/chores/{}", created.id);
+
+    let response = server.get(&url).await;
+    response.assert_status_ok();
+    let etag = response.header("etag");
+
+    let response = server
+        .get(&url)
+        .add_header("if-none-match", etag.clone())
+        .await;
+    response.assert_status(StatusCode::NOT_MODIFIED);
+    assert_eq!(response.header("etag"), etag);
+    assert!(response.as_bytes().is_empty());
+
+    // Completing the chore changes its last completion, not `updated_at`
+    common::complete_chore(&server, created.id, None).await;
+    let response = server
+        .get(&url)
+        .add_header("if-none-match", etag.clone())
+        .await;
+    response.assert_status_ok();
+    let completed_etag = response.header("etag");
+    assert_ne!(completed_etag, etag);
+
+    server
+        .put(&url)
+        .json(&serde_json::json!({ "name": "Vacuum upstairs" }))
+        .await
+        .assert_status_ok();
+    let response = server
+        .get(&url)
+        .add_header("if-none-match", completed_etag.clone())
+        .await;
+    response.assert_status_ok();
+    let chore: ChoreResponse = response.json();
+    assert_eq!(chore.name, "Vacuum upstairs");
+}
+
+// ============================================================================
+// Update Chore (PUT /api/chores/{id})
+// ============================================================================
+
+#[tokio::test]
+async fn test_update_chore_all_fields() {
+    let server = common::create_test_app().await;
+
+    let created = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+
+    let body = serde_json::json!({
+        "name": "Vacuum Thoroughly",
+        "description": "Including under furniture",
+        "schedule": {
+            "schedule_type": "cron",
+            "cron_schedule": "0 10 * * 0"
+        }
+    });
+
+    let response = server
+        .put(&format!("/api/chores/{}", created.id))
+        .json(&body)
+        .await;
+
+    response.assert_status_ok();
+
+    let chore: ChoreResponse = response.json();
+    assert_eq!(chore.name, "Vacuum Thoroughly");
+    assert_eq!(
+        chore.description,
+        Some("Including under furniture".to_string())
+    );
+    assert_eq!(chore.cron_schedule, Some("0 10 * * 0".to_string()));
+}
+
+#[tokio::test]
+async fn test_update_chore_partial() {
+    let server = common::create_test_app().await;
+
+    let created = common::create_cron_chore_with_description(
+        &server,
+        "Vacuum",
+        Some("Original"),
+        "0 9 * * 1",
+    )
+    .await;
+
+    // Only update name
+    let body = serde_json::json!({
+        "name": "Vacuum Thoroughly"
+    });
+
+    let response = server
+        .put(&format!("/api/chores/{}", created.id))
+        .json(&body)
+        .await;
+
+    response.assert_status_ok();
+
+    let chore: ChoreResponse = response.json();
+    assert_eq!(chore.name, "Vacuum Thoroughly");
+    // Other fields should be unchanged
+    assert_eq!(chore.description, Some("Original".to_string()));
+    assert_eq!(chore.cron_schedule, Some("0 9 * * 1".to_string()));
+}
+
+#[tokio::test]
+async fn test_update_chore_clear_description() {
+    let server = common::create_test_app().await;
+
+    let created = common::create_cron_chore_with_description(
+        &server,
+        "Vacuum",
+        Some("Original"),
+        "0 9 * * 1",
+    )
+    .await;
+
+    // Set description to null to clear it
+    let body = serde_json::json!({
+        "description": null
+    });
 
     let response = server
         .put(&format!("/api/chores/{}", created.id))
@@ -537,6 +1152,96 @@ async fn test_update_chore_updates_timestamp() {
     assert_eq!(updated.created_at, created.created_at);
 }
 
+// ============================================================================
+// Upsert by External ID (PUT /api/chores/by-external-id/{external_id})
+// ============================================================================
+
+#[tokio::test]
+async fn test_upsert_by_external_id_creates_then_updates() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .put("/api/chores/by-external-id/sync-42")
+        .json(&serde_json::json!({
+            "name": "Vacuum",
+            "description": "Living room",
+            "schedule_type": "cron",
+            "cron_schedule": "0 9 * * 1"
+        }))
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let created: ChoreResponse = response.json();
+    assert_eq!(created.external_id.as_deref(), Some("sync-42"));
+
+    let response = server
+        .put("/api/chores/by-external-id/sync-42")
+        .json(&serde_json::json!({
+            "name": "Vacuum upstairs",
+            "schedule_type": "interval",
+            "interval_days": 3
+        }))
+        .await;
+    response.assert_status_ok();
+    let updated: ChoreResponse = response.json();
+
+    assert_eq!(updated.id, created.id);
+    assert_eq!(updated.name, "Vacuum upstairs");
+    assert_eq!(updated.schedule_type, "interval");
+    assert_eq!(updated.interval_days, Some(3));
+    assert_eq!(updated.cron_schedule, None);
+    // Omitted fields are reset, as the body replaces the chore
+    assert_eq!(updated.description, None);
+    assert_eq!(updated.external_id.as_deref(), Some("sync-42"));
+
+    let chores: PaginatedResponse<ChoreResponse> = server.get("/api/chores").await.json();
+    assert_eq!(chores.items.len(), 1);
+}
+
+#[tokio::test]
+async fn test_upsert_by_external_id_keeps_ids_separate() {
+    let server = common::create_test_app().await;
+
+    for external_id in ["a", "b"] {
+        server
+            .put(&format!("/api/chores/by-external-id/{external_id}"))
+            .json(&serde_json::json!({
+                "name": "Water plants",
+                "schedule_type": "interval",
+                "interval_days": 2
+            }))
+            .await
+            .assert_status(StatusCode::CREATED);
+    }
+
+    let chores: PaginatedResponse<ChoreResponse> = server.get("/api/chores").await.json();
+    assert_eq!(chores.items.len(), 2);
+}
+
+#[tokio::test]
+async fn test_upsert_by_external_id_validates_schedule() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .put("/api/chores/by-external-id/sync-1")
+        .json(&serde_json::json!({
+            "name": "Vacuum",
+            "schedule_type": "cron",
+            "cron_schedule": "not a valid cron"
+        }))
+        .await;
+
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_create_chore_has_no_external_id() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+
+    assert_eq!(chore.external_id, None);
+}
+
 // ============================================================================
 // Delete Chore (DELETE /api/chores/{id})
 // ============================================================================
@@ -567,7 +1272,7 @@ async fn test_delete_chore_not_found() {
 }
 
 #[tokio::test]
-async fn test_delete_chore_also_deletes_completions() {
+async fn test_purge_chore_also_deletes_completions() {
     let server = common::create_test_app().await;
 
     let created = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
@@ -576,16 +1281,253 @@ async fn test_delete_chore_also_deletes_completions() {
     common::complete_chore(&server, created.id, Some("Done")).await;
     common::complete_chore(&server, created.id, None).await;
 
-    // Delete the chore
-    let response = server.delete(&format!("/api/chores/{}", created.id)).await;
-    response.assert_status(StatusCode::NO_CONTENT);
+    // Purge the chore
+    let response = server
+        .delete(&format!("/api/chores/{}?purge=true", created.id))
+        .await;
+    response.assert_status(StatusCode::NO_CONTENT);
 
     // Completions should be gone too (foreign key cascade)
-    // We can verify by trying to get the chore's completions - should 404
-    let completions_response = server
+    let feed: PaginatedResponse<CompletionResponse> = server.get("/api/completions").await.json();
+    assert!(feed.items.is_empty());
+
+    // Restoring or purging a purged chore finds nothing
+    server
+        .post(&format!("/api/chores/{}/restore", created.id))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+    server
+        .delete(&format!("/api/chores/{}?purge=true", created.id))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_deleted_chore_moves_to_trash_and_restores_with_completions() {
+    let server = common::create_test_app().await;
+
+    let created = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    let kept = common::create_chore(&server, "Dishes", "0 9 * * *").await;
+    let completion = common::complete_chore(&server, created.id, Some("Done")).await;
+
+    server
+        .delete(&format!("/api/chores/{}", created.id))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+
+    // Hidden from lists, due chores and the completion feed, and its
+    // completions can't be edited or deleted
+    let list: PaginatedResponse<ChoreResponse> = server.get("/api/chores").await.json();
+    assert_eq!(
+        list.items.iter().map(|c| c.id).collect::<Vec<_>>(),
+        vec![kept.id]
+    );
+    let due: Vec<ChoreWithDueResponse> = server
+        .get("/api/chores/due?include_upcoming=true")
+        .await
+        .json();
+    assert!(due.iter().all(|c| c.id != created.id));
+    let feed: PaginatedResponse<CompletionResponse> = server.get("/api/completions").await.json();
+    assert!(feed.items.is_empty());
+    server
+        .put(&format!("/api/completions/{}", completion.id))
+        .json(&serde_json::json!({ "notes": "Edited" }))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+    server
+        .delete(&format!("/api/completions/{}", completion.id))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    // Deleting it again finds nothing, but it shows in the trash
+    server
+        .delete(&format!("/api/chores/{}", created.id))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+    let trash: Vec<serde_json::Value> = server.get("/api/chores/trash").await.json();
+    assert_eq!(trash.len(), 1);
+    assert_eq!(trash[0]["id"], created.id.to_string());
+    assert!(trash[0]["deleted_at"].is_string());
+
+    let response = server
+        .post(&format!("/api/chores/{}/restore", created.id))
+        .await;
+    response.assert_status_ok();
+    let restored: ChoreResponse = response.json();
+    assert_eq!(restored.id, created.id);
+    assert!(restored.last_completed_at.is_some());
+
+    let completions: PaginatedResponse<CompletionResponse> = server
         .get(&format!("/api/chores/{}/completions", created.id))
+        .await
+        .json();
+    assert_eq!(completions.items.len(), 1);
+    assert_eq!(completions.items[0].notes.as_deref(), Some("Done"));
+    let trash: Vec<serde_json::Value> = server.get("/api/chores/trash").await.json();
+    assert!(trash.is_empty());
+}
+
+// ============================================================================
+// Priority
+// ============================================================================
+
+#[tokio::test]
+async fn test_chore_priority_defaults_to_normal_and_updates() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    assert_eq!(chore.priority, "normal");
+
+    let response = server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&serde_json::json!({ "priority": "high" }))
+        .await;
+    response.assert_status_ok();
+    let updated: ChoreResponse = response.json();
+    assert_eq!(updated.priority, "high");
+
+    let due: Vec<ChoreWithDueResponse> = server
+        .get("/api/chores/due?include_upcoming=true")
+        .await
+        .json();
+    assert_eq!(due[0].priority, "high");
+}
+
+#[tokio::test]
+async fn test_create_chore_with_priority() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .post("/api/chores")
+        .json(&serde_json::json!({
+            "name": "Take out trash",
+            "schedule_type": "cron",
+            "cron_schedule": "0 20 * * 0",
+            "priority": "low"
+        }))
+        .await;
+
+    response.assert_status(StatusCode::CREATED);
+    let chore: ChoreResponse = response.json();
+    assert_eq!(chore.priority, "low");
+}
+
+#[tokio::test]
+async fn test_invalid_priority_returns_400() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .post("/api/chores")
+        .json(&serde_json::json!({
+            "name": "Take out trash",
+            "schedule_type": "cron",
+            "cron_schedule": "0 20 * * 0",
+            "priority": "urgent"
+        }))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+    let problem: ProblemDetails = response.json();
+    assert!(problem.detail.unwrap().contains("urgent"));
+
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&serde_json::json!({ "priority": "HIGH" }))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+}
+
+// ============================================================================
+// Anchor Mode
+// ============================================================================
+
+#[tokio::test]
+async fn test_chore_anchor_mode_defaults_to_from_completion_and_updates() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_interval_chore(&server, "Water plants", 3).await;
+    assert_eq!(chore.anchor_mode, "from_completion");
+
+    let response = server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&serde_json::json!({ "anchor_mode": "from_schedule" }))
+        .await;
+    response.assert_status_ok();
+    let updated: ChoreResponse = response.json();
+    assert_eq!(updated.anchor_mode, "from_schedule");
+
+    let due: Vec<ChoreWithDueResponse> = server
+        .get("/api/chores/due?include_upcoming=true")
+        .await
+        .json();
+    assert_eq!(due[0].anchor_mode, "from_schedule");
+}
+
+#[tokio::test]
+async fn test_from_schedule_chore_completed_early_keeps_its_schedule() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .post("/api/chores")
+        .json(&serde_json::json!({
+            "name": "Water plants",
+            "schedule_type": "interval",
+            "interval_days": 3,
+            "anchor_mode": "from_schedule"
+        }))
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let chore: ChoreResponse = response.json();
+    assert_eq!(chore.anchor_mode, "from_schedule");
+
+    let first_due = (chore.created_at + chrono::Duration::days(3))
+        .date_naive()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+        .and_utc();
+    common::complete_chore_at(&server, chore.id, first_due - chrono::Duration::days(1)).await;
+
+    let due: Vec<ChoreWithDueResponse> = server
+        .get("/api/chores/due?include_upcoming=true")
+        .await
+        .json();
+    assert_eq!(due[0].next_due, Some(first_due + chrono::Duration::days(3)));
+}
+
+#[tokio::test]
+async fn test_invalid_anchor_mode_returns_400() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .post("/api/chores")
+        .json(&serde_json::json!({
+            "name": "Water plants",
+            "schedule_type": "interval",
+            "interval_days": 3,
+            "anchor_mode": "from_calendar"
+        }))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+    let problem: ProblemDetails = response.json();
+    assert!(problem.detail.unwrap().contains("from_calendar"));
+
+    // Only cron and interval chores follow a schedule laid out in advance
+    let response = server
+        .post("/api/chores")
+        .json(&serde_json::json!({
+            "name": "Call grandma",
+            "schedule_type": "once_in_a_while",
+            "anchor_mode": "from_schedule"
+        }))
         .await;
-    completions_response.assert_status(StatusCode::NOT_FOUND);
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    let chore = common::create_once_in_a_while_chore(&server, "Call grandma", None).await;
+    server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&serde_json::json!({ "anchor_mode": "from_schedule" }))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
 }
 
 // ============================================================================
@@ -684,6 +1626,58 @@ async fn test_due_chores_response_includes_due_info() {
     assert!(chore.updated_at <= chrono::Utc::now());
 }
 
+/// Daily chore 9 days overdue and monthly chore 10 days overdue.
+async fn create_overdue_daily_and_monthly(
+    server: &axum_test::TestServer,
+) -> (ChoreResponse, ChoreResponse) {
+    let now = chrono::Utc::now();
+    let daily = common::create_interval_chore(server, "Dishes", 1).await;
+    common::complete_chore_at(server, daily.id, now - chrono::Duration::days(10)).await;
+    let monthly = common::create_interval_chore(server, "Descale kettle", 30).await;
+    common::complete_chore_at(server, monthly.id, now - chrono::Duration::days(40)).await;
+    (daily, monthly)
+}
+
+#[tokio::test]
+async fn test_due_chores_sort_overdue_desc() {
+    let server = common::create_test_app().await;
+
+    let (daily, monthly) = create_overdue_daily_and_monthly(&server).await;
+    let upcoming = common::create_interval_chore(&server, "Mow lawn", 7).await;
+
+    let response = server
+        .get("/api/chores/due?include_upcoming=true&sort=overdue_desc")
+        .await;
+    response.assert_status_ok();
+
+    let chores: Vec<ChoreWithDueResponse> = response.json();
+    let ids: Vec<Uuid> = chores.iter().map(|c| c.id).collect();
+    assert_eq!(ids, vec![monthly.id, daily.id, upcoming.id]);
+}
+
+#[tokio::test]
+async fn test_due_chores_sort_priority() {
+    let server = common::create_test_app().await;
+
+    let (daily, monthly) = create_overdue_daily_and_monthly(&server).await;
+
+    // Nine missed days of a daily chore outrank ten days on a monthly one
+    let response = server.get("/api/chores/due?sort=priority").await;
+    response.assert_status_ok();
+
+    let chores: Vec<ChoreWithDueResponse> = response.json();
+    let ids: Vec<Uuid> = chores.iter().map(|c| c.id).collect();
+    assert_eq!(ids, vec![daily.id, monthly.id]);
+}
+
+#[tokio::test]
+async fn test_due_chores_invalid_sort() {
+    let server = common::create_test_app().await;
+
+    let response = server.get("/api/chores/due?sort=alphabetical").await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
 // ============================================================================
 // Complete Chore (POST /api/chores/{id}/complete)
 // ============================================================================
@@ -708,6 +1702,10 @@ async fn test_complete_chore_success() {
     assert!(completion.notes.is_none());
     assert!(completion.completed_at <= chrono::Utc::now());
     assert!(completion.created_at <= chrono::Utc::now());
+    assert_eq!(
+        response.header("location"),
+        format!("/api/completions/{}", completion.id)
+    );
 }
 
 #[tokio::test]
@@ -763,251 +1761,2520 @@ async fn test_complete_chore_with_custom_timestamp() {
     assert!(completion.created_at <= chrono::Utc::now());
 }
 
+/// Create a test server that gives API completions without notes a default
+async fn create_note_template_app() -> axum_test::TestServer {
+    let pool = nag_server::db::create_pool("sqlite::memory:")
+        .await
+        .expect("Failed to create test database pool");
+    let app = nag_server::http::build_test_app(pool).layer(axum::Extension(
+        CompletionNoteTemplate("Completed via web at {time}".to_string()),
+    ));
+
+    axum_test::TestServer::new(app.into_make_service()).expect("Failed to create test server")
+}
+
 #[tokio::test]
-async fn test_complete_chore_not_found() {
-    let server = common::create_test_app().await;
+async fn test_complete_chore_without_notes_uses_note_template() {
+    let server = create_note_template_app().await;
 
-    let fake_id = Uuid::new_v4();
-    let body = serde_json::json!({});
+    let created = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
 
     let response = server
-        .post(&format!("/api/chores/{}/complete", fake_id))
-        .json(&body)
+        .post(&format!("/api/chores/{}/complete", created.id))
+        .json(&serde_json::json!({ "completed_at": "2024-01-15T10:30:00Z" }))
         .await;
 
-    response.assert_status(StatusCode::NOT_FOUND);
+    response.assert_status(StatusCode::CREATED);
+    let completion: common::CompletionResponse = response.json();
+    assert_eq!(
+        completion.notes.as_deref(),
+        Some("Completed via web at 2024-01-15 10:30 UTC")
+    );
 }
 
-// ============================================================================
-// List Completions (GET /api/chores/{id}/completions)
-// ============================================================================
-
 #[tokio::test]
-async fn test_interval_chore_due_after_completion() {
-    let server = common::create_test_app().await;
+async fn test_complete_chore_with_notes_ignores_note_template() {
+    let server = create_note_template_app().await;
 
-    // Create an interval chore using the helper
-    let chore = common::create_interval_chore(&server, "Water plants", 7).await;
-    assert_eq!(chore.schedule_type, "interval");
-    assert_eq!(chore.interval_days, Some(7));
-
-    // Complete it now
-    common::complete_chore(&server, chore.id, None).await;
+    let created = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
 
-    // Check due chores - should not be due immediately after completion
-    let response = server.get("/api/chores/due").await;
-    let due_chores: Vec<ChoreWithDueResponse> = response.json();
+    let response = server
+        .post(&format!("/api/chores/{}/complete", created.id))
+        .json(&serde_json::json!({ "notes": "Used the new vacuum" }))
+        .await;
 
-    // Should not be overdue since just completed
-    let our_chore = due_chores.iter().find(|c| c.id == chore.id);
-    if let Some(our_chore) = our_chore {
-        assert!(!our_chore.is_overdue);
-        assert!(our_chore.next_due.is_some());
-    }
+    response.assert_status(StatusCode::CREATED);
+    let completion: common::CompletionResponse = response.json();
+    assert_eq!(completion.notes.as_deref(), Some("Used the new vacuum"));
 }
 
-// ============================================================================
-// List Completions (GET /api/chores/{id}/completions)
-// ============================================================================
-
 #[tokio::test]
-async fn test_list_completions_empty() {
+async fn test_complete_chore_not_found() {
     let server = common::create_test_app().await;
 
-    let created = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    let fake_id = Uuid::new_v4();
+    let body = serde_json::json!({});
 
     let response = server
-        .get(&format!("/api/chores/{}/completions", created.id))
+        .post(&format!("/api/chores/{}/complete", fake_id))
+        .json(&body)
         .await;
 
-    response.assert_status_ok();
-
-    let body: PaginatedResponse<common::CompletionResponse> = response.json();
-    assert!(body.items.is_empty());
-    assert!(body.next_cursor.is_none());
+    response.assert_status(StatusCode::NOT_FOUND);
 }
 
 #[tokio::test]
-async fn test_list_completions_with_items() {
+async fn test_complete_chore_and_snooze_next_occurrence() {
     let server = common::create_test_app().await;
 
-    let created = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    let created = common::create_interval_chore(&server, "Water plants", 2).await;
+    let snooze_until = chrono::Utc::now() + chrono::Duration::days(5);
 
-    // Add some completions
-    common::complete_chore(&server, created.id, Some("First")).await;
-    common::complete_chore(&server, created.id, Some("Second")).await;
+    let body = serde_json::json!({
+        "snooze_next_until": snooze_until
+    });
 
     let response = server
-        .get(&format!("/api/chores/{}/completions", created.id))
+        .post(&format!("/api/chores/{}/complete", created.id))
+        .json(&body)
         .await;
 
+    response.assert_status(StatusCode::CREATED);
+
+    let completion: common::CompletionResponse = response.json();
+    assert_eq!(completion.chore_id, created.id);
+    let snoozed_until = completion.snoozed_until.expect("snoozed_until set");
+    assert_eq!(snoozed_until.timestamp(), snooze_until.timestamp());
+
+    let response = server.get("/api/chores/due?include_upcoming=true").await;
     response.assert_status_ok();
+    let chores: Vec<ChoreWithDueResponse> = response.json();
+    let chore = chores.iter().find(|c| c.id == created.id).unwrap();
 
-    let body: PaginatedResponse<common::CompletionResponse> = response.json();
-    assert_eq!(body.items.len(), 2);
+    assert_eq!(chore.last_completed_at, Some(completion.completed_at));
+    // Pushed back from the regular occurrence two days after the completion
+    let next_due = chore.next_due.unwrap();
+    assert_eq!(next_due.timestamp(), snooze_until.timestamp());
+    assert!(next_due > completion.completed_at + chrono::Duration::days(2));
 }
 
 #[tokio::test]
-async fn test_list_completions_pagination() {
+async fn test_complete_chore_without_snooze_omits_snoozed_until() {
     let server = common::create_test_app().await;
 
-    let created = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    let created = common::create_interval_chore(&server, "Water plants", 2).await;
+    let completion = common::complete_chore(&server, created.id, None).await;
 
-    // Add 5 completions
-    for _ in 0..5 {
-        common::complete_chore(&server, created.id, None).await;
-    }
+    assert!(completion.snoozed_until.is_none());
+}
 
-    // Get first page with limit
-    let response = server
-        .get(&format!("/api/chores/{}/completions?limit=2", created.id))
-        .await;
+#[tokio::test]
+async fn test_complete_chore_snooze_before_completion() {
+    let server = common::create_test_app().await;
 
-    let body: PaginatedResponse<common::CompletionResponse> = response.json();
-    assert_eq!(body.items.len(), 2);
-    assert!(body.next_cursor.is_some());
+    let created = common::create_interval_chore(&server, "Water plants", 2).await;
+
+    let body = serde_json::json!({
+        "completed_at": "2024-01-15T10:30:00Z",
+        "snooze_next_until": "2024-01-15T09:00:00Z"
+    });
+
+    let response = server
+        .post(&format!("/api/chores/{}/complete", created.id))
+        .json(&body)
+        .await;
+
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    // Nothing should have been recorded
+    let response = server
+        .get(&format!("/api/chores/{}/completions", created.id))
+        .await;
+    response.assert_status_ok();
+    let page: PaginatedResponse<common::CompletionResponse> = response.json();
+    assert!(page.items.is_empty());
 }
 
 #[tokio::test]
-async fn test_list_completions_chore_not_found() {
+async fn test_complete_chore_records_api_source() {
     let server = common::create_test_app().await;
+    let chore = common::create_interval_chore(&server, "Water plants", 3).await;
+
+    let completion = common::complete_chore(&server, chore.id, None).await;
+
+    assert_eq!(completion.source, "api");
+}
+
+#[tokio::test]
+async fn test_complete_chore_disallowed_source() {
+    let server = common::create_test_app().await;
+
+    let body = serde_json::json!({
+        "name": "Take out trash",
+        "schedule_type": "interval",
+        "interval_days": 3,
+        "allowed_completion_sources": ["telegram"]
+    });
+    let response = server.post("/api/chores").json(&body).await;
+    response.assert_status(StatusCode::CREATED);
+    let chore: ChoreResponse = response.json();
+    assert_eq!(
+        chore.allowed_completion_sources,
+        Some(vec!["telegram".to_string()])
+    );
 
-    let fake_id = Uuid::new_v4();
     let response = server
-        .get(&format!("/api/chores/{}/completions", fake_id))
+        .post(&format!("/api/chores/{}/complete", chore.id))
+        .json(&serde_json::json!({}))
         .await;
+    response.assert_status(StatusCode::FORBIDDEN);
+    assert_eq!(completion_count(&server, chore.id).await, 0);
 
-    response.assert_status(StatusCode::NOT_FOUND);
+    // Allowing the API again lifts the restriction
+    server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&serde_json::json!({ "allowed_completion_sources": ["api", "telegram", "api"] }))
+        .await
+        .assert_status_ok();
+    common::complete_chore(&server, chore.id, None).await;
+}
+
+#[tokio::test]
+async fn test_create_chore_empty_completion_sources() {
+    let server = common::create_test_app().await;
+
+    let body = serde_json::json!({
+        "name": "Take out trash",
+        "schedule_type": "interval",
+        "interval_days": 3,
+        "allowed_completion_sources": []
+    });
+    let response = server.post("/api/chores").json(&body).await;
+
+    response.assert_status(StatusCode::BAD_REQUEST);
 }
 
 // ============================================================================
-// Once In A While Schedule
+// Bulk Complete Due (POST /api/chores/due/complete)
 // ============================================================================
 
+/// Create one overdue chore and one that is not yet due
+async fn create_overdue_and_upcoming(server: &axum_test::TestServer) -> (Uuid, Uuid) {
+    let overdue = common::create_interval_chore(server, "Water plants", 1).await;
+    common::complete_chore_at(
+        server,
+        overdue.id,
+        chrono::Utc::now() - chrono::Duration::days(10),
+    )
+    .await;
+    let upcoming = common::create_interval_chore(server, "Mow lawn", 7).await;
+    (overdue.id, upcoming.id)
+}
+
 #[tokio::test]
-async fn test_create_once_in_a_while_chore() {
+async fn test_complete_due_chores_dry_run_writes_nothing() {
     let server = common::create_test_app().await;
+    let (overdue, upcoming) = create_overdue_and_upcoming(&server).await;
 
-    let chore =
-        common::create_once_in_a_while_chore(&server, "Clean the attic", Some("When needed")).await;
+    let response = server
+        .post("/api/chores/due/complete")
+        .json(&serde_json::json!({ "dry_run": true }))
+        .await;
+    response.assert_status_ok();
+    let body: serde_json::Value = response.json();
 
-    assert_eq!(chore.name, "Clean the attic");
-    assert_eq!(chore.description.as_deref(), Some("When needed"));
-    assert_eq!(chore.schedule_type, "once_in_a_while");
-    assert!(chore.cron_schedule.is_none());
-    assert!(chore.interval_days.is_none());
-    assert!(chore.interval_time_hour.is_none());
-    assert!(chore.interval_time_minute.is_none());
+    assert_eq!(body["dry_run"], true);
+    let chores = body["chores"].as_array().unwrap();
+    assert_eq!(chores.len(), 1);
+    assert_eq!(chores[0]["chore_id"], overdue.to_string());
+    assert!(chores[0]["completed_at"].is_string());
+    assert!(chores[0]["completion_id"].is_null());
+
+    assert_eq!(completion_count(&server, overdue).await, 1);
+    assert_eq!(completion_count(&server, upcoming).await, 0);
 }
 
 #[tokio::test]
-async fn test_once_in_a_while_chore_appears_in_list() {
+async fn test_complete_due_chores_records_completions() {
     let server = common::create_test_app().await;
+    let (overdue, upcoming) = create_overdue_and_upcoming(&server).await;
 
-    let chore = common::create_once_in_a_while_chore(&server, "Organize garage", None).await;
-
-    let response = server.get("/api/chores").await;
+    let completed_at = chrono::Utc::now() - chrono::Duration::hours(1);
+    let response = server
+        .post("/api/chores/due/complete")
+        .json(&serde_json::json!({ "completed_at": completed_at }))
+        .await;
     response.assert_status_ok();
+    let body: serde_json::Value = response.json();
 
-    let body: PaginatedResponse<ChoreResponse> = response.json();
-    assert_eq!(body.items.len(), 1);
-    assert_eq!(body.items[0].id, chore.id);
-    assert_eq!(body.items[0].schedule_type, "once_in_a_while");
+    assert_eq!(body["dry_run"], false);
+    let chores = body["chores"].as_array().unwrap();
+    assert_eq!(chores.len(), 1);
+    assert_eq!(chores[0]["chore_id"], overdue.to_string());
+    assert!(chores[0]["completion_id"].is_string());
+
+    assert_eq!(completion_count(&server, overdue).await, 2);
+    assert_eq!(completion_count(&server, upcoming).await, 0);
+
+    // Nothing is overdue any more
+    let due: Vec<ChoreWithDueResponse> = server.get("/api/chores/due").await.json();
+    assert!(due.is_empty());
 }
 
+// ============================================================================
+// List Completions (GET /api/chores/{id}/completions)
+// ============================================================================
+
 #[tokio::test]
-async fn test_once_in_a_while_chore_in_due_endpoint() {
+async fn test_interval_chore_due_after_completion() {
     let server = common::create_test_app().await;
 
-    common::create_once_in_a_while_chore(&server, "Clean gutters", None).await;
+    // Create an interval chore using the helper
+    let chore = common::create_interval_chore(&server, "Water plants", 7).await;
+    assert_eq!(chore.schedule_type, "interval");
+    assert_eq!(chore.interval_days, Some(7));
 
-    // With include_upcoming=true, once_in_a_while chores should appear
-    let response = server.get("/api/chores/due?include_upcoming=true").await;
-    response.assert_status_ok();
+    // Complete it now
+    common::complete_chore(&server, chore.id, None).await;
 
-    let body: Vec<ChoreWithDueResponse> = response.json();
-    assert_eq!(body.len(), 1);
-    assert_eq!(body[0].schedule_type, "once_in_a_while");
-    assert!(body[0].next_due.is_none());
-    assert!(!body[0].is_overdue);
+    // Check due chores - should not be due immediately after completion
+    let response = server.get("/api/chores/due").await;
+    let due_chores: Vec<ChoreWithDueResponse> = response.json();
+
+    // Should not be overdue since just completed
+    let our_chore = due_chores.iter().find(|c| c.id == chore.id);
+    if let Some(our_chore) = our_chore {
+        assert!(!our_chore.is_overdue);
+        assert!(our_chore.next_due.is_some());
+    }
 }
 
+// ============================================================================
+// List Completions (GET /api/chores/{id}/completions)
+// ============================================================================
+
 #[tokio::test]
-async fn test_once_in_a_while_chore_not_overdue() {
+async fn test_list_completions_empty() {
     let server = common::create_test_app().await;
 
-    common::create_once_in_a_while_chore(&server, "Deep clean fridge", None).await;
+    let created = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+
+    let response = server
+        .get(&format!("/api/chores/{}/completions", created.id))
+        .await;
 
-    // Without include_upcoming, once_in_a_while chores should NOT appear
-    // (they are not overdue)
-    let response = server.get("/api/chores/due?include_upcoming=false").await;
     response.assert_status_ok();
 
-    let body: Vec<ChoreWithDueResponse> = response.json();
-    assert!(body.is_empty());
+    let body: PaginatedResponse<common::CompletionResponse> = response.json();
+    assert!(body.items.is_empty());
+    assert!(body.next_cursor.is_none());
 }
 
 #[tokio::test]
-async fn test_once_in_a_while_chore_can_be_completed() {
+async fn test_list_completions_with_items() {
     let server = common::create_test_app().await;
 
-    let chore = common::create_once_in_a_while_chore(&server, "Sort photo albums", None).await;
+    let created = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
 
-    let completion = common::complete_chore(&server, chore.id, Some("Finally done!")).await;
-    assert_eq!(completion.chore_id, chore.id);
-    assert_eq!(completion.notes.as_deref(), Some("Finally done!"));
+    // Add some completions
+    common::complete_chore(&server, created.id, Some("First")).await;
+    common::complete_chore(&server, created.id, Some("Second")).await;
+
+    let response = server
+        .get(&format!("/api/chores/{}/completions", created.id))
+        .await;
 
-    // Verify last_completed_at is set
-    let response = server.get(&format!("/api/chores/{}", chore.id)).await;
     response.assert_status_ok();
-    let updated: ChoreResponse = response.json();
-    assert!(updated.last_completed_at.is_some());
+
+    let body: PaginatedResponse<common::CompletionResponse> = response.json();
+    assert_eq!(body.items.len(), 2);
 }
 
 #[tokio::test]
-async fn test_update_chore_to_once_in_a_while() {
+async fn test_list_completions_pagination() {
     let server = common::create_test_app().await;
 
-    // Create a normal interval chore
-    let chore = common::create_interval_chore(&server, "Mow the lawn", 14).await;
-    assert_eq!(chore.schedule_type, "interval");
+    let created = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
 
-    // Switch to once_in_a_while
+    // Add 5 completions
+    for _ in 0..5 {
+        common::complete_chore(&server, created.id, None).await;
+    }
+
+    // Get first page with limit
     let response = server
-        .put(&format!("/api/chores/{}", chore.id))
-        .json(&serde_json::json!({
-            "schedule": {
-                "schedule_type": "once_in_a_while"
-            }
-        }))
+        .get(&format!("/api/chores/{}/completions?limit=2", created.id))
         .await;
-    response.assert_status_ok();
 
-    let updated: ChoreResponse = response.json();
-    assert_eq!(updated.schedule_type, "once_in_a_while");
-    assert!(updated.cron_schedule.is_none());
-    assert!(updated.interval_days.is_none());
+    let body: PaginatedResponse<common::CompletionResponse> = response.json();
+    assert_eq!(body.items.len(), 2);
+    assert!(body.next_cursor.is_some());
+    assert_eq!(
+        next_link(&response).expect("link header"),
+        format!(
+            "/api/chores/{}/completions?limit=2&cursor={}",
+            created.id,
+            body.next_cursor.unwrap()
+        )
+    );
 }
 
 #[tokio::test]
-async fn test_update_once_in_a_while_to_interval() {
+async fn test_list_completions_pagination_follows_completion_order() {
     let server = common::create_test_app().await;
 
-    let chore = common::create_once_in_a_while_chore(&server, "Paint fence", None).await;
+    let created = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
 
-    // Switch to interval
+    // Record completions out of chronological order, two sharing a timestamp
+    let mut expected = Vec::new();
+    for day in [3, 1, 5, 2, 4, 2] {
+        let completed_at = chrono::Utc::now() - chrono::Duration::days(day);
+        let completed_at = completed_at
+            .date_naive()
+            .and_hms_opt(9, 0, 0)
+            .unwrap()
+            .and_utc();
+        let completion = common::complete_chore_at(&server, created.id, completed_at).await;
+        expected.push((completion.completed_at, completion.id));
+    }
+    // Newest first, ties by id
+    expected.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+    let mut seen = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let url = match &cursor {
+            Some(cursor) => format!(
+                "/api/chores/{}/completions?limit=2&cursor={}",
+                created.id, cursor
+            ),
+            None => format!("/api/chores/{}/completions?limit=2", created.id),
+        };
+        let page: PaginatedResponse<common::CompletionResponse> = server.get(&url).await.json();
+        if page.items.is_empty() {
+            break;
+        }
+        seen.extend(page.items.iter().map(|c| (c.completed_at, c.id)));
+        cursor = page.next_cursor;
+    }
+
+    assert_eq!(seen, expected);
+}
+
+#[tokio::test]
+async fn test_list_completions_in_time_range() {
+    let server = common::create_test_app().await;
+
+    let created = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    let start = chrono::Utc::now()
+        .date_naive()
+        .and_hms_opt(9, 0, 0)
+        .unwrap()
+        .and_utc()
+        - chrono::Duration::days(10);
+    let mut in_range = Vec::new();
+    for day in 0..6 {
+        let completion =
+            common::complete_chore_at(&server, created.id, start + chrono::Duration::days(day))
+                .await;
+        if (1..=4).contains(&day) {
+            in_range.push(completion.id);
+        }
+    }
+    // Newest first
+    in_range.reverse();
+
+    let from =
+        (start + chrono::Duration::days(1)).to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+    let to = (start + chrono::Duration::days(4)).to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+
+    // Bounds are inclusive, and pages stay within the range
+    let mut seen = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let mut request = server
+            .get(&format!("/api/chores/{}/completions", created.id))
+            .add_query_param("from", &from)
+            .add_query_param("to", &to)
+            .add_query_param("limit", 3);
+        if let Some(cursor) = &cursor {
+            request = request.add_query_param("cursor", cursor);
+        }
+        let page: PaginatedResponse<common::CompletionResponse> = request.await.json();
+        if page.items.is_empty() {
+            break;
+        }
+        seen.extend(page.items.iter().map(|c| c.id));
+        cursor = page.next_cursor;
+    }
+    assert_eq!(seen, in_range);
+
+    // An open-ended range
+    let page: PaginatedResponse<common::CompletionResponse> = server
+        .get(&format!("/api/chores/{}/completions", created.id))
+        .add_query_param("from", &to)
+        .await
+        .json();
+    assert_eq!(page.items.len(), 2);
+}
+
+#[tokio::test]
+async fn test_list_completions_range_from_after_to() {
+    let server = common::create_test_app().await;
+
+    let created = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
     let response = server
-        .put(&format!("/api/chores/{}", chore.id))
-        .json(&serde_json::json!({
-            "schedule": {
-                "schedule_type": "interval",
-                "interval_days": 90
-            }
-        }))
+        .get(&format!("/api/chores/{}/completions", created.id))
+        .add_query_param("from", "2025-06-10T00:00:00Z")
+        .add_query_param("to", "2025-06-01T00:00:00Z")
+        .await;
+
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_list_completions_chore_not_found() {
+    let server = common::create_test_app().await;
+
+    let fake_id = Uuid::new_v4();
+    let response = server
+        .get(&format!("/api/chores/{}/completions", fake_id))
+        .await;
+
+    response.assert_status(StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_list_completions_classify() {
+    let server = common::create_test_app().await;
+    let chore =
+        common::create_interval_chore_with_time(&server, "Water plants", None, 1, Some(9), None)
+            .await;
+
+    let at = |s: &str| s.parse::<chrono::DateTime<chrono::Utc>>().unwrap();
+    common::complete_chore_at(&server, chore.id, at("2026-01-10T12:00:00Z")).await;
+    // Due 2026-01-11 09:00: done exactly then
+    let on_time = common::complete_chore_at(&server, chore.id, at("2026-01-11T09:00:00Z")).await;
+    // Due 2026-01-12 09:00: done the evening before
+    let early = common::complete_chore_at(&server, chore.id, at("2026-01-11T20:00:00Z")).await;
+    // Due 2026-01-12 09:00: done a day and an hour later
+    let late = common::complete_chore_at(&server, chore.id, at("2026-01-13T10:00:00Z")).await;
+
+    let response = server
+        .get(&format!(
+            "/api/chores/{}/completions?classify=true",
+            chore.id
+        ))
         .await;
     response.assert_status_ok();
+    let body: PaginatedResponse<common::CompletionResponse> = response.json();
+    let find = |id| body.items.iter().find(|c| c.id == id).unwrap();
+
+    assert_eq!(find(on_time.id).timing.as_deref(), Some("on_time"));
+    assert_eq!(find(on_time.id).lateness_seconds, Some(0));
+    assert_eq!(find(early.id).timing.as_deref(), Some("early"));
+    assert_eq!(find(early.id).lateness_seconds, Some(-13 * 3600));
+    assert_eq!(find(late.id).timing.as_deref(), Some("late"));
+    assert_eq!(find(late.id).lateness_seconds, Some(25 * 3600));
+}
 
-    let updated: ChoreResponse = response.json();
-    assert_eq!(updated.schedule_type, "interval");
-    assert_eq!(updated.interval_days, Some(90));
+#[tokio::test]
+async fn test_list_completions_classify_uses_previous_page() {
+    let server = common::create_test_app().await;
+    let chore =
+        common::create_interval_chore_with_time(&server, "Water plants", None, 1, Some(9), None)
+            .await;
+
+    let at = |s: &str| s.parse::<chrono::DateTime<chrono::Utc>>().unwrap();
+    common::complete_chore_at(&server, chore.id, at("2026-01-10T12:00:00Z")).await;
+    common::complete_chore_at(&server, chore.id, at("2026-01-11T09:30:00Z")).await;
+
+    // Only the newest completion is on this page; its occurrence still
+    // follows the older one
+    let response = server
+        .get(&format!(
+            "/api/chores/{}/completions?classify=true&limit=1",
+            chore.id
+        ))
+        .await;
+    let body: PaginatedResponse<common::CompletionResponse> = response.json();
+    assert_eq!(body.items.len(), 1);
+    assert_eq!(body.items[0].timing.as_deref(), Some("on_time"));
+    assert_eq!(body.items[0].lateness_seconds, Some(1800));
+}
+
+#[tokio::test]
+async fn test_list_completions_unclassified_by_default() {
+    let server = common::create_test_app().await;
+    let chore = common::create_interval_chore(&server, "Water plants", 1).await;
+    common::complete_chore(&server, chore.id, None).await;
+
+    let response = server
+        .get(&format!("/api/chores/{}/completions", chore.id))
+        .await;
+    let body: serde_json::Value = response.json();
+    assert!(body["items"][0].get("timing").is_none());
+    assert!(body["items"][0].get("lateness_seconds").is_none());
+}
+
+#[tokio::test]
+async fn test_list_completions_expand_chore() {
+    let server = common::create_test_app().await;
+    let chore = common::create_interval_chore(&server, "Water plants", 3).await;
+    let other = common::create_chore(&server, "Vacuum", "0 9 * * *").await;
+    common::complete_chore(&server, chore.id, None).await;
+    common::complete_chore(&server, chore.id, None).await;
+    common::complete_chore(&server, other.id, None).await;
+
+    let response = server
+        .get(&format!(
+            "/api/chores/{}/completions?expand=chore",
+            chore.id
+        ))
+        .await;
+    response.assert_status_ok();
+    let body: serde_json::Value = response.json();
+    let items = body["items"].as_array().unwrap();
+
+    assert_eq!(items.len(), 2);
+    for item in items {
+        assert_eq!(
+            item["chore"],
+            serde_json::json!({
+                "id": chore.id,
+                "name": "Water plants",
+                "schedule_type": "interval",
+            })
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_list_completions_without_expand_omits_chore() {
+    let server = common::create_test_app().await;
+    let chore = common::create_interval_chore(&server, "Water plants", 3).await;
+    common::complete_chore(&server, chore.id, None).await;
+
+    let response = server
+        .get(&format!("/api/chores/{}/completions", chore.id))
+        .await;
+    let body: serde_json::Value = response.json();
+    assert!(body["items"][0].get("chore").is_none());
+}
+
+#[tokio::test]
+async fn test_list_completions_unknown_expand() {
+    let server = common::create_test_app().await;
+    let chore = common::create_interval_chore(&server, "Water plants", 3).await;
+
+    let response = server
+        .get(&format!("/api/chores/{}/completions?expand=tags", chore.id))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+// ============================================================================
+// Notification Lead Time
+// ============================================================================
+
+#[tokio::test]
+async fn test_create_chore_with_notify_lead() {
+    let server = common::create_test_app().await;
+
+    let body = serde_json::json!({
+        "name": "Trash pickup",
+        "schedule_type": "cron",
+        "cron_schedule": "0 7 * * 2",
+        "notify_lead_minutes": 720
+    });
+
+    let response = server.post("/api/chores").json(&body).await;
+
+    response.assert_status(StatusCode::CREATED);
+
+    let chore: ChoreResponse = response.json();
+    assert_eq!(chore.notify_lead_minutes, Some(720));
+}
+
+#[tokio::test]
+async fn test_create_chore_negative_notify_lead() {
+    let server = common::create_test_app().await;
+
+    let body = serde_json::json!({
+        "name": "Trash pickup",
+        "schedule_type": "interval",
+        "interval_days": 7,
+        "notify_lead_minutes": -5
+    });
+
+    let response = server.post("/api/chores").json(&body).await;
+
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    let problem: ProblemDetails = response.json();
+    assert!(
+        problem
+            .detail
+            .unwrap()
+            .contains("Invalid notification lead time")
+    );
+}
+
+#[tokio::test]
+async fn test_create_chore_notify_lead_exceeds_interval() {
+    let server = common::create_test_app().await;
+
+    // Two days of lead on a daily chore
+    let body = serde_json::json!({
+        "name": "Dishes",
+        "schedule_type": "interval",
+        "interval_days": 1,
+        "notify_lead_minutes": 2880
+    });
+
+    let response = server.post("/api/chores").json(&body).await;
+
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_create_chore_notify_lead_exceeds_one_week() {
+    let server = common::create_test_app().await;
+
+    // Yearly chore: the interval allows it, but the one-week cap does not
+    let body = serde_json::json!({
+        "name": "Renew insurance",
+        "schedule_type": "cron",
+        "cron_schedule": "0 9 1 1 *",
+        "notify_lead_minutes": 10081
+    });
+
+    let response = server.post("/api/chores").json(&body).await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    let body = serde_json::json!({
+        "name": "Renew insurance",
+        "schedule_type": "cron",
+        "cron_schedule": "0 9 1 1 *",
+        "notify_lead_minutes": 10080
+    });
+
+    let response = server.post("/api/chores").json(&body).await;
+    response.assert_status(StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn test_update_chore_notify_lead_checked_against_existing_schedule() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_interval_chore(&server, "Dishes", 1).await;
+
+    let body = serde_json::json!({ "notify_lead_minutes": 2880 });
+    let response = server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&body)
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    let body = serde_json::json!({ "notify_lead_minutes": 60 });
+    let response = server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&body)
+        .await;
+    response.assert_status_ok();
+    let updated: ChoreResponse = response.json();
+    assert_eq!(updated.notify_lead_minutes, Some(60));
+
+    // Clearing with null
+    let body = serde_json::json!({ "notify_lead_minutes": null });
+    let response = server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&body)
+        .await;
+    response.assert_status_ok();
+    let updated: ChoreResponse = response.json();
+    assert!(updated.notify_lead_minutes.is_none());
+}
+
+// ============================================================================
+// Notification Recipient
+// ============================================================================
+
+#[tokio::test]
+async fn test_create_and_update_chore_recipient() {
+    let server = common::create_test_app().await;
+
+    let body = serde_json::json!({
+        "name": "Water plants",
+        "schedule_type": "interval",
+        "interval_days": 3,
+        "recipient": " partner "
+    });
+
+    let response = server.post("/api/chores").json(&body).await;
+    response.assert_status(StatusCode::CREATED);
+    let chore: ChoreResponse = response.json();
+    assert_eq!(chore.recipient.as_deref(), Some("partner"));
+
+    let body = serde_json::json!({ "recipient": null });
+    let response = server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&body)
+        .await;
+    response.assert_status_ok();
+    let updated: ChoreResponse = response.json();
+    assert!(updated.recipient.is_none());
+}
+
+#[tokio::test]
+async fn test_create_chore_empty_recipient() {
+    let server = common::create_test_app().await;
+
+    let body = serde_json::json!({
+        "name": "Water plants",
+        "schedule_type": "interval",
+        "interval_days": 3,
+        "recipient": "  "
+    });
+
+    let response = server.post("/api/chores").json(&body).await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+// ============================================================================
+// Chore Stats (GET /api/chores/{id}/stats)
+// ============================================================================
+
+#[tokio::test]
+async fn test_chore_stats_empty() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_interval_chore(&server, "Water plants", 7).await;
+
+    let response = server.get(&format!("/api/chores/{}/stats", chore.id)).await;
+
+    response.assert_status_ok();
+
+    let stats: common::ChoreStatsResponse = response.json();
+    assert_eq!(stats.chore_id, chore.id);
+    assert_eq!(stats.total_completions, 0);
+    assert_eq!(stats.current_streak, 0);
+    assert_eq!(stats.longest_streak, 0);
+    assert_eq!(stats.completions_last_30_days, 0);
+    assert_eq!(stats.average_interval_seconds, None);
+    assert_eq!(stats.longest_gap_seconds, None);
+    assert_eq!(stats.on_time_completions, 0);
+    assert_eq!(stats.late_completions, 0);
+}
+
+#[tokio::test]
+async fn test_chore_stats_interval_streaks() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_interval_chore(&server, "Water plants", 7).await;
+    let now = chrono::Utc::now();
+
+    // An old completion, then a gap that breaks the streak, then three on time
+    for days_ago in [60, 20, 14, 7] {
+        common::complete_chore_at(&server, chore.id, now - chrono::Duration::days(days_ago)).await;
+    }
+
+    let response = server.get(&format!("/api/chores/{}/stats", chore.id)).await;
+
+    response.assert_status_ok();
+
+    let stats: common::ChoreStatsResponse = response.json();
+    assert_eq!(stats.total_completions, 4);
+    assert_eq!(stats.current_streak, 3);
+    assert_eq!(stats.longest_streak, 3);
+    assert_eq!(stats.completions_last_30_days, 3);
+}
+
+#[tokio::test]
+async fn test_chore_stats_broken_current_streak() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_chore(&server, "Daily task", "0 9 * * *").await;
+    let now = chrono::Utc::now();
+
+    // Two consecutive days, long ago — the streak has since lapsed
+    for days_ago in [11, 10] {
+        common::complete_chore_at(&server, chore.id, now - chrono::Duration::days(days_ago)).await;
+    }
+
+    let response = server.get(&format!("/api/chores/{}/stats", chore.id)).await;
+
+    response.assert_status_ok();
+
+    let stats: common::ChoreStatsResponse = response.json();
+    assert_eq!(stats.total_completions, 2);
+    assert_eq!(stats.current_streak, 0);
+    assert_eq!(stats.longest_streak, 2);
+}
+
+#[tokio::test]
+async fn test_chore_stats_gaps_and_punctuality() {
+    let server = common::create_test_app().await;
+
+    let chore =
+        common::create_interval_chore_with_time(&server, "Water plants", None, 2, Some(9), None)
+            .await;
+
+    let at = |s: &str| s.parse::<chrono::DateTime<chrono::Utc>>().unwrap();
+    // Due every two days at 09:00 after the previous completion: the second
+    // completion is on time, the third a day late
+    for completed_at in [
+        "2026-01-10T09:00:00Z",
+        "2026-01-12T09:00:00Z",
+        "2026-01-15T09:00:00Z",
+    ] {
+        common::complete_chore_at(&server, chore.id, at(completed_at)).await;
+    }
+
+    let response = server.get(&format!("/api/chores/{}/stats", chore.id)).await;
+
+    response.assert_status_ok();
+
+    let stats: common::ChoreStatsResponse = response.json();
+    assert_eq!(stats.average_interval_seconds, Some(60 * 3600));
+    assert_eq!(stats.longest_gap_seconds, Some(3 * 24 * 3600));
+    assert_eq!(stats.late_completions, 1);
+    assert_eq!(stats.on_time_completions, 2);
+}
+
+#[tokio::test]
+async fn test_chore_stats_not_found() {
+    let server = common::create_test_app().await;
+
+    let fake_id = Uuid::new_v4();
+    let response = server.get(&format!("/api/chores/{}/stats", fake_id)).await;
+
+    response.assert_status(StatusCode::NOT_FOUND);
+}
+
+// ============================================================================
+// On-Time Streaks
+// ============================================================================
+
+/// The schedule entry of a chore
+async fn schedule_entry(server: &axum_test::TestServer, id: Uuid) -> ChoreWithDueResponse {
+    let response = server.get("/api/chores/schedule").await;
+    response.assert_status_ok();
+    let chores: Vec<ChoreWithDueResponse> = response.json();
+    chores.into_iter().find(|c| c.id == id).unwrap()
+}
+
+#[tokio::test]
+async fn test_due_chore_on_time_streaks() {
+    let server = common::create_test_app().await;
+    let chore =
+        common::create_interval_chore_with_time(&server, "Water plants", None, 1, Some(9), None)
+            .await;
+
+    let at = |s: &str| s.parse::<chrono::DateTime<chrono::Utc>>().unwrap();
+    // Daily at 09:00 after the previous completion: early, on time, later on
+    // the due day, a day late, then on time again
+    for completed_at in [
+        "2026-01-10T08:00:00Z",
+        "2026-01-11T09:00:00Z",
+        "2026-01-12T20:00:00Z",
+        "2026-01-14T09:00:00Z",
+        "2026-01-15T10:00:00Z",
+    ] {
+        common::complete_chore_at(&server, chore.id, at(completed_at)).await;
+    }
+
+    let entry = schedule_entry(&server, chore.id).await;
+    assert_eq!(entry.best_streak, 3);
+    assert_eq!(entry.current_streak, 1);
+}
+
+#[tokio::test]
+async fn test_due_chore_streak_without_completions() {
+    let server = common::create_test_app().await;
+    let chore = common::create_interval_chore(&server, "Water plants", 7).await;
+
+    let entry = schedule_entry(&server, chore.id).await;
+    assert_eq!(entry.current_streak, 0);
+    assert_eq!(entry.best_streak, 0);
+}
+
+#[tokio::test]
+async fn test_once_in_a_while_completions_are_always_on_time() {
+    let server = common::create_test_app().await;
+    let chore = common::create_once_in_a_while_chore(&server, "Clean windows", None).await;
+
+    let now = chrono::Utc::now();
+    for days_ago in [90, 30, 1] {
+        common::complete_chore_at(&server, chore.id, now - chrono::Duration::days(days_ago)).await;
+    }
+
+    let response = server.get("/api/chores/due?include_upcoming=true").await;
+    let chores: Vec<ChoreWithDueResponse> = response.json();
+    let entry = chores.iter().find(|c| c.id == chore.id).unwrap();
+    assert_eq!(entry.current_streak, 3);
+    assert_eq!(entry.best_streak, 3);
+}
+
+// ============================================================================
+// Once In A While Schedule
+// ============================================================================
+
+#[tokio::test]
+async fn test_create_once_in_a_while_chore() {
+    let server = common::create_test_app().await;
+
+    let chore =
+        common::create_once_in_a_while_chore(&server, "Clean the attic", Some("When needed")).await;
+
+    assert_eq!(chore.name, "Clean the attic");
+    assert_eq!(chore.description.as_deref(), Some("When needed"));
+    assert_eq!(chore.schedule_type, "once_in_a_while");
+    assert!(chore.cron_schedule.is_none());
+    assert!(chore.interval_days.is_none());
+    assert!(chore.interval_time_hour.is_none());
+    assert!(chore.interval_time_minute.is_none());
+}
+
+#[tokio::test]
+async fn test_once_in_a_while_chore_appears_in_list() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_once_in_a_while_chore(&server, "Organize garage", None).await;
+
+    let response = server.get("/api/chores").await;
+    response.assert_status_ok();
+
+    let body: PaginatedResponse<ChoreResponse> = response.json();
+    assert_eq!(body.items.len(), 1);
+    assert_eq!(body.items[0].id, chore.id);
+    assert_eq!(body.items[0].schedule_type, "once_in_a_while");
+}
+
+#[tokio::test]
+async fn test_once_in_a_while_chore_in_due_endpoint() {
+    let server = common::create_test_app().await;
+
+    common::create_once_in_a_while_chore(&server, "Clean gutters", None).await;
+
+    // With include_upcoming=true, once_in_a_while chores should appear
+    let response = server.get("/api/chores/due?include_upcoming=true").await;
+    response.assert_status_ok();
+
+    let body: Vec<ChoreWithDueResponse> = response.json();
+    assert_eq!(body.len(), 1);
+    assert_eq!(body[0].schedule_type, "once_in_a_while");
+    assert!(body[0].next_due.is_none());
+    assert!(!body[0].is_overdue);
+}
+
+#[tokio::test]
+async fn test_once_in_a_while_chore_not_overdue() {
+    let server = common::create_test_app().await;
+
+    common::create_once_in_a_while_chore(&server, "Deep clean fridge", None).await;
+
+    // Without include_upcoming, once_in_a_while chores should NOT appear
+    // (they are not overdue)
+    let response = server.get("/api/chores/due?include_upcoming=false").await;
+    response.assert_status_ok();
+
+    let body: Vec<ChoreWithDueResponse> = response.json();
+    assert!(body.is_empty());
+}
+
+#[tokio::test]
+async fn test_once_in_a_while_chore_can_be_completed() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_once_in_a_while_chore(&server, "Sort photo albums", None).await;
+
+    let completion = common::complete_chore(&server, chore.id, Some("Finally done!")).await;
+    assert_eq!(completion.chore_id, chore.id);
+    assert_eq!(completion.notes.as_deref(), Some("Finally done!"));
+
+    // Verify last_completed_at is set
+    let response = server.get(&format!("/api/chores/{}", chore.id)).await;
+    response.assert_status_ok();
+    let updated: ChoreResponse = response.json();
+    assert!(updated.last_completed_at.is_some());
+}
+
+#[tokio::test]
+async fn test_update_chore_to_once_in_a_while() {
+    let server = common::create_test_app().await;
+
+    // Create a normal interval chore
+    let chore = common::create_interval_chore(&server, "Mow the lawn", 14).await;
+    assert_eq!(chore.schedule_type, "interval");
+
+    // Switch to once_in_a_while
+    let response = server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&serde_json::json!({
+            "schedule": {
+                "schedule_type": "once_in_a_while"
+            }
+        }))
+        .await;
+    response.assert_status_ok();
+
+    let updated: ChoreResponse = response.json();
+    assert_eq!(updated.schedule_type, "once_in_a_while");
+    assert!(updated.cron_schedule.is_none());
+    assert!(updated.interval_days.is_none());
+}
+
+#[tokio::test]
+async fn test_update_once_in_a_while_to_interval() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_once_in_a_while_chore(&server, "Paint fence", None).await;
+
+    // Switch to interval
+    let response = server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&serde_json::json!({
+            "schedule": {
+                "schedule_type": "interval",
+                "interval_days": 90
+            }
+        }))
+        .await;
+    response.assert_status_ok();
+
+    let updated: ChoreResponse = response.json();
+    assert_eq!(updated.schedule_type, "interval");
+    assert_eq!(updated.interval_days, Some(90));
+}
+
+// ============================================================================
+// Days Of Week Schedule
+// ============================================================================
+
+#[tokio::test]
+async fn test_create_days_of_week_chore() {
+    let server = common::create_test_app().await;
+
+    // Friday listed before Tuesday; stored sorted
+    let chore =
+        common::create_days_of_week_chore(&server, "Take out trash", &[5, 2], Some(7), Some(30))
+            .await;
+
+    assert_eq!(chore.schedule_type, "days_of_week");
+    assert_eq!(chore.days_of_week, Some(vec![2, 5]));
+    assert_eq!(chore.interval_time_hour, Some(7));
+    assert_eq!(chore.interval_time_minute, Some(30));
+    assert!(chore.cron_schedule.is_none());
+    assert!(chore.interval_days.is_none());
+}
+
+#[tokio::test]
+async fn test_create_weekly_chore_alias() {
+    let server = common::create_test_app().await;
+
+    let body = serde_json::json!({
+        "name": "Water plants",
+        "schedule_type": "weekly",
+        "weekdays": [1, 4],
+        "time_hour": 9,
+        "time_minute": 0
+    });
+
+    let response = server.post("/api/chores").json(&body).await;
+    response.assert_status(StatusCode::CREATED);
+    let chore: ChoreResponse = response.json();
+
+    assert_eq!(chore.schedule_type, "days_of_week");
+    assert_eq!(chore.days_of_week, Some(vec![1, 4]));
+    assert_eq!(chore.interval_time_hour, Some(9));
+}
+
+#[tokio::test]
+async fn test_days_of_week_chore_next_due_is_selected_weekday() {
+    use chrono::{Datelike, Timelike};
+
+    let server = common::create_test_app().await;
+
+    let chore =
+        common::create_days_of_week_chore(&server, "Take out trash", &[2, 5], Some(7), Some(30))
+            .await;
+    let completion = common::complete_chore(&server, chore.id, None).await;
+
+    let response = server.get("/api/chores/due?include_upcoming=true").await;
+    response.assert_status_ok();
+    let chores: Vec<ChoreWithDueResponse> = response.json();
+    let due = chores[0].next_due.unwrap();
+
+    assert_eq!(chores[0].days_of_week, Some(vec![2, 5]));
+    assert!([2, 5].contains(&due.weekday().num_days_from_sunday()));
+    assert!(due.date_naive() > completion.completed_at.date_naive());
+    assert!(due.date_naive() <= completion.completed_at.date_naive() + chrono::Duration::days(4));
+    assert_eq!((due.hour(), due.minute()), (7, 30));
+    assert!(!chores[0].is_overdue);
+}
+
+#[tokio::test]
+async fn test_create_days_of_week_chore_invalid_days() {
+    let server = common::create_test_app().await;
+
+    for days in [vec![], vec![7], vec![1, 3, 1]] {
+        let body = serde_json::json!({
+            "name": "Take out trash",
+            "schedule_type": "days_of_week",
+            "days": days
+        });
+
+        let response = server.post("/api/chores").json(&body).await;
+        response.assert_status(StatusCode::BAD_REQUEST);
+
+        let problem: ProblemDetails = response.json();
+        assert!(
+            problem
+                .detail
+                .unwrap()
+                .contains("Invalid days of week schedule")
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_update_chore_to_days_of_week() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_interval_chore(&server, "Water plants", 3).await;
+
+    let body = serde_json::json!({
+        "schedule": {
+            "schedule_type": "days_of_week",
+            "days": [0, 3]
+        }
+    });
+
+    let response = server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&body)
+        .await;
+    response.assert_status_ok();
+
+    let updated: ChoreResponse = response.json();
+    assert_eq!(updated.schedule_type, "days_of_week");
+    assert_eq!(updated.days_of_week, Some(vec![0, 3]));
+    assert!(updated.interval_days.is_none());
+}
+
+#[tokio::test]
+async fn test_days_of_week_notify_lead_limited_by_shortest_gap() {
+    let server = common::create_test_app().await;
+
+    // Monday and Tuesday are one day apart
+    let body = serde_json::json!({
+        "name": "Take out trash",
+        "schedule_type": "days_of_week",
+        "days": [1, 2],
+        "notify_lead_minutes": 24 * 60 + 1
+    });
+
+    let response = server.post("/api/chores").json(&body).await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+// ============================================================================
+// One-Time Schedule
+// ============================================================================
+
+#[tokio::test]
+async fn test_create_one_time_chore() {
+    let server = common::create_test_app().await;
+    let due_at = chrono::Utc::now() + chrono::Duration::days(3);
+
+    let chore = common::create_one_time_chore(&server, "Renew passport", due_at).await;
+
+    assert_eq!(chore.schedule_type, "one_time");
+    assert_eq!(chore.due_at, Some(due_at));
+    assert!(chore.cron_schedule.is_none());
+    assert!(chore.interval_days.is_none());
+
+    let response = server.get("/api/chores/due?include_upcoming=true").await;
+    response.assert_status_ok();
+
+    let body: Vec<ChoreWithDueResponse> = response.json();
+    assert_eq!(body.len(), 1);
+    assert_eq!(body[0].next_due, Some(due_at));
+    assert!(!body[0].is_overdue);
+}
+
+#[tokio::test]
+async fn test_create_one_time_chore_in_past_rejected() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .post("/api/chores")
+        .json(&serde_json::json!({
+            "name": "Renew passport",
+            "schedule_type": "one_time",
+            "due_at": chrono::Utc::now() - chrono::Duration::hours(1)
+        }))
+        .await;
+
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_completed_one_time_chore_is_never_due_again() {
+    let server = common::create_test_app().await;
+    let due_at = chrono::Utc::now() + chrono::Duration::days(1);
+
+    let chore = common::create_one_time_chore(&server, "Renew passport", due_at).await;
+    common::complete_chore(&server, chore.id, None).await;
+
+    let response = server.get("/api/chores/due?include_upcoming=true").await;
+    response.assert_status_ok();
+
+    let body: Vec<ChoreWithDueResponse> = response.json();
+    assert!(body.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_chore_to_one_time() {
+    let server = common::create_test_app().await;
+    let due_at = chrono::Utc::now() + chrono::Duration::days(7);
+
+    let chore = common::create_interval_chore(&server, "Renew passport", 30).await;
+
+    let response = server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&serde_json::json!({
+            "schedule": {
+                "schedule_type": "one_time",
+                "due_at": due_at
+            }
+        }))
+        .await;
+    response.assert_status_ok();
+
+    let updated: ChoreResponse = response.json();
+    assert_eq!(updated.schedule_type, "one_time");
+    assert_eq!(updated.due_at, Some(due_at));
+    assert!(updated.interval_days.is_none());
+}
+
+// ============================================================================
+// Auto-Complete Links
+// ============================================================================
+
+async fn create_linked_chore(
+    server: &axum_test::TestServer,
+    name: &str,
+    auto_complete_with: Uuid,
+) -> ChoreResponse {
+    let body = serde_json::json!({
+        "name": name,
+        "schedule_type": "interval",
+        "interval_days": 1,
+        "auto_complete_with": auto_complete_with
+    });
+
+    let response = server.post("/api/chores").json(&body).await;
+    response.assert_status(StatusCode::CREATED);
+    response.json()
+}
+
+async fn completion_count(server: &axum_test::TestServer, chore_id: Uuid) -> usize {
+    let response = server
+        .get(&format!("/api/chores/{}/completions", chore_id))
+        .await;
+    response.assert_status_ok();
+    let page: PaginatedResponse<common::CompletionResponse> = response.json();
+    page.items.len()
+}
+
+#[tokio::test]
+async fn test_completing_chore_auto_completes_linked_chore() {
+    let server = common::create_test_app().await;
+
+    let run = common::create_interval_chore(&server, "Run the dishwasher", 1).await;
+    let reset = create_linked_chore(&server, "Reset the dishwasher", run.id).await;
+    assert_eq!(reset.auto_complete_with, Some(run.id));
+
+    let completion = common::complete_chore(&server, run.id, None).await;
+
+    let response = server
+        .get(&format!("/api/chores/{}/completions", reset.id))
+        .await;
+    response.assert_status_ok();
+    let page: PaginatedResponse<common::CompletionResponse> = response.json();
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].completed_at, completion.completed_at);
+    assert_eq!(completion_count(&server, run.id).await, 1);
+}
+
+#[tokio::test]
+async fn test_auto_complete_is_not_recursive() {
+    let server = common::create_test_app().await;
+
+    let a = common::create_interval_chore(&server, "A", 1).await;
+    let b = create_linked_chore(&server, "B", a.id).await;
+    let c = create_linked_chore(&server, "C", b.id).await;
+
+    common::complete_chore(&server, a.id, None).await;
+
+    assert_eq!(completion_count(&server, a.id).await, 1);
+    assert_eq!(completion_count(&server, b.id).await, 1);
+    assert_eq!(completion_count(&server, c.id).await, 0);
+
+    // Completing the dependent directly does not complete its source
+    common::complete_chore(&server, b.id, None).await;
+    assert_eq!(completion_count(&server, a.id).await, 1);
+    assert_eq!(completion_count(&server, b.id).await, 2);
+    assert_eq!(completion_count(&server, c.id).await, 1);
+}
+
+#[tokio::test]
+async fn test_auto_complete_link_rejects_cycles() {
+    let server = common::create_test_app().await;
+
+    let a = common::create_interval_chore(&server, "A", 1).await;
+    let b = create_linked_chore(&server, "B", a.id).await;
+    let c = create_linked_chore(&server, "C", b.id).await;
+
+    for (chore, target) in [(a.id, c.id), (a.id, b.id), (a.id, a.id)] {
+        let body = serde_json::json!({ "auto_complete_with": target });
+        let response = server
+            .put(&format!("/api/chores/{}", chore))
+            .json(&body)
+            .await;
+        response.assert_status(StatusCode::BAD_REQUEST);
+    }
+}
+
+#[tokio::test]
+async fn test_auto_complete_link_unknown_chore() {
+    let server = common::create_test_app().await;
+
+    let body = serde_json::json!({
+        "name": "Reset the dishwasher",
+        "schedule_type": "interval",
+        "interval_days": 1,
+        "auto_complete_with": Uuid::new_v4()
+    });
+
+    let response = server.post("/api/chores").json(&body).await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_auto_complete_link_can_be_cleared() {
+    let server = common::create_test_app().await;
+
+    let run = common::create_interval_chore(&server, "Run the dishwasher", 1).await;
+    let reset = create_linked_chore(&server, "Reset the dishwasher", run.id).await;
+
+    let body = serde_json::json!({ "auto_complete_with": null });
+    let response = server
+        .put(&format!("/api/chores/{}", reset.id))
+        .json(&body)
+        .await;
+    response.assert_status_ok();
+    let updated: ChoreResponse = response.json();
+    assert!(updated.auto_complete_with.is_none());
+
+    common::complete_chore(&server, run.id, None).await;
+    assert_eq!(completion_count(&server, reset.id).await, 0);
+}
+
+// ============================================================================
+// Pause / Resume (POST /api/chores/{id}/pause, /resume)
+// ============================================================================
+
+#[tokio::test]
+async fn test_pause_chore_hides_it_from_due_and_list() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_interval_chore(&server, "Mow lawn", 7).await;
+    assert!(chore.is_active);
+
+    let response = server
+        .post(&format!("/api/chores/{}/pause", chore.id))
+        .await;
+    response.assert_status_ok();
+    let paused: ChoreResponse = response.json();
+    assert!(!paused.is_active);
+
+    let response = server.get("/api/chores/due?include_upcoming=true").await;
+    let due: Vec<ChoreWithDueResponse> = response.json();
+    assert!(due.is_empty());
+
+    let response = server.get("/api/chores").await;
+    let page: PaginatedResponse<ChoreResponse> = response.json();
+    assert!(page.items.is_empty());
+
+    let response = server.get("/api/chores?include_paused=true").await;
+    let page: PaginatedResponse<ChoreResponse> = response.json();
+    assert_eq!(page.items.len(), 1);
+    assert!(!page.items[0].is_active);
+}
+
+#[tokio::test]
+async fn test_resume_chore_restores_it() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_interval_chore(&server, "Mow lawn", 7).await;
+    server
+        .post(&format!("/api/chores/{}/pause", chore.id))
+        .await
+        .assert_status_ok();
+
+    let response = server
+        .post(&format!("/api/chores/{}/resume", chore.id))
+        .await;
+    response.assert_status_ok();
+    let resumed: ChoreResponse = response.json();
+    assert!(resumed.is_active);
+
+    let response = server.get("/api/chores/due?include_upcoming=true").await;
+    let due: Vec<ChoreWithDueResponse> = response.json();
+    assert_eq!(due.len(), 1);
+}
+
+#[tokio::test]
+async fn test_create_chore_disabled() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .post("/api/chores")
+        .json(&serde_json::json!({
+            "name": "Clean gutters",
+            "schedule_type": "interval",
+            "interval_days": 30,
+            "enabled": false
+        }))
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let chore: ChoreResponse = response.json();
+    assert!(!chore.is_active);
+
+    let response = server.get("/api/chores/due?include_upcoming=true").await;
+    let due: Vec<ChoreWithDueResponse> = response.json();
+    assert!(due.is_empty());
+
+    // Still fetchable and completable while disabled
+    server
+        .get(&format!("/api/chores/{}", chore.id))
+        .await
+        .assert_status_ok();
+    common::complete_chore(&server, chore.id, None).await;
+}
+
+#[tokio::test]
+async fn test_update_chore_enabled() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_interval_chore(&server, "Mow lawn", 7).await;
+
+    let response = server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&serde_json::json!({ "enabled": false }))
+        .await;
+    response.assert_status_ok();
+    let updated: ChoreResponse = response.json();
+    assert!(!updated.is_active);
+
+    let response = server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&serde_json::json!({ "is_active": true }))
+        .await;
+    response.assert_status_ok();
+    let updated: ChoreResponse = response.json();
+    assert!(updated.is_active);
+}
+
+#[tokio::test]
+async fn test_pause_chore_not_found() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .post(&format!("/api/chores/{}/pause", Uuid::new_v4()))
+        .await;
+    response.assert_status(StatusCode::NOT_FOUND);
+}
+
+// ============================================================================
+// Skip (POST /api/chores/{id}/skip)
+// ============================================================================
+
+/// Next due time of the only chore in the due list
+async fn only_next_due(server: &axum_test::TestServer) -> chrono::DateTime<chrono::Utc> {
+    let response = server.get("/api/chores/due?include_upcoming=true").await;
+    response.assert_status_ok();
+    let due: Vec<ChoreWithDueResponse> = response.json();
+    assert_eq!(due.len(), 1);
+    due[0].next_due.expect("next due")
+}
+
+#[tokio::test]
+async fn test_skip_interval_chore_advances_next_due() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_interval_chore(&server, "Water plants", 3).await;
+    let first = only_next_due(&server).await;
+
+    let response = server.post(&format!("/api/chores/{}/skip", chore.id)).await;
+    response.assert_status_ok();
+    let skipped: ChoreWithDueResponse = response.json();
+    assert_eq!(skipped.next_due, Some(first + chrono::Duration::days(3)));
+    assert!(skipped.last_completed_at.is_none());
+
+    assert_eq!(
+        only_next_due(&server).await,
+        first + chrono::Duration::days(3)
+    );
+}
+
+#[tokio::test]
+async fn test_skip_cron_chore_advances_to_following_occurrence() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_chore(&server, "Morning check", "0 9 * * *").await;
+    let first = only_next_due(&server).await;
+
+    let response = server.post(&format!("/api/chores/{}/skip", chore.id)).await;
+    response.assert_status_ok();
+    let skipped: ChoreWithDueResponse = response.json();
+    assert_eq!(skipped.next_due, Some(first + chrono::Duration::days(1)));
+}
+
+#[tokio::test]
+async fn test_skip_is_not_counted_as_a_completion() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_interval_chore(&server, "Water plants", 3).await;
+    server
+        .post(&format!("/api/chores/{}/skip", chore.id))
+        .await
+        .assert_status_ok();
+
+    let completions: PaginatedResponse<CompletionResponse> = server
+        .get(&format!("/api/chores/{}/completions", chore.id))
+        .await
+        .json();
+    assert!(completions.items.is_empty());
+    let feed: PaginatedResponse<CompletionResponse> = server.get("/api/completions").await.json();
+    assert!(feed.items.is_empty());
+
+    let stats: common::ChoreStatsResponse = server
+        .get(&format!("/api/chores/{}/stats", chore.id))
+        .await
+        .json();
+    assert_eq!(stats.total_completions, 0);
+    assert_eq!(stats.completions_last_30_days, 0);
+}
+
+#[tokio::test]
+async fn test_skip_chore_not_found() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .post(&format!("/api/chores/{}/skip", Uuid::new_v4()))
+        .await;
+    response.assert_status(StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_skip_chore_without_pending_occurrence_conflicts() {
+    let server = common::create_test_app().await;
+
+    let whenever = common::create_once_in_a_while_chore(&server, "Clean windows", None).await;
+    let response = server
+        .post(&format!("/api/chores/{}/skip", whenever.id))
+        .await;
+    response.assert_status(StatusCode::CONFLICT);
+
+    // A skipped one-time chore has nothing left to skip
+    let once = common::create_one_time_chore(
+        &server,
+        "Renew passport",
+        chrono::Utc::now() + chrono::Duration::days(1),
+    )
+    .await;
+    server
+        .post(&format!("/api/chores/{}/skip", once.id))
+        .await
+        .assert_status_ok();
+    let response = server.post(&format!("/api/chores/{}/skip", once.id)).await;
+    response.assert_status(StatusCode::CONFLICT);
+}
+
+// ============================================================================
+// Calendar feed (GET /api/chores/calendar.ics)
+// ============================================================================
+
+#[tokio::test]
+async fn test_chores_calendar_feed() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_interval_chore(&server, "Water plants, herbs", 3).await;
+    common::create_once_in_a_while_chore(&server, "Clean windows", None).await;
+
+    let response = server.get("/api/chores/calendar.ics").await;
+    response.assert_status_ok();
+    assert!(
+        response
+            .header("content-type")
+            .to_str()
+            .unwrap()
+            .starts_with("text/calendar")
+    );
+
+    let body = response.text();
+    assert!(body.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(body.ends_with("END:VCALENDAR\r\n"));
+    assert_eq!(body.matches("BEGIN:VEVENT").count(), 1);
+    let due: Vec<ChoreWithDueResponse> = server
+        .get("/api/chores/due?include_upcoming=true")
+        .await
+        .json();
+    let start = due[0].next_due.unwrap().format("%Y%m%dT%H%M%SZ");
+    assert!(body.contains(&format!("UID:{}-{start}@nag", chore.id)));
+    assert!(body.contains(&format!("DTSTART:{start}")));
+    assert!(body.contains("SUMMARY:Water plants\\, herbs"));
+    assert!(!body.contains("Clean windows"));
+}
+
+// ============================================================================
+// Schedule (GET /api/chores/schedule)
+// ============================================================================
+
+#[tokio::test]
+async fn test_chore_schedule_lists_each_active_chore_once() {
+    let server = common::create_test_app().await;
+
+    let weekly = common::create_interval_chore(&server, "Vacuum", 7).await;
+    let daily = common::create_interval_chore(&server, "Dishes", 1).await;
+    let overdue = common::create_interval_chore(&server, "Laundry", 2).await;
+    let whenever = common::create_once_in_a_while_chore(&server, "Clean windows", None).await;
+    let paused = common::create_interval_chore(&server, "Mow lawn", 3).await;
+
+    common::complete_chore(&server, weekly.id, None).await;
+    common::complete_chore(&server, daily.id, None).await;
+    common::complete_chore_at(
+        &server,
+        overdue.id,
+        chrono::Utc::now() - chrono::Duration::days(5),
+    )
+    .await;
+    server
+        .post(&format!("/api/chores/{}/pause", paused.id))
+        .await
+        .assert_status_ok();
+
+    let response = server.get("/api/chores/schedule").await;
+    response.assert_status_ok();
+    let chores: Vec<ChoreWithDueResponse> = response.json();
+
+    let ids: Vec<Uuid> = chores.iter().map(|c| c.id).collect();
+    assert_eq!(ids, vec![overdue.id, daily.id, weekly.id, whenever.id]);
+    assert!(chores[0].is_overdue);
+    assert!(!chores[1].is_overdue);
+    assert!(chores[3].next_due.is_none());
+}
+
+// ============================================================================
+// Timezones
+// ============================================================================
+
+#[tokio::test]
+async fn test_cron_chore_due_in_its_timezone_across_dst() {
+    let server = common::create_test_app().await;
+
+    let body = serde_json::json!({
+        "name": "Morning walk",
+        "schedule_type": "cron",
+        "cron_schedule": "0 9 * * *",
+        "timezone": "America/New_York"
+    });
+    let response = server.post("/api/chores").json(&body).await;
+    response.assert_status(StatusCode::CREATED);
+    let chore: ChoreResponse = response.json();
+    assert_eq!(chore.timezone.as_deref(), Some("America/New_York"));
+
+    // Completed the day before the switch to daylight saving time: the next
+    // 9am local is 13:00 UTC rather than 14:00 UTC.
+    let completed_at = "2025-03-08T15:00:00Z".parse().unwrap();
+    common::complete_chore_at(&server, chore.id, completed_at).await;
+
+    let response = server.get("/api/chores/due").await;
+    let due: Vec<ChoreWithDueResponse> = response.json();
+    assert_eq!(
+        due[0].next_due,
+        Some("2025-03-09T13:00:00Z".parse().unwrap())
+    );
+}
+
+#[tokio::test]
+async fn test_create_chore_invalid_timezone() {
+    let server = common::create_test_app().await;
+
+    let body = serde_json::json!({
+        "name": "Morning walk",
+        "schedule_type": "cron",
+        "cron_schedule": "0 9 * * *",
+        "timezone": "Mars/Olympus_Mons"
+    });
+    let response = server.post("/api/chores").json(&body).await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    let problem: ProblemDetails = response.json();
+    assert!(problem.detail.unwrap().contains("Invalid timezone"));
+}
+
+#[tokio::test]
+async fn test_update_chore_timezone() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_chore(&server, "Morning walk", "0 9 * * *").await;
+    assert!(chore.timezone.is_none());
+
+    let response = server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&serde_json::json!({ "timezone": "Europe/Budapest" }))
+        .await;
+    response.assert_status_ok();
+    let updated: ChoreResponse = response.json();
+    assert_eq!(updated.timezone.as_deref(), Some("Europe/Budapest"));
+
+    let response = server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&serde_json::json!({ "timezone": "Nowhere/Special" }))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    let response = server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&serde_json::json!({ "timezone": null }))
+        .await;
+    response.assert_status_ok();
+    let updated: ChoreResponse = response.json();
+    assert!(updated.timezone.is_none());
+}
+
+// ============================================================================
+// Search (GET /api/chores/search)
+// ============================================================================
+
+#[tokio::test]
+async fn test_search_chores_matches_name_and_description() {
+    let server = common::create_test_app().await;
+
+    let by_name = common::create_chore(&server, "Water Plants", "0 9 * * *").await;
+    let by_description = common::create_cron_chore_with_description(
+        &server,
+        "Garden",
+        Some("water the tomatoes"),
+        "0 9 * * *",
+    )
+    .await;
+    common::create_chore(&server, "Vacuum", "0 9 * * *").await;
+
+    let response = server.get("/api/chores/search?q=WATER").await;
+    response.assert_status_ok();
+    let body: PaginatedResponse<ChoreResponse> = response.json();
+
+    let mut ids: Vec<Uuid> = body.items.iter().map(|c| c.id).collect();
+    ids.sort();
+    let mut expected = vec![by_name.id, by_description.id];
+    expected.sort();
+    assert_eq!(ids, expected);
+}
+
+#[tokio::test]
+async fn test_search_chores_pagination_and_tags() {
+    let server = common::create_test_app().await;
+
+    for i in 1..=3 {
+        common::create_chore_with_tags(
+            &server,
+            &format!("Dust shelf {}", i),
+            "0 9 * * *",
+            &["Living room"],
+        )
+        .await;
+    }
+
+    let response = server.get("/api/chores/search?q=dust&limit=2").await;
+    let first_page: PaginatedResponse<ChoreResponse> = response.json();
+    assert_eq!(first_page.items.len(), 2);
+    assert_eq!(first_page.items[0].tags[0].name, "Living room");
+
+    let response = server
+        .get(&format!(
+            "/api/chores/search?q=dust&limit=2&cursor={}",
+            first_page.next_cursor.unwrap()
+        ))
+        .await;
+    let second_page: PaginatedResponse<ChoreResponse> = response.json();
+    assert_eq!(second_page.items.len(), 1);
+}
+
+#[tokio::test]
+async fn test_search_chores_treats_wildcards_literally() {
+    let server = common::create_test_app().await;
+
+    common::create_chore(&server, "Refill 100% juice", "0 9 * * *").await;
+    common::create_chore(&server, "Refill juice", "0 9 * * *").await;
+
+    let response = server.get("/api/chores/search?q=0%25").await;
+    let body: PaginatedResponse<ChoreResponse> = response.json();
+    assert_eq!(body.items.len(), 1);
+    assert_eq!(body.items[0].name, "Refill 100% juice");
+}
+
+#[tokio::test]
+async fn test_search_chores_empty_query() {
+    let server = common::create_test_app().await;
+
+    let response = server.get("/api/chores/search?q=%20").await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+// ============================================================================
+// Ownership
+// ============================================================================
+
+#[tokio::test]
+async fn test_chores_are_scoped_to_their_owner() {
+    let (anonymous, pool) = common::create_test_app_with_pool().await;
+    let alice_id = common::create_user(&pool, "alice").await;
+    let bob_id = common::create_user(&pool, "bob").await;
+    let alice = common::create_test_app_as_user(pool.clone(), alice_id);
+    let bob = common::create_test_app_as_user(pool, bob_id);
+
+    let shared = common::create_interval_chore(&anonymous, "Shared", 1).await;
+    let alices = common::create_interval_chore(&alice, "Alice's", 1).await;
+    let bobs = common::create_interval_chore(&bob, "Bob's", 1).await;
+
+    // Each user sees their own chores plus unowned ones
+    let page: PaginatedResponse<ChoreResponse> =
+        alice.get("/api/chores?with_total=true").await.json();
+    let mut ids: Vec<_> = page.items.iter().map(|c| c.id).collect();
+    ids.sort();
+    let mut expected = vec![shared.id, alices.id];
+    expected.sort();
+    assert_eq!(ids, expected);
+    assert_eq!(page.total, Some(2));
+
+    let due: Vec<ChoreWithDueResponse> = bob
+        .get("/api/chores/due?include_upcoming=true")
+        .await
+        .json();
+    let mut ids: Vec<_> = due.iter().map(|c| c.id).collect();
+    ids.sort();
+    let mut expected = vec![shared.id, bobs.id];
+    expected.sort();
+    assert_eq!(ids, expected);
+
+    // Without auth, chores stay global
+    let page: PaginatedResponse<ChoreResponse> = anonymous.get("/api/chores").await.json();
+    assert_eq!(page.items.len(), 3);
+}
+
+#[tokio::test]
+async fn test_other_users_chores_are_not_found() {
+    let (_, pool) = common::create_test_app_with_pool().await;
+    let alice_id = common::create_user(&pool, "alice").await;
+    let bob_id = common::create_user(&pool, "bob").await;
+    let alice = common::create_test_app_as_user(pool.clone(), alice_id);
+    let bob = common::create_test_app_as_user(pool, bob_id);
+
+    let chore = common::create_interval_chore(&alice, "Alice's", 1).await;
+    let completion = common::complete_chore(&alice, chore.id, None).await;
+    let path = format!("/api/chores/{}", chore.id);
+
+    bob.get(&path).await.assert_status_not_found();
+    bob.put(&path)
+        .json(&serde_json::json!({ "name": "Bob's now" }))
+        .await
+        .assert_status_not_found();
+    bob.delete(&path).await.assert_status_not_found();
+    bob.post(&format!("{path}/complete"))
+        .json(&serde_json::json!({}))
+        .await
+        .assert_status_not_found();
+    bob.get(&format!("{path}/completions"))
+        .await
+        .assert_status_not_found();
+    bob.put(&format!("/api/completions/{}", completion.id))
+        .json(&serde_json::json!({ "notes": "not mine" }))
+        .await
+        .assert_status_not_found();
+
+    let heatmap: serde_json::Value = bob.get("/api/completions/heatmap").await.json();
+    assert_eq!(heatmap["counts"], serde_json::json!({}));
+
+    // Alice's chore is untouched
+    let response = alice.get(&path).await;
+    response.assert_status_ok();
+    let mine: ChoreResponse = response.json();
+    assert_eq!(mine.name, "Alice's");
+    let completions: PaginatedResponse<CompletionResponse> =
+        alice.get(&format!("{path}/completions")).await.json();
+    assert_eq!(completions.items.len(), 1);
+    assert_eq!(completions.items[0].notes, completion.notes);
+}
+
+#[tokio::test]
+async fn test_chore_audit_log_records_schedule_changes() {
+    let (_, pool) = common::create_test_app_with_pool().await;
+    let user_id = common::create_user(&pool, "alice").await;
+    let server = common::create_test_app_as_user(pool, user_id);
+
+    let chore = common::create_interval_chore(&server, "Water plants", 3).await;
+
+    server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&serde_json::json!({
+            "schedule": { "schedule_type": "interval", "interval_days": 5 }
+        }))
+        .await
+        .assert_status_ok();
+    server
+        .delete(&format!("/api/chores/{}", chore.id))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+
+    // The log outlives the chore
+    let entries: Vec<serde_json::Value> = server
+        .get(&format!("/api/chores/{}/audit", chore.id))
+        .await
+        .json();
+    let actions: Vec<_> = entries
+        .iter()
+        .map(|e| e["action"].as_str().unwrap())
+        .collect();
+    assert_eq!(actions, ["create", "update", "delete"]);
+    for entry in &entries {
+        assert_eq!(entry["user_id"], serde_json::json!(user_id));
+    }
+
+    assert!(entries[0]["schedule_before"].is_null());
+    assert_eq!(entries[0]["schedule_after"]["interval_days"], 3);
+    assert_eq!(entries[1]["schedule_before"]["interval_days"], 3);
+    assert_eq!(entries[1]["schedule_after"]["interval_days"], 5);
+    assert_eq!(entries[2]["schedule_before"]["interval_days"], 5);
+    assert!(entries[2]["schedule_after"].is_null());
+}
+
+#[tokio::test]
+async fn test_chore_audit_log_unknown_chore() {
+    let server = common::create_test_app().await;
+
+    server
+        .get(&format!("/api/chores/{}/audit", Uuid::new_v4()))
+        .await
+        .assert_status_not_found();
+}
+
+/// Create a daily chore with an estimate that fell due yesterday.
+async fn create_overdue_estimated_chore(
+    server: &axum_test::TestServer,
+    name: &str,
+    priority: &str,
+    estimated_minutes: i32,
+) -> ChoreResponse {
+    let response = server
+        .post("/api/chores")
+        .json(&serde_json::json!({
+            "name": name,
+            "schedule_type": "interval",
+            "interval_days": 1,
+            "priority": priority,
+            "estimated_minutes": estimated_minutes
+        }))
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let chore: ChoreResponse = response.json();
+    common::complete_chore_at(
+        server,
+        chore.id,
+        chrono::Utc::now() - chrono::Duration::days(2),
+    )
+    .await;
+    chore
+}
+
+#[tokio::test]
+async fn test_day_budget_reports_overflow_and_defers_low_priority_first() {
+    let server = common::create_test_app().await;
+
+    let dishes = create_overdue_estimated_chore(&server, "Dishes", "high", 30).await;
+    let windows = create_overdue_estimated_chore(&server, "Windows", "low", 20).await;
+    let laundry = create_overdue_estimated_chore(&server, "Laundry", "normal", 25).await;
+    // Due next week, so not part of today
+    let response = server
+        .post("/api/chores")
+        .json(&serde_json::json!({
+            "name": "Gutters",
+            "schedule_type": "interval",
+            "interval_days": 7,
+            "estimated_minutes": 90
+        }))
+        .await;
+    let gutters: ChoreResponse = response.json();
+    common::complete_chore(&server, gutters.id, None).await;
+
+    let response = server
+        .get("/api/chores/today/budget")
+        .add_query_param("limit_minutes", 40)
+        .await;
+    response.assert_status_ok();
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["limit_minutes"], 40);
+    assert_eq!(body["total_minutes"], 75);
+    assert_eq!(body["fits"], false);
+    assert_eq!(body["overflow_minutes"], 35);
+
+    let today: Vec<&str> = body["chores"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(today.len(), 3);
+    assert!(!today.contains(&gutters.id.to_string().as_str()));
+
+    let defer: Vec<&str> = body["defer"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        defer,
+        [windows.id.to_string(), laundry.id.to_string()],
+        "low priority goes first and the high priority chore is kept"
+    );
+    assert!(!defer.contains(&dishes.id.to_string().as_str()));
+}
+
+#[tokio::test]
+async fn test_day_budget_fits() {
+    let server = common::create_test_app().await;
+    create_overdue_estimated_chore(&server, "Dishes", "high", 30).await;
+
+    let response = server
+        .get("/api/chores/today/budget")
+        .add_query_param("limit_minutes", 60)
+        .await;
+    response.assert_status_ok();
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["total_minutes"], 30);
+    assert_eq!(body["fits"], true);
+    assert_eq!(body["overflow_minutes"], 0);
+    assert!(body["defer"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_day_budget_requires_limit() {
+    let server = common::create_test_app().await;
+
+    let response = server.get("/api/chores/today/budget").await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    let response = server
+        .get("/api/chores/today/budget")
+        .add_query_param("limit_minutes", -5)
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_create_chore_rejects_invalid_estimate() {
+    let server = common::create_test_app().await;
+
+    for minutes in [0, 24 * 60 + 1] {
+        let response = server
+            .post("/api/chores")
+            .json(&serde_json::json!({
+                "name": "Dishes",
+                "schedule_type": "interval",
+                "interval_days": 1,
+                "estimated_minutes": minutes
+            }))
+            .await;
+        response.assert_status(StatusCode::BAD_REQUEST);
+        let problem: ProblemDetails = response.json();
+        assert!(problem.detail.unwrap().contains("estimated_minutes"));
+    }
+}
+
+// ============================================================================
+// Undo (POST /api/chores/{id}/complete/undo)
+// ============================================================================
+
+fn undo_window_app(pool: sqlx::SqlitePool, minutes: i64) -> axum_test::TestServer {
+    let app = nag_server::http::build_test_app(pool).layer(axum::Extension(
+        nag_server::http::UndoWindow(chrono::Duration::minutes(minutes)),
+    ));
+    axum_test::TestServer::new(app.into_make_service()).expect("Failed to create test server")
+}
+
+#[tokio::test]
+async fn test_undo_completion_removes_latest_within_window() {
+    let pool = nag_server::db::create_pool("sqlite::memory:")
+        .await
+        .expect("Failed to create test database pool");
+    let server = undo_window_app(pool, 10);
+
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    let first = common::complete_chore(&server, chore.id, Some("first")).await;
+    let second = common::complete_chore(&server, chore.id, Some("second")).await;
+
+    let response = server
+        .post(&format!("/api/chores/{}/complete/undo", chore.id))
+        .await;
+    response.assert_status_ok();
+    let undone: CompletionResponse = response.json();
+    assert_eq!(undone.id, second.id);
+
+    let completions: PaginatedResponse<CompletionResponse> = server
+        .get(&format!("/api/chores/{}/completions", chore.id))
+        .await
+        .json();
+    assert_eq!(
+        completions.items.iter().map(|c| c.id).collect::<Vec<_>>(),
+        vec![first.id]
+    );
+}
+
+#[tokio::test]
+async fn test_undo_completion_rejected_after_window() {
+    let pool = nag_server::db::create_pool("sqlite::memory:")
+        .await
+        .expect("Failed to create test database pool");
+    let server = undo_window_app(pool.clone(), 10);
+
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    let completion = common::complete_chore(&server, chore.id, None).await;
+
+    // Recorded 11 minutes ago
+    sqlx::query("UPDATE completions SET created_at = ? WHERE id = ?")
+        .bind(chrono::Utc::now() - chrono::Duration::minutes(11))
+        .bind(completion.id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let response = server
+        .post(&format!("/api/chores/{}/complete/undo", chore.id))
+        .await;
+    response.assert_status(StatusCode::CONFLICT);
+
+    let completions: PaginatedResponse<CompletionResponse> = server
+        .get(&format!("/api/chores/{}/completions", chore.id))
+        .await
+        .json();
+    assert_eq!(completions.items.len(), 1);
+}
+
+#[tokio::test]
+async fn test_undo_completion_without_completions_not_found() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    server
+        .post(&format!("/api/chores/{}/complete/undo", chore.id))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+    server
+        .post(&format!("/api/chores/{}/complete/undo", Uuid::new_v4()))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+}
+
+// ============================================================================
+// Uncomplete (POST /api/chores/{id}/uncomplete)
+// ============================================================================
+
+#[tokio::test]
+async fn test_uncomplete_removes_latest_completed_at() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    let now = chrono::Utc::now();
+    let latest =
+        common::complete_chore_at(&server, chore.id, now - chrono::Duration::days(1)).await;
+    // Backfilled after the latest one
+    let earlier =
+        common::complete_chore_at(&server, chore.id, now - chrono::Duration::days(3)).await;
+
+    let response = server
+        .post(&format!("/api/chores/{}/uncomplete", chore.id))
+        .await;
+    response.assert_status_ok();
+    let removed: CompletionResponse = response.json();
+    assert_eq!(removed.id, latest.id);
+
+    let chore: ChoreResponse = server
+        .get(&format!("/api/chores/{}", chore.id))
+        .await
+        .json();
+    assert_eq!(chore.last_completed_at, Some(earlier.completed_at));
+}
+
+#[tokio::test]
+async fn test_uncomplete_respects_undo_window() {
+    let pool = nag_server::db::create_pool("sqlite::memory:")
+        .await
+        .expect("Failed to create test database pool");
+    let server = undo_window_app(pool.clone(), 10);
+
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    let completion = common::complete_chore(&server, chore.id, None).await;
+
+    // Recorded 11 minutes ago
+    sqlx::query("UPDATE completions SET created_at = ? WHERE id = ?")
+        .bind(chrono::Utc::now() - chrono::Duration::minutes(11))
+        .bind(completion.id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    server
+        .post(&format!("/api/chores/{}/uncomplete", chore.id))
+        .await
+        .assert_status(StatusCode::CONFLICT);
+
+    let completions: PaginatedResponse<CompletionResponse> = server
+        .get(&format!("/api/chores/{}/completions", chore.id))
+        .await
+        .json();
+    assert_eq!(completions.items.len(), 1);
+}
+
+#[tokio::test]
+async fn test_uncomplete_without_completions_not_found() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    server
+        .post(&format!("/api/chores/{}/uncomplete", chore.id))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+    server
+        .post(&format!("/api/chores/{}/uncomplete", Uuid::new_v4()))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+}
+
+// ============================================================================
+// Destructive action PIN (X-Nag-Pin)
+// ============================================================================
+
+fn pin_app(pool: sqlx::SqlitePool) -> axum_test::TestServer {
+    let app = nag_server::http::build_test_app(pool).layer(axum::Extension(
+        nag_server::http::DestructiveActionPin("4321".to_string()),
+    ));
+    axum_test::TestServer::new(app.into_make_service()).expect("Failed to create test server")
+}
+
+#[tokio::test]
+async fn test_delete_requires_pin_when_configured() {
+    let pool = nag_server::db::create_pool("sqlite::memory:")
+        .await
+        .expect("Failed to create test database pool");
+    let server = pin_app(pool);
+
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    let tag = common::create_tag(&server, "kitchen").await;
+
+    server
+        .delete(&format!("/api/chores/{}", chore.id))
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+    server
+        .delete(&format!("/api/chores/{}", chore.id))
+        .add_header("X-Nag-Pin", "1234")
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+    server
+        .delete(&format!("/api/tags/{}", tag.id))
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+
+    // Other methods don't need the PIN
+    server
+        .get(&format!("/api/chores/{}", chore.id))
+        .await
+        .assert_status_ok();
+}
+
+#[tokio::test]
+async fn test_delete_succeeds_with_pin() {
+    let pool = nag_server::db::create_pool("sqlite::memory:")
+        .await
+        .expect("Failed to create test database pool");
+    let server = pin_app(pool);
+
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+
+    server
+        .delete(&format!("/api/chores/{}", chore.id))
+        .add_header("X-Nag-Pin", "4321")
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+    server
+        .get(&format!("/api/chores/{}", chore.id))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_merge_and_undo_require_pin_when_configured() {
+    let pool = nag_server::db::create_pool("sqlite::memory:")
+        .await
+        .expect("Failed to create test database pool");
+    let server = pin_app(pool);
+
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    common::complete_chore(&server, chore.id, None).await;
+    common::complete_chore(&server, chore.id, None).await;
+    let source = common::create_tag(&server, "kitchen").await;
+    let target = common::create_tag(&server, "cleaning").await;
+    let merge = serde_json::json!({ "source_id": source.id, "target_id": target.id });
+
+    server
+        .post("/api/tags/merge")
+        .json(&merge)
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+    server
+        .post(&format!("/api/chores/{}/uncomplete", chore.id))
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+    server
+        .post(&format!("/api/chores/{}/complete/undo", chore.id))
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+
+    server
+        .post("/api/tags/merge")
+        .json(&merge)
+        .add_header("X-Nag-Pin", "4321")
+        .await
+        .assert_status_ok();
+    server
+        .post(&format!("/api/chores/{}/uncomplete", chore.id))
+        .add_header("X-Nag-Pin", "4321")
+        .await
+        .assert_status_ok();
+    server
+        .post(&format!("/api/chores/{}/complete/undo", chore.id))
+        .add_header("X-Nag-Pin", "4321")
+        .await
+        .assert_status_ok();
+}
+
+#[tokio::test]
+async fn test_delete_without_pin_configured_needs_no_header() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    server
+        .delete(&format!("/api/chores/{}", chore.id))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+}
+
+// ============================================================================
+// Review (POST /api/chores/{id}/reviewed)
+// ============================================================================
+
+#[tokio::test]
+async fn test_mark_chore_reviewed_sets_last_reviewed_at() {
+    let server = common::create_test_app().await;
+
+    let body = serde_json::json!({
+        "name": "Descale kettle",
+        "schedule_type": "interval",
+        "interval_days": 30,
+        "review_interval_days": 180
+    });
+    let response = server.post("/api/chores").json(&body).await;
+    response.assert_status(StatusCode::CREATED);
+    let chore: ChoreResponse = response.json();
+    assert_eq!(chore.review_interval_days, Some(180));
+    assert!(chore.last_reviewed_at.is_none());
+
+    let before = chrono::Utc::now();
+    let response = server
+        .post(&format!("/api/chores/{}/reviewed", chore.id))
+        .await;
+    response.assert_status_ok();
+    let reviewed: ChoreResponse = response.json();
+    assert!(reviewed.last_reviewed_at.is_some_and(|at| at >= before));
+    assert_eq!(reviewed.review_interval_days, Some(180));
+
+    server
+        .post(&format!("/api/chores/{}/reviewed", Uuid::new_v4()))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_update_chore_review_interval() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    assert!(chore.review_interval_days.is_none());
+
+    let url = format!("/api/chores/{}", chore.id);
+    let updated: ChoreResponse = server
+        .put(&url)
+        .json(&serde_json::json!({ "review_interval_days": 90 }))
+        .await
+        .json();
+    assert_eq!(updated.review_interval_days, Some(90));
+
+    server
+        .put(&url)
+        .json(&serde_json::json!({ "review_interval_days": 0 }))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+
+    let cleared: ChoreResponse = server
+        .put(&url)
+        .json(&serde_json::json!({ "review_interval_days": null }))
+        .await
+        .json();
+    assert!(cleared.review_interval_days.is_none());
+}
+
+#[tokio::test]
+async fn test_chore_min_notify_interval() {
+    let server = common::create_test_app().await;
+
+    let body = serde_json::json!({
+        "name": "Water plants",
+        "schedule_type": "cron",
+        "cron_schedule": "0 * * * *",
+        "min_notify_interval_minutes": 1440
+    });
+    let response = server.post("/api/chores").json(&body).await;
+    response.assert_status(StatusCode::CREATED);
+    let chore: ChoreResponse = response.json();
+    assert_eq!(chore.min_notify_interval_minutes, Some(1440));
+
+    let url = format!("/api/chores/{}", chore.id);
+    server
+        .put(&url)
+        .json(&serde_json::json!({ "min_notify_interval_minutes": 0 }))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+
+    let cleared: ChoreResponse = server
+        .put(&url)
+        .json(&serde_json::json!({ "min_notify_interval_minutes": null }))
+        .await
+        .json();
+    assert!(cleared.min_notify_interval_minutes.is_none());
 }
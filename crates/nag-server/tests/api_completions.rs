@@ -1,7 +1,11 @@
 //! Completion API endpoint tests.
 //!
 //! Tests cover:
+//! - Update completion (PUT /api/completions/{id})
 //! - Delete completion (DELETE /api/completions/{id})
+//! - Completion heatmap (GET /api/completions/heatmap)
+//! - Completion calendar (GET /api/completions/calendar)
+//! - Completions feed across chores (GET /api/completions)
 //!
 //! Note: Other completion operations (create, list) are tested in api_chores.rs
 //! as they are accessed through the chore resource.
@@ -9,9 +13,92 @@
 mod common;
 
 use axum::http::StatusCode;
-use common::ProblemDetails;
+use common::{CompletionResponse, PaginatedResponse, ProblemDetails};
 use uuid::Uuid;
 
+// ============================================================================
+// Update Completion (PUT /api/completions/{id})
+// ============================================================================
+
+#[tokio::test]
+async fn test_update_completion_time_only() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    let completion = common::complete_chore(&server, chore.id, Some("Done")).await;
+
+    let response = server
+        .put(&format!("/api/completions/{}", completion.id))
+        .json(&serde_json::json!({ "completed_at": "2026-03-02T08:30:00Z" }))
+        .await;
+
+    response.assert_status_ok();
+    let updated: common::CompletionResponse = response.json();
+    assert_eq!(updated.id, completion.id);
+    assert_eq!(
+        updated.completed_at,
+        at(2, 8) + chrono::Duration::minutes(30)
+    );
+    assert_eq!(updated.notes.as_deref(), Some("Done"));
+    assert_eq!(updated.created_at, completion.created_at);
+}
+
+#[tokio::test]
+async fn test_update_completion_notes_only() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    let completion = common::complete_chore(&server, chore.id, Some("Done")).await;
+
+    let response = server
+        .put(&format!("/api/completions/{}", completion.id))
+        .json(&serde_json::json!({ "notes": "Done, and mopped" }))
+        .await;
+
+    response.assert_status_ok();
+    let updated: common::CompletionResponse = response.json();
+    assert_eq!(updated.completed_at, completion.completed_at);
+    assert_eq!(updated.notes.as_deref(), Some("Done, and mopped"));
+}
+
+#[tokio::test]
+async fn test_update_completion_null_clears_notes() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    let completion = common::complete_chore(&server, chore.id, Some("Done")).await;
+
+    let response = server
+        .put(&format!("/api/completions/{}", completion.id))
+        .json(&serde_json::json!({ "notes": null }))
+        .await;
+
+    response.assert_status_ok();
+    let updated: common::CompletionResponse = response.json();
+    assert_eq!(updated.notes, None);
+
+    // The change is persisted
+    let list_response = server
+        .get(&format!("/api/chores/{}/completions", chore.id))
+        .await;
+    let completions: common::PaginatedResponse<common::CompletionResponse> = list_response.json();
+    assert_eq!(completions.items.len(), 1);
+    assert_eq!(completions.items[0].notes, None);
+}
+
+#[tokio::test]
+async fn test_update_completion_not_found() {
+    let server = common::create_test_app().await;
+
+    let fake_id = Uuid::new_v4();
+    let response = server
+        .put(&format!("/api/completions/{}", fake_id))
+        .json(&serde_json::json!({ "notes": "Done" }))
+        .await;
+
+    response.assert_status(StatusCode::NOT_FOUND);
+}
+
 // ============================================================================
 // Delete Completion (DELETE /api/completions/{id})
 // ============================================================================
@@ -118,3 +205,226 @@ async fn test_delete_completion_does_not_affect_chore() {
     assert_eq!(fetched_chore.id, chore.id);
     assert_eq!(fetched_chore.name, "Vacuum");
 }
+
+// ============================================================================
+// Completion Heatmap (GET /api/completions/heatmap)
+// ============================================================================
+
+fn at(day: u32, hour: u32) -> chrono::DateTime<chrono::Utc> {
+    chrono::NaiveDate::from_ymd_opt(2026, 3, day)
+        .unwrap()
+        .and_hms_opt(hour, 0, 0)
+        .unwrap()
+        .and_utc()
+}
+
+#[tokio::test]
+async fn test_completion_heatmap_counts_per_day() {
+    let server = common::create_test_app().await;
+
+    let vacuum = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    let dishes = common::create_chore(&server, "Dishes", "0 20 * * *").await;
+
+    common::complete_chore_at(&server, vacuum.id, at(1, 8)).await;
+    common::complete_chore_at(&server, dishes.id, at(1, 23)).await;
+    common::complete_chore_at(&server, dishes.id, at(3, 0)).await;
+    // Outside the requested range
+    common::complete_chore_at(&server, dishes.id, at(10, 12)).await;
+
+    let response = server
+        .get("/api/completions/heatmap?from=2026-03-01&to=2026-03-05")
+        .await;
+    response.assert_status_ok();
+
+    let heatmap: common::CompletionHeatmapResponse = response.json();
+    assert_eq!(heatmap.counts.len(), 2);
+    assert_eq!(heatmap.counts[&at(1, 0).date_naive()], 2);
+    assert_eq!(heatmap.counts[&at(3, 0).date_naive()], 1);
+}
+
+#[tokio::test]
+async fn test_completion_heatmap_filter_by_tag() {
+    let server = common::create_test_app().await;
+
+    let vacuum = common::create_chore_with_tags(&server, "Vacuum", "0 9 * * 1", &["floors"]).await;
+    let dishes = common::create_chore(&server, "Dishes", "0 20 * * *").await;
+
+    common::complete_chore_at(&server, vacuum.id, at(2, 9)).await;
+    common::complete_chore_at(&server, dishes.id, at(2, 20)).await;
+
+    let response = server
+        .get("/api/completions/heatmap?from=2026-03-01&to=2026-03-05&tag=Floors")
+        .await;
+    response.assert_status_ok();
+
+    let heatmap: common::CompletionHeatmapResponse = response.json();
+    assert_eq!(heatmap.counts.len(), 1);
+    assert_eq!(heatmap.counts[&at(2, 0).date_naive()], 1);
+}
+
+#[tokio::test]
+async fn test_completion_heatmap_invalid_range() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .get("/api/completions/heatmap?from=2026-03-05&to=2026-03-01")
+        .await;
+
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_completion_heatmap_rejects_range_over_two_years() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .get("/api/completions/heatmap?from=2024-03-01&to=2026-03-05")
+        .await;
+
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+// ============================================================================
+// Completion Calendar (GET /api/completions/calendar)
+// ============================================================================
+
+#[tokio::test]
+async fn test_completion_calendar_lists_days() {
+    let server = common::create_test_app().await;
+
+    let vacuum = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    let dishes = common::create_chore(&server, "Dishes", "0 20 * * *").await;
+
+    common::complete_chore_at(&server, vacuum.id, at(1, 8)).await;
+    common::complete_chore_at(&server, dishes.id, at(1, 23)).await;
+    common::complete_chore_at(&server, dishes.id, at(3, 0)).await;
+    // Outside the requested range
+    common::complete_chore_at(&server, dishes.id, at(10, 12)).await;
+
+    let response = server
+        .get("/api/completions/calendar?from=2026-03-01&to=2026-03-05")
+        .await;
+    response.assert_status_ok();
+
+    let days: serde_json::Value = response.json();
+    assert_eq!(
+        days,
+        serde_json::json!([
+            { "date": "2026-03-01", "count": 2 },
+            { "date": "2026-03-03", "count": 1 },
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_completion_calendar_defaults_to_last_year() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_chore(&server, "Dishes", "0 20 * * *").await;
+    let now = chrono::Utc::now();
+    common::complete_chore_at(&server, chore.id, now - chrono::Duration::days(10)).await;
+    common::complete_chore_at(&server, chore.id, now - chrono::Duration::days(400)).await;
+
+    let response = server.get("/api/completions/calendar").await;
+    response.assert_status_ok();
+
+    let days: serde_json::Value = response.json();
+    assert_eq!(days.as_array().unwrap().len(), 1);
+    assert_eq!(
+        days[0]["date"],
+        (now - chrono::Duration::days(10)).date_naive().to_string()
+    );
+}
+
+#[tokio::test]
+async fn test_completion_calendar_invalid_range() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .get("/api/completions/calendar?from=2026-03-05&to=2026-03-01")
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    let response = server
+        .get("/api/completions/calendar?from=2024-01-01&to=2026-03-01")
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+    let problem: ProblemDetails = response.json();
+    assert!(problem.detail.unwrap().contains("24 months"));
+}
+
+// ============================================================================
+// Completions Feed (GET /api/completions)
+// ============================================================================
+
+#[tokio::test]
+async fn test_completion_feed_pages_through_tag_filter() {
+    let server = common::create_test_app().await;
+
+    let vacuum = common::create_chore_with_tags(&server, "Vacuum", "0 9 * * 1", &["floors"]).await;
+    let mop = common::create_chore_with_tags(&server, "Mop", "0 9 * * 2", &["floors"]).await;
+    let dishes = common::create_chore(&server, "Dishes", "0 20 * * *").await;
+
+    let mut expected = [
+        common::complete_chore_at(&server, vacuum.id, at(1, 8)).await,
+        common::complete_chore_at(&server, mop.id, at(2, 9)).await,
+        // Same time as the mop completion, so pages must break the tie by id
+        common::complete_chore_at(&server, vacuum.id, at(2, 9)).await,
+        common::complete_chore_at(&server, mop.id, at(4, 10)).await,
+        common::complete_chore_at(&server, vacuum.id, at(5, 7)).await,
+    ];
+    for day in 1..=5 {
+        common::complete_chore_at(&server, dishes.id, at(day, 20)).await;
+    }
+    expected.sort_by(|a, b| b.completed_at.cmp(&a.completed_at).then(b.id.cmp(&a.id)));
+
+    let mut seen = Vec::new();
+    let mut url = "/api/completions?tag=floors&limit=2".to_string();
+    loop {
+        let page: PaginatedResponse<CompletionResponse> = server.get(&url).await.json();
+        if page.items.is_empty() {
+            break;
+        }
+        assert!(page.items.len() <= 2);
+        seen.extend(page.items);
+        let cursor = page.next_cursor.expect("non-empty page has a cursor");
+        url = format!("/api/completions?tag=floors&limit=2&cursor={cursor}");
+    }
+
+    let seen_ids: Vec<Uuid> = seen.iter().map(|c| c.id).collect();
+    let expected_ids: Vec<Uuid> = expected.iter().map(|c| c.id).collect();
+    assert_eq!(seen_ids, expected_ids);
+}
+
+#[tokio::test]
+async fn test_completion_feed_filters_by_date_range() {
+    let server = common::create_test_app().await;
+
+    let dishes = common::create_chore(&server, "Dishes", "0 20 * * *").await;
+    for day in 1..=5 {
+        common::complete_chore_at(&server, dishes.id, at(day, 20)).await;
+    }
+
+    let page: PaginatedResponse<CompletionResponse> = server
+        .get("/api/completions?from=2026-03-02&to=2026-03-03")
+        .await
+        .json();
+
+    let times: Vec<_> = page.items.iter().map(|c| c.completed_at).collect();
+    assert_eq!(times, vec![at(3, 20), at(2, 20)]);
+}
+
+#[tokio::test]
+async fn test_completion_feed_rejects_corrupt_cursor() {
+    let server = common::create_test_app().await;
+
+    for cursor in ["not-a-cursor", &Uuid::new_v4().to_string(), "abc.zz"] {
+        let response = server
+            .get(&format!("/api/completions?cursor={cursor}"))
+            .await;
+
+        response.assert_status(StatusCode::BAD_REQUEST);
+        let problem: ProblemDetails = response.json();
+        assert!(problem.detail.unwrap().contains("Invalid cursor"));
+    }
+}
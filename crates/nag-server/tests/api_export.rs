@@ -0,0 +1,404 @@
+//! Export endpoint tests.
+//!
+//! Tests cover:
+//! - Chores export (GET /api/export/chores.csv)
+//! - Completions export (GET /api/export/completions.csv)
+//! - A chore's completion log (GET /api/chores/{id}/completions.csv)
+//! - Ownership scoping of both exports
+//! - JSON export and import (GET /api/export, POST /api/import)
+
+mod common;
+
+use axum::http::StatusCode;
+use axum_test::{TestResponse, TestServer};
+use common::{ChoreResponse, PaginatedResponse, TagResponse};
+use serde_json::json;
+
+/// Parse a CSV response into its header and rows, checking the content type
+fn csv_rows(response: TestResponse) -> (Vec<String>, Vec<Vec<String>>) {
+    response.assert_status_ok();
+    assert!(
+        response
+            .header("content-type")
+            .to_str()
+            .unwrap()
+            .starts_with("text/csv")
+    );
+
+    let text = response.text();
+    let mut reader = csv::Reader::from_reader(text.as_bytes());
+    let header = reader
+        .headers()
+        .expect("header row")
+        .iter()
+        .map(String::from)
+        .collect();
+    let rows = reader
+        .records()
+        .map(|record| {
+            record
+                .expect("valid row")
+                .iter()
+                .map(String::from)
+                .collect()
+        })
+        .collect();
+    (header, rows)
+}
+
+/// Value of `column` in `row`
+fn field<'a>(header: &[String], row: &'a [String], column: &str) -> &'a str {
+    let index = header
+        .iter()
+        .position(|c| c == column)
+        .unwrap_or_else(|| panic!("no column {column}"));
+    &row[index]
+}
+
+async fn export_chores(server: &TestServer) -> (Vec<String>, Vec<Vec<String>>) {
+    csv_rows(server.get("/api/export/chores.csv").await)
+}
+
+#[tokio::test]
+async fn test_export_chores_csv_round_trips_schedule_columns() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .post("/api/chores")
+        .json(&serde_json::json!({
+            "name": "Water plants, herbs",
+            "description": "Balcony\nand kitchen",
+            "schedule_type": "interval",
+            "interval_days": 3,
+            "interval_time_hour": 8,
+            "interval_time_minute": 30,
+            "tags": ["garden", "indoor"],
+            "priority": "high",
+            "is_active": false
+        }))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+
+    let (header, rows) = export_chores(&server).await;
+
+    assert_eq!(rows.len(), 2, "paused chores are exported too");
+    let plants = rows
+        .iter()
+        .find(|row| field(&header, row, "name") == "Water plants, herbs")
+        .expect("chore with a comma in its name");
+    assert_eq!(
+        field(&header, plants, "description"),
+        "Balcony\nand kitchen"
+    );
+    assert_eq!(field(&header, plants, "schedule_type"), "interval");
+    assert_eq!(field(&header, plants, "interval_days"), "3");
+    assert_eq!(field(&header, plants, "interval_time_hour"), "8");
+    assert_eq!(field(&header, plants, "interval_time_minute"), "30");
+    assert_eq!(field(&header, plants, "cron_schedule"), "");
+    assert_eq!(field(&header, plants, "tags"), "garden,indoor");
+    assert_eq!(field(&header, plants, "priority"), "high");
+    assert_eq!(field(&header, plants, "is_active"), "false");
+
+    let vacuum = rows
+        .iter()
+        .find(|row| field(&header, row, "name") == "Vacuum")
+        .expect("cron chore");
+    assert_eq!(field(&header, vacuum, "cron_schedule"), "0 9 * * 1");
+}
+
+#[tokio::test]
+async fn test_export_completions_csv() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    let first = common::complete_chore(&server, chore.id, Some("Living room, hallway")).await;
+    let second = common::complete_chore(&server, chore.id, None).await;
+
+    let (header, rows) = csv_rows(server.get("/api/export/completions.csv").await);
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(field(&header, &rows[0], "id"), first.id.to_string());
+    assert_eq!(field(&header, &rows[0], "chore_name"), "Vacuum");
+    assert_eq!(field(&header, &rows[0], "notes"), "Living room, hallway");
+    assert_eq!(field(&header, &rows[0], "source"), "api");
+    assert_eq!(field(&header, &rows[1], "id"), second.id.to_string());
+}
+
+#[tokio::test]
+async fn test_export_chore_completions_csv() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    let other = common::create_chore(&server, "Dishes", "0 9 * * *").await;
+    let now = chrono::Utc::now();
+    for days in 1..30 {
+        common::complete_chore_at(&server, chore.id, now - chrono::Duration::days(days)).await;
+    }
+    common::complete_chore(&server, other.id, None).await;
+    let noted = common::complete_chore(&server, chore.id, Some("Rug, \"the big one\"")).await;
+
+    let (header, rows) = csv_rows(
+        server
+            .get(&format!("/api/chores/{}/completions.csv", chore.id))
+            .await,
+    );
+
+    assert_eq!(header, ["completed_at", "notes", "created_at"]);
+    // Every completion of the chore, not just the first page, oldest first
+    assert_eq!(rows.len(), 30);
+    let last = rows.last().unwrap();
+    assert_eq!(field(&header, last, "notes"), "Rug, \"the big one\"");
+    assert_eq!(
+        field(&header, last, "completed_at")
+            .parse::<chrono::DateTime<chrono::Utc>>()
+            .unwrap(),
+        noted.completed_at
+    );
+}
+
+#[tokio::test]
+async fn test_export_chore_completions_csv_unknown_chore() {
+    let server = common::create_test_app().await;
+
+    server
+        .get(&format!(
+            "/api/chores/{}/completions.csv",
+            uuid::Uuid::new_v4()
+        ))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_exports_are_scoped_to_the_user() {
+    let (anonymous, pool) = common::create_test_app_with_pool().await;
+    let alice_id = common::create_user(&pool, "alice").await;
+    let bob_id = common::create_user(&pool, "bob").await;
+    let alice = common::create_test_app_as_user(pool.clone(), alice_id);
+    let bob = common::create_test_app_as_user(pool, bob_id);
+
+    common::create_interval_chore(&anonymous, "Shared", 1).await;
+    common::create_interval_chore(&alice, "Alice's", 1).await;
+    let bobs = common::create_interval_chore(&bob, "Bob's", 1).await;
+    common::complete_chore(&bob, bobs.id, None).await;
+
+    let (header, rows) = export_chores(&alice).await;
+    let mut names: Vec<_> = rows.iter().map(|row| field(&header, row, "name")).collect();
+    names.sort();
+    assert_eq!(names, ["Alice's", "Shared"]);
+
+    let (_, rows) = csv_rows(alice.get("/api/export/completions.csv").await);
+    assert!(rows.is_empty());
+}
+
+#[tokio::test]
+async fn test_json_export_round_trips_into_a_fresh_database() {
+    let source = common::create_test_app().await;
+    common::create_tag_with_color(&source, "garden", Some("sage")).await;
+    common::create_chore_with_tags(&source, "Mow lawn", "0 9 * * 6", &["garden"]).await;
+    source
+        .post("/api/chores")
+        .json(&json!({
+            "name": "Water plants",
+            "schedule_type": "interval",
+            "interval_days": 3,
+            "interval_time_hour": 8,
+            "priority": "high",
+            "is_active": false
+        }))
+        .await
+        .assert_status(StatusCode::CREATED);
+
+    let export = source.get("/api/export").await;
+    export.assert_status_ok();
+    let document: serde_json::Value = export.json();
+    assert_eq!(
+        document["tags"],
+        json!([{ "name": "garden", "color": "sage" }])
+    );
+
+    let target = common::create_test_app().await;
+    let response = target.post("/api/import").json(&document).await;
+    response.assert_status_ok();
+    let summary: serde_json::Value = response.json();
+    assert_eq!(
+        summary,
+        json!({
+            "chores_created": 2,
+            "chores_skipped": 0,
+            "completions_created": 0,
+            "completions_skipped": 0,
+            "tags_imported": 1
+        })
+    );
+
+    let page: PaginatedResponse<ChoreResponse> =
+        target.get("/api/chores?include_paused=true").await.json();
+    assert_eq!(page.items.len(), 2);
+    let mow = page.items.iter().find(|c| c.name == "Mow lawn").unwrap();
+    assert_eq!(mow.cron_schedule.as_deref(), Some("0 9 * * 6"));
+    assert_eq!(mow.tags[0].name, "garden");
+    assert_eq!(mow.tags[0].color.as_deref(), Some("sage"));
+    let plants = page
+        .items
+        .iter()
+        .find(|c| c.name == "Water plants")
+        .unwrap();
+    assert_eq!(plants.interval_days, Some(3));
+    assert_eq!(plants.interval_time_hour, Some(8));
+    assert!(!plants.is_active);
+
+    // Exporting again gives back the same document
+    let sorted_chores = |document: &serde_json::Value| {
+        let mut chores = document["chores"].as_array().unwrap().clone();
+        chores.sort_by_key(|c| c["name"].as_str().unwrap().to_string());
+        chores
+    };
+    let again: serde_json::Value = target.get("/api/export").await.json();
+    assert_eq!(again["tags"], document["tags"]);
+    assert_eq!(sorted_chores(&again), sorted_chores(&document));
+}
+
+#[tokio::test]
+async fn test_json_import_reuses_existing_tags_by_name() {
+    let server = common::create_test_app().await;
+    let existing = common::create_tag_with_color(&server, "Garden", Some("moss")).await;
+
+    server
+        .post("/api/import")
+        .json(&json!({
+            "tags": [{ "name": "garden", "color": "sage" }],
+            "chores": [{
+                "name": "Weed beds",
+                "schedule_type": "interval",
+                "interval_days": 7,
+                "tags": ["garden", "outdoor"]
+            }]
+        }))
+        .await
+        .assert_status_ok();
+
+    let tags: Vec<TagResponse> = server.get("/api/tags").await.json();
+    let names: Vec<_> = tags.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, ["Garden", "outdoor"]);
+    assert_eq!(tags[0].id, existing.id);
+    assert_eq!(tags[0].color.as_deref(), Some("moss"));
+}
+
+#[tokio::test]
+async fn test_json_import_rejects_invalid_schedule_without_writing() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .post("/api/import")
+        .json(&json!({
+            "tags": [{ "name": "garden" }],
+            "chores": [
+                { "name": "Fine", "schedule_type": "interval", "interval_days": 2, "tags": ["garden"] },
+                { "name": "Broken", "schedule_type": "cron", "cron_schedule": "not a cron" }
+            ]
+        }))
+        .await;
+    response.assert_status_bad_request();
+    let problem: common::ProblemDetails = response.json();
+    assert!(problem.detail.unwrap().contains("chores[1] (\"Broken\")"));
+
+    let page: PaginatedResponse<ChoreResponse> = server.get("/api/chores").await.json();
+    assert!(page.items.is_empty());
+    let tags: Vec<TagResponse> = server.get("/api/tags").await.json();
+    assert!(tags.is_empty());
+}
+
+#[tokio::test]
+async fn test_json_import_rolls_back_on_external_id_conflict() {
+    let server = common::create_test_app().await;
+    server
+        .put("/api/chores/by-external-id/trash")
+        .json(&json!({ "name": "Trash", "schedule_type": "interval", "interval_days": 7 }))
+        .await
+        .assert_status(StatusCode::CREATED);
+
+    server
+        .post("/api/import")
+        .json(&json!({
+            "chores": [
+                { "name": "New", "schedule_type": "once_in_a_while" },
+                {
+                    "name": "Trash again",
+                    "schedule_type": "interval",
+                    "interval_days": 7,
+                    "external_id": "trash"
+                }
+            ]
+        }))
+        .await
+        .assert_status(StatusCode::CONFLICT);
+
+    let page: PaginatedResponse<ChoreResponse> = server.get("/api/chores").await.json();
+    let names: Vec<_> = page.items.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["Trash"]);
+}
+
+#[tokio::test]
+async fn test_json_import_restores_completions_and_keeps_ids() {
+    let source = common::create_test_app().await;
+    let chore = common::create_interval_chore(&source, "Vacuum", 7).await;
+    let completion = common::complete_chore(&source, chore.id, Some("Hallway")).await;
+    let document: serde_json::Value = source.get("/api/export").await.json();
+    assert_eq!(document["chores"][0]["id"], json!(chore.id));
+    assert_eq!(document["completions"][0]["id"], json!(completion.id));
+
+    let target = common::create_test_app().await;
+    let summary: serde_json::Value = target.post("/api/import").json(&document).await.json();
+    assert_eq!(summary["chores_created"], 1);
+    assert_eq!(summary["completions_created"], 1);
+
+    let restored: ChoreResponse = target
+        .get(&format!("/api/chores/{}", chore.id))
+        .await
+        .json();
+    assert_eq!(restored.name, "Vacuum");
+    assert_eq!(restored.last_completed_at, Some(completion.completed_at));
+    let page: PaginatedResponse<common::CompletionResponse> = target
+        .get(&format!("/api/chores/{}/completions", chore.id))
+        .await
+        .json();
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].id, completion.id);
+    assert_eq!(page.items[0].notes.as_deref(), Some("Hallway"));
+
+    // Importing the same backup again skips what already exists
+    let summary: serde_json::Value = target.post("/api/import").json(&document).await.json();
+    assert_eq!(summary["chores_created"], 0);
+    assert_eq!(summary["chores_skipped"], 1);
+    assert_eq!(summary["completions_created"], 0);
+    assert_eq!(summary["completions_skipped"], 1);
+}
+
+#[tokio::test]
+async fn test_json_import_rejects_completion_of_unknown_chore() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .post("/api/import")
+        .json(&json!({
+            "chores": [{
+                "id": uuid::Uuid::new_v4(),
+                "name": "Vacuum",
+                "schedule_type": "once_in_a_while"
+            }],
+            "completions": [{
+                "chore_id": uuid::Uuid::new_v4(),
+                "completed_at": "2026-01-01T09:00:00Z",
+                "source": "api"
+            }]
+        }))
+        .await;
+    response.assert_status_bad_request();
+    let problem: common::ProblemDetails = response.json();
+    assert!(problem.detail.unwrap().contains("completions[0]"));
+
+    let page: PaginatedResponse<ChoreResponse> = server.get("/api/chores").await.json();
+    assert!(page.items.is_empty());
+}
@@ -2,7 +2,11 @@
 
 mod common;
 
+use chrono::Utc;
+use nag_server::db::NotificationRepository;
+use nag_server::db::models::NotificationChannel;
 use serde::Deserialize;
+use uuid::Uuid;
 
 #[derive(Debug, Deserialize)]
 struct HealthResponse {
@@ -38,3 +42,145 @@ async fn test_health_check_content_type_is_json() {
             .contains("application/json")
     );
 }
+
+#[tokio::test]
+async fn test_readiness_check_returns_ok() {
+    let server = common::create_test_app().await;
+
+    let response = server.get("/health/ready").await;
+
+    response.assert_status_ok();
+
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["status"], "ok");
+    let size = body["pool"]["size"].as_u64().unwrap();
+    let idle = body["pool"]["idle"].as_u64().unwrap();
+    assert!(size >= 1);
+    assert!(idle <= size);
+}
+
+#[tokio::test]
+async fn test_readiness_check_returns_503_without_database() {
+    let (server, pool) = common::create_test_app_with_pool().await;
+    pool.close().await;
+
+    let response = server.get("/health/ready").await;
+
+    response.assert_status(axum::http::StatusCode::SERVICE_UNAVAILABLE);
+
+    let body: common::ProblemDetails = response.json();
+    assert_eq!(body.status, Some(503));
+
+    // Liveness doesn't depend on the database
+    server.get("/health").await.assert_status_ok();
+}
+
+#[derive(Debug, Deserialize)]
+struct MigrationHealthResponse {
+    status: String,
+    current_version: Option<i64>,
+    applied: usize,
+    pending: Vec<i64>,
+    dirty: bool,
+}
+
+#[tokio::test]
+async fn test_migration_health_reports_applied_version() {
+    let server = common::create_test_app().await;
+
+    let response = server.get("/health/migrations").await;
+
+    response.assert_status_ok();
+
+    let body: MigrationHealthResponse = response.json();
+    assert_eq!(body.status, "ok");
+    assert!(body.current_version.is_some_and(|v| v > 0));
+    assert!(body.applied > 0);
+    assert!(body.pending.is_empty());
+    assert!(!body.dirty);
+}
+
+#[tokio::test]
+async fn test_migration_health_dirty_returns_503() {
+    let (server, pool) = common::create_test_app_with_pool().await;
+
+    sqlx::query(
+        "UPDATE _sqlx_migrations SET success = 0 WHERE version = (SELECT MAX(version) FROM _sqlx_migrations)",
+    )
+    .execute(&pool)
+    .await
+    .expect("mark migration dirty");
+
+    let response = server.get("/health/migrations").await;
+
+    response.assert_status(axum::http::StatusCode::SERVICE_UNAVAILABLE);
+
+    let body: MigrationHealthResponse = response.json();
+    assert_eq!(body.status, "dirty");
+    assert!(body.dirty);
+}
+
+// The recorder is process-global, so a single test scrapes it to keep the
+// gauges from racing between tests
+#[tokio::test]
+async fn test_metrics_reports_all_series() {
+    let (server, pool) = common::create_test_app_with_pool().await;
+
+    let chore = common::create_chore(&server, "Daily task", "0 9 * * *").await;
+    common::complete_chore_at(&server, chore.id, "2020-01-01T00:00:00Z".parse().unwrap()).await;
+
+    // One pending delivery; the failed one is not counted as pending
+    let event_id = NotificationRepository::upsert_due_event_with_deliveries(
+        &pool,
+        chore.id,
+        Utc::now(),
+        "Chore due",
+        "Daily task is due",
+        &[NotificationChannel::Telegram, NotificationChannel::Discord],
+    )
+    .await
+    .expect("upsert event");
+    let failed: Uuid = sqlx::query_scalar(
+        "SELECT id FROM notification_deliveries WHERE event_id = ? AND channel = ?",
+    )
+    .bind(event_id)
+    .bind(NotificationChannel::Discord)
+    .fetch_one(&pool)
+    .await
+    .expect("fetch delivery id");
+    NotificationRepository::mark_failed(&pool, failed, "Unauthorized", 30)
+        .await
+        .expect("mark failed");
+
+    let response = server.get("/metrics").await;
+
+    response.assert_status_ok();
+    assert!(
+        response
+            .headers()
+            .get("content-type")
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .starts_with("text/plain")
+    );
+
+    let body = response.text();
+    for name in [
+        "nag_notifications_dispatched_total",
+        "nag_notifications_delivered_total",
+        "nag_notifications_failed_total",
+        "nag_notifications_pending",
+    ] {
+        assert!(body.contains(name), "missing {name} in:\n{body}");
+    }
+    assert!(
+        body.lines().any(|line| line == "nag_chores_overdue 1"),
+        "unexpected metrics:\n{body}"
+    );
+    assert!(
+        body.lines()
+            .any(|line| line == "nag_notifications_pending 1"),
+        "unexpected metrics:\n{body}"
+    );
+}
@@ -0,0 +1,212 @@
+//! Holiday API endpoint tests.
+//!
+//! Tests cover:
+//! - CRUD operations (list, create, delete)
+//! - Due chores are shifted past holidays
+
+mod common;
+
+use std::collections::HashSet;
+
+use axum::http::StatusCode;
+use chrono::{Duration, NaiveTime, Utc};
+use common::{ChoreWithDueResponse, HolidayResponse, ProblemDetails};
+use nag_server::db::{
+    self, ChoreRepository, CompletionRepository, HolidayRepository,
+    chores::CreateChoreParams,
+    models::{ChoreAnchorMode, ChorePriority, CompletionSource, ScheduleType},
+};
+use nag_server::services::ChoreService;
+
+// ============================================================================
+// CRUD
+// ============================================================================
+
+#[tokio::test]
+async fn test_list_holidays_empty() {
+    let server = common::create_test_app().await;
+
+    let response = server.get("/api/holidays").await;
+    response.assert_status_ok();
+
+    let holidays: Vec<HolidayResponse> = response.json();
+    assert!(holidays.is_empty());
+}
+
+#[tokio::test]
+async fn test_create_and_list_holidays_ordered_by_date() {
+    let server = common::create_test_app().await;
+
+    let christmas = chrono::NaiveDate::from_ymd_opt(2026, 12, 25).unwrap();
+    let new_year = chrono::NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+
+    let created = common::create_holiday(&server, christmas, Some("Christmas")).await;
+    assert_eq!(created.date, christmas);
+    assert_eq!(created.name.as_deref(), Some("Christmas"));
+    common::create_holiday(&server, new_year, None).await;
+
+    let response = server.get("/api/holidays").await;
+    response.assert_status_ok();
+
+    let holidays: Vec<HolidayResponse> = response.json();
+    let dates: Vec<_> = holidays.iter().map(|h| h.date).collect();
+    assert_eq!(dates, vec![new_year, christmas]);
+}
+
+#[tokio::test]
+async fn test_create_holiday_duplicate_date_returns_409() {
+    let server = common::create_test_app().await;
+
+    let date = chrono::NaiveDate::from_ymd_opt(2026, 12, 25).unwrap();
+    common::create_holiday(&server, date, None).await;
+
+    let body = serde_json::json!({ "date": date });
+    let response = server.post("/api/holidays").json(&body).await;
+    response.assert_status(StatusCode::CONFLICT);
+
+    let problem: ProblemDetails = response.json();
+    assert_eq!(problem.status, Some(409));
+}
+
+#[tokio::test]
+async fn test_delete_holiday() {
+    let server = common::create_test_app().await;
+
+    let date = chrono::NaiveDate::from_ymd_opt(2026, 12, 25).unwrap();
+    common::create_holiday(&server, date, None).await;
+
+    let response = server.delete("/api/holidays/2026-12-25").await;
+    response.assert_status(StatusCode::NO_CONTENT);
+
+    let holidays: Vec<HolidayResponse> = server.get("/api/holidays").await.json();
+    assert!(holidays.is_empty());
+}
+
+#[tokio::test]
+async fn test_delete_holiday_not_found() {
+    let server = common::create_test_app().await;
+
+    let response = server.delete("/api/holidays/2026-12-25").await;
+    response.assert_status(StatusCode::NOT_FOUND);
+}
+
+// ============================================================================
+// Due calculation
+// ============================================================================
+
+#[tokio::test]
+async fn test_chore_due_on_holiday_is_not_overdue() {
+    let server = common::create_test_app().await;
+
+    // Daily chore completed yesterday is due today at midnight — normally overdue
+    let chore = common::create_interval_chore(&server, "Dishes", 1).await;
+    let now = Utc::now();
+    common::complete_chore_at(&server, chore.id, now - Duration::days(1)).await;
+
+    let today = now.date_naive();
+    common::create_holiday(&server, today, Some("Day off")).await;
+
+    let response = server.get("/api/chores/due").await;
+    let overdue: Vec<ChoreWithDueResponse> = response.json();
+    assert!(overdue.iter().all(|c| c.id != chore.id));
+
+    let response = server.get("/api/chores/due?include_upcoming=true").await;
+    let upcoming: Vec<ChoreWithDueResponse> = response.json();
+    let due = upcoming.iter().find(|c| c.id == chore.id).unwrap();
+    assert!(!due.is_overdue);
+    assert_eq!(
+        due.next_due.unwrap(),
+        (today + Duration::days(1))
+            .and_time(NaiveTime::MIN)
+            .and_utc()
+    );
+}
+
+#[tokio::test]
+async fn test_chore_due_on_holiday_becomes_due_next_non_holiday() {
+    let pool = db::create_pool("sqlite::memory:")
+        .await
+        .expect("create pool");
+
+    let chore = ChoreRepository::create(
+        &pool,
+        CreateChoreParams {
+            name: "Laundry",
+            description: None,
+            schedule_type: ScheduleType::Interval,
+            cron_schedule: None,
+            interval_days: Some(1),
+            interval_time_hour: Some(9),
+            interval_time_minute: Some(0),
+            days_of_week: None,
+            due_at: None,
+            timezone: None,
+            notify_lead_minutes: None,
+            max_snoozes: None,
+            estimated_minutes: None,
+            review_interval_days: None,
+            min_notify_interval_minutes: None,
+            recipient: None,
+            auto_complete_with: None,
+            allowed_completion_sources: None,
+            external_id: None,
+            priority: ChorePriority::Normal,
+            anchor_mode: ChoreAnchorMode::FromCompletion,
+            user_id: None,
+            is_active: true,
+        },
+    )
+    .await
+    .expect("create chore");
+
+    let completed_at = chrono::NaiveDate::from_ymd_opt(2026, 12, 23)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap()
+        .and_utc();
+    CompletionRepository::create(
+        &pool,
+        chore.id,
+        Some(completed_at),
+        None,
+        CompletionSource::Api,
+        None,
+    )
+    .await
+    .expect("create completion");
+
+    // Due on the 24th at 09:00, but the 24th and 25th are holidays
+    for day in [24, 25] {
+        HolidayRepository::create(
+            &pool,
+            chrono::NaiveDate::from_ymd_opt(2026, 12, day).unwrap(),
+            None,
+        )
+        .await
+        .expect("create holiday");
+    }
+
+    let holidays: HashSet<_> = HolidayRepository::list_dates(&pool)
+        .await
+        .expect("list holidays");
+    let chore = ChoreRepository::get_by_id(&pool, chore.id, None)
+        .await
+        .expect("get chore")
+        .unwrap();
+
+    let expected_due = chrono::NaiveDate::from_ymd_opt(2026, 12, 26)
+        .unwrap()
+        .and_hms_opt(9, 0, 0)
+        .unwrap()
+        .and_utc();
+
+    let on_holiday = expected_due - Duration::days(1);
+    let info = ChoreService::compute_due_info(&chore, on_holiday, &holidays).unwrap();
+    assert_eq!(info.next_due, Some(expected_due));
+    assert!(!info.is_overdue);
+
+    let after_holidays = expected_due + Duration::hours(1);
+    let info = ChoreService::compute_due_info(&chore, after_holidays, &holidays).unwrap();
+    assert_eq!(info.next_due, Some(expected_due));
+    assert!(info.is_overdue);
+}
@@ -0,0 +1,181 @@
+//! Integration endpoint tests.
+//!
+//! Tests cover:
+//! - Completing a chore by email reply (POST /api/integrations/email-inbound)
+//! - Shared secret checks (401, and 404 while the webhook is disabled)
+
+mod common;
+
+use axum::http::StatusCode;
+use axum_test::TestServer;
+use common::{CompletionResponse, PaginatedResponse};
+use nag_server::db;
+use nag_server::http::{self, routes::integrations::EmailInboundSecret};
+use serde::Deserialize;
+
+const SECRET: &str = "s3cret";
+
+#[derive(Debug, Deserialize)]
+struct InboundEmailResponse {
+    completed: bool,
+    completion: Option<CompletionResponse>,
+}
+
+/// Create a test server with the inbound email webhook enabled
+async fn create_email_app() -> TestServer {
+    let pool = db::create_pool("sqlite::memory:")
+        .await
+        .expect("Failed to create test database pool");
+    let app =
+        http::build_test_app(pool).layer(axum::Extension(EmailInboundSecret(SECRET.to_string())));
+
+    TestServer::new(app.into_make_service()).expect("Failed to create test server")
+}
+
+/// A Mailgun-style inbound payload replying `reply` to the chore's reminder
+fn mailgun_payload(chore_id: uuid::Uuid, reply: &str) -> serde_json::Value {
+    serde_json::json!({
+        "recipient": format!("nag+{chore_id}@mail.example.com"),
+        "sender": "me@example.com",
+        "from": "Me <me@example.com>",
+        "subject": "Re: Chore due: Vacuum",
+        "body-plain": format!("{reply}\n\nOn Mon, Nag wrote:\n> Vacuum is due"),
+        "stripped-text": reply,
+        "timestamp": "1767225600"
+    })
+}
+
+async fn completions(server: &TestServer, chore_id: uuid::Uuid) -> Vec<CompletionResponse> {
+    let page: PaginatedResponse<CompletionResponse> = server
+        .get(&format!("/api/chores/{chore_id}/completions"))
+        .await
+        .json();
+    page.items
+}
+
+#[tokio::test]
+async fn test_email_inbound_done_reply_completes_chore() {
+    let server = create_email_app().await;
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+
+    let response = server
+        .post("/api/integrations/email-inbound")
+        .add_header("x-webhook-secret", SECRET)
+        .json(&mailgun_payload(chore.id, "Done!"))
+        .await;
+
+    response.assert_status_ok();
+    let body: InboundEmailResponse = response.json();
+    assert!(body.completed);
+    let completion = body.completion.expect("completion");
+    assert_eq!(completion.chore_id, chore.id);
+    assert_eq!(completion.source, "email");
+
+    let recorded = completions(&server, chore.id).await;
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded[0].source, "email");
+}
+
+#[tokio::test]
+async fn test_email_inbound_accepts_secret_in_query() {
+    let server = create_email_app().await;
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+
+    let response = server
+        .post(&format!("/api/integrations/email-inbound?secret={SECRET}"))
+        .json(&mailgun_payload(chore.id, "done"))
+        .await;
+
+    response.assert_status_ok();
+    assert_eq!(completions(&server, chore.id).await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_email_inbound_ignores_other_replies() {
+    let server = create_email_app().await;
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+
+    let response = server
+        .post("/api/integrations/email-inbound")
+        .add_header("x-webhook-secret", SECRET)
+        .json(&mailgun_payload(chore.id, "Will do it tomorrow"))
+        .await;
+
+    response.assert_status_ok();
+    let body: InboundEmailResponse = response.json();
+    assert!(!body.completed);
+    assert!(body.completion.is_none());
+    assert!(completions(&server, chore.id).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_email_inbound_rejects_wrong_secret() {
+    let server = create_email_app().await;
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+
+    for request in [
+        server.post("/api/integrations/email-inbound"),
+        server
+            .post("/api/integrations/email-inbound")
+            .add_header("x-webhook-secret", "wrong"),
+    ] {
+        request
+            .json(&mailgun_payload(chore.id, "done"))
+            .await
+            .assert_status(StatusCode::UNAUTHORIZED);
+    }
+    assert!(completions(&server, chore.id).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_email_inbound_disabled_without_secret() {
+    let server = common::create_test_app().await;
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+
+    server
+        .post("/api/integrations/email-inbound")
+        .add_header("x-webhook-secret", SECRET)
+        .json(&mailgun_payload(chore.id, "done"))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_email_inbound_unknown_chore_or_missing_token() {
+    let server = create_email_app().await;
+
+    server
+        .post("/api/integrations/email-inbound")
+        .add_header("x-webhook-secret", SECRET)
+        .json(&mailgun_payload(uuid::Uuid::new_v4(), "done"))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    server
+        .post("/api/integrations/email-inbound")
+        .add_header("x-webhook-secret", SECRET)
+        .json(&serde_json::json!({
+            "recipient": "nag@mail.example.com",
+            "stripped-text": "done"
+        }))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_email_inbound_respects_allowed_sources() {
+    let server = create_email_app().await;
+    let chore = common::create_chore(&server, "Vacuum", "0 9 * * 1").await;
+    server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&serde_json::json!({ "allowed_completion_sources": ["telegram"] }))
+        .await
+        .assert_status_ok();
+
+    server
+        .post("/api/integrations/email-inbound")
+        .add_header("x-webhook-secret", SECRET)
+        .json(&mailgun_payload(chore.id, "done"))
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+}
@@ -0,0 +1,91 @@
+use chrono::Utc;
+use nag_server::db::{self, ApiKeyRepository, UserRepository};
+use nag_server::http::routes::auth::{generate_api_key, hash_api_key};
+
+async fn create_user(pool: &sqlx::SqlitePool, subject: &str) -> uuid::Uuid {
+    UserRepository::upsert(pool, "https://issuer.test", subject, None, None, None)
+        .await
+        .expect("create user")
+        .id
+}
+
+#[tokio::test]
+async fn test_api_key_authenticates_until_revoked() {
+    let pool = db::create_pool("sqlite::memory:")
+        .await
+        .expect("create pool");
+    let user_id = create_user(&pool, "alice").await;
+
+    let key = generate_api_key();
+    let api_key = ApiKeyRepository::create(
+        &pool,
+        user_id,
+        "backup script",
+        &key[..12],
+        &hash_api_key(&key),
+    )
+    .await
+    .expect("create key");
+
+    let authenticated = ApiKeyRepository::authenticate(&pool, &hash_api_key(&key), Utc::now())
+        .await
+        .expect("authenticate");
+    assert_eq!(authenticated, Some(user_id));
+
+    let keys = ApiKeyRepository::list_for_user(&pool, user_id)
+        .await
+        .expect("list keys");
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0].prefix, &key[..12]);
+    assert!(keys[0].last_used_at.is_some());
+
+    assert!(
+        ApiKeyRepository::revoke(&pool, api_key.id, user_id)
+            .await
+            .expect("revoke")
+    );
+
+    let authenticated = ApiKeyRepository::authenticate(&pool, &hash_api_key(&key), Utc::now())
+        .await
+        .expect("authenticate");
+    assert_eq!(authenticated, None);
+}
+
+#[tokio::test]
+async fn test_api_key_unknown_key_is_rejected() {
+    let pool = db::create_pool("sqlite::memory:")
+        .await
+        .expect("create pool");
+
+    let authenticated =
+        ApiKeyRepository::authenticate(&pool, &hash_api_key(&generate_api_key()), Utc::now())
+            .await
+            .expect("authenticate");
+    assert_eq!(authenticated, None);
+}
+
+#[tokio::test]
+async fn test_api_key_revoke_requires_owner() {
+    let pool = db::create_pool("sqlite::memory:")
+        .await
+        .expect("create pool");
+    let alice = create_user(&pool, "alice").await;
+    let bob = create_user(&pool, "bob").await;
+
+    let key = generate_api_key();
+    let api_key = ApiKeyRepository::create(&pool, alice, "cron", &key[..12], &hash_api_key(&key))
+        .await
+        .expect("create key");
+
+    assert!(
+        !ApiKeyRepository::revoke(&pool, api_key.id, bob)
+            .await
+            .expect("revoke")
+    );
+    assert!(
+        ApiKeyRepository::list_for_user(&pool, bob)
+            .await
+            .expect("list keys")
+            .is_empty()
+    );
+}
@@ -0,0 +1,758 @@
+//! Notification API endpoint tests.
+//!
+//! Tests cover:
+//! - Delivery history for a chore (GET /api/chores/{id}/notifications)
+//! - Failed deliveries across chores (GET /api/notifications/deliveries)
+//! - Requeue a delivery (POST /api/notifications/deliveries/{delivery_id}/retry)
+//! - Event history by type and due time (GET /api/notifications/events)
+//! - Snooze a chore (POST /api/chores/{id}/snooze)
+//! - Simulate the due event generator (POST /api/admin/notifications/simulate)
+
+mod common;
+
+use axum::http::StatusCode;
+use chrono::{Duration, NaiveTime, Utc};
+use common::{NotificationDeliveryResponse, NotificationEventResponse, PaginatedResponse};
+use nag_server::db::{
+    NotificationRepository,
+    models::{NotificationChannel, NotificationEventType},
+};
+use uuid::Uuid;
+
+async fn delivery_id_for_event(pool: &sqlx::SqlitePool, event_id: Uuid) -> Uuid {
+    sqlx::query_scalar("SELECT id FROM notification_deliveries WHERE event_id = ?")
+        .bind(event_id)
+        .fetch_one(pool)
+        .await
+        .expect("fetch delivery id")
+}
+
+// ============================================================================
+// Chore Notifications (GET /api/chores/{id}/notifications)
+// ============================================================================
+
+#[tokio::test]
+async fn test_list_chore_notifications_empty() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_interval_chore(&server, "Water plants", 2).await;
+
+    let response = server
+        .get(&format!("/api/chores/{}/notifications", chore.id))
+        .await;
+
+    response.assert_status_ok();
+    let deliveries: Vec<NotificationDeliveryResponse> = response.json();
+    assert!(deliveries.is_empty());
+}
+
+#[tokio::test]
+async fn test_list_chore_notifications_reports_delivery_state() {
+    let (server, pool) = common::create_test_app_with_pool().await;
+
+    let chore = common::create_interval_chore(&server, "Water plants", 2).await;
+    let other = common::create_interval_chore(&server, "Vacuum", 2).await;
+
+    let earlier = Utc::now() - Duration::days(2);
+    let later = Utc::now();
+
+    let failed_event = NotificationRepository::upsert_due_event_with_deliveries(
+        &pool,
+        chore.id,
+        earlier,
+        "Chore due",
+        "Water plants is due",
+        &[NotificationChannel::Telegram],
+    )
+    .await
+    .expect("upsert failed event");
+    let delivered_event = NotificationRepository::upsert_due_event_with_deliveries(
+        &pool,
+        chore.id,
+        later,
+        "Chore due",
+        "Water plants is due",
+        &[NotificationChannel::Telegram],
+    )
+    .await
+    .expect("upsert delivered event");
+    NotificationRepository::upsert_due_event_with_deliveries(
+        &pool,
+        other.id,
+        later,
+        "Chore due",
+        "Vacuum is due",
+        &[NotificationChannel::Telegram],
+    )
+    .await
+    .expect("upsert other event");
+
+    let failed = delivery_id_for_event(&pool, failed_event).await;
+    NotificationRepository::mark_failed(&pool, failed, "chat not found", 0)
+        .await
+        .expect("mark failed");
+    let delivered = delivery_id_for_event(&pool, delivered_event).await;
+    NotificationRepository::mark_delivered(&pool, delivered)
+        .await
+        .expect("mark delivered");
+
+    let response = server
+        .get(&format!("/api/chores/{}/notifications", chore.id))
+        .await;
+
+    response.assert_status_ok();
+    let deliveries: Vec<NotificationDeliveryResponse> = response.json();
+    assert_eq!(deliveries.len(), 2);
+
+    // Newest event first
+    assert_eq!(deliveries[0].id, delivered);
+    assert_eq!(deliveries[0].status, "delivered");
+    assert_eq!(deliveries[0].channel, "telegram");
+    assert_eq!(deliveries[0].event_type, "due");
+    assert!(deliveries[0].delivered_at.is_some());
+
+    assert_eq!(deliveries[1].id, failed);
+    assert_eq!(deliveries[1].event_id, failed_event);
+    assert_eq!(deliveries[1].status, "failed");
+    assert_eq!(deliveries[1].attempt_count, 1);
+    assert_eq!(deliveries[1].last_error.as_deref(), Some("chat not found"));
+    assert!(deliveries[1].delivered_at.is_none());
+}
+
+#[tokio::test]
+async fn test_list_chore_notifications_not_found() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .get(&format!("/api/chores/{}/notifications", Uuid::new_v4()))
+        .await;
+
+    response.assert_status(StatusCode::NOT_FOUND);
+}
+
+// ============================================================================
+// List Deliveries (GET /api/notifications/deliveries)
+// ============================================================================
+
+#[tokio::test]
+async fn test_list_deliveries_filters_by_status() {
+    let (server, pool) = common::create_test_app_with_pool().await;
+
+    let chore = common::create_interval_chore(&server, "Water plants", 2).await;
+    let other = common::create_interval_chore(&server, "Vacuum", 2).await;
+    let failed_event = NotificationRepository::upsert_due_event_with_deliveries(
+        &pool,
+        chore.id,
+        Utc::now(),
+        "Chore due",
+        "Water plants is due",
+        &[NotificationChannel::Telegram],
+    )
+    .await
+    .expect("upsert event");
+    let failed_id = delivery_id_for_event(&pool, failed_event).await;
+    NotificationRepository::mark_failed(&pool, failed_id, "Unauthorized", 30)
+        .await
+        .expect("mark failed");
+    NotificationRepository::upsert_due_event_with_deliveries(
+        &pool,
+        other.id,
+        Utc::now(),
+        "Chore due",
+        "Vacuum is due",
+        &[NotificationChannel::Telegram],
+    )
+    .await
+    .expect("upsert event");
+
+    let all: PaginatedResponse<NotificationDeliveryResponse> =
+        server.get("/api/notifications/deliveries").await.json();
+    assert_eq!(all.items.len(), 2);
+
+    let response = server
+        .get("/api/notifications/deliveries")
+        .add_query_param("status", "failed")
+        .await;
+
+    response.assert_status_ok();
+    let failed: PaginatedResponse<NotificationDeliveryResponse> = response.json();
+    assert_eq!(failed.items.len(), 1);
+    let delivery = &failed.items[0];
+    assert_eq!(delivery.id, failed_id);
+    assert_eq!(delivery.chore_id, chore.id);
+    assert_eq!(delivery.channel, "telegram");
+    assert_eq!(delivery.attempt_count, 1);
+    assert_eq!(delivery.last_error.as_deref(), Some("Unauthorized"));
+    assert!(delivery.last_attempted_at.is_some());
+}
+
+#[tokio::test]
+async fn test_list_deliveries_paginates() {
+    let (server, pool) = common::create_test_app_with_pool().await;
+
+    let chore = common::create_interval_chore(&server, "Water plants", 2).await;
+    for days in 0..3 {
+        NotificationRepository::upsert_due_event_with_deliveries(
+            &pool,
+            chore.id,
+            Utc::now() - Duration::days(days),
+            "Chore due",
+            "Water plants is due",
+            &[NotificationChannel::Telegram, NotificationChannel::Discord],
+        )
+        .await
+        .expect("upsert event");
+    }
+
+    let mut seen = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let mut request = server
+            .get("/api/notifications/deliveries")
+            .add_query_param("limit", 4);
+        if let Some(cursor) = &cursor {
+            request = request.add_query_param("cursor", cursor);
+        }
+        let page: PaginatedResponse<NotificationDeliveryResponse> = request.await.json();
+        if page.items.is_empty() {
+            break;
+        }
+        seen.extend(page.items.into_iter().map(|d| d.id));
+        cursor = page.next_cursor;
+    }
+
+    assert_eq!(seen.len(), 6);
+    seen.sort();
+    seen.dedup();
+    assert_eq!(seen.len(), 6);
+}
+
+#[tokio::test]
+async fn test_list_deliveries_rejects_unknown_status() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .get("/api/notifications/deliveries")
+        .add_query_param("status", "lost")
+        .await;
+
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+// ============================================================================
+// List Events (GET /api/notifications/events)
+// ============================================================================
+
+#[tokio::test]
+async fn test_list_events_filters_by_type_and_due_time() {
+    let (server, pool) = common::create_test_app_with_pool().await;
+
+    let chore = common::create_interval_chore(&server, "Water plants", 2).await;
+    let now = Utc::now();
+    let mut due_events = Vec::new();
+    for days in 1..=3 {
+        let event_id = NotificationRepository::upsert_due_event_with_deliveries(
+            &pool,
+            chore.id,
+            now - Duration::days(days),
+            "Chore due",
+            "Water plants is due",
+            &[NotificationChannel::Telegram],
+        )
+        .await
+        .expect("upsert due event");
+        due_events.push(event_id);
+    }
+    let review_event = NotificationRepository::upsert_event_with_deliveries(
+        &pool,
+        chore.id,
+        NotificationEventType::Review,
+        now - Duration::days(2),
+        "Review chore",
+        "Is Water plants still needed?",
+        &[NotificationChannel::Telegram, NotificationChannel::Discord],
+    )
+    .await
+    .expect("upsert review event");
+    let delivered = delivery_id_for_event(&pool, due_events[0]).await;
+    NotificationRepository::mark_delivered(&pool, delivered)
+        .await
+        .expect("mark delivered");
+
+    let all: PaginatedResponse<NotificationEventResponse> =
+        server.get("/api/notifications/events").await.json();
+    assert_eq!(all.items.len(), 4);
+
+    let response = server
+        .get("/api/notifications/events")
+        .add_query_param("type", "due")
+        .await;
+    response.assert_status_ok();
+    let due: PaginatedResponse<NotificationEventResponse> = response.json();
+    // Latest due time first
+    assert_eq!(
+        due.items.iter().map(|e| e.id).collect::<Vec<_>>(),
+        due_events
+    );
+    assert_eq!(due.items[0].deliveries.len(), 1);
+    assert_eq!(due.items[0].deliveries[0].status, "delivered");
+    assert!(due.items[0].deliveries[0].delivered_at.is_some());
+    assert_eq!(due.items[1].deliveries[0].status, "pending");
+
+    let review: PaginatedResponse<NotificationEventResponse> = server
+        .get("/api/notifications/events")
+        .add_query_param("type", "review")
+        .await
+        .json();
+    assert_eq!(review.items.len(), 1);
+    assert_eq!(review.items[0].id, review_event);
+    assert_eq!(review.items[0].event_type, "review");
+    assert_eq!(review.items[0].chore_id, chore.id);
+    let channels: Vec<&str> = review.items[0]
+        .deliveries
+        .iter()
+        .map(|d| d.channel.as_str())
+        .collect();
+    assert_eq!(channels, vec!["discord", "telegram"]);
+
+    // Both bounds are inclusive
+    let window: PaginatedResponse<NotificationEventResponse> = server
+        .get("/api/notifications/events")
+        .add_query_param("type", "due")
+        .add_query_param("from", (now - Duration::days(3)).to_rfc3339())
+        .add_query_param("to", (now - Duration::days(2)).to_rfc3339())
+        .await
+        .json();
+    assert_eq!(
+        window.items.iter().map(|e| e.id).collect::<Vec<_>>(),
+        due_events[1..]
+    );
+
+    let since: PaginatedResponse<NotificationEventResponse> = server
+        .get("/api/notifications/events")
+        .add_query_param("from", (now - Duration::hours(36)).to_rfc3339())
+        .await
+        .json();
+    assert_eq!(
+        since.items.iter().map(|e| e.id).collect::<Vec<_>>(),
+        vec![due_events[0]]
+    );
+}
+
+#[tokio::test]
+async fn test_list_events_paginates_across_equal_due_times() {
+    let (server, pool) = common::create_test_app_with_pool().await;
+
+    let due_at = Utc::now() - Duration::hours(1);
+    for name in ["Water plants", "Vacuum", "Dishes"] {
+        let chore = common::create_interval_chore(&server, name, 2).await;
+        for hours in [0, 24] {
+            NotificationRepository::upsert_due_event_with_deliveries(
+                &pool,
+                chore.id,
+                due_at - Duration::hours(hours),
+                "Chore due",
+                name,
+                &[NotificationChannel::Telegram],
+            )
+            .await
+            .expect("upsert event");
+        }
+    }
+
+    let mut seen = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let mut request = server
+            .get("/api/notifications/events")
+            .add_query_param("limit", 4);
+        if let Some(cursor) = &cursor {
+            request = request.add_query_param("cursor", cursor);
+        }
+        let page: PaginatedResponse<NotificationEventResponse> = request.await.json();
+        if page.items.is_empty() {
+            break;
+        }
+        seen.extend(page.items.into_iter().map(|e| (e.due_at, e.id)));
+        cursor = page.next_cursor;
+    }
+
+    assert_eq!(seen.len(), 6);
+    assert!(seen.windows(2).all(|pair| pair[0].0 >= pair[1].0));
+    seen.sort();
+    seen.dedup();
+    assert_eq!(seen.len(), 6);
+}
+
+#[tokio::test]
+async fn test_list_events_rejects_invalid_filters() {
+    let server = common::create_test_app().await;
+
+    let now = Utc::now();
+    for (param, value) in [
+        ("type", "reminder".to_string()),
+        ("cursor", "not-a-cursor".to_string()),
+        ("from", "yesterday".to_string()),
+    ] {
+        server
+            .get("/api/notifications/events")
+            .add_query_param(param, value)
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+    }
+
+    server
+        .get("/api/notifications/events")
+        .add_query_param("from", now.to_rfc3339())
+        .add_query_param("to", (now - Duration::days(1)).to_rfc3339())
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+}
+
+// ============================================================================
+// Retry Delivery (POST /api/notifications/deliveries/{delivery_id}/retry)
+// ============================================================================
+
+#[tokio::test]
+async fn test_retry_exhausted_delivery_requeues_it() {
+    let (server, pool) = common::create_test_app_with_pool().await;
+
+    let chore = common::create_interval_chore(&server, "Water plants", 2).await;
+    let event_id = NotificationRepository::upsert_due_event_with_deliveries(
+        &pool,
+        chore.id,
+        Utc::now(),
+        "Chore due",
+        "Water plants is due",
+        &[NotificationChannel::Telegram],
+    )
+    .await
+    .expect("upsert event");
+    let delivery_id = delivery_id_for_event(&pool, event_id).await;
+    for _ in 0..5 {
+        NotificationRepository::mark_failed(&pool, delivery_id, "Unauthorized", 30)
+            .await
+            .expect("mark failed");
+    }
+    NotificationRepository::mark_chat_delivered(&pool, delivery_id, 100)
+        .await
+        .expect("mark chat delivered");
+
+    let exhausted = NotificationRepository::list_pending(&pool, 50, 5, Utc::now())
+        .await
+        .expect("list pending");
+    assert!(exhausted.is_empty());
+
+    let response = server
+        .post(&format!(
+            "/api/notifications/deliveries/{}/retry",
+            delivery_id
+        ))
+        .await;
+
+    response.assert_status_ok();
+    let delivery: NotificationDeliveryResponse = response.json();
+    assert_eq!(delivery.id, delivery_id);
+    assert_eq!(delivery.status, "pending");
+    assert_eq!(delivery.attempt_count, 0);
+    assert!(delivery.last_attempted_at.is_none());
+
+    // Picked up immediately, even with a retry backoff configured
+    let pending = NotificationRepository::list_pending(&pool, 50, 5, Utc::now())
+        .await
+        .expect("list pending");
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].delivery_id, delivery_id);
+
+    // Requeued for every chat again
+    let reached = NotificationRepository::delivered_chats(&pool, delivery_id)
+        .await
+        .expect("delivered chats");
+    assert!(reached.is_empty());
+}
+
+#[tokio::test]
+async fn test_retry_delivery_not_found() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .post(&format!(
+            "/api/notifications/deliveries/{}/retry",
+            Uuid::new_v4()
+        ))
+        .await;
+
+    response.assert_status(StatusCode::NOT_FOUND);
+}
+
+// ============================================================================
+// Snooze Chore (POST /api/chores/{id}/snooze)
+// ============================================================================
+
+async fn create_chore_with_snooze_limit(
+    server: &axum_test::TestServer,
+    max_snoozes: i32,
+) -> common::ChoreResponse {
+    let response = server
+        .post("/api/chores")
+        .json(&serde_json::json!({
+            "name": "Water plants",
+            "schedule_type": "interval",
+            "interval_days": 2,
+            "max_snoozes": max_snoozes
+        }))
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    response.json()
+}
+
+#[tokio::test]
+async fn test_snooze_chore() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_interval_chore(&server, "Water plants", 2).await;
+    let until = Utc::now() + Duration::hours(3);
+
+    let response = server
+        .post(&format!("/api/chores/{}/snooze", chore.id))
+        .json(&serde_json::json!({ "until": until }))
+        .await;
+
+    response.assert_status_ok();
+    let body: serde_json::Value = response.json();
+    let snoozed_until: chrono::DateTime<Utc> =
+        serde_json::from_value(body["snoozed_until"].clone()).expect("snoozed_until");
+    assert_eq!(snoozed_until.timestamp(), until.timestamp());
+}
+
+#[tokio::test]
+async fn test_snooze_chore_beyond_limit_conflicts() {
+    let server = common::create_test_app().await;
+
+    let chore = create_chore_with_snooze_limit(&server, 2).await;
+    assert_eq!(chore.max_snoozes, Some(2));
+
+    for hours in 1..=2 {
+        server
+            .post(&format!("/api/chores/{}/snooze", chore.id))
+            .json(&serde_json::json!({ "until": Utc::now() + Duration::hours(hours) }))
+            .await
+            .assert_status_ok();
+    }
+
+    let response = server
+        .post(&format!("/api/chores/{}/snooze", chore.id))
+        .json(&serde_json::json!({ "until": Utc::now() + Duration::hours(3) }))
+        .await;
+
+    response.assert_status(StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_completing_chore_resets_snooze_count() {
+    let server = common::create_test_app().await;
+
+    let chore = create_chore_with_snooze_limit(&server, 1).await;
+    let snooze = |hours| serde_json::json!({ "until": Utc::now() + Duration::hours(hours) });
+
+    server
+        .post(&format!("/api/chores/{}/snooze", chore.id))
+        .json(&snooze(1))
+        .await
+        .assert_status_ok();
+    server
+        .post(&format!("/api/chores/{}/snooze", chore.id))
+        .json(&snooze(2))
+        .await
+        .assert_status(StatusCode::CONFLICT);
+
+    common::complete_chore(&server, chore.id, None).await;
+
+    server
+        .post(&format!("/api/chores/{}/snooze", chore.id))
+        .json(&snooze(3))
+        .await
+        .assert_status_ok();
+}
+
+#[tokio::test]
+async fn test_complete_and_snooze_rejected_when_snoozing_disabled() {
+    let server = common::create_test_app().await;
+
+    let chore = create_chore_with_snooze_limit(&server, 0).await;
+
+    let response = server
+        .post(&format!("/api/chores/{}/complete", chore.id))
+        .json(&serde_json::json!({ "snooze_next_until": Utc::now() + Duration::days(1) }))
+        .await;
+
+    response.assert_status(StatusCode::CONFLICT);
+
+    // The rejected request must not have recorded a completion
+    let response = server
+        .get(&format!("/api/chores/{}/completions", chore.id))
+        .await;
+    response.assert_status_ok();
+    let completions: common::PaginatedResponse<common::CompletionResponse> = response.json();
+    assert!(completions.items.is_empty());
+}
+
+#[tokio::test]
+async fn test_snooze_chore_in_past() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_interval_chore(&server, "Water plants", 2).await;
+
+    let response = server
+        .post(&format!("/api/chores/{}/snooze", chore.id))
+        .json(&serde_json::json!({ "until": Utc::now() - Duration::hours(1) }))
+        .await;
+
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_snooze_chore_not_found() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .post(&format!("/api/chores/{}/snooze", Uuid::new_v4()))
+        .json(&serde_json::json!({ "until": Utc::now() + Duration::hours(1) }))
+        .await;
+
+    response.assert_status(StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_create_chore_negative_max_snoozes() {
+    let server = common::create_test_app().await;
+
+    let response = server
+        .post("/api/chores")
+        .json(&serde_json::json!({
+            "name": "Water plants",
+            "schedule_type": "interval",
+            "interval_days": 2,
+            "max_snoozes": -1
+        }))
+        .await;
+
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+// ============================================================================
+// Simulation (POST /api/admin/notifications/simulate)
+// ============================================================================
+
+#[derive(Debug, serde::Deserialize)]
+struct SimulatedNotification {
+    chore_id: Uuid,
+    due_at: chrono::DateTime<Utc>,
+    notify_at: chrono::DateTime<Utc>,
+    title: String,
+    channels: Vec<String>,
+}
+
+async fn count_rows(pool: &sqlx::SqlitePool, table: &str) -> i64 {
+    sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {table}"))
+        .fetch_one(pool)
+        .await
+        .expect("count rows")
+}
+
+#[tokio::test]
+async fn test_simulate_notifications_lists_due_events_without_writing() {
+    let (_, pool) = common::create_test_app_with_pool().await;
+    let app = nag_server::http::build_test_app(pool.clone()).layer(axum::Extension(
+        nag_server::services::EventGeneratorSettings {
+            channels: vec![NotificationChannel::Telegram, NotificationChannel::Slack],
+            config: Default::default(),
+        },
+    ));
+    let server = axum_test::TestServer::new(app.into_make_service()).unwrap();
+
+    let plants = common::create_interval_chore(&server, "Water plants", 2).await;
+    let dishes = common::create_chore(&server, "Dishes", "0 9 * * *").await;
+    common::create_once_in_a_while_chore(&server, "Descale kettle", None).await;
+
+    let response = server
+        .post("/api/admin/notifications/simulate")
+        .json(&serde_json::json!({ "window_days": 7 }))
+        .await;
+
+    response.assert_status_ok();
+    let events: Vec<SimulatedNotification> = response.json();
+
+    let plant_due: Vec<_> = events
+        .iter()
+        .filter(|e| e.chore_id == plants.id)
+        .map(|e| e.due_at)
+        .collect();
+    // Interval chores without a time of day fall due at midnight
+    let created = plants
+        .created_at
+        .date_naive()
+        .and_time(NaiveTime::MIN)
+        .and_utc();
+    assert_eq!(
+        plant_due,
+        [2, 4, 6].map(|days| created + Duration::days(days))
+    );
+    assert_eq!(events.iter().filter(|e| e.chore_id == dishes.id).count(), 7);
+    assert_eq!(events.len(), 10, "once-in-a-while chores never notify");
+
+    assert!(events.is_sorted_by_key(|e| e.notify_at));
+    let first_plants = events.iter().find(|e| e.chore_id == plants.id).unwrap();
+    assert_eq!(first_plants.title, "Chore due: Water plants");
+    assert_eq!(first_plants.channels, ["telegram", "slack"]);
+
+    assert_eq!(count_rows(&pool, "notification_events").await, 0);
+    assert_eq!(count_rows(&pool, "notification_deliveries").await, 0);
+    assert_eq!(count_rows(&pool, "completions").await, 0);
+}
+
+#[tokio::test]
+async fn test_simulate_notifications_skips_events_already_raised() {
+    let (server, pool) = common::create_test_app_with_pool().await;
+
+    let chore = common::create_interval_chore(&server, "Water plants", 2).await;
+    let created = chore
+        .created_at
+        .date_naive()
+        .and_time(NaiveTime::MIN)
+        .and_utc();
+    let first_due = created + Duration::days(2);
+    NotificationRepository::upsert_due_event_with_deliveries(
+        &pool,
+        chore.id,
+        first_due,
+        "Chore due: Water plants",
+        "",
+        &[],
+    )
+    .await
+    .expect("raise event");
+
+    // Without notifications enabled no channels are reported
+    let events: Vec<SimulatedNotification> = server
+        .post("/api/admin/notifications/simulate")
+        .await
+        .json();
+
+    let due: Vec<_> = events.iter().map(|e| e.due_at).collect();
+    assert_eq!(due, [4, 6].map(|days| created + Duration::days(days)));
+    assert!(events.iter().all(|e| e.channels.is_empty()));
+}
+
+#[tokio::test]
+async fn test_simulate_notifications_rejects_out_of_range_window() {
+    let server = common::create_test_app().await;
+
+    for days in [0, 32] {
+        server
+            .post("/api/admin/notifications/simulate")
+            .json(&serde_json::json!({ "window_days": days }))
+            .await
+            .assert_status_bad_request();
+    }
+}
@@ -3,14 +3,19 @@
 //! Tests cover:
 //! - Tag CRUD (create, list, delete)
 //! - Tag assignment via chore create/update
-//! - Tag filtering on list/due endpoints
+//! - Tag filtering on list/due endpoints, matching any or all of several tags
 //! - Conflict on duplicate tag names
 //! - Cascade behavior (deleting tag removes from chores, deleting chore doesn't delete tag)
+//! - Soft delete, restore and purge
+//! - Automatic colors for tags created without one
+//! - Muting and unmuting tags
+//! - Merging one tag into another
 
 mod common;
 
 use axum::http::StatusCode;
 use common::{ChoreResponse, ChoreWithDueResponse, PaginatedResponse, ProblemDetails, TagResponse};
+use nag_server::db::tags::{TAG_PALETTE, TagColorStrategy, auto_tag_color};
 use uuid::Uuid;
 
 // ============================================================================
@@ -32,10 +37,16 @@ async fn test_list_tags_empty() {
 async fn test_create_tag() {
     let server = common::create_test_app().await;
 
-    let tag = common::create_tag(&server, "kitchen").await;
+    let response = server
+        .post("/api/tags")
+        .json(&serde_json::json!({ "name": "kitchen" }))
+        .await;
+    response.assert_status(StatusCode::CREATED);
 
+    let tag: TagResponse = response.json();
     assert_eq!(tag.name, "kitchen");
     assert!(!tag.id.is_nil());
+    assert_eq!(response.header("location"), format!("/api/tags/{}", tag.id));
 }
 
 #[tokio::test]
@@ -369,6 +380,90 @@ async fn test_due_chores_filter_by_tag() {
     }
 }
 
+#[tokio::test]
+async fn test_list_chores_filter_by_several_tags() {
+    let server = common::create_test_app().await;
+
+    common::create_chore_with_tags(&server, "Vacuum", "0 9 * * 1", &["kitchen"]).await;
+    common::create_chore_with_tags(&server, "Dishes", "0 20 * * *", &["kitchen", "daily"]).await;
+    common::create_chore_with_tags(&server, "Mow lawn", "0 10 * * 6", &["outdoor"]).await;
+    common::create_chore_with_tags(&server, "Water plants", "0 8 * * *", &["daily"]).await;
+
+    let names = |body: PaginatedResponse<ChoreResponse>| {
+        let mut names: Vec<String> = body.items.into_iter().map(|c| c.name).collect();
+        names.sort();
+        names
+    };
+
+    // `any` is the default
+    let response = server
+        .get("/api/chores?tag=kitchen,OUTDOOR&with_total=true")
+        .await;
+    response.assert_status_ok();
+    let body: PaginatedResponse<ChoreResponse> = response.json();
+    assert_eq!(body.total, Some(3));
+    assert_eq!(names(body), vec!["Dishes", "Mow lawn", "Vacuum"]);
+
+    let response = server
+        .get("/api/chores?tag=kitchen,daily&tag_match=all&with_total=true")
+        .await;
+    let body: PaginatedResponse<ChoreResponse> = response.json();
+    assert_eq!(body.total, Some(1));
+    assert_eq!(names(body), vec!["Dishes"]);
+
+    // Pages only hold matching chores
+    let response = server
+        .get("/api/chores?tag=kitchen,daily&tag_match=any&limit=2&with_total=true")
+        .await;
+    let body: PaginatedResponse<ChoreResponse> = response.json();
+    assert_eq!(body.total, Some(3));
+    assert_eq!(body.items.len(), 2);
+
+    // Repeating a tag doesn't make `all` impossible to satisfy
+    let response = server
+        .get("/api/chores?tag=daily,Daily&tag_match=all")
+        .await;
+    let body: PaginatedResponse<ChoreResponse> = response.json();
+    assert_eq!(names(body), vec!["Dishes", "Water plants"]);
+}
+
+#[tokio::test]
+async fn test_due_chores_filter_by_all_tags() {
+    let server = common::create_test_app().await;
+
+    common::create_chore_with_tags(&server, "Vacuum", "0 9 * * 1", &["kitchen"]).await;
+    common::create_chore_with_tags(&server, "Dishes", "0 20 * * *", &["kitchen", "daily"]).await;
+    common::create_chore_with_tags(&server, "Mow lawn", "0 10 * * 6", &["outdoor"]).await;
+
+    let response = server
+        .get("/api/chores/due?include_upcoming=true&tag=kitchen,daily&tag_match=all")
+        .await;
+    response.assert_status_ok();
+    let chores: Vec<ChoreWithDueResponse> = response.json();
+    assert_eq!(chores.len(), 1);
+    assert_eq!(chores[0].name, "Dishes");
+
+    let response = server
+        .get("/api/chores/due?include_upcoming=true&tag=kitchen,outdoor")
+        .await;
+    let chores: Vec<ChoreWithDueResponse> = response.json();
+    assert_eq!(chores.len(), 3);
+}
+
+#[tokio::test]
+async fn test_unknown_tag_match_rejected() {
+    let server = common::create_test_app().await;
+
+    server
+        .get("/api/chores?tag=kitchen&tag_match=most")
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+    server
+        .get("/api/chores/due?tag=kitchen&tag_match=most")
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+}
+
 // ============================================================================
 // Cascade Behavior
 // ============================================================================
@@ -416,7 +511,7 @@ async fn test_create_tag_without_color() {
 
     let tag = common::create_tag(&server, "kitchen").await;
     assert_eq!(tag.name, "kitchen");
-    assert!(tag.color.is_none());
+    assert_eq!(tag.color.as_deref(), Some(auto_tag_color("kitchen")));
 }
 
 #[tokio::test]
@@ -440,12 +535,35 @@ async fn test_create_tag_with_invalid_color_returns_400() {
     assert!(problem.detail.unwrap().contains("Invalid tag color"));
 }
 
+#[tokio::test]
+async fn test_create_tag_with_hex_color() {
+    let server = common::create_test_app().await;
+
+    let tag = common::create_tag_with_color(&server, "kitchen", Some("#1a2b3c")).await;
+
+    assert_eq!(tag.color, Some("#1a2b3c".to_string()));
+}
+
+#[tokio::test]
+async fn test_create_tag_with_invalid_hex_color_returns_400() {
+    let server = common::create_test_app().await;
+
+    for color in ["#xyz", "#12345", "1a2b3c", "#1a2b3c4"] {
+        let body = serde_json::json!({ "name": "kitchen", "color": color });
+        let response = server.post("/api/tags").json(&body).await;
+        response.assert_status(StatusCode::BAD_REQUEST);
+
+        let problem: ProblemDetails = response.json();
+        assert!(problem.detail.unwrap().contains("#RRGGBB"));
+    }
+}
+
 #[tokio::test]
 async fn test_update_tag_set_color() {
     let server = common::create_test_app().await;
 
     let tag = common::create_tag(&server, "kitchen").await;
-    assert!(tag.color.is_none());
+    assert_eq!(tag.color.as_deref(), Some(auto_tag_color("kitchen")));
 
     let body = serde_json::json!({ "color": "terracotta" });
     let response = server
@@ -474,7 +592,7 @@ async fn test_update_tag_clear_color() {
     response.assert_status_ok();
 
     let updated: TagResponse = response.json();
-    assert!(updated.color.is_none());
+    assert_eq!(updated.color.as_deref(), Some(auto_tag_color("kitchen")));
 }
 
 #[tokio::test]
@@ -537,3 +655,312 @@ async fn test_update_tag_name() {
     assert_eq!(updated.name, "Kitchen Area");
     assert_eq!(updated.color, Some("sage".to_string()));
 }
+
+#[tokio::test]
+async fn test_update_tag_name_to_existing_name_returns_409() {
+    let server = common::create_test_app().await;
+
+    let kitchen = common::create_tag(&server, "kitchen").await;
+    common::create_tag(&server, "bathroom").await;
+
+    let response = server
+        .put(&format!("/api/tags/{}", kitchen.id))
+        .json(&serde_json::json!({ "name": "Bathroom" }))
+        .await;
+    response.assert_status(StatusCode::CONFLICT);
+    let problem: ProblemDetails = response.json();
+    assert!(problem.detail.unwrap().contains("already exists"));
+
+    // Changing only the case of its own name is fine
+    let response = server
+        .put(&format!("/api/tags/{}", kitchen.id))
+        .json(&serde_json::json!({ "name": "Kitchen" }))
+        .await;
+    response.assert_status_ok();
+    let updated: TagResponse = response.json();
+    assert_eq!(updated.name, "Kitchen");
+}
+
+// ============================================================================
+// Soft Delete, Restore and Purge
+// ============================================================================
+
+#[tokio::test]
+async fn test_restore_tag_keeps_color_and_chore_associations() {
+    let server = common::create_test_app().await;
+
+    let tag = common::create_tag_with_color(&server, "kitchen", Some("sage")).await;
+    let chore = common::create_chore_with_tags(&server, "Vacuum", "0 9 * * 1", &["kitchen"]).await;
+
+    server
+        .delete(&format!("/api/tags/{}", tag.id))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+
+    let tags: Vec<TagResponse> = server.get("/api/tags").await.json();
+    assert!(tags.is_empty());
+    let chores: PaginatedResponse<ChoreResponse> = server
+        .get("/api/chores")
+        .add_query_param("tag", "kitchen")
+        .await
+        .json();
+    assert!(chores.items.is_empty());
+
+    let response = server.post(&format!("/api/tags/{}/restore", tag.id)).await;
+    response.assert_status_ok();
+    let restored: TagResponse = response.json();
+    assert_eq!(restored.id, tag.id);
+    assert_eq!(restored.color.as_deref(), Some("sage"));
+
+    let fetched: ChoreResponse = server
+        .get(&format!("/api/chores/{}", chore.id))
+        .await
+        .json();
+    assert_eq!(fetched.tags.len(), 1);
+    assert_eq!(fetched.tags[0].id, tag.id);
+}
+
+#[tokio::test]
+async fn test_updating_chore_tags_keeps_deleted_tag_association() {
+    let server = common::create_test_app().await;
+
+    let chore =
+        common::create_chore_with_tags(&server, "Vacuum", "0 9 * * 1", &["kitchen", "weekly"])
+            .await;
+    let kitchen = chore.tags.iter().find(|t| t.name == "kitchen").unwrap().id;
+    server
+        .delete(&format!("/api/tags/{}", kitchen))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+
+    server
+        .put(&format!("/api/chores/{}", chore.id))
+        .json(&serde_json::json!({ "tags": ["weekly", "daily"] }))
+        .await
+        .assert_status_ok();
+
+    server
+        .post(&format!("/api/tags/{}/restore", kitchen))
+        .await
+        .assert_status_ok();
+    let fetched: ChoreResponse = server
+        .get(&format!("/api/chores/{}", chore.id))
+        .await
+        .json();
+    let mut names: Vec<&str> = fetched.tags.iter().map(|t| t.name.as_str()).collect();
+    names.sort();
+    assert_eq!(names, ["daily", "kitchen", "weekly"]);
+}
+
+#[tokio::test]
+async fn test_create_tag_with_name_of_deleted_tag_conflicts() {
+    let server = common::create_test_app().await;
+
+    let tag = common::create_tag(&server, "kitchen").await;
+    server
+        .delete(&format!("/api/tags/{}", tag.id))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+
+    let response = server
+        .post("/api/tags")
+        .json(&serde_json::json!({ "name": "Kitchen" }))
+        .await;
+    response.assert_status(StatusCode::CONFLICT);
+    let problem: ProblemDetails = response.json();
+    assert!(problem.detail.unwrap().contains("restore or purge"));
+
+    // Deleting again is a 404 until it's restored
+    server
+        .delete(&format!("/api/tags/{}", tag.id))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_purge_tag_deletes_permanently() {
+    let server = common::create_test_app().await;
+
+    let chore = common::create_chore_with_tags(&server, "Vacuum", "0 9 * * 1", &["kitchen"]).await;
+    let tag_id = chore.tags[0].id;
+    server
+        .delete(&format!("/api/tags/{}", tag_id))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+
+    server
+        .delete(&format!("/api/tags/{}", tag_id))
+        .add_query_param("purge", true)
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+
+    server
+        .post(&format!("/api/tags/{}/restore", tag_id))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    // The name is free again, and the new tag doesn't inherit the chore
+    let tag = common::create_tag(&server, "kitchen").await;
+    assert_ne!(tag.id, tag_id);
+    let fetched: ChoreResponse = server
+        .get(&format!("/api/chores/{}", chore.id))
+        .await
+        .json();
+    assert!(fetched.tags.is_empty());
+}
+
+// ============================================================================
+// Automatic Colors
+// ============================================================================
+
+async fn create_least_used_color_app() -> axum_test::TestServer {
+    let pool = nag_server::db::create_pool("sqlite::memory:")
+        .await
+        .expect("Failed to create test database pool");
+    let app = nag_server::http::build_test_app_with_state(nag_server::http::AppState {
+        tag_color_strategy: TagColorStrategy::LeastUsed,
+        ..nag_server::http::test_state(pool)
+    });
+
+    axum_test::TestServer::new(app.into_make_service()).expect("Failed to create test server")
+}
+
+#[tokio::test]
+async fn test_colorless_tags_spread_over_palette() {
+    let server = create_least_used_color_app().await;
+
+    // One tag already uses the first palette color
+    common::create_tag_with_color(&server, "kitchen", Some(TAG_PALETTE[0])).await;
+
+    let mut colors = Vec::new();
+    for i in 0..2 * TAG_PALETTE.len() - 1 {
+        let tag = common::create_tag(&server, &format!("tag-{i}")).await;
+        colors.push(tag.color.expect("auto-assigned color"));
+    }
+
+    // The first round fills every color but the one in use, in palette order
+    assert_eq!(colors[..TAG_PALETTE.len() - 1], TAG_PALETTE[1..]);
+    // After that every color is used exactly twice
+    for color in TAG_PALETTE {
+        let uses =
+            colors.iter().filter(|c| c == color).count() + usize::from(*color == TAG_PALETTE[0]);
+        assert_eq!(uses, 2, "{color} is used {uses} times");
+    }
+}
+
+#[tokio::test]
+async fn test_chore_tags_created_on_the_fly_get_least_used_color() {
+    let server = create_least_used_color_app().await;
+
+    common::create_tag_with_color(&server, "kitchen", Some(TAG_PALETTE[0])).await;
+    let chore =
+        common::create_chore_with_tags(&server, "Vacuum", "0 9 * * 1", &["kitchen", "weekly"])
+            .await;
+
+    let weekly = chore.tags.iter().find(|t| t.name == "weekly").unwrap();
+    assert_eq!(weekly.color.as_deref(), Some(TAG_PALETTE[1]));
+    let kitchen = chore.tags.iter().find(|t| t.name == "kitchen").unwrap();
+    assert_eq!(kitchen.color.as_deref(), Some(TAG_PALETTE[0]));
+}
+
+#[tokio::test]
+async fn test_colorless_tags_get_a_color_from_their_name() {
+    let server = common::create_test_app().await;
+
+    let tag = common::create_tag(&server, "Kitchen").await;
+    assert_eq!(tag.color.as_deref(), Some(auto_tag_color("kitchen")));
+}
+
+// ============================================================================
+// Muting (POST /api/tags/{id}/mute, /unmute)
+// ============================================================================
+
+#[tokio::test]
+async fn test_mute_and_unmute_tag() {
+    let server = common::create_test_app().await;
+
+    let tag = common::create_tag(&server, "kitchen").await;
+    assert!(!tag.muted);
+
+    let response = server.post(&format!("/api/tags/{}/mute", tag.id)).await;
+    response.assert_status_ok();
+    let muted: TagResponse = response.json();
+    assert!(muted.muted);
+
+    let chore = common::create_chore_with_tags(&server, "Vacuum", "0 9 * * 1", &["kitchen"]).await;
+    assert!(chore.tags[0].muted);
+
+    let response = server.post(&format!("/api/tags/{}/unmute", tag.id)).await;
+    response.assert_status_ok();
+    let unmuted: TagResponse = response.json();
+    assert!(!unmuted.muted);
+}
+
+#[tokio::test]
+async fn test_mute_deleted_tag_not_found() {
+    let server = common::create_test_app().await;
+
+    let tag = common::create_tag(&server, "kitchen").await;
+    server
+        .delete(&format!("/api/tags/{}", tag.id))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+
+    server
+        .post(&format!("/api/tags/{}/mute", tag.id))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+    server
+        .post(&format!("/api/tags/{}/unmute", Uuid::new_v4()))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_merge_tags_moves_chores_to_target() {
+    let server = common::create_test_app().await;
+
+    let only_source =
+        common::create_chore_with_tags(&server, "Vacuum", "0 9 * * 1", &["cleaning"]).await;
+    let both =
+        common::create_chore_with_tags(&server, "Mop", "0 9 * * 2", &["cleaning", "housework"])
+            .await;
+    let source_id = only_source.tags[0].id;
+    let target_id = both.tags.iter().find(|t| t.name == "housework").unwrap().id;
+
+    let body = serde_json::json!({ "source_id": source_id, "target_id": target_id });
+    let response = server.post("/api/tags/merge").json(&body).await;
+    response.assert_status_ok();
+    let merged: TagResponse = response.json();
+    assert_eq!(merged.id, target_id);
+
+    for id in [only_source.id, both.id] {
+        let chore: ChoreResponse = server.get(&format!("/api/chores/{}", id)).await.json();
+        let names: Vec<&str> = chore.tags.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["housework"]);
+    }
+
+    let tags: Vec<TagResponse> = server.get("/api/tags").await.json();
+    assert_eq!(tags.len(), 1);
+    assert_eq!(tags[0].id, target_id);
+}
+
+#[tokio::test]
+async fn test_merge_tags_rejects_same_or_missing_tag() {
+    let server = common::create_test_app().await;
+
+    let tag = common::create_tag(&server, "kitchen").await;
+
+    let body = serde_json::json!({ "source_id": tag.id, "target_id": tag.id });
+    let response = server.post("/api/tags/merge").json(&body).await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    let body = serde_json::json!({ "source_id": Uuid::new_v4(), "target_id": tag.id });
+    let response = server.post("/api/tags/merge").json(&body).await;
+    response.assert_status(StatusCode::NOT_FOUND);
+    let problem: ProblemDetails = response.json();
+    assert_eq!(problem.status, Some(404));
+
+    let tags: Vec<TagResponse> = server.get("/api/tags").await.json();
+    assert_eq!(tags.len(), 1);
+}
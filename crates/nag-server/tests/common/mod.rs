@@ -26,12 +26,44 @@ pub async fn create_test_app() -> TestServer {
     TestServer::new(app.into_make_service()).expect("Failed to create test server")
 }
 
+/// Create a test server and also return its database pool, for tests that
+/// need to seed state the API cannot create (e.g. notification deliveries).
+pub async fn create_test_app_with_pool() -> (TestServer, sqlx::SqlitePool) {
+    let pool = db::create_pool("sqlite::memory:")
+        .await
+        .expect("Failed to create test database pool");
+
+    let app = http::build_test_app(pool.clone());
+
+    let server = TestServer::new(app.into_make_service()).expect("Failed to create test server");
+    (server, pool)
+}
+
+/// Create a test server that handles every request as `user_id`, the way the
+/// auth guard does for an authenticated session.
+pub fn create_test_app_as_user(pool: sqlx::SqlitePool, user_id: uuid::Uuid) -> TestServer {
+    let app = http::build_test_app(pool).layer(axum::Extension(http::routes::auth::CurrentUser(
+        Some(user_id),
+    )));
+
+    TestServer::new(app.into_make_service()).expect("Failed to create test server")
+}
+
+/// Create a user directly in the database.
+pub async fn create_user(pool: &sqlx::SqlitePool, subject: &str) -> uuid::Uuid {
+    db::UserRepository::upsert(pool, "https://issuer.test", subject, None, None, None)
+        .await
+        .expect("create user")
+        .id
+}
+
 /// Response structure for tags (matches TagResponse from the API).
 #[derive(Debug, Deserialize)]
 pub struct TagResponse {
     pub id: uuid::Uuid,
     pub name: String,
     pub color: Option<String>,
+    pub muted: bool,
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
@@ -46,6 +78,22 @@ pub struct ChoreResponse {
     pub interval_days: Option<i32>,
     pub interval_time_hour: Option<i32>,
     pub interval_time_minute: Option<i32>,
+    pub days_of_week: Option<Vec<u8>>,
+    pub due_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub notify_lead_minutes: Option<i32>,
+    pub max_snoozes: Option<i32>,
+    pub estimated_minutes: Option<i32>,
+    pub review_interval_days: Option<i32>,
+    pub min_notify_interval_minutes: Option<i32>,
+    pub last_reviewed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub timezone: Option<String>,
+    pub recipient: Option<String>,
+    pub auto_complete_with: Option<uuid::Uuid>,
+    pub allowed_completion_sources: Option<Vec<String>>,
+    pub external_id: Option<String>,
+    pub priority: String,
+    pub anchor_mode: String,
+    pub is_active: bool,
     pub last_completed_at: Option<chrono::DateTime<chrono::Utc>>,
     pub tags: Vec<TagResponse>,
     pub created_at: chrono::DateTime<chrono::Utc>,
@@ -63,9 +111,21 @@ pub struct ChoreWithDueResponse {
     pub interval_days: Option<i32>,
     pub interval_time_hour: Option<i32>,
     pub interval_time_minute: Option<i32>,
+    pub days_of_week: Option<Vec<u8>>,
+    pub due_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub notify_lead_minutes: Option<i32>,
+    pub max_snoozes: Option<i32>,
+    pub estimated_minutes: Option<i32>,
+    pub timezone: Option<String>,
+    pub recipient: Option<String>,
+    pub auto_complete_with: Option<uuid::Uuid>,
+    pub priority: String,
+    pub anchor_mode: String,
     pub last_completed_at: Option<chrono::DateTime<chrono::Utc>>,
     pub next_due: Option<chrono::DateTime<chrono::Utc>>,
     pub is_overdue: bool,
+    pub current_streak: i64,
+    pub best_streak: i64,
     pub tags: Vec<TagResponse>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
@@ -78,14 +138,92 @@ pub struct CompletionResponse {
     pub chore_id: uuid::Uuid,
     pub completed_at: chrono::DateTime<chrono::Utc>,
     pub notes: Option<String>,
+    pub source: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub snoozed_until: Option<chrono::DateTime<chrono::Utc>>,
+    pub timing: Option<String>,
+    pub lateness_seconds: Option<i64>,
+}
+
+/// Response structure for notification deliveries.
+#[derive(Debug, Deserialize)]
+pub struct NotificationDeliveryResponse {
+    pub id: uuid::Uuid,
+    pub event_id: uuid::Uuid,
+    pub chore_id: uuid::Uuid,
+    pub event_type: String,
+    pub due_at: chrono::DateTime<chrono::Utc>,
+    pub title: String,
+    pub channel: String,
+    pub status: String,
+    pub attempt_count: i32,
+    pub last_error: Option<String>,
+    pub last_attempted_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub delivered_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Response structure for notification events.
+#[derive(Debug, Deserialize)]
+pub struct NotificationEventResponse {
+    pub id: uuid::Uuid,
+    pub chore_id: uuid::Uuid,
+    pub event_type: String,
+    pub due_at: chrono::DateTime<chrono::Utc>,
+    pub title: String,
+    pub body: String,
     pub created_at: chrono::DateTime<chrono::Utc>,
+    pub deliveries: Vec<EventDeliveryResponse>,
+}
+
+/// Response structure for the delivery summaries of a notification event.
+#[derive(Debug, Deserialize)]
+pub struct EventDeliveryResponse {
+    pub id: uuid::Uuid,
+    pub channel: String,
+    pub status: String,
+    pub attempt_count: i32,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Response structure for chore statistics.
+#[derive(Debug, Deserialize)]
+pub struct ChoreStatsResponse {
+    pub chore_id: uuid::Uuid,
+    pub total_completions: i64,
+    pub current_streak: i64,
+    pub longest_streak: i64,
+    pub completions_last_30_days: i64,
+    pub average_interval_seconds: Option<i64>,
+    pub longest_gap_seconds: Option<i64>,
+    pub on_time_completions: i64,
+    pub late_completions: i64,
+}
+
+/// Response structure for holidays (matches HolidayResponse from the API).
+#[derive(Debug, Deserialize)]
+pub struct HolidayResponse {
+    pub date: chrono::NaiveDate,
+    pub name: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Response structure for the completion heatmap.
+#[derive(Debug, Deserialize)]
+pub struct CompletionHeatmapResponse {
+    pub from: chrono::NaiveDate,
+    pub to: chrono::NaiveDate,
+    pub counts: std::collections::BTreeMap<chrono::NaiveDate, i64>,
 }
 
 /// Paginated response wrapper.
 #[derive(Debug, Deserialize)]
 pub struct PaginatedResponse<T> {
     pub items: Vec<T>,
-    pub next_cursor: Option<uuid::Uuid>,
+    pub next_cursor: Option<String>,
+    #[serde(default)]
+    pub total: Option<i64>,
 }
 
 /// RFC 7807 Problem Details response.
@@ -175,6 +313,27 @@ pub async fn complete_chore(
     response.json()
 }
 
+/// Helper to create a days_of_week chore.
+pub async fn create_days_of_week_chore(
+    server: &TestServer,
+    name: &str,
+    days: &[u8],
+    time_hour: Option<i32>,
+    time_minute: Option<i32>,
+) -> ChoreResponse {
+    let body = serde_json::json!({
+        "name": name,
+        "schedule_type": "days_of_week",
+        "days": days,
+        "time_hour": time_hour,
+        "time_minute": time_minute
+    });
+
+    let response = server.post("/api/chores").json(&body).await;
+    response.assert_status(StatusCode::CREATED);
+    response.json()
+}
+
 /// Helper to create a once_in_a_while chore.
 pub async fn create_once_in_a_while_chore(
     server: &TestServer,
@@ -192,6 +351,23 @@ pub async fn create_once_in_a_while_chore(
     response.json()
 }
 
+/// Helper to create a one_time chore due at `due_at`.
+pub async fn create_one_time_chore(
+    server: &TestServer,
+    name: &str,
+    due_at: chrono::DateTime<chrono::Utc>,
+) -> ChoreResponse {
+    let body = serde_json::json!({
+        "name": name,
+        "schedule_type": "one_time",
+        "due_at": due_at
+    });
+
+    let response = server.post("/api/chores").json(&body).await;
+    response.assert_status(StatusCode::CREATED);
+    response.json()
+}
+
 /// Helper to create a tag via the API.
 pub async fn create_tag(server: &TestServer, name: &str) -> TagResponse {
     let body = serde_json::json!({ "name": name });
@@ -230,3 +406,33 @@ pub async fn create_tag_with_color(
     response.assert_status(StatusCode::CREATED);
     response.json()
 }
+
+/// Helper to complete a chore at a specific timestamp via the API.
+pub async fn complete_chore_at(
+    server: &TestServer,
+    chore_id: uuid::Uuid,
+    completed_at: chrono::DateTime<chrono::Utc>,
+) -> CompletionResponse {
+    let body = serde_json::json!({
+        "completed_at": completed_at
+    });
+
+    let response = server
+        .post(&format!("/api/chores/{}/complete", chore_id))
+        .json(&body)
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    response.json()
+}
+
+/// Helper to create a holiday via the API.
+pub async fn create_holiday(
+    server: &TestServer,
+    date: chrono::NaiveDate,
+    name: Option<&str>,
+) -> HolidayResponse {
+    let body = serde_json::json!({ "date": date, "name": name });
+    let response = server.post("/api/holidays").json(&body).await;
+    response.assert_status(StatusCode::CREATED);
+    response.json()
+}
@@ -3,8 +3,8 @@ mod common;
 use chrono::Utc;
 use nag_server::db::{
     self, ChoreRepository, NotificationRepository,
-    chores::CreateChoreParams,
-    models::{NotificationChannel, ScheduleType},
+    chores::{CreateChoreParams, UpdateChoreParams},
+    models::{ChoreAnchorMode, ChorePriority, NotificationChannel, ScheduleType},
 };
 
 async fn create_chore(pool: &sqlx::SqlitePool, name: &str) -> uuid::Uuid {
@@ -18,6 +18,22 @@ async fn create_chore(pool: &sqlx::SqlitePool, name: &str) -> uuid::Uuid {
             interval_days: Some(1),
             interval_time_hour: Some(9),
             interval_time_minute: Some(0),
+            days_of_week: None,
+            due_at: None,
+            timezone: None,
+            notify_lead_minutes: None,
+            max_snoozes: None,
+            estimated_minutes: None,
+            review_interval_days: None,
+            min_notify_interval_minutes: None,
+            recipient: None,
+            auto_complete_with: None,
+            allowed_completion_sources: None,
+            external_id: None,
+            priority: ChorePriority::Normal,
+            anchor_mode: ChoreAnchorMode::FromCompletion,
+            user_id: None,
+            is_active: true,
         },
     )
     .await
@@ -82,6 +98,46 @@ async fn test_upsert_due_event_deduplicates_events_and_deliveries() {
     assert_eq!(deliveries_count, 1);
 }
 
+#[tokio::test]
+async fn test_upsert_due_event_enqueues_every_channel() {
+    let pool = db::create_pool("sqlite::memory:")
+        .await
+        .expect("create pool");
+    let chore_id = create_chore(&pool, "Vacuum").await;
+
+    NotificationRepository::upsert_due_event_with_deliveries(
+        &pool,
+        chore_id,
+        Utc::now(),
+        "Chore due: Vacuum",
+        "Vacuum is due",
+        &[
+            NotificationChannel::Telegram,
+            NotificationChannel::Discord,
+            NotificationChannel::Slack,
+        ],
+    )
+    .await
+    .expect("upsert");
+
+    let mut channels: Vec<NotificationChannel> =
+        NotificationRepository::list_pending(&pool, 50, 5, Utc::now())
+            .await
+            .expect("list pending")
+            .into_iter()
+            .map(|p| p.channel)
+            .collect();
+    channels.sort_by_key(|c| format!("{c:?}"));
+    assert_eq!(
+        channels,
+        vec![
+            NotificationChannel::Discord,
+            NotificationChannel::Slack,
+            NotificationChannel::Telegram,
+        ]
+    );
+}
+
 #[tokio::test]
 async fn test_list_pending_filters_delivered_and_exhausted_failures() {
     let pool = db::create_pool("sqlite::memory:")
@@ -141,19 +197,19 @@ async fn test_list_pending_filters_delivered_and_exhausted_failures() {
     let delivery_c = delivery_id_for_event(&pool, event_c).await;
     let delivery_d = delivery_id_for_event(&pool, event_d).await;
 
-    NotificationRepository::mark_failed(&pool, delivery_b, "temporary")
+    NotificationRepository::mark_failed(&pool, delivery_b, "temporary", 0)
         .await
         .expect("fail b once");
     NotificationRepository::mark_delivered(&pool, delivery_c)
         .await
         .expect("deliver c");
     for _ in 0..5 {
-        NotificationRepository::mark_failed(&pool, delivery_d, "retrying")
+        NotificationRepository::mark_failed(&pool, delivery_d, "retrying", 0)
             .await
             .expect("fail d");
     }
 
-    let pending = NotificationRepository::list_pending(&pool, 50, 5)
+    let pending = NotificationRepository::list_pending(&pool, 50, 5, Utc::now())
         .await
         .expect("list pending");
 
@@ -184,7 +240,7 @@ async fn test_mark_failed_and_mark_delivered_transition_delivery_state() {
 
     let delivery_id = delivery_id_for_event(&pool, event_id).await;
 
-    NotificationRepository::mark_failed(&pool, delivery_id, "network timeout")
+    NotificationRepository::mark_failed(&pool, delivery_id, "network timeout", 0)
         .await
         .expect("mark failed");
 
@@ -218,3 +274,79 @@ async fn test_mark_failed_and_mark_delivered_transition_delivery_state() {
     assert!(delivered_row.2.is_none());
     assert!(delivered_row.3.is_some());
 }
+
+#[tokio::test]
+async fn test_snooze_until_only_advances() {
+    let pool = db::create_pool("sqlite::memory:")
+        .await
+        .expect("create pool");
+    let chore_id = create_chore(&pool, "Mop").await;
+    let now = Utc::now();
+
+    assert!(
+        !NotificationRepository::is_snoozed(&pool, chore_id, now)
+            .await
+            .expect("check snooze")
+    );
+
+    let later = now + chrono::Duration::hours(2);
+    NotificationRepository::snooze_until(&pool, chore_id, later)
+        .await
+        .expect("snooze");
+
+    // A shorter snooze must not cut the existing one short
+    let sooner = now + chrono::Duration::hours(1);
+    let until = NotificationRepository::snooze_until(&pool, chore_id, sooner)
+        .await
+        .expect("snooze again");
+    assert_eq!(until, Some(later));
+
+    assert!(
+        NotificationRepository::is_snoozed(&pool, chore_id, now)
+            .await
+            .expect("check snooze")
+    );
+    assert!(
+        !NotificationRepository::is_snoozed(&pool, chore_id, later)
+            .await
+            .expect("check snooze")
+    );
+}
+
+#[tokio::test]
+async fn test_list_pending_includes_chore_recipient() {
+    let pool = db::create_pool("sqlite::memory:")
+        .await
+        .expect("create pool");
+    let chore_id = create_chore(&pool, "Trash").await;
+
+    ChoreRepository::update(
+        &pool,
+        chore_id,
+        UpdateChoreParams {
+            recipient: Some(Some("partner")),
+            ..Default::default()
+        },
+        None,
+    )
+    .await
+    .expect("set recipient");
+
+    NotificationRepository::upsert_due_event_with_deliveries(
+        &pool,
+        chore_id,
+        Utc::now(),
+        "Trash due",
+        "Trash is due",
+        &[NotificationChannel::Telegram],
+    )
+    .await
+    .expect("upsert event");
+
+    let pending = NotificationRepository::list_pending(&pool, 50, 5, Utc::now())
+        .await
+        .expect("list pending");
+
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].recipient.as_deref(), Some("partner"));
+}
@@ -3,7 +3,8 @@
  *
  * Colors are stored as key strings in the database (e.g. "sage", "terracotta").
  * CSS custom properties define the actual color values for light/dark mode.
- * When a tag has no explicit color (null), one is auto-assigned via name hash.
+ * When a tag has no explicit color, the server assigns one via name hash;
+ * the same hash is used here as a fallback.
  */
 
 export interface TagColor {
@@ -49,7 +50,7 @@ export function resolveTagColorKey(
   tagName: string,
 ): string {
   if (color && TAG_COLOR_MAP.has(color)) return color;
-  return TAG_COLORS[hashString(tagName) % TAG_COLORS.length].key;
+  return TAG_COLORS[hashString(tagName.toLowerCase()) % TAG_COLORS.length].key;
 }
 
 /**